@@ -0,0 +1,12 @@
+/// The way a CRC instance processes its input, which can be selected with the `with_backend` methods.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Backend {
+    /// Bit by bit. It is available for every algorithm, and it is the default one when the length of bits is not a multiple of 8.
+    Bitwise,
+    /// Byte by byte with a lookup table of 256 entries. It is available when the length of bits is a multiple of 8, and it is the default one in that case. A `CRCu128` instance never stores its own table, so it is only available for it when the table cache has a table for the algorithm or room for a new one.
+    Table,
+    /// Eight bytes at a time with eight lookup tables (slicing-by-8). Its tables are built at compile time, so it is only available for 32-bit and 64-bit algorithms with the expression `0x04C11DB7` (such as CRC-32 and CRC-32/BZIP2), the expression `0x1EDC6F41` (CRC-32C), or the expression `0x42F0E1EBA9EA3693` (such as CRC-64/XZ and CRC-64/ECMA-182). It is never the default one, and must be selected with `with_backend`.
+    Sliced,
+    /// With the SSE4.2 instructions of `x86` and `x86_64` CPUs. It is only available for CRC-32C, when the CPU supports them, and it is the default one in that case.
+    Hardware,
+}
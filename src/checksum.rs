@@ -0,0 +1,763 @@
+/*!
+One-shot functions which compute the CRC value of a buffer with a built-in algorithm, and [`CrcExt`], an extension trait of byte slices for the common ones.
+
+No CRC instance is created. The algorithms with a static lookup table use a [`Digest`], except that CRC-32/ISCSI uses the SSE4.2 instructions directly when they are available, and the others (whose length of bits is not a multiple of 8) use the bitwise `checksum` associated functions of the CRC structs.
+
+```rust
+use crc_any::checksum::{self, CrcExt};
+
+assert_eq!(0x4B37, checksum::crc16_modbus(b"123456789"));
+assert_eq!(0xCBF43926, checksum::crc32_iso_hdlc(b"123456789"));
+
+assert_eq!(0xCBF43926, b"123456789".crc32());
+```
+*/
+
+use crate::{
+    CRCu8, CRCu16, CRCu32, CRCu128,
+    crc_u32::crc32c_hardware_update,
+    params::*,
+    preset::{self, Digest, Preset},
+};
+
+/// An extension trait of byte slices for computing the CRC values of some common algorithms in one shot.
+pub trait CrcExt {
+    /// Compute the CRC value with the preset `P`.
+    fn crc<P: Preset>(&self) -> P::Register;
+
+    /// Compute the `CRC-8/SMBUS` value, the same algorithm as [`CRC::crc8`](crate::CRC::crc8).
+    fn crc8(&self) -> u8;
+
+    /// Compute the `CRC-16/ARC` value, the same algorithm as [`CRC::crc16`](crate::CRC::crc16).
+    fn crc16(&self) -> u16;
+
+    /// Compute the `CRC-32/ISO-HDLC` value, the same algorithm as [`CRC::crc32`](crate::CRC::crc32).
+    fn crc32(&self) -> u32;
+
+    /// Compute the `CRC-32/ISCSI` value, the same algorithm as [`CRC::crc32c`](crate::CRC::crc32c).
+    fn crc32c(&self) -> u32;
+
+    /// Compute the `CRC-64/ECMA-182` value, the same algorithm as [`CRC::crc64`](crate::CRC::crc64).
+    fn crc64(&self) -> u64;
+}
+
+impl CrcExt for [u8] {
+    #[inline]
+    fn crc<P: Preset>(&self) -> P::Register {
+        Digest::<P>::checksum(self)
+    }
+
+    #[inline]
+    fn crc8(&self) -> u8 {
+        crc8_smbus(self)
+    }
+
+    #[inline]
+    fn crc16(&self) -> u16 {
+        crc16_arc(self)
+    }
+
+    #[inline]
+    fn crc32(&self) -> u32 {
+        crc32_iso_hdlc(self)
+    }
+
+    #[inline]
+    fn crc32c(&self) -> u32 {
+        crc32_iscsi(self)
+    }
+
+    #[inline]
+    fn crc64(&self) -> u64 {
+        crc64_ecma_182(self)
+    }
+}
+
+/// Compute the `CRC-3/GSM` value of `data` in one shot, the same algorithm as [`CRC::crc3gsm`](crate::CRC::crc3gsm).
+#[inline]
+pub fn crc3_gsm(data: &[u8]) -> u8 {
+    CRCu8::checksum(&CRC_3_GSM, data)
+}
+
+/// Compute the `CRC-3/ROHC` value of `data` in one shot, the same algorithm as [`CRC::crc3rohc`](crate::CRC::crc3rohc).
+#[inline]
+pub fn crc3_rohc(data: &[u8]) -> u8 {
+    CRCu8::checksum(&CRC_3_ROHC, data)
+}
+
+/// Compute the `CRC-4/G-704` value of `data` in one shot, the same algorithm as [`CRC::crc4itu`](crate::CRC::crc4itu).
+#[inline]
+pub fn crc4_g_704(data: &[u8]) -> u8 {
+    CRCu8::checksum(&CRC_4_G_704, data)
+}
+
+/// Compute the `CRC-4/INTERLAKEN` value of `data` in one shot, the same algorithm as [`CRC::crc4interlaken`](crate::CRC::crc4interlaken).
+#[inline]
+pub fn crc4_interlaken(data: &[u8]) -> u8 {
+    CRCu8::checksum(&CRC_4_INTERLAKEN, data)
+}
+
+/// Compute the `CRC-5/EPC-C1G2` value of `data` in one shot, the same algorithm as [`CRC::crc5epc`](crate::CRC::crc5epc).
+#[inline]
+pub fn crc5_epc_c1g2(data: &[u8]) -> u8 {
+    CRCu8::checksum(&CRC_5_EPC_C1G2, data)
+}
+
+/// Compute the `CRC-5/G-704` value of `data` in one shot, the same algorithm as [`CRC::crc5itu`](crate::CRC::crc5itu).
+#[inline]
+pub fn crc5_g_704(data: &[u8]) -> u8 {
+    CRCu8::checksum(&CRC_5_G_704, data)
+}
+
+/// Compute the `CRC-5/USB` value of `data` in one shot, the same algorithm as [`CRC::crc5usb`](crate::CRC::crc5usb).
+#[inline]
+pub fn crc5_usb(data: &[u8]) -> u8 {
+    CRCu8::checksum(&CRC_5_USB, data)
+}
+
+/// Compute the `CRC-6/CDMA2000-A` value of `data` in one shot, the same algorithm as [`CRC::crc6cdma2000_a`](crate::CRC::crc6cdma2000_a).
+#[inline]
+pub fn crc6_cdma2000_a(data: &[u8]) -> u8 {
+    CRCu8::checksum(&CRC_6_CDMA2000_A, data)
+}
+
+/// Compute the `CRC-6/CDMA2000-B` value of `data` in one shot, the same algorithm as [`CRC::crc6cdma2000_b`](crate::CRC::crc6cdma2000_b).
+#[inline]
+pub fn crc6_cdma2000_b(data: &[u8]) -> u8 {
+    CRCu8::checksum(&CRC_6_CDMA2000_B, data)
+}
+
+/// Compute the `CRC-6/DARC` value of `data` in one shot, the same algorithm as [`CRC::crc6darc`](crate::CRC::crc6darc).
+#[inline]
+pub fn crc6_darc(data: &[u8]) -> u8 {
+    CRCu8::checksum(&CRC_6_DARC, data)
+}
+
+/// Compute the `CRC-6/G-704` value of `data` in one shot, the same algorithm as [`CRC::crc6itu`](crate::CRC::crc6itu).
+#[inline]
+pub fn crc6_g_704(data: &[u8]) -> u8 {
+    CRCu8::checksum(&CRC_6_G_704, data)
+}
+
+/// Compute the `CRC-6/GSM` value of `data` in one shot, the same algorithm as [`CRC::crc6gsm`](crate::CRC::crc6gsm).
+#[inline]
+pub fn crc6_gsm(data: &[u8]) -> u8 {
+    CRCu8::checksum(&CRC_6_GSM, data)
+}
+
+/// Compute the `CRC-7/MMC` value of `data` in one shot, the same algorithm as [`CRC::crc7`](crate::CRC::crc7).
+#[inline]
+pub fn crc7_mmc(data: &[u8]) -> u8 {
+    CRCu8::checksum(&CRC_7_MMC, data)
+}
+
+/// Compute the `CRC-7/ROHC` value of `data` in one shot, the same algorithm as [`CRC::crc7rohc`](crate::CRC::crc7rohc).
+#[inline]
+pub fn crc7_rohc(data: &[u8]) -> u8 {
+    CRCu8::checksum(&CRC_7_ROHC, data)
+}
+
+/// Compute the `CRC-7/UMTS` value of `data` in one shot, the same algorithm as [`CRC::crc7umts`](crate::CRC::crc7umts).
+#[inline]
+pub fn crc7_umts(data: &[u8]) -> u8 {
+    CRCu8::checksum(&CRC_7_UMTS, data)
+}
+
+/// Compute the `CRC-8/AUTOSAR` value of `data` in one shot, the same algorithm as [`CRC::crc8autosar`](crate::CRC::crc8autosar).
+#[inline]
+pub fn crc8_autosar(data: &[u8]) -> u8 {
+    Digest::<preset::Crc8Autosar>::checksum(data)
+}
+
+/// Compute the `CRC-8/BLUETOOTH` value of `data` in one shot, the same algorithm as [`CRC::crc8bluetooth`](crate::CRC::crc8bluetooth).
+#[inline]
+pub fn crc8_bluetooth(data: &[u8]) -> u8 {
+    Digest::<preset::Crc8Bluetooth>::checksum(data)
+}
+
+/// Compute the `CRC-8/CDMA2000` value of `data` in one shot, the same algorithm as [`CRC::crc8cdma2000`](crate::CRC::crc8cdma2000).
+#[inline]
+pub fn crc8_cdma2000(data: &[u8]) -> u8 {
+    Digest::<preset::Crc8Cdma2000>::checksum(data)
+}
+
+/// Compute the `CRC-8/DARC` value of `data` in one shot, the same algorithm as [`CRC::crc8darc`](crate::CRC::crc8darc).
+#[inline]
+pub fn crc8_darc(data: &[u8]) -> u8 {
+    Digest::<preset::Crc8Darc>::checksum(data)
+}
+
+/// Compute the `CRC-8/DVB-S2` value of `data` in one shot, the same algorithm as [`CRC::crc8dvb_s2`](crate::CRC::crc8dvb_s2).
+#[inline]
+pub fn crc8_dvb_s2(data: &[u8]) -> u8 {
+    Digest::<preset::Crc8DvbS2>::checksum(data)
+}
+
+/// Compute the `CRC-8/GSM-A` value of `data` in one shot, the same algorithm as [`CRC::crc8gsm_a`](crate::CRC::crc8gsm_a).
+#[inline]
+pub fn crc8_gsm_a(data: &[u8]) -> u8 {
+    Digest::<preset::Crc8GsmA>::checksum(data)
+}
+
+/// Compute the `CRC-8/GSM-B` value of `data` in one shot, the same algorithm as [`CRC::crc8gsm_b`](crate::CRC::crc8gsm_b).
+#[inline]
+pub fn crc8_gsm_b(data: &[u8]) -> u8 {
+    Digest::<preset::Crc8GsmB>::checksum(data)
+}
+
+/// Compute the `CRC-8/HITAG` value of `data` in one shot, the same algorithm as [`CRC::crc8hitag`](crate::CRC::crc8hitag).
+#[inline]
+pub fn crc8_hitag(data: &[u8]) -> u8 {
+    Digest::<preset::Crc8Hitag>::checksum(data)
+}
+
+/// Compute the `CRC-8/I-432-1` value of `data` in one shot, the same algorithm as [`CRC::crc8itu`](crate::CRC::crc8itu).
+#[inline]
+pub fn crc8_i_432_1(data: &[u8]) -> u8 {
+    Digest::<preset::Crc8I4321>::checksum(data)
+}
+
+/// Compute the `CRC-8/I-CODE` value of `data` in one shot, the same algorithm as [`CRC::crc8icode`](crate::CRC::crc8icode).
+#[inline]
+pub fn crc8_i_code(data: &[u8]) -> u8 {
+    Digest::<preset::Crc8ICode>::checksum(data)
+}
+
+/// Compute the `CRC-8/LTE` value of `data` in one shot, the same algorithm as [`CRC::crc8lte`](crate::CRC::crc8lte).
+#[inline]
+pub fn crc8_lte(data: &[u8]) -> u8 {
+    Digest::<preset::Crc8Lte>::checksum(data)
+}
+
+/// Compute the `CRC-8/MAXIM-DOW` value of `data` in one shot, the same algorithm as [`CRC::crc8maxim`](crate::CRC::crc8maxim).
+#[inline]
+pub fn crc8_maxim_dow(data: &[u8]) -> u8 {
+    Digest::<preset::Crc8MaximDow>::checksum(data)
+}
+
+/// Compute the `CRC-8/MIFARE-MAD` value of `data` in one shot, the same algorithm as [`CRC::crc8mifare_mad`](crate::CRC::crc8mifare_mad).
+#[inline]
+pub fn crc8_mifare_mad(data: &[u8]) -> u8 {
+    Digest::<preset::Crc8MifareMad>::checksum(data)
+}
+
+/// Compute the `CRC-8/NRSC-5` value of `data` in one shot, the same algorithm as [`CRC::crc8nrsc5`](crate::CRC::crc8nrsc5).
+#[inline]
+pub fn crc8_nrsc_5(data: &[u8]) -> u8 {
+    Digest::<preset::Crc8Nrsc5>::checksum(data)
+}
+
+/// Compute the `CRC-8/OPENSAFETY` value of `data` in one shot, the same algorithm as [`CRC::crc8opensafety`](crate::CRC::crc8opensafety).
+#[inline]
+pub fn crc8_opensafety(data: &[u8]) -> u8 {
+    Digest::<preset::Crc8Opensafety>::checksum(data)
+}
+
+/// Compute the `CRC-8/ROHC` value of `data` in one shot, the same algorithm as [`CRC::crc8rohc`](crate::CRC::crc8rohc).
+#[inline]
+pub fn crc8_rohc(data: &[u8]) -> u8 {
+    Digest::<preset::Crc8Rohc>::checksum(data)
+}
+
+/// Compute the `CRC-8/SAE-J1850` value of `data` in one shot, the same algorithm as [`CRC::crc8sae_j1850`](crate::CRC::crc8sae_j1850).
+#[inline]
+pub fn crc8_sae_j1850(data: &[u8]) -> u8 {
+    Digest::<preset::Crc8SaeJ1850>::checksum(data)
+}
+
+/// Compute the `CRC-8/SMBUS` value of `data` in one shot, the same algorithm as [`CRC::crc8`](crate::CRC::crc8).
+#[inline]
+pub fn crc8_smbus(data: &[u8]) -> u8 {
+    Digest::<preset::Crc8Smbus>::checksum(data)
+}
+
+/// Compute the `CRC-8/TECH-3250` value of `data` in one shot, the same algorithm as [`CRC::crc8ebu`](crate::CRC::crc8ebu).
+#[inline]
+pub fn crc8_tech_3250(data: &[u8]) -> u8 {
+    Digest::<preset::Crc8Tech3250>::checksum(data)
+}
+
+/// Compute the `CRC-8/WCDMA` value of `data` in one shot, the same algorithm as [`CRC::crc8wcdma`](crate::CRC::crc8wcdma).
+#[inline]
+pub fn crc8_wcdma(data: &[u8]) -> u8 {
+    Digest::<preset::Crc8Wcdma>::checksum(data)
+}
+
+/// Compute the `CRC-10/ATM` value of `data` in one shot, the same algorithm as [`CRC::crc10`](crate::CRC::crc10).
+#[inline]
+pub fn crc10_atm(data: &[u8]) -> u16 {
+    CRCu16::checksum(&CRC_10_ATM, data)
+}
+
+/// Compute the `CRC-10/CDMA2000` value of `data` in one shot, the same algorithm as [`CRC::crc10cdma2000`](crate::CRC::crc10cdma2000).
+#[inline]
+pub fn crc10_cdma2000(data: &[u8]) -> u16 {
+    CRCu16::checksum(&CRC_10_CDMA2000, data)
+}
+
+/// Compute the `CRC-10/GSM` value of `data` in one shot, the same algorithm as [`CRC::crc10gsm`](crate::CRC::crc10gsm).
+#[inline]
+pub fn crc10_gsm(data: &[u8]) -> u16 {
+    CRCu16::checksum(&CRC_10_GSM, data)
+}
+
+/// Compute the `CRC-11/FLEXRAY` value of `data` in one shot, the same algorithm as [`CRC::crc11`](crate::CRC::crc11).
+#[inline]
+pub fn crc11_flexray(data: &[u8]) -> u16 {
+    CRCu16::checksum(&CRC_11_FLEXRAY, data)
+}
+
+/// Compute the `CRC-11/UMTS` value of `data` in one shot, the same algorithm as [`CRC::crc11umts`](crate::CRC::crc11umts).
+#[inline]
+pub fn crc11_umts(data: &[u8]) -> u16 {
+    CRCu16::checksum(&CRC_11_UMTS, data)
+}
+
+/// Compute the `CRC-12/CDMA2000` value of `data` in one shot, the same algorithm as [`CRC::crc12cdma2000`](crate::CRC::crc12cdma2000).
+#[inline]
+pub fn crc12_cdma2000(data: &[u8]) -> u16 {
+    CRCu16::checksum(&CRC_12_CDMA2000, data)
+}
+
+/// Compute the `CRC-12/DECT` value of `data` in one shot, the same algorithm as [`CRC::crc12`](crate::CRC::crc12).
+#[inline]
+pub fn crc12_dect(data: &[u8]) -> u16 {
+    CRCu16::checksum(&CRC_12_DECT, data)
+}
+
+/// Compute the `CRC-12/GSM` value of `data` in one shot, the same algorithm as [`CRC::crc12gsm`](crate::CRC::crc12gsm).
+#[inline]
+pub fn crc12_gsm(data: &[u8]) -> u16 {
+    CRCu16::checksum(&CRC_12_GSM, data)
+}
+
+/// Compute the `CRC-12/UMTS` value of `data` in one shot, the same algorithm as [`CRC::crc12umts`](crate::CRC::crc12umts).
+#[inline]
+pub fn crc12_umts(data: &[u8]) -> u16 {
+    CRCu16::checksum(&CRC_12_UMTS, data)
+}
+
+/// Compute the `CRC-13/BBC` value of `data` in one shot, the same algorithm as [`CRC::crc13bbc`](crate::CRC::crc13bbc).
+#[inline]
+pub fn crc13_bbc(data: &[u8]) -> u16 {
+    CRCu16::checksum(&CRC_13_BBC, data)
+}
+
+/// Compute the `CRC-14/DARC` value of `data` in one shot, the same algorithm as [`CRC::crc14darc`](crate::CRC::crc14darc).
+#[inline]
+pub fn crc14_darc(data: &[u8]) -> u16 {
+    CRCu16::checksum(&CRC_14_DARC, data)
+}
+
+/// Compute the `CRC-14/GSM` value of `data` in one shot, the same algorithm as [`CRC::crc14gsm`](crate::CRC::crc14gsm).
+#[inline]
+pub fn crc14_gsm(data: &[u8]) -> u16 {
+    CRCu16::checksum(&CRC_14_GSM, data)
+}
+
+/// Compute the `CRC-15/CAN` value of `data` in one shot, the same algorithm as [`CRC::crc15can`](crate::CRC::crc15can).
+#[inline]
+pub fn crc15_can(data: &[u8]) -> u16 {
+    CRCu16::checksum(&CRC_15_CAN, data)
+}
+
+/// Compute the `CRC-15/MPT1327` value of `data` in one shot, the same algorithm as [`CRC::crc15mpt1327`](crate::CRC::crc15mpt1327).
+#[inline]
+pub fn crc15_mpt1327(data: &[u8]) -> u16 {
+    CRCu16::checksum(&CRC_15_MPT1327, data)
+}
+
+/// Compute the `CRC-16/ARC` value of `data` in one shot, the same algorithm as [`CRC::crc16`](crate::CRC::crc16).
+#[inline]
+pub fn crc16_arc(data: &[u8]) -> u16 {
+    Digest::<preset::Crc16Arc>::checksum(data)
+}
+
+/// Compute the `CRC-16/CDMA2000` value of `data` in one shot, the same algorithm as [`CRC::crc16cdma2000`](crate::CRC::crc16cdma2000).
+#[inline]
+pub fn crc16_cdma2000(data: &[u8]) -> u16 {
+    Digest::<preset::Crc16Cdma2000>::checksum(data)
+}
+
+/// Compute the `CRC-16/CMS` value of `data` in one shot, the same algorithm as [`CRC::crc16cms`](crate::CRC::crc16cms).
+#[inline]
+pub fn crc16_cms(data: &[u8]) -> u16 {
+    Digest::<preset::Crc16Cms>::checksum(data)
+}
+
+/// Compute the `CRC-16/DDS-110` value of `data` in one shot, the same algorithm as [`CRC::crc16dds_110`](crate::CRC::crc16dds_110).
+#[inline]
+pub fn crc16_dds_110(data: &[u8]) -> u16 {
+    Digest::<preset::Crc16Dds110>::checksum(data)
+}
+
+/// Compute the `CRC-16/DECT-R` value of `data` in one shot, the same algorithm as [`CRC::crc16dect_r`](crate::CRC::crc16dect_r).
+#[inline]
+pub fn crc16_dect_r(data: &[u8]) -> u16 {
+    Digest::<preset::Crc16DectR>::checksum(data)
+}
+
+/// Compute the `CRC-16/DECT-X` value of `data` in one shot, the same algorithm as [`CRC::crc16dect_x`](crate::CRC::crc16dect_x).
+#[inline]
+pub fn crc16_dect_x(data: &[u8]) -> u16 {
+    Digest::<preset::Crc16DectX>::checksum(data)
+}
+
+/// Compute the `CRC-16/DNP` value of `data` in one shot, the same algorithm as [`CRC::crc16dnp`](crate::CRC::crc16dnp).
+#[inline]
+pub fn crc16_dnp(data: &[u8]) -> u16 {
+    Digest::<preset::Crc16Dnp>::checksum(data)
+}
+
+/// Compute the `CRC-16/EN-13757` value of `data` in one shot, the same algorithm as [`CRC::crc16en_13757`](crate::CRC::crc16en_13757).
+#[inline]
+pub fn crc16_en_13757(data: &[u8]) -> u16 {
+    Digest::<preset::Crc16En13757>::checksum(data)
+}
+
+/// Compute the `CRC-16/GENIBUS` value of `data` in one shot, the same algorithm as [`CRC::crc16genibus`](crate::CRC::crc16genibus).
+#[inline]
+pub fn crc16_genibus(data: &[u8]) -> u16 {
+    Digest::<preset::Crc16Genibus>::checksum(data)
+}
+
+/// Compute the `CRC-16/GSM` value of `data` in one shot, the same algorithm as [`CRC::crc16gsm`](crate::CRC::crc16gsm).
+#[inline]
+pub fn crc16_gsm(data: &[u8]) -> u16 {
+    Digest::<preset::Crc16Gsm>::checksum(data)
+}
+
+/// Compute the `CRC-16/IBM-3740` value of `data` in one shot, the same algorithm as [`CRC::crc16ccitt_false`](crate::CRC::crc16ccitt_false).
+#[inline]
+pub fn crc16_ibm_3740(data: &[u8]) -> u16 {
+    Digest::<preset::Crc16Ibm3740>::checksum(data)
+}
+
+/// Compute the `CRC-16/IBM-SDLC` value of `data` in one shot, the same algorithm as [`CRC::crc16_x25`](crate::CRC::crc16_x25).
+#[inline]
+pub fn crc16_ibm_sdlc(data: &[u8]) -> u16 {
+    Digest::<preset::Crc16IbmSdlc>::checksum(data)
+}
+
+/// Compute the `CRC-16/ISO-IEC-14443-3-A` value of `data` in one shot, the same algorithm as [`CRC::crc_a`](crate::CRC::crc_a).
+#[inline]
+pub fn crc16_iso_iec_14443_3_a(data: &[u8]) -> u16 {
+    Digest::<preset::Crc16IsoIec144433A>::checksum(data)
+}
+
+/// Compute the `CRC-16/KERMIT` value of `data` in one shot, the same algorithm as [`CRC::crc16kermit`](crate::CRC::crc16kermit).
+#[inline]
+pub fn crc16_kermit(data: &[u8]) -> u16 {
+    Digest::<preset::Crc16Kermit>::checksum(data)
+}
+
+/// Compute the `CRC-16/LJ1200` value of `data` in one shot, the same algorithm as [`CRC::crc16lj1200`](crate::CRC::crc16lj1200).
+#[inline]
+pub fn crc16_lj1200(data: &[u8]) -> u16 {
+    Digest::<preset::Crc16Lj1200>::checksum(data)
+}
+
+/// Compute the `CRC-16/M17` value of `data` in one shot, the same algorithm as [`CRC::crc16m17`](crate::CRC::crc16m17).
+#[inline]
+pub fn crc16_m17(data: &[u8]) -> u16 {
+    Digest::<preset::Crc16M17>::checksum(data)
+}
+
+/// Compute the `CRC-16/MAXIM-DOW` value of `data` in one shot, the same algorithm as [`CRC::crc16maxim`](crate::CRC::crc16maxim).
+#[inline]
+pub fn crc16_maxim_dow(data: &[u8]) -> u16 {
+    Digest::<preset::Crc16MaximDow>::checksum(data)
+}
+
+/// Compute the `CRC-16/MCRF4XX` value of `data` in one shot, the same algorithm as [`CRC::crc16mcrf4cc`](crate::CRC::crc16mcrf4cc).
+#[inline]
+pub fn crc16_mcrf4xx(data: &[u8]) -> u16 {
+    Digest::<preset::Crc16Mcrf4xx>::checksum(data)
+}
+
+/// Compute the `CRC-16/MODBUS` value of `data` in one shot, the same algorithm as [`CRC::crc16modbus`](crate::CRC::crc16modbus).
+#[inline]
+pub fn crc16_modbus(data: &[u8]) -> u16 {
+    Digest::<preset::Crc16Modbus>::checksum(data)
+}
+
+/// Compute the `CRC-16/NRSC-5` value of `data` in one shot, the same algorithm as [`CRC::crc16nrsc5`](crate::CRC::crc16nrsc5).
+#[inline]
+pub fn crc16_nrsc_5(data: &[u8]) -> u16 {
+    Digest::<preset::Crc16Nrsc5>::checksum(data)
+}
+
+/// Compute the `CRC-16/OPENSAFETY-A` value of `data` in one shot, the same algorithm as [`CRC::crc16opensafety_a`](crate::CRC::crc16opensafety_a).
+#[inline]
+pub fn crc16_opensafety_a(data: &[u8]) -> u16 {
+    Digest::<preset::Crc16OpensafetyA>::checksum(data)
+}
+
+/// Compute the `CRC-16/OPENSAFETY-B` value of `data` in one shot, the same algorithm as [`CRC::crc16opensafety_b`](crate::CRC::crc16opensafety_b).
+#[inline]
+pub fn crc16_opensafety_b(data: &[u8]) -> u16 {
+    Digest::<preset::Crc16OpensafetyB>::checksum(data)
+}
+
+/// Compute the `CRC-16/PROFIBUS` value of `data` in one shot, the same algorithm as [`CRC::crc16profibus`](crate::CRC::crc16profibus).
+#[inline]
+pub fn crc16_profibus(data: &[u8]) -> u16 {
+    Digest::<preset::Crc16Profibus>::checksum(data)
+}
+
+/// Compute the `CRC-16/RIELLO` value of `data` in one shot, the same algorithm as [`CRC::crc16riello`](crate::CRC::crc16riello).
+#[inline]
+pub fn crc16_riello(data: &[u8]) -> u16 {
+    Digest::<preset::Crc16Riello>::checksum(data)
+}
+
+/// Compute the `CRC-16/SPI-FUJITSU` value of `data` in one shot, the same algorithm as [`CRC::crc16aug_ccitt`](crate::CRC::crc16aug_ccitt).
+#[inline]
+pub fn crc16_spi_fujitsu(data: &[u8]) -> u16 {
+    Digest::<preset::Crc16SpiFujitsu>::checksum(data)
+}
+
+/// Compute the `CRC-16/T10-DIF` value of `data` in one shot, the same algorithm as [`CRC::crc16t10_dif`](crate::CRC::crc16t10_dif).
+#[inline]
+pub fn crc16_t10_dif(data: &[u8]) -> u16 {
+    Digest::<preset::Crc16T10Dif>::checksum(data)
+}
+
+/// Compute the `CRC-16/TELEDISK` value of `data` in one shot, the same algorithm as [`CRC::crc16teledisk`](crate::CRC::crc16teledisk).
+#[inline]
+pub fn crc16_teledisk(data: &[u8]) -> u16 {
+    Digest::<preset::Crc16Teledisk>::checksum(data)
+}
+
+/// Compute the `CRC-16/TMS37157` value of `data` in one shot, the same algorithm as [`CRC::crc16tms13157`](crate::CRC::crc16tms13157).
+#[inline]
+pub fn crc16_tms37157(data: &[u8]) -> u16 {
+    Digest::<preset::Crc16Tms37157>::checksum(data)
+}
+
+/// Compute the `CRC-16/UMTS` value of `data` in one shot, the same algorithm as [`CRC::crc16buypass`](crate::CRC::crc16buypass).
+#[inline]
+pub fn crc16_umts(data: &[u8]) -> u16 {
+    Digest::<preset::Crc16Umts>::checksum(data)
+}
+
+/// Compute the `CRC-16/USB` value of `data` in one shot, the same algorithm as [`CRC::crc16usb`](crate::CRC::crc16usb).
+#[inline]
+pub fn crc16_usb(data: &[u8]) -> u16 {
+    Digest::<preset::Crc16Usb>::checksum(data)
+}
+
+/// Compute the `CRC-16/XMODEM` value of `data` in one shot, the same algorithm as [`CRC::crc16xmodem`](crate::CRC::crc16xmodem).
+#[inline]
+pub fn crc16_xmodem(data: &[u8]) -> u16 {
+    Digest::<preset::Crc16Xmodem>::checksum(data)
+}
+
+/// Compute the `CRC-17/CAN-FD` value of `data` in one shot, the same algorithm as [`CRC::crc17can`](crate::CRC::crc17can).
+#[inline]
+pub fn crc17_can_fd(data: &[u8]) -> u32 {
+    CRCu32::checksum(&CRC_17_CAN_FD, data)
+}
+
+/// Compute the `CRC-21/CAN-FD` value of `data` in one shot, the same algorithm as [`CRC::crc21can`](crate::CRC::crc21can).
+#[inline]
+pub fn crc21_can_fd(data: &[u8]) -> u32 {
+    CRCu32::checksum(&CRC_21_CAN_FD, data)
+}
+
+/// Compute the `CRC-24/BLE` value of `data` in one shot, the same algorithm as [`CRC::crc24ble`](crate::CRC::crc24ble).
+#[inline]
+pub fn crc24_ble(data: &[u8]) -> u32 {
+    Digest::<preset::Crc24Ble>::checksum(data)
+}
+
+/// Compute the `CRC-24/FLEXRAY-A` value of `data` in one shot, the same algorithm as [`CRC::crc24flexray_a`](crate::CRC::crc24flexray_a).
+#[inline]
+pub fn crc24_flexray_a(data: &[u8]) -> u32 {
+    Digest::<preset::Crc24FlexrayA>::checksum(data)
+}
+
+/// Compute the `CRC-24/FLEXRAY-B` value of `data` in one shot, the same algorithm as [`CRC::crc24flexray_b`](crate::CRC::crc24flexray_b).
+#[inline]
+pub fn crc24_flexray_b(data: &[u8]) -> u32 {
+    Digest::<preset::Crc24FlexrayB>::checksum(data)
+}
+
+/// Compute the `CRC-24/INTERLAKEN` value of `data` in one shot, the same algorithm as [`CRC::crc24interlaken`](crate::CRC::crc24interlaken).
+#[inline]
+pub fn crc24_interlaken(data: &[u8]) -> u32 {
+    Digest::<preset::Crc24Interlaken>::checksum(data)
+}
+
+/// Compute the `CRC-24/LTE-A` value of `data` in one shot, the same algorithm as [`CRC::crc24lte_a`](crate::CRC::crc24lte_a).
+#[inline]
+pub fn crc24_lte_a(data: &[u8]) -> u32 {
+    Digest::<preset::Crc24LteA>::checksum(data)
+}
+
+/// Compute the `CRC-24/LTE-B` value of `data` in one shot, the same algorithm as [`CRC::crc24lte_b`](crate::CRC::crc24lte_b).
+#[inline]
+pub fn crc24_lte_b(data: &[u8]) -> u32 {
+    Digest::<preset::Crc24LteB>::checksum(data)
+}
+
+/// Compute the `CRC-24/OPENPGP` value of `data` in one shot, the same algorithm as [`CRC::crc24`](crate::CRC::crc24).
+#[inline]
+pub fn crc24_openpgp(data: &[u8]) -> u32 {
+    Digest::<preset::Crc24Openpgp>::checksum(data)
+}
+
+/// Compute the `CRC-24/OS-9` value of `data` in one shot, the same algorithm as [`CRC::crc24os9`](crate::CRC::crc24os9).
+#[inline]
+pub fn crc24_os_9(data: &[u8]) -> u32 {
+    Digest::<preset::Crc24Os9>::checksum(data)
+}
+
+/// Compute the `CRC-30/CDMA` value of `data` in one shot, the same algorithm as [`CRC::crc30cdma`](crate::CRC::crc30cdma).
+#[inline]
+pub fn crc30_cdma(data: &[u8]) -> u32 {
+    CRCu32::checksum(&CRC_30_CDMA, data)
+}
+
+/// Compute the `CRC-31/PHILIPS` value of `data` in one shot, the same algorithm as [`CRC::crc31philips`](crate::CRC::crc31philips).
+#[inline]
+pub fn crc31_philips(data: &[u8]) -> u32 {
+    CRCu32::checksum(&CRC_31_PHILIPS, data)
+}
+
+/// Compute the `CRC-32/AIXM` value of `data` in one shot, the same algorithm as [`CRC::crc32q`](crate::CRC::crc32q).
+#[inline]
+pub fn crc32_aixm(data: &[u8]) -> u32 {
+    Digest::<preset::Crc32Aixm>::checksum(data)
+}
+
+/// Compute the `CRC-32/AUTOSAR` value of `data` in one shot, the same algorithm as [`CRC::crc32autosar`](crate::CRC::crc32autosar).
+#[inline]
+pub fn crc32_autosar(data: &[u8]) -> u32 {
+    Digest::<preset::Crc32Autosar>::checksum(data)
+}
+
+/// Compute the `CRC-32/BASE91-D` value of `data` in one shot, the same algorithm as [`CRC::crc32d`](crate::CRC::crc32d).
+#[inline]
+pub fn crc32_base91_d(data: &[u8]) -> u32 {
+    Digest::<preset::Crc32Base91D>::checksum(data)
+}
+
+/// Compute the `CRC-32/BZIP2` value of `data` in one shot, the same algorithm as [`CRC::crc32bzip2`](crate::CRC::crc32bzip2).
+#[inline]
+pub fn crc32_bzip2(data: &[u8]) -> u32 {
+    Digest::<preset::Crc32Bzip2>::checksum(data)
+}
+
+/// Compute the `crc32` value of the `mhash` library of `data` in one shot, the same algorithm as [`CRC::crc32mhash`](crate::CRC::crc32mhash).
+#[inline]
+pub fn crc32_mhash(data: &[u8]) -> u32 {
+    crc32_bzip2(data).swap_bytes()
+}
+
+/// Compute the `CRC-32/CD-ROM-EDC` value of `data` in one shot, the same algorithm as [`CRC::crc32cdrom_edc`](crate::CRC::crc32cdrom_edc).
+#[inline]
+pub fn crc32_cd_rom_edc(data: &[u8]) -> u32 {
+    Digest::<preset::Crc32CdRomEdc>::checksum(data)
+}
+
+/// Compute the `CRC-32/CKSUM` value of `data` in one shot, the same algorithm as [`CRC::crc32posix`](crate::CRC::crc32posix).
+#[inline]
+pub fn crc32_cksum(data: &[u8]) -> u32 {
+    Digest::<preset::Crc32Cksum>::checksum(data)
+}
+
+/// Compute the `CRC-32/ISCSI` value of `data` in one shot, the same algorithm as [`CRC::crc32c`](crate::CRC::crc32c).
+///
+/// This uses the SSE4.2 instructions when they are available, like [`CRCu32::crc32c`].
+#[inline]
+pub fn crc32_iscsi(data: &[u8]) -> u32 {
+    match crc32c_hardware_update(!0, data) {
+        Some(sum) => !sum,
+        None => Digest::<preset::Crc32Iscsi>::checksum(data),
+    }
+}
+
+/// Compute the `CRC-32/ISO-HDLC` value of `data` in one shot, the same algorithm as [`CRC::crc32`](crate::CRC::crc32).
+#[inline]
+pub fn crc32_iso_hdlc(data: &[u8]) -> u32 {
+    Digest::<preset::Crc32IsoHdlc>::checksum(data)
+}
+
+/// Compute the `CRC-32/JAMCRC` value of `data` in one shot, the same algorithm as [`CRC::crc32jamcrc`](crate::CRC::crc32jamcrc).
+#[inline]
+pub fn crc32_jamcrc(data: &[u8]) -> u32 {
+    Digest::<preset::Crc32Jamcrc>::checksum(data)
+}
+
+/// Compute the `CRC-32/MEF` value of `data` in one shot, the same algorithm as [`CRC::crc32mef`](crate::CRC::crc32mef).
+#[inline]
+pub fn crc32_mef(data: &[u8]) -> u32 {
+    Digest::<preset::Crc32Mef>::checksum(data)
+}
+
+/// Compute the `CRC-32/MPEG-2` value of `data` in one shot, the same algorithm as [`CRC::crc32mpeg2`](crate::CRC::crc32mpeg2).
+#[inline]
+pub fn crc32_mpeg_2(data: &[u8]) -> u32 {
+    Digest::<preset::Crc32Mpeg2>::checksum(data)
+}
+
+/// Compute the `CRC-32/XFER` value of `data` in one shot, the same algorithm as [`CRC::crc32xfer`](crate::CRC::crc32xfer).
+#[inline]
+pub fn crc32_xfer(data: &[u8]) -> u32 {
+    Digest::<preset::Crc32Xfer>::checksum(data)
+}
+
+/// Compute the `CRC-40/GSM` value of `data` in one shot, the same algorithm as [`CRC::crc40gsm`](crate::CRC::crc40gsm).
+#[inline]
+pub fn crc40_gsm(data: &[u8]) -> u64 {
+    Digest::<preset::Crc40Gsm>::checksum(data)
+}
+
+/// Compute the `CRC-64/ECMA-182` value of `data` in one shot, the same algorithm as [`CRC::crc64`](crate::CRC::crc64).
+#[inline]
+pub fn crc64_ecma_182(data: &[u8]) -> u64 {
+    Digest::<preset::Crc64Ecma182>::checksum(data)
+}
+
+/// Compute the `CRC-64/GO-ISO` value of `data` in one shot, the same algorithm as [`CRC::crc64iso`](crate::CRC::crc64iso).
+#[inline]
+pub fn crc64_go_iso(data: &[u8]) -> u64 {
+    Digest::<preset::Crc64GoIso>::checksum(data)
+}
+
+/// Compute the `CRC-64/MS` value of `data` in one shot, the same algorithm as [`CRC::crc64ms`](crate::CRC::crc64ms).
+#[inline]
+pub fn crc64_ms(data: &[u8]) -> u64 {
+    Digest::<preset::Crc64Ms>::checksum(data)
+}
+
+/// Compute the `CRC-64/NVME` value of `data` in one shot, the same algorithm as [`CRC::crc64nvme`](crate::CRC::crc64nvme).
+#[inline]
+pub fn crc64_nvme(data: &[u8]) -> u64 {
+    Digest::<preset::Crc64Nvme>::checksum(data)
+}
+
+/// Compute the `CRC-64/REDIS` value of `data` in one shot, the same algorithm as [`CRC::crc64redis`](crate::CRC::crc64redis).
+#[inline]
+pub fn crc64_redis(data: &[u8]) -> u64 {
+    Digest::<preset::Crc64Redis>::checksum(data)
+}
+
+/// Compute the `CRC-64/WE` value of `data` in one shot, the same algorithm as [`CRC::crc64we`](crate::CRC::crc64we).
+#[inline]
+pub fn crc64_we(data: &[u8]) -> u64 {
+    Digest::<preset::Crc64We>::checksum(data)
+}
+
+/// Compute the `CRC-64/XZ` value of `data` in one shot, the same algorithm as [`CRC::crc64xz`](crate::CRC::crc64xz).
+#[inline]
+pub fn crc64_xz(data: &[u8]) -> u64 {
+    Digest::<preset::Crc64Xz>::checksum(data)
+}
+
+/// Compute the `CRC-82/DARC` value of `data` in one shot, the same algorithm as [`CRC::crc82darc`](crate::CRC::crc82darc).
+#[inline]
+pub fn crc82_darc(data: &[u8]) -> u128 {
+    CRCu128::checksum(&CRC_82_DARC, data)
+}
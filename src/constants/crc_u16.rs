@@ -1,29 +1,67 @@
 #[rustfmt::skip]
-pub(crate) const NO_REF_16_0589: [u16; 256] = [0u16, 1417u16, 2834u16, 3739u16, 5668u16, 5037u16, 7478u16, 6335u16, 11336u16, 10689u16, 10074u16, 8915u16, 14956u16, 16357u16, 12670u16, 13559u16, 22672u16, 23833u16, 21378u16, 22027u16, 20148u16, 19261u16, 17830u16, 16431u16, 29912u16, 29009u16, 32714u16, 31299u16, 25340u16, 26485u16, 27118u16, 27751u16, 45344u16, 46249u16, 47666u16, 49083u16, 42756u16, 41613u16, 44054u16, 43423u16, 40296u16, 39137u16, 38522u16, 37875u16, 35660u16, 36549u16, 32862u16, 34263u16, 59824u16, 60473u16, 58018u16, 59179u16, 65428u16, 64029u16, 62598u16, 61711u16, 50680u16, 49265u16, 52970u16, 52067u16, 54236u16, 54869u16, 55502u16, 56647u16, 26569u16, 25152u16, 27867u16, 26962u16, 29165u16, 29796u16, 31487u16, 32630u16, 19329u16, 19976u16, 16531u16, 17690u16, 23973u16, 22572u16, 22199u16, 21310u16, 16217u16, 15056u16, 13387u16, 12738u16, 10621u16, 11508u16, 8815u16, 10214u16, 4881u16, 5784u16, 6147u16, 7562u16, 1333u16, 188u16, 3623u16, 2990u16, 55017u16, 54112u16, 56827u16, 55410u16, 49357u16, 50500u16, 52191u16, 52822u16, 64161u16, 65320u16, 61875u16, 62522u16, 60549u16, 59660u16, 59287u16, 57886u16, 36473u16, 35824u16, 34155u16, 32994u16, 39005u16, 40404u16, 37711u16, 38598u16, 41521u16, 42936u16, 43299u16, 44202u16, 46101u16, 45468u16, 48903u16, 47758u16, 53138u16, 51739u16, 50304u16, 49417u16, 55734u16, 56383u16, 53924u16, 55085u16, 58330u16, 58963u16, 59592u16, 60737u16, 62974u16, 61559u16, 65260u16, 64357u16, 38658u16, 37515u16, 39952u16, 39321u16, 33062u16, 33967u16, 35380u16, 36797u16, 47946u16, 48835u16, 45144u16, 46545u16, 44398u16, 43239u16, 42620u16, 41973u16, 32434u16, 31547u16, 30112u16, 28713u16, 26774u16, 27935u16, 25476u16, 26125u16, 21242u16, 22387u16, 23016u16, 23649u16, 17630u16, 16727u16, 20428u16, 19013u16, 9762u16, 9131u16, 11568u16, 10425u16, 12294u16, 13711u16, 15124u16, 16029u16, 2666u16, 4067u16, 376u16, 1265u16, 7246u16, 6599u16, 5980u16, 4821u16, 43099u16, 44498u16, 41801u16, 42688u16, 48767u16, 48118u16, 46445u16, 45284u16, 33811u16, 33178u16, 36609u16, 35464u16, 37431u16, 38846u16, 39205u16, 40108u16, 61643u16, 62786u16, 64473u16, 65104u16, 59119u16, 58214u16, 60925u16, 59508u16, 56451u16, 55562u16, 55185u16, 53784u16, 51879u16, 53038u16, 49589u16, 50236u16, 6523u16, 7410u16, 4713u16, 6112u16, 3935u16, 2774u16, 1101u16, 452u16, 13619u16, 12474u16, 15905u16, 15272u16, 8983u16, 9886u16, 10245u16, 11660u16, 16875u16, 17506u16, 19193u16, 20336u16, 22479u16, 21062u16, 23773u16, 22868u16, 28067u16, 26666u16, 26289u16, 25400u16, 31623u16, 32270u16, 28821u16, 29980u16];
+pub(crate) static NO_REF_16_0589: [u16; 256] = [0u16, 1417u16, 2834u16, 3739u16, 5668u16, 5037u16, 7478u16, 6335u16, 11336u16, 10689u16, 10074u16, 8915u16, 14956u16, 16357u16, 12670u16, 13559u16, 22672u16, 23833u16, 21378u16, 22027u16, 20148u16, 19261u16, 17830u16, 16431u16, 29912u16, 29009u16, 32714u16, 31299u16, 25340u16, 26485u16, 27118u16, 27751u16, 45344u16, 46249u16, 47666u16, 49083u16, 42756u16, 41613u16, 44054u16, 43423u16, 40296u16, 39137u16, 38522u16, 37875u16, 35660u16, 36549u16, 32862u16, 34263u16, 59824u16, 60473u16, 58018u16, 59179u16, 65428u16, 64029u16, 62598u16, 61711u16, 50680u16, 49265u16, 52970u16, 52067u16, 54236u16, 54869u16, 55502u16, 56647u16, 26569u16, 25152u16, 27867u16, 26962u16, 29165u16, 29796u16, 31487u16, 32630u16, 19329u16, 19976u16, 16531u16, 17690u16, 23973u16, 22572u16, 22199u16, 21310u16, 16217u16, 15056u16, 13387u16, 12738u16, 10621u16, 11508u16, 8815u16, 10214u16, 4881u16, 5784u16, 6147u16, 7562u16, 1333u16, 188u16, 3623u16, 2990u16, 55017u16, 54112u16, 56827u16, 55410u16, 49357u16, 50500u16, 52191u16, 52822u16, 64161u16, 65320u16, 61875u16, 62522u16, 60549u16, 59660u16, 59287u16, 57886u16, 36473u16, 35824u16, 34155u16, 32994u16, 39005u16, 40404u16, 37711u16, 38598u16, 41521u16, 42936u16, 43299u16, 44202u16, 46101u16, 45468u16, 48903u16, 47758u16, 53138u16, 51739u16, 50304u16, 49417u16, 55734u16, 56383u16, 53924u16, 55085u16, 58330u16, 58963u16, 59592u16, 60737u16, 62974u16, 61559u16, 65260u16, 64357u16, 38658u16, 37515u16, 39952u16, 39321u16, 33062u16, 33967u16, 35380u16, 36797u16, 47946u16, 48835u16, 45144u16, 46545u16, 44398u16, 43239u16, 42620u16, 41973u16, 32434u16, 31547u16, 30112u16, 28713u16, 26774u16, 27935u16, 25476u16, 26125u16, 21242u16, 22387u16, 23016u16, 23649u16, 17630u16, 16727u16, 20428u16, 19013u16, 9762u16, 9131u16, 11568u16, 10425u16, 12294u16, 13711u16, 15124u16, 16029u16, 2666u16, 4067u16, 376u16, 1265u16, 7246u16, 6599u16, 5980u16, 4821u16, 43099u16, 44498u16, 41801u16, 42688u16, 48767u16, 48118u16, 46445u16, 45284u16, 33811u16, 33178u16, 36609u16, 35464u16, 37431u16, 38846u16, 39205u16, 40108u16, 61643u16, 62786u16, 64473u16, 65104u16, 59119u16, 58214u16, 60925u16, 59508u16, 56451u16, 55562u16, 55185u16, 53784u16, 51879u16, 53038u16, 49589u16, 50236u16, 6523u16, 7410u16, 4713u16, 6112u16, 3935u16, 2774u16, 1101u16, 452u16, 13619u16, 12474u16, 15905u16, 15272u16, 8983u16, 9886u16, 10245u16, 11660u16, 16875u16, 17506u16, 19193u16, 20336u16, 22479u16, 21062u16, 23773u16, 22868u16, 28067u16, 26666u16, 26289u16, 25400u16, 31623u16, 32270u16, 28821u16, 29980u16];
 
 #[rustfmt::skip]
-pub(crate) const NO_REF_16_1021: [u16; 256] = [0u16, 4129u16, 8258u16, 12387u16, 16516u16, 20645u16, 24774u16, 28903u16, 33032u16, 37161u16, 41290u16, 45419u16, 49548u16, 53677u16, 57806u16, 61935u16, 4657u16, 528u16, 12915u16, 8786u16, 21173u16, 17044u16, 29431u16, 25302u16, 37689u16, 33560u16, 45947u16, 41818u16, 54205u16, 50076u16, 62463u16, 58334u16, 9314u16, 13379u16, 1056u16, 5121u16, 25830u16, 29895u16, 17572u16, 21637u16, 42346u16, 46411u16, 34088u16, 38153u16, 58862u16, 62927u16, 50604u16, 54669u16, 13907u16, 9842u16, 5649u16, 1584u16, 30423u16, 26358u16, 22165u16, 18100u16, 46939u16, 42874u16, 38681u16, 34616u16, 63455u16, 59390u16, 55197u16, 51132u16, 18628u16, 22757u16, 26758u16, 30887u16, 2112u16, 6241u16, 10242u16, 14371u16, 51660u16, 55789u16, 59790u16, 63919u16, 35144u16, 39273u16, 43274u16, 47403u16, 23285u16, 19156u16, 31415u16, 27286u16, 6769u16, 2640u16, 14899u16, 10770u16, 56317u16, 52188u16, 64447u16, 60318u16, 39801u16, 35672u16, 47931u16, 43802u16, 27814u16, 31879u16, 19684u16, 23749u16, 11298u16, 15363u16, 3168u16, 7233u16, 60846u16, 64911u16, 52716u16, 56781u16, 44330u16, 48395u16, 36200u16, 40265u16, 32407u16, 28342u16, 24277u16, 20212u16, 15891u16, 11826u16, 7761u16, 3696u16, 65439u16, 61374u16, 57309u16, 53244u16, 48923u16, 44858u16, 40793u16, 36728u16, 37256u16, 33193u16, 45514u16, 41451u16, 53516u16, 49453u16, 61774u16, 57711u16, 4224u16, 161u16, 12482u16, 8419u16, 20484u16, 16421u16, 28742u16, 24679u16, 33721u16, 37784u16, 41979u16, 46042u16, 49981u16, 54044u16, 58239u16, 62302u16, 689u16, 4752u16, 8947u16, 13010u16, 16949u16, 21012u16, 25207u16, 29270u16, 46570u16, 42443u16, 38312u16, 34185u16, 62830u16, 58703u16, 54572u16, 50445u16, 13538u16, 9411u16, 5280u16, 1153u16, 29798u16, 25671u16, 21540u16, 17413u16, 42971u16, 47098u16, 34713u16, 38840u16, 59231u16, 63358u16, 50973u16, 55100u16, 9939u16, 14066u16, 1681u16, 5808u16, 26199u16, 30326u16, 17941u16, 22068u16, 55628u16, 51565u16, 63758u16, 59695u16, 39368u16, 35305u16, 47498u16, 43435u16, 22596u16, 18533u16, 30726u16, 26663u16, 6336u16, 2273u16, 14466u16, 10403u16, 52093u16, 56156u16, 60223u16, 64286u16, 35833u16, 39896u16, 43963u16, 48026u16, 19061u16, 23124u16, 27191u16, 31254u16, 2801u16, 6864u16, 10931u16, 14994u16, 64814u16, 60687u16, 56684u16, 52557u16, 48554u16, 44427u16, 40424u16, 36297u16, 31782u16, 27655u16, 23652u16, 19525u16, 15522u16, 11395u16, 7392u16, 3265u16, 61215u16, 65342u16, 53085u16, 57212u16, 44955u16, 49082u16, 36825u16, 40952u16, 28183u16, 32310u16, 20053u16, 24180u16, 11923u16, 16050u16, 3793u16, 7920u16];
+pub(crate) static NO_REF_16_1021: [u16; 256] = [0u16, 4129u16, 8258u16, 12387u16, 16516u16, 20645u16, 24774u16, 28903u16, 33032u16, 37161u16, 41290u16, 45419u16, 49548u16, 53677u16, 57806u16, 61935u16, 4657u16, 528u16, 12915u16, 8786u16, 21173u16, 17044u16, 29431u16, 25302u16, 37689u16, 33560u16, 45947u16, 41818u16, 54205u16, 50076u16, 62463u16, 58334u16, 9314u16, 13379u16, 1056u16, 5121u16, 25830u16, 29895u16, 17572u16, 21637u16, 42346u16, 46411u16, 34088u16, 38153u16, 58862u16, 62927u16, 50604u16, 54669u16, 13907u16, 9842u16, 5649u16, 1584u16, 30423u16, 26358u16, 22165u16, 18100u16, 46939u16, 42874u16, 38681u16, 34616u16, 63455u16, 59390u16, 55197u16, 51132u16, 18628u16, 22757u16, 26758u16, 30887u16, 2112u16, 6241u16, 10242u16, 14371u16, 51660u16, 55789u16, 59790u16, 63919u16, 35144u16, 39273u16, 43274u16, 47403u16, 23285u16, 19156u16, 31415u16, 27286u16, 6769u16, 2640u16, 14899u16, 10770u16, 56317u16, 52188u16, 64447u16, 60318u16, 39801u16, 35672u16, 47931u16, 43802u16, 27814u16, 31879u16, 19684u16, 23749u16, 11298u16, 15363u16, 3168u16, 7233u16, 60846u16, 64911u16, 52716u16, 56781u16, 44330u16, 48395u16, 36200u16, 40265u16, 32407u16, 28342u16, 24277u16, 20212u16, 15891u16, 11826u16, 7761u16, 3696u16, 65439u16, 61374u16, 57309u16, 53244u16, 48923u16, 44858u16, 40793u16, 36728u16, 37256u16, 33193u16, 45514u16, 41451u16, 53516u16, 49453u16, 61774u16, 57711u16, 4224u16, 161u16, 12482u16, 8419u16, 20484u16, 16421u16, 28742u16, 24679u16, 33721u16, 37784u16, 41979u16, 46042u16, 49981u16, 54044u16, 58239u16, 62302u16, 689u16, 4752u16, 8947u16, 13010u16, 16949u16, 21012u16, 25207u16, 29270u16, 46570u16, 42443u16, 38312u16, 34185u16, 62830u16, 58703u16, 54572u16, 50445u16, 13538u16, 9411u16, 5280u16, 1153u16, 29798u16, 25671u16, 21540u16, 17413u16, 42971u16, 47098u16, 34713u16, 38840u16, 59231u16, 63358u16, 50973u16, 55100u16, 9939u16, 14066u16, 1681u16, 5808u16, 26199u16, 30326u16, 17941u16, 22068u16, 55628u16, 51565u16, 63758u16, 59695u16, 39368u16, 35305u16, 47498u16, 43435u16, 22596u16, 18533u16, 30726u16, 26663u16, 6336u16, 2273u16, 14466u16, 10403u16, 52093u16, 56156u16, 60223u16, 64286u16, 35833u16, 39896u16, 43963u16, 48026u16, 19061u16, 23124u16, 27191u16, 31254u16, 2801u16, 6864u16, 10931u16, 14994u16, 64814u16, 60687u16, 56684u16, 52557u16, 48554u16, 44427u16, 40424u16, 36297u16, 31782u16, 27655u16, 23652u16, 19525u16, 15522u16, 11395u16, 7392u16, 3265u16, 61215u16, 65342u16, 53085u16, 57212u16, 44955u16, 49082u16, 36825u16, 40952u16, 28183u16, 32310u16, 20053u16, 24180u16, 11923u16, 16050u16, 3793u16, 7920u16];
 
 #[rustfmt::skip]
-pub(crate) const NO_REF_16_3D65: [u16; 256] = [0u16, 15717u16, 31434u16, 18351u16, 62868u16, 51441u16, 36702u16, 45627u16, 54861u16, 60200u16, 44167u16, 37346u16, 9177u16, 7868u16, 22803u16, 25718u16, 37375u16, 44186u16, 60213u16, 54864u16, 25707u16, 22798u16, 7841u16, 9156u16, 18354u16, 31447u16, 15736u16, 29u16, 45606u16, 36675u16, 51436u16, 62857u16, 7835u16, 9214u16, 25681u16, 22836u16, 60175u16, 54890u16, 37317u16, 44192u16, 51414u16, 62899u16, 45596u16, 36729u16, 15682u16, 39u16, 18312u16, 31469u16, 36708u16, 45569u16, 62894u16, 51403u16, 31472u16, 18325u16, 58u16, 15711u16, 22825u16, 25676u16, 9187u16, 7814u16, 44221u16, 37336u16, 54903u16, 60178u16, 15670u16, 83u16, 18428u16, 31385u16, 51362u16, 62919u16, 45672u16, 36621u16, 60283u16, 54814u16, 37297u16, 44244u16, 7919u16, 9098u16, 25637u16, 22848u16, 44233u16, 37292u16, 54787u16, 60262u16, 22877u16, 25656u16, 9111u16, 7922u16, 31364u16, 18401u16, 78u16, 15659u16, 36624u16, 45685u16, 62938u16, 51391u16, 9133u16, 7880u16, 22887u16, 25602u16, 54841u16, 60252u16, 44275u16, 37270u16, 62944u16, 51333u16, 36650u16, 45647u16, 116u16, 15633u16, 31422u16, 18395u16, 45650u16, 36663u16, 51352u16, 62973u16, 18374u16, 31395u16, 15628u16, 105u16, 25631u16, 22906u16, 7893u16, 9136u16, 37259u16, 44270u16, 60225u16, 54820u16, 31340u16, 18185u16, 166u16, 15811u16, 36856u16, 45725u16, 62770u16, 51287u16, 44065u16, 37188u16, 55019u16, 60302u16, 22965u16, 25808u16, 9087u16, 7706u16, 60307u16, 55030u16, 37209u16, 44092u16, 7687u16, 9058u16, 25805u16, 22952u16, 15838u16, 187u16, 18196u16, 31345u16, 51274u16, 62767u16, 45696u16, 36837u16, 25847u16, 22930u16, 7741u16, 9048u16, 37219u16, 44038u16, 60329u16, 54988u16, 45754u16, 36831u16, 51312u16, 62741u16, 18222u16, 31307u16, 15844u16, 129u16, 62728u16, 51309u16, 36802u16, 45735u16, 156u16, 15865u16, 31318u16, 18227u16, 9029u16, 7712u16, 22927u16, 25834u16, 54993u16, 60340u16, 44059u16, 37246u16, 18266u16, 31295u16, 15760u16, 245u16, 45774u16, 36779u16, 51204u16, 62817u16, 37143u16, 44146u16, 60381u16, 54968u16, 25731u16, 23014u16, 7753u16, 9004u16, 54949u16, 60352u16, 44143u16, 37130u16, 9009u16, 7764u16, 23035u16, 25758u16, 232u16, 15757u16, 31266u16, 18247u16, 62844u16, 51225u16, 36790u16, 45779u16, 22977u16, 25764u16, 8971u16, 7790u16, 44117u16, 37168u16, 54943u16, 60410u16, 36748u16, 45801u16, 62790u16, 51235u16, 31256u16, 18301u16, 210u16, 15799u16, 51262u16, 62811u16, 45812u16, 36753u16, 15786u16, 207u16, 18272u16, 31237u16, 7795u16, 8982u16, 25785u16, 23004u16, 60391u16, 54914u16, 37165u16, 44104u16];
+pub(crate) static NO_REF_16_3D65: [u16; 256] = [0u16, 15717u16, 31434u16, 18351u16, 62868u16, 51441u16, 36702u16, 45627u16, 54861u16, 60200u16, 44167u16, 37346u16, 9177u16, 7868u16, 22803u16, 25718u16, 37375u16, 44186u16, 60213u16, 54864u16, 25707u16, 22798u16, 7841u16, 9156u16, 18354u16, 31447u16, 15736u16, 29u16, 45606u16, 36675u16, 51436u16, 62857u16, 7835u16, 9214u16, 25681u16, 22836u16, 60175u16, 54890u16, 37317u16, 44192u16, 51414u16, 62899u16, 45596u16, 36729u16, 15682u16, 39u16, 18312u16, 31469u16, 36708u16, 45569u16, 62894u16, 51403u16, 31472u16, 18325u16, 58u16, 15711u16, 22825u16, 25676u16, 9187u16, 7814u16, 44221u16, 37336u16, 54903u16, 60178u16, 15670u16, 83u16, 18428u16, 31385u16, 51362u16, 62919u16, 45672u16, 36621u16, 60283u16, 54814u16, 37297u16, 44244u16, 7919u16, 9098u16, 25637u16, 22848u16, 44233u16, 37292u16, 54787u16, 60262u16, 22877u16, 25656u16, 9111u16, 7922u16, 31364u16, 18401u16, 78u16, 15659u16, 36624u16, 45685u16, 62938u16, 51391u16, 9133u16, 7880u16, 22887u16, 25602u16, 54841u16, 60252u16, 44275u16, 37270u16, 62944u16, 51333u16, 36650u16, 45647u16, 116u16, 15633u16, 31422u16, 18395u16, 45650u16, 36663u16, 51352u16, 62973u16, 18374u16, 31395u16, 15628u16, 105u16, 25631u16, 22906u16, 7893u16, 9136u16, 37259u16, 44270u16, 60225u16, 54820u16, 31340u16, 18185u16, 166u16, 15811u16, 36856u16, 45725u16, 62770u16, 51287u16, 44065u16, 37188u16, 55019u16, 60302u16, 22965u16, 25808u16, 9087u16, 7706u16, 60307u16, 55030u16, 37209u16, 44092u16, 7687u16, 9058u16, 25805u16, 22952u16, 15838u16, 187u16, 18196u16, 31345u16, 51274u16, 62767u16, 45696u16, 36837u16, 25847u16, 22930u16, 7741u16, 9048u16, 37219u16, 44038u16, 60329u16, 54988u16, 45754u16, 36831u16, 51312u16, 62741u16, 18222u16, 31307u16, 15844u16, 129u16, 62728u16, 51309u16, 36802u16, 45735u16, 156u16, 15865u16, 31318u16, 18227u16, 9029u16, 7712u16, 22927u16, 25834u16, 54993u16, 60340u16, 44059u16, 37246u16, 18266u16, 31295u16, 15760u16, 245u16, 45774u16, 36779u16, 51204u16, 62817u16, 37143u16, 44146u16, 60381u16, 54968u16, 25731u16, 23014u16, 7753u16, 9004u16, 54949u16, 60352u16, 44143u16, 37130u16, 9009u16, 7764u16, 23035u16, 25758u16, 232u16, 15757u16, 31266u16, 18247u16, 62844u16, 51225u16, 36790u16, 45779u16, 22977u16, 25764u16, 8971u16, 7790u16, 44117u16, 37168u16, 54943u16, 60410u16, 36748u16, 45801u16, 62790u16, 51235u16, 31256u16, 18301u16, 210u16, 15799u16, 51262u16, 62811u16, 45812u16, 36753u16, 15786u16, 207u16, 18272u16, 31237u16, 7795u16, 8982u16, 25785u16, 23004u16, 60391u16, 54914u16, 37165u16, 44104u16];
 
 #[rustfmt::skip]
-pub(crate) const NO_REF_16_8005: [u16; 256] = [0u16, 32773u16, 32783u16, 10u16, 32795u16, 30u16, 20u16, 32785u16, 32819u16, 54u16, 60u16, 32825u16, 40u16, 32813u16, 32807u16, 34u16, 32867u16, 102u16, 108u16, 32873u16, 120u16, 32893u16, 32887u16, 114u16, 80u16, 32853u16, 32863u16, 90u16, 32843u16, 78u16, 68u16, 32833u16, 32963u16, 198u16, 204u16, 32969u16, 216u16, 32989u16, 32983u16, 210u16, 240u16, 33013u16, 33023u16, 250u16, 33003u16, 238u16, 228u16, 32993u16, 160u16, 32933u16, 32943u16, 170u16, 32955u16, 190u16, 180u16, 32945u16, 32915u16, 150u16, 156u16, 32921u16, 136u16, 32909u16, 32903u16, 130u16, 33155u16, 390u16, 396u16, 33161u16, 408u16, 33181u16, 33175u16, 402u16, 432u16, 33205u16, 33215u16, 442u16, 33195u16, 430u16, 420u16, 33185u16, 480u16, 33253u16, 33263u16, 490u16, 33275u16, 510u16, 500u16, 33265u16, 33235u16, 470u16, 476u16, 33241u16, 456u16, 33229u16, 33223u16, 450u16, 320u16, 33093u16, 33103u16, 330u16, 33115u16, 350u16, 340u16, 33105u16, 33139u16, 374u16, 380u16, 33145u16, 360u16, 33133u16, 33127u16, 354u16, 33059u16, 294u16, 300u16, 33065u16, 312u16, 33085u16, 33079u16, 306u16, 272u16, 33045u16, 33055u16, 282u16, 33035u16, 270u16, 260u16, 33025u16, 33539u16, 774u16, 780u16, 33545u16, 792u16, 33565u16, 33559u16, 786u16, 816u16, 33589u16, 33599u16, 826u16, 33579u16, 814u16, 804u16, 33569u16, 864u16, 33637u16, 33647u16, 874u16, 33659u16, 894u16, 884u16, 33649u16, 33619u16, 854u16, 860u16, 33625u16, 840u16, 33613u16, 33607u16, 834u16, 960u16, 33733u16, 33743u16, 970u16, 33755u16, 990u16, 980u16, 33745u16, 33779u16, 1014u16, 1020u16, 33785u16, 1000u16, 33773u16, 33767u16, 994u16, 33699u16, 934u16, 940u16, 33705u16, 952u16, 33725u16, 33719u16, 946u16, 912u16, 33685u16, 33695u16, 922u16, 33675u16, 910u16, 900u16, 33665u16, 640u16, 33413u16, 33423u16, 650u16, 33435u16, 670u16, 660u16, 33425u16, 33459u16, 694u16, 700u16, 33465u16, 680u16, 33453u16, 33447u16, 674u16, 33507u16, 742u16, 748u16, 33513u16, 760u16, 33533u16, 33527u16, 754u16, 720u16, 33493u16, 33503u16, 730u16, 33483u16, 718u16, 708u16, 33473u16, 33347u16, 582u16, 588u16, 33353u16, 600u16, 33373u16, 33367u16, 594u16, 624u16, 33397u16, 33407u16, 634u16, 33387u16, 622u16, 612u16, 33377u16, 544u16, 33317u16, 33327u16, 554u16, 33339u16, 574u16, 564u16, 33329u16, 33299u16, 534u16, 540u16, 33305u16, 520u16, 33293u16, 33287u16, 514u16];
+pub(crate) static NO_REF_16_8005: [u16; 256] = [0u16, 32773u16, 32783u16, 10u16, 32795u16, 30u16, 20u16, 32785u16, 32819u16, 54u16, 60u16, 32825u16, 40u16, 32813u16, 32807u16, 34u16, 32867u16, 102u16, 108u16, 32873u16, 120u16, 32893u16, 32887u16, 114u16, 80u16, 32853u16, 32863u16, 90u16, 32843u16, 78u16, 68u16, 32833u16, 32963u16, 198u16, 204u16, 32969u16, 216u16, 32989u16, 32983u16, 210u16, 240u16, 33013u16, 33023u16, 250u16, 33003u16, 238u16, 228u16, 32993u16, 160u16, 32933u16, 32943u16, 170u16, 32955u16, 190u16, 180u16, 32945u16, 32915u16, 150u16, 156u16, 32921u16, 136u16, 32909u16, 32903u16, 130u16, 33155u16, 390u16, 396u16, 33161u16, 408u16, 33181u16, 33175u16, 402u16, 432u16, 33205u16, 33215u16, 442u16, 33195u16, 430u16, 420u16, 33185u16, 480u16, 33253u16, 33263u16, 490u16, 33275u16, 510u16, 500u16, 33265u16, 33235u16, 470u16, 476u16, 33241u16, 456u16, 33229u16, 33223u16, 450u16, 320u16, 33093u16, 33103u16, 330u16, 33115u16, 350u16, 340u16, 33105u16, 33139u16, 374u16, 380u16, 33145u16, 360u16, 33133u16, 33127u16, 354u16, 33059u16, 294u16, 300u16, 33065u16, 312u16, 33085u16, 33079u16, 306u16, 272u16, 33045u16, 33055u16, 282u16, 33035u16, 270u16, 260u16, 33025u16, 33539u16, 774u16, 780u16, 33545u16, 792u16, 33565u16, 33559u16, 786u16, 816u16, 33589u16, 33599u16, 826u16, 33579u16, 814u16, 804u16, 33569u16, 864u16, 33637u16, 33647u16, 874u16, 33659u16, 894u16, 884u16, 33649u16, 33619u16, 854u16, 860u16, 33625u16, 840u16, 33613u16, 33607u16, 834u16, 960u16, 33733u16, 33743u16, 970u16, 33755u16, 990u16, 980u16, 33745u16, 33779u16, 1014u16, 1020u16, 33785u16, 1000u16, 33773u16, 33767u16, 994u16, 33699u16, 934u16, 940u16, 33705u16, 952u16, 33725u16, 33719u16, 946u16, 912u16, 33685u16, 33695u16, 922u16, 33675u16, 910u16, 900u16, 33665u16, 640u16, 33413u16, 33423u16, 650u16, 33435u16, 670u16, 660u16, 33425u16, 33459u16, 694u16, 700u16, 33465u16, 680u16, 33453u16, 33447u16, 674u16, 33507u16, 742u16, 748u16, 33513u16, 760u16, 33533u16, 33527u16, 754u16, 720u16, 33493u16, 33503u16, 730u16, 33483u16, 718u16, 708u16, 33473u16, 33347u16, 582u16, 588u16, 33353u16, 600u16, 33373u16, 33367u16, 594u16, 624u16, 33397u16, 33407u16, 634u16, 33387u16, 622u16, 612u16, 33377u16, 544u16, 33317u16, 33327u16, 554u16, 33339u16, 574u16, 564u16, 33329u16, 33299u16, 534u16, 540u16, 33305u16, 520u16, 33293u16, 33287u16, 514u16];
 
 #[rustfmt::skip]
-pub(crate) const NO_REF_16_8BB7: [u16; 256] = [0u16, 35767u16, 40153u16, 5998u16, 45573u16, 14770u16, 11996u16, 42347u16, 61373u16, 25610u16, 29540u16, 63699u16, 23992u16, 54799u16, 49505u16, 19158u16, 21709u16, 57210u16, 51220u16, 17315u16, 59080u16, 28031u16, 31249u16, 61862u16, 47984u16, 12487u16, 10153u16, 44062u16, 2421u16, 33474u16, 38316u16, 7707u16, 43418u16, 8749u16, 13635u16, 48884u16, 7071u16, 36904u16, 34630u16, 3313u16, 17959u16, 52624u16, 56062u16, 20809u16, 62498u16, 32661u16, 26875u16, 58188u16, 64855u16, 30432u16, 24974u16, 59961u16, 20306u16, 50405u16, 54155u16, 22588u16, 4842u16, 39261u16, 36403u16, 1412u16, 41199u16, 11096u16, 15414u16, 46977u16, 55427u16, 21300u16, 17498u16, 53229u16, 27270u16, 57649u16, 63071u16, 32232u16, 14142u16, 48265u16, 44007u16, 8272u16, 34107u16, 3724u16, 6626u16, 37461u16, 35918u16, 2041u16, 4247u16, 39712u16, 15947u16, 46588u16, 41618u16, 10533u16, 25587u16, 59460u16, 65322u16, 29853u16, 53750u16, 23105u16, 19759u16, 50840u16, 28953u16, 64174u16, 60864u16, 26231u16, 49948u16, 18603u16, 24517u16, 54386u16, 40612u16, 5395u16, 637u16, 35274u16, 11425u16, 42774u16, 45176u16, 15311u16, 9684u16, 44643u16, 47373u16, 12986u16, 38865u16, 7270u16, 2824u16, 32959u16, 51817u16, 16862u16, 22192u16, 56583u16, 30828u16, 62427u16, 58549u16, 28418u16, 15025u16, 45318u16, 42600u16, 11743u16, 34996u16, 771u16, 5229u16, 40922u16, 54540u16, 24251u16, 18901u16, 49762u16, 26377u16, 60606u16, 64464u16, 28775u16, 28284u16, 58827u16, 62117u16, 30994u16, 56441u16, 22478u16, 16544u16, 51991u16, 33217u16, 2678u16, 7448u16, 38575u16, 13252u16, 47219u16, 44829u16, 9386u16, 37675u16, 6300u16, 4082u16, 33861u16, 8494u16, 43673u16, 48631u16, 13888u16, 31894u16, 63265u16, 57423u16, 27640u16, 52883u16, 17700u16, 21066u16, 55805u16, 51174u16, 19537u16, 23359u16, 53384u16, 30179u16, 65108u16, 59706u16, 25229u16, 10331u16, 41964u16, 46210u16, 16181u16, 39518u16, 4585u16, 1671u16, 36144u16, 57906u16, 27013u16, 32491u16, 62812u16, 20535u16, 56192u16, 52462u16, 18265u16, 3471u16, 34360u16, 37206u16, 6881u16, 49034u16, 13373u16, 9043u16, 43236u16, 46847u16, 15688u16, 10790u16, 41361u16, 1274u16, 36685u16, 38947u16, 5012u16, 22850u16, 54005u16, 50587u16, 20012u16, 60231u16, 24816u16, 30622u16, 64553u16, 19368u16, 49183u16, 55153u16, 23750u16, 63917u16, 29210u16, 25972u16, 61123u16, 42005u16, 12194u16, 14540u16, 45947u16, 5648u16, 40359u16, 35529u16, 382u16, 8037u16, 38098u16, 33724u16, 2059u16, 44384u16, 9943u16, 12729u16, 47630u16, 61656u16, 31599u16, 27649u16, 59318u16, 17117u16, 51562u16, 56836u16, 21939u16];
+pub(crate) static NO_REF_16_8BB7: [u16; 256] = [0u16, 35767u16, 40153u16, 5998u16, 45573u16, 14770u16, 11996u16, 42347u16, 61373u16, 25610u16, 29540u16, 63699u16, 23992u16, 54799u16, 49505u16, 19158u16, 21709u16, 57210u16, 51220u16, 17315u16, 59080u16, 28031u16, 31249u16, 61862u16, 47984u16, 12487u16, 10153u16, 44062u16, 2421u16, 33474u16, 38316u16, 7707u16, 43418u16, 8749u16, 13635u16, 48884u16, 7071u16, 36904u16, 34630u16, 3313u16, 17959u16, 52624u16, 56062u16, 20809u16, 62498u16, 32661u16, 26875u16, 58188u16, 64855u16, 30432u16, 24974u16, 59961u16, 20306u16, 50405u16, 54155u16, 22588u16, 4842u16, 39261u16, 36403u16, 1412u16, 41199u16, 11096u16, 15414u16, 46977u16, 55427u16, 21300u16, 17498u16, 53229u16, 27270u16, 57649u16, 63071u16, 32232u16, 14142u16, 48265u16, 44007u16, 8272u16, 34107u16, 3724u16, 6626u16, 37461u16, 35918u16, 2041u16, 4247u16, 39712u16, 15947u16, 46588u16, 41618u16, 10533u16, 25587u16, 59460u16, 65322u16, 29853u16, 53750u16, 23105u16, 19759u16, 50840u16, 28953u16, 64174u16, 60864u16, 26231u16, 49948u16, 18603u16, 24517u16, 54386u16, 40612u16, 5395u16, 637u16, 35274u16, 11425u16, 42774u16, 45176u16, 15311u16, 9684u16, 44643u16, 47373u16, 12986u16, 38865u16, 7270u16, 2824u16, 32959u16, 51817u16, 16862u16, 22192u16, 56583u16, 30828u16, 62427u16, 58549u16, 28418u16, 15025u16, 45318u16, 42600u16, 11743u16, 34996u16, 771u16, 5229u16, 40922u16, 54540u16, 24251u16, 18901u16, 49762u16, 26377u16, 60606u16, 64464u16, 28775u16, 28284u16, 58827u16, 62117u16, 30994u16, 56441u16, 22478u16, 16544u16, 51991u16, 33217u16, 2678u16, 7448u16, 38575u16, 13252u16, 47219u16, 44829u16, 9386u16, 37675u16, 6300u16, 4082u16, 33861u16, 8494u16, 43673u16, 48631u16, 13888u16, 31894u16, 63265u16, 57423u16, 27640u16, 52883u16, 17700u16, 21066u16, 55805u16, 51174u16, 19537u16, 23359u16, 53384u16, 30179u16, 65108u16, 59706u16, 25229u16, 10331u16, 41964u16, 46210u16, 16181u16, 39518u16, 4585u16, 1671u16, 36144u16, 57906u16, 27013u16, 32491u16, 62812u16, 20535u16, 56192u16, 52462u16, 18265u16, 3471u16, 34360u16, 37206u16, 6881u16, 49034u16, 13373u16, 9043u16, 43236u16, 46847u16, 15688u16, 10790u16, 41361u16, 1274u16, 36685u16, 38947u16, 5012u16, 22850u16, 54005u16, 50587u16, 20012u16, 60231u16, 24816u16, 30622u16, 64553u16, 19368u16, 49183u16, 55153u16, 23750u16, 63917u16, 29210u16, 25972u16, 61123u16, 42005u16, 12194u16, 14540u16, 45947u16, 5648u16, 40359u16, 35529u16, 382u16, 8037u16, 38098u16, 33724u16, 2059u16, 44384u16, 9943u16, 12729u16, 47630u16, 61656u16, 31599u16, 27649u16, 59318u16, 17117u16, 51562u16, 56836u16, 21939u16];
 
 #[rustfmt::skip]
-pub(crate) const NO_REF_16_C867: [u16; 256] = [0u16, 51303u16, 22697u16, 37070u16, 45394u16, 31029u16, 59899u16, 8604u16, 43715u16, 25252u16, 62058u16, 14861u16, 7057u16, 54262u16, 17208u16, 35679u16, 40417u16, 21894u16, 50504u16, 3375u16, 11443u16, 58580u16, 29722u16, 48253u16, 14114u16, 65349u16, 28555u16, 42988u16, 34416u16, 19991u16, 57049u16, 5822u16, 62373u16, 15298u16, 43788u16, 25451u16, 17143u16, 35472u16, 6750u16, 53817u16, 22886u16, 37121u16, 463u16, 51624u16, 59444u16, 8275u16, 45213u16, 30970u16, 28228u16, 42531u16, 14061u16, 65162u16, 57110u16, 6001u16, 34751u16, 20440u16, 50311u16, 3296u16, 39982u16, 21577u16, 30165u16, 48562u16, 11644u16, 58651u16, 12077u16, 59210u16, 30596u16, 49123u16, 40575u16, 22040u16, 50902u16, 3761u16, 34286u16, 19849u16, 56647u16, 5408u16, 13500u16, 64731u16, 27669u16, 42098u16, 45772u16, 31403u16, 60005u16, 8706u16, 926u16, 52217u16, 23351u16, 37712u16, 6159u16, 53352u16, 16550u16, 35009u16, 43357u16, 24890u16, 61940u16, 14739u16, 56456u16, 5359u16, 33825u16, 19526u16, 28122u16, 42429u16, 13683u16, 64788u16, 30283u16, 48684u16, 12002u16, 59013u16, 50969u16, 3966u16, 40880u16, 22487u16, 16745u16, 35086u16, 6592u16, 53671u16, 61499u16, 14428u16, 43154u16, 24821u16, 60330u16, 9165u16, 45827u16, 31588u16, 23288u16, 37535u16, 593u16, 51766u16, 24154u16, 38461u16, 1779u16, 52884u16, 61192u16, 10095u16, 47009u16, 32710u16, 62617u16, 15614u16, 44080u16, 25687u16, 17867u16, 36268u16, 7522u16, 54533u16, 50107u16, 3036u16, 39698u16, 21365u16, 29417u16, 47758u16, 10816u16, 57895u16, 27000u16, 41247u16, 12753u16, 63926u16, 55338u16, 4173u16, 32899u16, 18660u16, 44543u16, 26008u16, 62806u16, 15665u16, 7341u16, 54474u16, 17412u16, 35939u16, 1852u16, 53083u16, 24469u16, 38898u16, 46702u16, 32265u16, 61127u16, 9888u16, 12318u16, 63609u16, 26807u16, 41168u16, 33100u16, 18731u16, 55781u16, 4482u16, 39645u16, 21178u16, 49780u16, 2579u16, 11151u16, 58344u16, 29478u16, 47937u16, 29047u16, 47376u16, 10718u16, 57785u16, 49189u16, 2114u16, 39052u16, 20715u16, 56244u16, 5075u16, 33565u16, 19322u16, 27366u16, 41601u16, 12879u16, 64040u16, 60566u16, 9457u16, 46143u16, 31832u16, 24004u16, 38307u16, 1389u16, 52490u16, 18005u16, 36402u16, 7932u16, 54939u16, 63239u16, 16224u16, 44974u16, 26569u16, 33490u16, 19125u16, 55931u16, 4636u16, 13184u16, 64487u16, 27433u16, 41806u16, 10257u16, 57462u16, 28856u16, 47327u16, 39235u16, 20772u16, 49642u16, 2445u16, 7987u16, 55124u16, 18330u16, 36861u16, 44641u16, 26118u16, 63176u16, 16047u16, 46576u16, 32151u16, 60761u16, 9534u16, 1186u16, 52421u16, 23563u16, 37996u16];
+pub(crate) static NO_REF_16_C867: [u16; 256] = [0u16, 51303u16, 22697u16, 37070u16, 45394u16, 31029u16, 59899u16, 8604u16, 43715u16, 25252u16, 62058u16, 14861u16, 7057u16, 54262u16, 17208u16, 35679u16, 40417u16, 21894u16, 50504u16, 3375u16, 11443u16, 58580u16, 29722u16, 48253u16, 14114u16, 65349u16, 28555u16, 42988u16, 34416u16, 19991u16, 57049u16, 5822u16, 62373u16, 15298u16, 43788u16, 25451u16, 17143u16, 35472u16, 6750u16, 53817u16, 22886u16, 37121u16, 463u16, 51624u16, 59444u16, 8275u16, 45213u16, 30970u16, 28228u16, 42531u16, 14061u16, 65162u16, 57110u16, 6001u16, 34751u16, 20440u16, 50311u16, 3296u16, 39982u16, 21577u16, 30165u16, 48562u16, 11644u16, 58651u16, 12077u16, 59210u16, 30596u16, 49123u16, 40575u16, 22040u16, 50902u16, 3761u16, 34286u16, 19849u16, 56647u16, 5408u16, 13500u16, 64731u16, 27669u16, 42098u16, 45772u16, 31403u16, 60005u16, 8706u16, 926u16, 52217u16, 23351u16, 37712u16, 6159u16, 53352u16, 16550u16, 35009u16, 43357u16, 24890u16, 61940u16, 14739u16, 56456u16, 5359u16, 33825u16, 19526u16, 28122u16, 42429u16, 13683u16, 64788u16, 30283u16, 48684u16, 12002u16, 59013u16, 50969u16, 3966u16, 40880u16, 22487u16, 16745u16, 35086u16, 6592u16, 53671u16, 61499u16, 14428u16, 43154u16, 24821u16, 60330u16, 9165u16, 45827u16, 31588u16, 23288u16, 37535u16, 593u16, 51766u16, 24154u16, 38461u16, 1779u16, 52884u16, 61192u16, 10095u16, 47009u16, 32710u16, 62617u16, 15614u16, 44080u16, 25687u16, 17867u16, 36268u16, 7522u16, 54533u16, 50107u16, 3036u16, 39698u16, 21365u16, 29417u16, 47758u16, 10816u16, 57895u16, 27000u16, 41247u16, 12753u16, 63926u16, 55338u16, 4173u16, 32899u16, 18660u16, 44543u16, 26008u16, 62806u16, 15665u16, 7341u16, 54474u16, 17412u16, 35939u16, 1852u16, 53083u16, 24469u16, 38898u16, 46702u16, 32265u16, 61127u16, 9888u16, 12318u16, 63609u16, 26807u16, 41168u16, 33100u16, 18731u16, 55781u16, 4482u16, 39645u16, 21178u16, 49780u16, 2579u16, 11151u16, 58344u16, 29478u16, 47937u16, 29047u16, 47376u16, 10718u16, 57785u16, 49189u16, 2114u16, 39052u16, 20715u16, 56244u16, 5075u16, 33565u16, 19322u16, 27366u16, 41601u16, 12879u16, 64040u16, 60566u16, 9457u16, 46143u16, 31832u16, 24004u16, 38307u16, 1389u16, 52490u16, 18005u16, 36402u16, 7932u16, 54939u16, 63239u16, 16224u16, 44974u16, 26569u16, 33490u16, 19125u16, 55931u16, 4636u16, 13184u16, 64487u16, 27433u16, 41806u16, 10257u16, 57462u16, 28856u16, 47327u16, 39235u16, 20772u16, 49642u16, 2445u16, 7987u16, 55124u16, 18330u16, 36861u16, 44641u16, 26118u16, 63176u16, 16047u16, 46576u16, 32151u16, 60761u16, 9534u16, 1186u16, 52421u16, 23563u16, 37996u16];
 
 #[rustfmt::skip]
-pub(crate) const REF_16_8408: [u16; 256] = [0u16, 4489u16, 8978u16, 12955u16, 17956u16, 22445u16, 25910u16, 29887u16, 35912u16, 40385u16, 44890u16, 48851u16, 51820u16, 56293u16, 59774u16, 63735u16, 4225u16, 264u16, 13203u16, 8730u16, 22181u16, 18220u16, 30135u16, 25662u16, 40137u16, 36160u16, 49115u16, 44626u16, 56045u16, 52068u16, 63999u16, 59510u16, 8450u16, 12427u16, 528u16, 5017u16, 26406u16, 30383u16, 17460u16, 21949u16, 44362u16, 48323u16, 36440u16, 40913u16, 60270u16, 64231u16, 51324u16, 55797u16, 12675u16, 8202u16, 4753u16, 792u16, 30631u16, 26158u16, 21685u16, 17724u16, 48587u16, 44098u16, 40665u16, 36688u16, 64495u16, 60006u16, 55549u16, 51572u16, 16900u16, 21389u16, 24854u16, 28831u16, 1056u16, 5545u16, 10034u16, 14011u16, 52812u16, 57285u16, 60766u16, 64727u16, 34920u16, 39393u16, 43898u16, 47859u16, 21125u16, 17164u16, 29079u16, 24606u16, 5281u16, 1320u16, 14259u16, 9786u16, 57037u16, 53060u16, 64991u16, 60502u16, 39145u16, 35168u16, 48123u16, 43634u16, 25350u16, 29327u16, 16404u16, 20893u16, 9506u16, 13483u16, 1584u16, 6073u16, 61262u16, 65223u16, 52316u16, 56789u16, 43370u16, 47331u16, 35448u16, 39921u16, 29575u16, 25102u16, 20629u16, 16668u16, 13731u16, 9258u16, 5809u16, 1848u16, 65487u16, 60998u16, 56541u16, 52564u16, 47595u16, 43106u16, 39673u16, 35696u16, 33800u16, 38273u16, 42778u16, 46739u16, 49708u16, 54181u16, 57662u16, 61623u16, 2112u16, 6601u16, 11090u16, 15067u16, 20068u16, 24557u16, 28022u16, 31999u16, 38025u16, 34048u16, 47003u16, 42514u16, 53933u16, 49956u16, 61887u16, 57398u16, 6337u16, 2376u16, 15315u16, 10842u16, 24293u16, 20332u16, 32247u16, 27774u16, 42250u16, 46211u16, 34328u16, 38801u16, 58158u16, 62119u16, 49212u16, 53685u16, 10562u16, 14539u16, 2640u16, 7129u16, 28518u16, 32495u16, 19572u16, 24061u16, 46475u16, 41986u16, 38553u16, 34576u16, 62383u16, 57894u16, 53437u16, 49460u16, 14787u16, 10314u16, 6865u16, 2904u16, 32743u16, 28270u16, 23797u16, 19836u16, 50700u16, 55173u16, 58654u16, 62615u16, 32808u16, 37281u16, 41786u16, 45747u16, 19012u16, 23501u16, 26966u16, 30943u16, 3168u16, 7657u16, 12146u16, 16123u16, 54925u16, 50948u16, 62879u16, 58390u16, 37033u16, 33056u16, 46011u16, 41522u16, 23237u16, 19276u16, 31191u16, 26718u16, 7393u16, 3432u16, 16371u16, 11898u16, 59150u16, 63111u16, 50204u16, 54677u16, 41258u16, 45219u16, 33336u16, 37809u16, 27462u16, 31439u16, 18516u16, 23005u16, 11618u16, 15595u16, 3696u16, 8185u16, 63375u16, 58886u16, 54429u16, 50452u16, 45483u16, 40994u16, 37561u16, 33584u16, 31687u16, 27214u16, 22741u16, 18780u16, 15843u16, 11370u16, 7921u16, 3960u16];
+pub(crate) static REF_16_8408: [u16; 256] = [0u16, 4489u16, 8978u16, 12955u16, 17956u16, 22445u16, 25910u16, 29887u16, 35912u16, 40385u16, 44890u16, 48851u16, 51820u16, 56293u16, 59774u16, 63735u16, 4225u16, 264u16, 13203u16, 8730u16, 22181u16, 18220u16, 30135u16, 25662u16, 40137u16, 36160u16, 49115u16, 44626u16, 56045u16, 52068u16, 63999u16, 59510u16, 8450u16, 12427u16, 528u16, 5017u16, 26406u16, 30383u16, 17460u16, 21949u16, 44362u16, 48323u16, 36440u16, 40913u16, 60270u16, 64231u16, 51324u16, 55797u16, 12675u16, 8202u16, 4753u16, 792u16, 30631u16, 26158u16, 21685u16, 17724u16, 48587u16, 44098u16, 40665u16, 36688u16, 64495u16, 60006u16, 55549u16, 51572u16, 16900u16, 21389u16, 24854u16, 28831u16, 1056u16, 5545u16, 10034u16, 14011u16, 52812u16, 57285u16, 60766u16, 64727u16, 34920u16, 39393u16, 43898u16, 47859u16, 21125u16, 17164u16, 29079u16, 24606u16, 5281u16, 1320u16, 14259u16, 9786u16, 57037u16, 53060u16, 64991u16, 60502u16, 39145u16, 35168u16, 48123u16, 43634u16, 25350u16, 29327u16, 16404u16, 20893u16, 9506u16, 13483u16, 1584u16, 6073u16, 61262u16, 65223u16, 52316u16, 56789u16, 43370u16, 47331u16, 35448u16, 39921u16, 29575u16, 25102u16, 20629u16, 16668u16, 13731u16, 9258u16, 5809u16, 1848u16, 65487u16, 60998u16, 56541u16, 52564u16, 47595u16, 43106u16, 39673u16, 35696u16, 33800u16, 38273u16, 42778u16, 46739u16, 49708u16, 54181u16, 57662u16, 61623u16, 2112u16, 6601u16, 11090u16, 15067u16, 20068u16, 24557u16, 28022u16, 31999u16, 38025u16, 34048u16, 47003u16, 42514u16, 53933u16, 49956u16, 61887u16, 57398u16, 6337u16, 2376u16, 15315u16, 10842u16, 24293u16, 20332u16, 32247u16, 27774u16, 42250u16, 46211u16, 34328u16, 38801u16, 58158u16, 62119u16, 49212u16, 53685u16, 10562u16, 14539u16, 2640u16, 7129u16, 28518u16, 32495u16, 19572u16, 24061u16, 46475u16, 41986u16, 38553u16, 34576u16, 62383u16, 57894u16, 53437u16, 49460u16, 14787u16, 10314u16, 6865u16, 2904u16, 32743u16, 28270u16, 23797u16, 19836u16, 50700u16, 55173u16, 58654u16, 62615u16, 32808u16, 37281u16, 41786u16, 45747u16, 19012u16, 23501u16, 26966u16, 30943u16, 3168u16, 7657u16, 12146u16, 16123u16, 54925u16, 50948u16, 62879u16, 58390u16, 37033u16, 33056u16, 46011u16, 41522u16, 23237u16, 19276u16, 31191u16, 26718u16, 7393u16, 3432u16, 16371u16, 11898u16, 59150u16, 63111u16, 50204u16, 54677u16, 41258u16, 45219u16, 33336u16, 37809u16, 27462u16, 31439u16, 18516u16, 23005u16, 11618u16, 15595u16, 3696u16, 8185u16, 63375u16, 58886u16, 54429u16, 50452u16, 45483u16, 40994u16, 37561u16, 33584u16, 31687u16, 27214u16, 22741u16, 18780u16, 15843u16, 11370u16, 7921u16, 3960u16];
 
 #[rustfmt::skip]
-pub(crate) const REF_16_A001: [u16; 256] = [0u16, 49345u16, 49537u16, 320u16, 49921u16, 960u16, 640u16, 49729u16, 50689u16, 1728u16, 1920u16, 51009u16, 1280u16, 50625u16, 50305u16, 1088u16, 52225u16, 3264u16, 3456u16, 52545u16, 3840u16, 53185u16, 52865u16, 3648u16, 2560u16, 51905u16, 52097u16, 2880u16, 51457u16, 2496u16, 2176u16, 51265u16, 55297u16, 6336u16, 6528u16, 55617u16, 6912u16, 56257u16, 55937u16, 6720u16, 7680u16, 57025u16, 57217u16, 8000u16, 56577u16, 7616u16, 7296u16, 56385u16, 5120u16, 54465u16, 54657u16, 5440u16, 55041u16, 6080u16, 5760u16, 54849u16, 53761u16, 4800u16, 4992u16, 54081u16, 4352u16, 53697u16, 53377u16, 4160u16, 61441u16, 12480u16, 12672u16, 61761u16, 13056u16, 62401u16, 62081u16, 12864u16, 13824u16, 63169u16, 63361u16, 14144u16, 62721u16, 13760u16, 13440u16, 62529u16, 15360u16, 64705u16, 64897u16, 15680u16, 65281u16, 16320u16, 16000u16, 65089u16, 64001u16, 15040u16, 15232u16, 64321u16, 14592u16, 63937u16, 63617u16, 14400u16, 10240u16, 59585u16, 59777u16, 10560u16, 60161u16, 11200u16, 10880u16, 59969u16, 60929u16, 11968u16, 12160u16, 61249u16, 11520u16, 60865u16, 60545u16, 11328u16, 58369u16, 9408u16, 9600u16, 58689u16, 9984u16, 59329u16, 59009u16, 9792u16, 8704u16, 58049u16, 58241u16, 9024u16, 57601u16, 8640u16, 8320u16, 57409u16, 40961u16, 24768u16, 24960u16, 41281u16, 25344u16, 41921u16, 41601u16, 25152u16, 26112u16, 42689u16, 42881u16, 26432u16, 42241u16, 26048u16, 25728u16, 42049u16, 27648u16, 44225u16, 44417u16, 27968u16, 44801u16, 28608u16, 28288u16, 44609u16, 43521u16, 27328u16, 27520u16, 43841u16, 26880u16, 43457u16, 43137u16, 26688u16, 30720u16, 47297u16, 47489u16, 31040u16, 47873u16, 31680u16, 31360u16, 47681u16, 48641u16, 32448u16, 32640u16, 48961u16, 32000u16, 48577u16, 48257u16, 31808u16, 46081u16, 29888u16, 30080u16, 46401u16, 30464u16, 47041u16, 46721u16, 30272u16, 29184u16, 45761u16, 45953u16, 29504u16, 45313u16, 29120u16, 28800u16, 45121u16, 20480u16, 37057u16, 37249u16, 20800u16, 37633u16, 21440u16, 21120u16, 37441u16, 38401u16, 22208u16, 22400u16, 38721u16, 21760u16, 38337u16, 38017u16, 21568u16, 39937u16, 23744u16, 23936u16, 40257u16, 24320u16, 40897u16, 40577u16, 24128u16, 23040u16, 39617u16, 39809u16, 23360u16, 39169u16, 22976u16, 22656u16, 38977u16, 34817u16, 18624u16, 18816u16, 35137u16, 19200u16, 35777u16, 35457u16, 19008u16, 19968u16, 36545u16, 36737u16, 20288u16, 36097u16, 19904u16, 19584u16, 35905u16, 17408u16, 33985u16, 34177u16, 17728u16, 34561u16, 18368u16, 18048u16, 34369u16, 33281u16, 17088u16, 17280u16, 33601u16, 16640u16, 33217u16, 32897u16, 16448u16];
+pub(crate) static REF_16_A001: [u16; 256] = [0u16, 49345u16, 49537u16, 320u16, 49921u16, 960u16, 640u16, 49729u16, 50689u16, 1728u16, 1920u16, 51009u16, 1280u16, 50625u16, 50305u16, 1088u16, 52225u16, 3264u16, 3456u16, 52545u16, 3840u16, 53185u16, 52865u16, 3648u16, 2560u16, 51905u16, 52097u16, 2880u16, 51457u16, 2496u16, 2176u16, 51265u16, 55297u16, 6336u16, 6528u16, 55617u16, 6912u16, 56257u16, 55937u16, 6720u16, 7680u16, 57025u16, 57217u16, 8000u16, 56577u16, 7616u16, 7296u16, 56385u16, 5120u16, 54465u16, 54657u16, 5440u16, 55041u16, 6080u16, 5760u16, 54849u16, 53761u16, 4800u16, 4992u16, 54081u16, 4352u16, 53697u16, 53377u16, 4160u16, 61441u16, 12480u16, 12672u16, 61761u16, 13056u16, 62401u16, 62081u16, 12864u16, 13824u16, 63169u16, 63361u16, 14144u16, 62721u16, 13760u16, 13440u16, 62529u16, 15360u16, 64705u16, 64897u16, 15680u16, 65281u16, 16320u16, 16000u16, 65089u16, 64001u16, 15040u16, 15232u16, 64321u16, 14592u16, 63937u16, 63617u16, 14400u16, 10240u16, 59585u16, 59777u16, 10560u16, 60161u16, 11200u16, 10880u16, 59969u16, 60929u16, 11968u16, 12160u16, 61249u16, 11520u16, 60865u16, 60545u16, 11328u16, 58369u16, 9408u16, 9600u16, 58689u16, 9984u16, 59329u16, 59009u16, 9792u16, 8704u16, 58049u16, 58241u16, 9024u16, 57601u16, 8640u16, 8320u16, 57409u16, 40961u16, 24768u16, 24960u16, 41281u16, 25344u16, 41921u16, 41601u16, 25152u16, 26112u16, 42689u16, 42881u16, 26432u16, 42241u16, 26048u16, 25728u16, 42049u16, 27648u16, 44225u16, 44417u16, 27968u16, 44801u16, 28608u16, 28288u16, 44609u16, 43521u16, 27328u16, 27520u16, 43841u16, 26880u16, 43457u16, 43137u16, 26688u16, 30720u16, 47297u16, 47489u16, 31040u16, 47873u16, 31680u16, 31360u16, 47681u16, 48641u16, 32448u16, 32640u16, 48961u16, 32000u16, 48577u16, 48257u16, 31808u16, 46081u16, 29888u16, 30080u16, 46401u16, 30464u16, 47041u16, 46721u16, 30272u16, 29184u16, 45761u16, 45953u16, 29504u16, 45313u16, 29120u16, 28800u16, 45121u16, 20480u16, 37057u16, 37249u16, 20800u16, 37633u16, 21440u16, 21120u16, 37441u16, 38401u16, 22208u16, 22400u16, 38721u16, 21760u16, 38337u16, 38017u16, 21568u16, 39937u16, 23744u16, 23936u16, 40257u16, 24320u16, 40897u16, 40577u16, 24128u16, 23040u16, 39617u16, 39809u16, 23360u16, 39169u16, 22976u16, 22656u16, 38977u16, 34817u16, 18624u16, 18816u16, 35137u16, 19200u16, 35777u16, 35457u16, 19008u16, 19968u16, 36545u16, 36737u16, 20288u16, 36097u16, 19904u16, 19584u16, 35905u16, 17408u16, 33985u16, 34177u16, 17728u16, 34561u16, 18368u16, 18048u16, 34369u16, 33281u16, 17088u16, 17280u16, 33601u16, 16640u16, 33217u16, 32897u16, 16448u16];
 
 #[rustfmt::skip]
-pub(crate) const REF_16_A097: [u16; 256] = [0u16, 41111u16, 57785u16, 16686u16, 25573u16, 50034u16, 33372u16, 8907u16, 51146u16, 26461u16, 9843u16, 34532u16, 42031u16, 1208u16, 17814u16, 58625u16, 12035u16, 36756u16, 52922u16, 28205u16, 19686u16, 60529u16, 44383u16, 3528u16, 59593u16, 18526u16, 2416u16, 43495u16, 35628u16, 11195u16, 27285u16, 51714u16, 24070u16, 65169u16, 49087u16, 7976u16, 15843u16, 40308u16, 56410u16, 31949u16, 39372u16, 14683u16, 30837u16, 55522u16, 64041u16, 23230u16, 7056u16, 47879u16, 28933u16, 53650u16, 37052u16, 12331u16, 4832u16, 45687u16, 62297u16, 21454u16, 46799u16, 5720u16, 22390u16, 63457u16, 54570u16, 30141u16, 13459u16, 37892u16, 48140u16, 7323u16, 23989u16, 64802u16, 57321u16, 32638u16, 15952u16, 40647u16, 31686u16, 56145u16, 39551u16, 15080u16, 6179u16, 47284u16, 63898u16, 22797u16, 37647u16, 13208u16, 29366u16, 53793u16, 61674u16, 20605u16, 4435u16, 45508u16, 21701u16, 62546u16, 46460u16, 5611u16, 14112u16, 38839u16, 54937u16, 30222u16, 57866u16, 17053u16, 947u16, 41764u16, 33263u16, 8568u16, 24662u16, 49345u16, 9664u16, 34135u16, 50297u16, 25838u16, 17957u16, 59058u16, 42908u16, 1803u16, 52489u16, 28062u16, 11440u16, 35879u16, 44780u16, 3707u16, 20309u16, 61378u16, 2755u16, 43604u16, 60282u16, 19437u16, 26918u16, 51633u16, 34975u16, 10248u16, 55439u16, 30744u16, 14646u16, 39329u16, 47978u16, 7165u16, 23251u16, 64068u16, 8005u16, 49106u16, 65276u16, 24171u16, 31904u16, 56375u16, 40217u16, 15758u16, 63372u16, 22299u16, 5685u16, 46754u16, 37993u16, 13566u16, 30160u16, 54599u16, 12358u16, 37073u16, 53759u16, 29032u16, 21411u16, 62260u16, 45594u16, 4749u16, 34441u16, 9758u16, 26416u16, 51111u16, 58732u16, 17915u16, 1237u16, 42050u16, 16707u16, 57812u16, 41210u16, 109u16, 8870u16, 33329u16, 49951u16, 25480u16, 43402u16, 2333u16, 18483u16, 59556u16, 51823u16, 27384u16, 11222u16, 35649u16, 28224u16, 52951u16, 36857u16, 12142u16, 3493u16, 44338u16, 60444u16, 19595u16, 25731u16, 50196u16, 34106u16, 9645u16, 1894u16, 42993u16, 59103u16, 17992u16, 41801u16, 990u16, 17136u16, 57959u16, 49324u16, 24635u16, 8469u16, 33154u16, 19328u16, 60183u16, 43577u16, 2734u16, 10341u16, 35058u16, 51676u16, 26955u16, 35914u16, 11485u16, 28147u16, 52580u16, 61359u16, 20280u16, 3606u16, 44673u16, 14981u16, 39442u16, 56124u16, 31659u16, 22880u16, 63991u16, 47321u16, 6222u16, 64847u16, 24024u16, 7414u16, 48225u16, 40618u16, 15933u16, 32531u16, 57220u16, 5510u16, 46353u16, 62527u16, 21672u16, 30307u16, 55028u16, 38874u16, 14157u16, 53836u16, 29403u16, 13301u16, 37730u16, 45481u16, 4414u16, 20496u16, 61575u16];
+pub(crate) static REF_16_A097: [u16; 256] = [0u16, 41111u16, 57785u16, 16686u16, 25573u16, 50034u16, 33372u16, 8907u16, 51146u16, 26461u16, 9843u16, 34532u16, 42031u16, 1208u16, 17814u16, 58625u16, 12035u16, 36756u16, 52922u16, 28205u16, 19686u16, 60529u16, 44383u16, 3528u16, 59593u16, 18526u16, 2416u16, 43495u16, 35628u16, 11195u16, 27285u16, 51714u16, 24070u16, 65169u16, 49087u16, 7976u16, 15843u16, 40308u16, 56410u16, 31949u16, 39372u16, 14683u16, 30837u16, 55522u16, 64041u16, 23230u16, 7056u16, 47879u16, 28933u16, 53650u16, 37052u16, 12331u16, 4832u16, 45687u16, 62297u16, 21454u16, 46799u16, 5720u16, 22390u16, 63457u16, 54570u16, 30141u16, 13459u16, 37892u16, 48140u16, 7323u16, 23989u16, 64802u16, 57321u16, 32638u16, 15952u16, 40647u16, 31686u16, 56145u16, 39551u16, 15080u16, 6179u16, 47284u16, 63898u16, 22797u16, 37647u16, 13208u16, 29366u16, 53793u16, 61674u16, 20605u16, 4435u16, 45508u16, 21701u16, 62546u16, 46460u16, 5611u16, 14112u16, 38839u16, 54937u16, 30222u16, 57866u16, 17053u16, 947u16, 41764u16, 33263u16, 8568u16, 24662u16, 49345u16, 9664u16, 34135u16, 50297u16, 25838u16, 17957u16, 59058u16, 42908u16, 1803u16, 52489u16, 28062u16, 11440u16, 35879u16, 44780u16, 3707u16, 20309u16, 61378u16, 2755u16, 43604u16, 60282u16, 19437u16, 26918u16, 51633u16, 34975u16, 10248u16, 55439u16, 30744u16, 14646u16, 39329u16, 47978u16, 7165u16, 23251u16, 64068u16, 8005u16, 49106u16, 65276u16, 24171u16, 31904u16, 56375u16, 40217u16, 15758u16, 63372u16, 22299u16, 5685u16, 46754u16, 37993u16, 13566u16, 30160u16, 54599u16, 12358u16, 37073u16, 53759u16, 29032u16, 21411u16, 62260u16, 45594u16, 4749u16, 34441u16, 9758u16, 26416u16, 51111u16, 58732u16, 17915u16, 1237u16, 42050u16, 16707u16, 57812u16, 41210u16, 109u16, 8870u16, 33329u16, 49951u16, 25480u16, 43402u16, 2333u16, 18483u16, 59556u16, 51823u16, 27384u16, 11222u16, 35649u16, 28224u16, 52951u16, 36857u16, 12142u16, 3493u16, 44338u16, 60444u16, 19595u16, 25731u16, 50196u16, 34106u16, 9645u16, 1894u16, 42993u16, 59103u16, 17992u16, 41801u16, 990u16, 17136u16, 57959u16, 49324u16, 24635u16, 8469u16, 33154u16, 19328u16, 60183u16, 43577u16, 2734u16, 10341u16, 35058u16, 51676u16, 26955u16, 35914u16, 11485u16, 28147u16, 52580u16, 61359u16, 20280u16, 3606u16, 44673u16, 14981u16, 39442u16, 56124u16, 31659u16, 22880u16, 63991u16, 47321u16, 6222u16, 64847u16, 24024u16, 7414u16, 48225u16, 40618u16, 15933u16, 32531u16, 57220u16, 5510u16, 46353u16, 62527u16, 21672u16, 30307u16, 55028u16, 38874u16, 14157u16, 53836u16, 29403u16, 13301u16, 37730u16, 45481u16, 4414u16, 20496u16, 61575u16];
 
 #[rustfmt::skip]
-pub(crate) const REF_16_A6BC: [u16; 256] = [0u16, 13918u16, 27836u16, 23266u16, 55672u16, 61222u16, 46532u16, 33690u16, 65417u16, 51671u16, 37685u16, 42347u16, 9969u16, 4271u16, 19021u16, 31763u16, 45675u16, 33845u16, 57047u16, 59529u16, 27411u16, 23885u16, 1967u16, 12785u16, 19938u16, 31676u16, 8542u16, 5888u16, 38042u16, 41668u16, 63526u16, 52856u16, 10671u16, 8177u16, 17683u16, 29517u16, 61655u16, 50825u16, 40043u16, 43573u16, 54822u16, 57464u16, 47770u16, 36036u16, 3934u16, 14592u16, 25570u16, 21948u16, 39876u16, 44442u16, 63352u16, 49446u16, 17084u16, 29922u16, 11776u16, 6238u16, 25677u16, 21011u16, 2289u16, 16047u16, 48437u16, 35691u16, 53641u16, 59351u16, 21342u16, 25856u16, 16354u16, 2492u16, 35366u16, 48248u16, 59034u16, 53444u16, 44247u16, 39561u16, 49259u16, 63029u16, 30127u16, 17393u16, 6419u16, 12109u16, 57653u16, 55147u16, 36233u16, 48087u16, 14413u16, 3603u16, 21745u16, 25263u16, 7868u16, 10466u16, 29184u16, 17502u16, 51140u16, 61850u16, 43896u16, 40230u16, 31473u16, 19631u16, 5709u16, 8211u16, 41865u16, 38359u16, 53045u16, 63851u16, 34168u16, 45862u16, 59844u16, 57242u16, 23552u16, 27230u16, 12476u16, 1762u16, 51354u16, 65220u16, 42022u16, 37496u16, 4578u16, 10172u16, 32094u16, 19200u16, 14099u16, 333u16, 23471u16, 28145u16, 61035u16, 55349u16, 33495u16, 46217u16, 42684u16, 37090u16, 51712u16, 64606u16, 32708u16, 18842u16, 4984u16, 9510u16, 22837u16, 28523u16, 13705u16, 983u16, 32845u16, 46611u16, 60657u16, 55983u16, 5335u16, 8841u16, 30827u16, 20021u16, 52655u16, 64497u16, 41235u16, 38733u16, 60254u16, 56576u16, 34786u16, 45500u16, 12838u16, 1144u16, 24218u16, 26820u16, 36627u16, 47437u16, 58287u16, 54769u16, 22123u16, 24629u16, 15063u16, 3209u16, 28826u16, 18116u16, 7206u16, 10872u16, 43490u16, 40892u16, 50526u16, 62208u16, 15736u16, 2854u16, 20932u16, 26522u16, 58368u16, 53854u16, 35004u16, 48866u16, 49905u16, 62639u16, 44621u16, 38931u16, 7049u16, 11735u16, 30517u16, 16747u16, 62946u16, 50108u16, 39262u16, 44800u16, 11418u16, 6852u16, 16422u16, 30328u16, 2667u16, 15413u16, 26327u16, 20617u16, 54035u16, 58701u16, 49071u16, 35313u16, 18313u16, 29143u16, 11061u16, 7531u16, 40689u16, 43183u16, 62029u16, 50195u16, 47104u16, 36446u16, 54460u16, 58082u16, 24952u16, 22310u16, 3524u16, 15258u16, 56397u16, 59923u16, 45297u16, 34479u16, 1333u16, 13163u16, 27017u16, 24535u16, 9156u16, 5530u16, 20344u16, 31014u16, 64188u16, 52450u16, 38400u16, 41054u16, 28198u16, 22648u16, 666u16, 13508u16, 46942u16, 33024u16, 56290u16, 60860u16, 37295u16, 42993u16, 64787u16, 52045u16, 18647u16, 32393u16, 9323u16, 4661u16];
+pub(crate) static REF_16_A6BC: [u16; 256] = [0u16, 13918u16, 27836u16, 23266u16, 55672u16, 61222u16, 46532u16, 33690u16, 65417u16, 51671u16, 37685u16, 42347u16, 9969u16, 4271u16, 19021u16, 31763u16, 45675u16, 33845u16, 57047u16, 59529u16, 27411u16, 23885u16, 1967u16, 12785u16, 19938u16, 31676u16, 8542u16, 5888u16, 38042u16, 41668u16, 63526u16, 52856u16, 10671u16, 8177u16, 17683u16, 29517u16, 61655u16, 50825u16, 40043u16, 43573u16, 54822u16, 57464u16, 47770u16, 36036u16, 3934u16, 14592u16, 25570u16, 21948u16, 39876u16, 44442u16, 63352u16, 49446u16, 17084u16, 29922u16, 11776u16, 6238u16, 25677u16, 21011u16, 2289u16, 16047u16, 48437u16, 35691u16, 53641u16, 59351u16, 21342u16, 25856u16, 16354u16, 2492u16, 35366u16, 48248u16, 59034u16, 53444u16, 44247u16, 39561u16, 49259u16, 63029u16, 30127u16, 17393u16, 6419u16, 12109u16, 57653u16, 55147u16, 36233u16, 48087u16, 14413u16, 3603u16, 21745u16, 25263u16, 7868u16, 10466u16, 29184u16, 17502u16, 51140u16, 61850u16, 43896u16, 40230u16, 31473u16, 19631u16, 5709u16, 8211u16, 41865u16, 38359u16, 53045u16, 63851u16, 34168u16, 45862u16, 59844u16, 57242u16, 23552u16, 27230u16, 12476u16, 1762u16, 51354u16, 65220u16, 42022u16, 37496u16, 4578u16, 10172u16, 32094u16, 19200u16, 14099u16, 333u16, 23471u16, 28145u16, 61035u16, 55349u16, 33495u16, 46217u16, 42684u16, 37090u16, 51712u16, 64606u16, 32708u16, 18842u16, 4984u16, 9510u16, 22837u16, 28523u16, 13705u16, 983u16, 32845u16, 46611u16, 60657u16, 55983u16, 5335u16, 8841u16, 30827u16, 20021u16, 52655u16, 64497u16, 41235u16, 38733u16, 60254u16, 56576u16, 34786u16, 45500u16, 12838u16, 1144u16, 24218u16, 26820u16, 36627u16, 47437u16, 58287u16, 54769u16, 22123u16, 24629u16, 15063u16, 3209u16, 28826u16, 18116u16, 7206u16, 10872u16, 43490u16, 40892u16, 50526u16, 62208u16, 15736u16, 2854u16, 20932u16, 26522u16, 58368u16, 53854u16, 35004u16, 48866u16, 49905u16, 62639u16, 44621u16, 38931u16, 7049u16, 11735u16, 30517u16, 16747u16, 62946u16, 50108u16, 39262u16, 44800u16, 11418u16, 6852u16, 16422u16, 30328u16, 2667u16, 15413u16, 26327u16, 20617u16, 54035u16, 58701u16, 49071u16, 35313u16, 18313u16, 29143u16, 11061u16, 7531u16, 40689u16, 43183u16, 62029u16, 50195u16, 47104u16, 36446u16, 54460u16, 58082u16, 24952u16, 22310u16, 3524u16, 15258u16, 56397u16, 59923u16, 45297u16, 34479u16, 1333u16, 13163u16, 27017u16, 24535u16, 9156u16, 5530u16, 20344u16, 31014u16, 64188u16, 52450u16, 38400u16, 41054u16, 28198u16, 22648u16, 666u16, 13508u16, 46942u16, 33024u16, 56290u16, 60860u16, 37295u16, 42993u16, 64787u16, 52045u16, 18647u16, 32393u16, 9323u16, 4661u16];
+
+#[rustfmt::skip]
+pub(crate) static NO_REF_16_6F63: [u16; 256] = [0u16, 28515u16, 57030u16, 45477u16, 53999u16, 48524u16, 3113u16, 25418u16, 51901u16, 42462u16, 5243u16, 31512u16, 6226u16, 30513u16, 50836u16, 43511u16, 64025u16, 38266u16, 9439u16, 19388u16, 10486u16, 18325u16, 63024u16, 39251u16, 12452u16, 24519u16, 61026u16, 33025u16, 57931u16, 36136u16, 15501u16, 21486u16, 39761u16, 62514u16, 17815u16, 10996u16, 18878u16, 9949u16, 38776u16, 63515u16, 20972u16, 16015u16, 36650u16, 57417u16, 33539u16, 60512u16, 24005u16, 12966u16, 24904u16, 3627u16, 49038u16, 53485u16, 45991u16, 56516u16, 28001u16, 514u16, 44021u16, 50326u16, 30003u16, 6736u16, 31002u16, 5753u16, 42972u16, 51391u16, 22977u16, 13986u16, 34567u16, 59492u16, 35630u16, 58445u16, 21992u16, 14987u16, 37756u16, 64543u16, 19898u16, 8921u16, 16787u16, 12016u16, 40789u16, 61494u16, 41944u16, 52411u16, 32030u16, 4733u16, 28983u16, 7764u16, 45041u16, 49298u16, 26981u16, 1542u16, 47011u16, 55488u16, 48010u16, 54505u16, 25932u16, 2607u16, 49808u16, 44531u16, 7254u16, 29493u16, 4223u16, 32540u16, 52921u16, 41434u16, 2093u16, 26446u16, 55019u16, 47496u16, 56002u16, 46497u16, 1028u16, 27495u16, 14473u16, 22506u16, 58959u16, 35116u16, 60006u16, 34053u16, 13472u16, 23491u16, 62004u16, 40279u16, 11506u16, 17297u16, 8411u16, 20408u16, 65053u16, 37246u16, 45954u16, 56545u16, 27972u16, 551u16, 24941u16, 3598u16, 49067u16, 53448u16, 31039u16, 5724u16, 43001u16, 51354u16, 43984u16, 50355u16, 29974u16, 6773u16, 18843u16, 9976u16, 38749u16, 63550u16, 39796u16, 62487u16, 17842u16, 10961u16, 33574u16, 60485u16, 24032u16, 12931u16, 20937u16, 16042u16, 36623u16, 57452u16, 10451u16, 18352u16, 62997u16, 39286u16, 64060u16, 38239u16, 9466u16, 19353u16, 57966u16, 36109u16, 15528u16, 21451u16, 12417u16, 24546u16, 60999u16, 33060u16, 53962u16, 48553u16, 3084u16, 25455u16, 37u16, 28486u16, 57059u16, 45440u16, 6263u16, 30484u16, 50865u16, 43474u16, 51864u16, 42491u16, 5214u16, 31549u16, 59971u16, 34080u16, 13445u16, 23526u16, 14508u16, 22479u16, 58986u16, 35081u16, 8446u16, 20381u16, 65080u16, 37211u16, 61969u16, 40306u16, 11479u16, 17332u16, 4186u16, 32569u16, 52892u16, 41471u16, 49845u16, 44502u16, 7283u16, 29456u16, 56039u16, 46468u16, 1057u16, 27458u16, 2056u16, 26475u16, 54990u16, 47533u16, 28946u16, 7793u16, 45012u16, 49335u16, 41981u16, 52382u16, 32059u16, 4696u16, 48047u16, 54476u16, 25961u16, 2570u16, 26944u16, 1571u16, 46982u16, 55525u16, 35595u16, 58472u16, 21965u16, 15022u16, 23012u16, 13959u16, 34594u16, 59457u16, 16822u16, 11989u16, 40816u16, 61459u16, 37721u16, 64570u16, 19871u16, 8956u16];
+
+#[rustfmt::skip]
+pub(crate) static NO_REF_16_5935: [u16; 256] = [0u16, 22837u16, 45674u16, 60255u16, 15841u16, 25812u16, 36747u16, 54974u16, 31682u16, 8951u16, 51624u16, 37021u16, 17955u16, 7958u16, 62537u16, 44412u16, 63364u16, 44721u16, 17902u16, 7387u16, 51813u16, 37712u16, 30735u16, 8506u16, 35910u16, 54643u16, 15916u16, 26393u16, 45479u16, 59538u16, 973u16, 23288u16, 46653u16, 61192u16, 1111u16, 23906u16, 35804u16, 53993u16, 14774u16, 24707u16, 52735u16, 38090u16, 32661u16, 9888u16, 61470u16, 43307u16, 17012u16, 6977u16, 16825u16, 6284u16, 62419u16, 43750u16, 31832u16, 9581u16, 52786u16, 38663u16, 14971u16, 25422u16, 34833u16, 53540u16, 1946u16, 24239u16, 46576u16, 60613u16, 13647u16, 27770u16, 34597u16, 56848u16, 2222u16, 20891u16, 47812u16, 58353u16, 20109u16, 6072u16, 64743u16, 42450u16, 29548u16, 10841u16, 49414u16, 38963u16, 49867u16, 39934u16, 28833u16, 10644u16, 65322u16, 42527u16, 19776u16, 5237u16, 47369u16, 57404u16, 2915u16, 21078u16, 34024u16, 56797u16, 13954u16, 28599u16, 33650u16, 55879u16, 12568u16, 26669u16, 48787u16, 59302u16, 3321u16, 21964u16, 63664u16, 41349u16, 19162u16, 5103u16, 50513u16, 40036u16, 30523u16, 11790u16, 29942u16, 11715u16, 50844u16, 40873u16, 18711u16, 4130u16, 64381u16, 41544u16, 3892u16, 22017u16, 48478u16, 58475u16, 13013u16, 27616u16, 32959u16, 55690u16, 27294u16, 13227u16, 55540u16, 33217u16, 22399u16, 3658u16, 58645u16, 48160u16, 4444u16, 18537u16, 41782u16, 64003u16, 11453u16, 30088u16, 40663u16, 51170u16, 40218u16, 50223u16, 12144u16, 30277u16, 41211u16, 63950u16, 4753u16, 19364u16, 59096u16, 49133u16, 21682u16, 3463u16, 56121u16, 33292u16, 26963u16, 12390u16, 56483u16, 34198u16, 28361u16, 14332u16, 57666u16, 47223u16, 21288u16, 2589u16, 42849u16, 65108u16, 5387u16, 19518u16, 39552u16, 50101u16, 10474u16, 29151u16, 11047u16, 29202u16, 39245u16, 49272u16, 5830u16, 20467u16, 42156u16, 64921u16, 20709u16, 2512u16, 57999u16, 48058u16, 27908u16, 13361u16, 57198u16, 34395u16, 24529u16, 1764u16, 60859u16, 46222u16, 25136u16, 15109u16, 53338u16, 35183u16, 9235u16, 32038u16, 38521u16, 53068u16, 6642u16, 16583u16, 43928u16, 62125u16, 43093u16, 61792u16, 6719u16, 17162u16, 38324u16, 52353u16, 10206u16, 32491u16, 54167u16, 35490u16, 25085u16, 14536u16, 61046u16, 46915u16, 23580u16, 1321u16, 59884u16, 45273u16, 23430u16, 691u16, 54285u16, 36152u16, 26215u16, 16210u16, 37422u16, 51995u16, 8260u16, 31089u16, 45007u16, 63226u16, 7589u16, 17552u16, 7784u16, 18269u16, 44034u16, 62775u16, 9097u16, 31420u16, 37347u16, 51414u16, 26026u16, 15519u16, 55232u16, 36597u16, 22603u16, 382u16, 59937u16, 45844u16];
+
+#[rustfmt::skip]
+pub(crate) static REF_16_D010: [u16; 256] = [0u16, 13732u16, 27464u16, 24300u16, 54928u16, 58164u16, 48600u16, 34940u16, 3329u16, 14501u16, 26185u16, 21485u16, 56209u16, 60981u16, 45273u16, 34173u16, 6658u16, 12198u16, 29002u16, 17646u16, 52370u16, 63798u16, 42970u16, 37502u16, 5891u16, 8871u16, 31819u16, 18927u16, 49555u16, 62519u16, 43739u16, 40831u16, 13316u16, 416u16, 24396u16, 27368u16, 58004u16, 55088u16, 35292u16, 48248u16, 14597u16, 3233u16, 21069u16, 26601u16, 61333u16, 55857u16, 34013u16, 45433u16, 11782u16, 7074u16, 17742u16, 28906u16, 63638u16, 52530u16, 37854u16, 42618u16, 8967u16, 5795u16, 18511u16, 32235u16, 62871u16, 49203u16, 40671u16, 43899u16, 26632u16, 23980u16, 832u16, 14052u16, 48792u16, 35644u16, 54736u16, 57460u16, 25865u16, 20653u16, 3649u16, 15333u16, 45977u16, 34365u16, 55505u16, 60789u16, 29194u16, 18350u16, 6466u16, 11494u16, 42138u16, 37182u16, 53202u16, 64118u16, 32523u16, 19119u16, 5187u16, 8679u16, 43419u16, 39999u16, 49875u16, 63351u16, 23564u16, 27048u16, 14148u16, 736u16, 35484u16, 48952u16, 57812u16, 54384u16, 20749u16, 25769u16, 14917u16, 4065u16, 34717u16, 45625u16, 60629u16, 55665u16, 17934u16, 29610u16, 11590u16, 6370u16, 37022u16, 42298u16, 64470u16, 52850u16, 19215u16, 32427u16, 8263u16, 5603u16, 40351u16, 43067u16, 63191u16, 50035u16, 53264u16, 58804u16, 47960u16, 36604u16, 1664u16, 13092u16, 28104u16, 22636u16, 56593u16, 59573u16, 46681u16, 33789u16, 2945u16, 15909u16, 24777u16, 21869u16, 51730u16, 65462u16, 41306u16, 38142u16, 7298u16, 10534u16, 30666u16, 17006u16, 50963u16, 62135u16, 44123u16, 39423u16, 4483u16, 9255u16, 31435u16, 20335u16, 58388u16, 53680u16, 36700u16, 47864u16, 12932u16, 1824u16, 22988u16, 27752u16, 59669u16, 56497u16, 33373u16, 47097u16, 16261u16, 2593u16, 21709u16, 24937u16, 65046u16, 52146u16, 38238u16, 41210u16, 10374u16, 7458u16, 17358u16, 30314u16, 62231u16, 50867u16, 39007u16, 44539u16, 9607u16, 4131u16, 20175u16, 31595u16, 47128u16, 36284u16, 54096u16, 59124u16, 28296u16, 23340u16, 1472u16, 12388u16, 46361u16, 32957u16, 56913u16, 60405u16, 25481u16, 22061u16, 2241u16, 15717u16, 41498u16, 38846u16, 51538u16, 64758u16, 29834u16, 16686u16, 8130u16, 10854u16, 44827u16, 39615u16, 50259u16, 61943u16, 31115u16, 19503u16, 4803u16, 10087u16, 35868u16, 47544u16, 59220u16, 54000u16, 23180u16, 28456u16, 12740u16, 1120u16, 33053u16, 46265u16, 59989u16, 57329u16, 22413u16, 25129u16, 15557u16, 2401u16, 38430u16, 41914u16, 64854u16, 51442u16, 16526u16, 29994u16, 11206u16, 7778u16, 39711u16, 44731u16, 61527u16, 50675u16, 19855u16, 30763u16, 9927u16, 4963u16];
+
+#[rustfmt::skip]
+pub(crate) static NO_REF_16_755B: [u16; 256] = [0u16, 30043u16, 60086u16, 40941u16, 41015u16, 54636u16, 19073u16, 16346u16, 13621u16, 16494u16, 57219u16, 43736u16, 38146u16, 57433u16, 32692u16, 2799u16, 27242u16, 7985u16, 32988u16, 62855u16, 51805u16, 48902u16, 8427u16, 21936u16, 24415u16, 10756u16, 46569u16, 49330u16, 65384u16, 35379u16, 5598u16, 24709u16, 54484u16, 41359u16, 15970u16, 19257u16, 29923u16, 440u16, 40533u16, 60174u16, 57825u16, 38074u16, 2903u16, 32268u16, 16854u16, 13453u16, 43872u16, 56891u16, 48830u16, 52197u16, 21512u16, 8531u16, 7817u16, 27602u16, 62527u16, 33124u16, 35723u16, 65232u16, 24893u16, 5222u16, 11196u16, 24295u16, 49418u16, 46161u16, 56563u16, 43432u16, 13893u16, 17182u16, 31940u16, 2463u16, 38514u16, 58153u16, 59846u16, 40093u16, 880u16, 30251u16, 18929u16, 15530u16, 41799u16, 54812u16, 46745u16, 50114u16, 23599u16, 10612u16, 5806u16, 25589u16, 64536u16, 35139u16, 33708u16, 63223u16, 26906u16, 7233u16, 9115u16, 22208u16, 51501u16, 48246u16, 2087u16, 32124u16, 58001u16, 38858u16, 43024u16, 56651u16, 17062u16, 14333u16, 15634u16, 18505u16, 55204u16, 41727u16, 40229u16, 59518u16, 30611u16, 712u16, 25165u16, 5910u16, 35067u16, 64928u16, 49786u16, 46881u16, 10444u16, 23959u16, 22392u16, 8739u16, 48590u16, 51349u16, 63311u16, 33300u16, 7673u16, 26786u16, 52413u16, 47590u16, 9739u16, 21328u16, 27786u16, 6609u16, 34364u16, 62311u16, 63880u16, 36051u16, 4926u16, 26213u16, 22975u16, 11492u16, 45833u16, 50770u16, 42711u16, 54156u16, 19553u16, 14650u16, 1760u16, 29627u16, 60502u16, 39181u16, 37858u16, 59065u16, 31060u16, 3087u16, 13269u16, 18062u16, 55651u16, 44088u16, 6249u16, 27954u16, 62175u16, 34692u16, 47198u16, 52485u16, 21224u16, 10163u16, 11612u16, 22535u16, 51178u16, 45745u16, 36203u16, 63536u16, 26589u16, 4742u16, 29187u16, 1880u16, 39093u16, 60910u16, 53812u16, 42863u16, 14466u16, 19929u16, 18230u16, 12909u16, 44416u16, 55515u16, 59137u16, 37466u16, 3511u16, 30956u16, 4174u16, 25877u16, 64248u16, 36771u16, 45177u16, 50466u16, 23247u16, 12180u16, 9595u16, 20512u16, 53197u16, 47766u16, 34124u16, 61463u16, 28666u16, 6817u16, 31268u16, 3967u16, 37010u16, 58825u16, 55827u16, 44872u16, 12453u16, 17918u16, 20241u16, 14922u16, 42407u16, 53500u16, 61222u16, 39549u16, 1424u16, 28875u16, 50330u16, 45505u16, 11820u16, 23415u16, 25773u16, 4598u16, 36379u16, 64320u16, 61871u16, 34036u16, 6937u16, 28226u16, 20888u16, 9411u16, 47918u16, 52853u16, 44784u16, 56235u16, 17478u16, 12573u16, 3783u16, 31644u16, 58481u16, 37162u16, 39877u16, 61086u16, 29043u16, 1064u16, 15346u16, 20137u16, 53572u16, 42015u16];
+
+#[rustfmt::skip]
+pub(crate) static NO_REF_16_1DCF: [u16; 256] = [0u16, 7631u16, 15262u16, 9809u16, 30524u16, 27379u16, 19618u16, 20845u16, 61048u16, 62391u16, 54758u16, 51241u16, 39236u16, 33931u16, 41690u16, 48917u16, 49471u16, 56560u16, 64161u16, 59246u16, 46595u16, 43980u16, 36253u16, 36946u16, 12103u16, 12936u16, 5337u16, 2326u16, 22651u16, 17844u16, 25573u16, 32298u16, 40881u16, 33406u16, 42031u16, 47584u16, 59533u16, 62786u16, 54035u16, 52956u16, 29129u16, 27654u16, 19031u16, 22424u16, 1781u16, 6970u16, 15723u16, 8356u16, 24206u16, 17217u16, 25872u16, 30943u16, 10674u16, 13437u16, 4652u16, 4067u16, 45302u16, 44345u16, 35688u16, 38567u16, 51146u16, 55813u16, 64596u16, 57755u16, 8877u16, 16226u16, 6451u16, 1276u16, 21905u16, 18526u16, 28175u16, 29632u16, 52437u16, 53530u16, 63307u16, 60036u16, 48105u16, 42534u16, 32887u16, 40376u16, 58258u16, 65117u16, 55308u16, 50627u16, 38062u16, 35169u16, 44848u16, 45823u16, 3562u16, 4133u16, 13940u16, 11195u16, 31446u16, 26393u16, 16712u16, 23687u16, 48412u16, 41171u16, 34434u16, 39757u16, 51744u16, 55279u16, 61886u16, 60529u16, 21348u16, 20139u16, 26874u16, 30005u16, 9304u16, 14743u16, 8134u16, 521u16, 31779u16, 25068u16, 18365u16, 23154u16, 2847u16, 5840u16, 12417u16, 11598u16, 37467u16, 36756u16, 43461u16, 46090u16, 58727u16, 63656u16, 57081u16, 49974u16, 17754u16, 22677u16, 32452u16, 25355u16, 12902u16, 12201u16, 2552u16, 5175u16, 43810u16, 46829u16, 37052u16, 36211u16, 56350u16, 49617u16, 59264u16, 64079u16, 33893u16, 39338u16, 49147u16, 41524u16, 62297u16, 61078u16, 51399u16, 54536u16, 27165u16, 30674u16, 20867u16, 19532u16, 7457u16, 238u16, 9919u16, 15216u16, 56043u16, 50980u16, 57717u16, 64698u16, 44503u16, 45080u16, 38473u16, 35718u16, 13459u16, 10588u16, 3853u16, 4802u16, 17327u16, 24160u16, 30769u16, 26110u16, 7124u16, 1563u16, 8266u16, 15749u16, 27880u16, 28967u16, 22390u16, 19129u16, 62892u16, 59491u16, 52786u16, 54269u16, 33424u16, 40799u16, 47374u16, 42177u16, 26615u16, 31288u16, 23657u16, 16806u16, 4299u16, 3332u16, 11093u16, 13978u16, 35215u16, 37952u16, 45585u16, 45022u16, 65203u16, 58236u16, 50477u16, 55522u16, 42696u16, 47879u16, 40278u16, 32921u16, 53748u16, 52283u16, 60010u16, 63397u16, 18608u16, 21887u16, 29486u16, 28385u16, 16268u16, 8771u16, 1042u16, 6621u16, 63558u16, 58761u16, 50136u16, 56855u16, 36730u16, 37557u16, 46308u16, 43307u16, 5694u16, 3057u16, 11680u16, 12399u16, 24834u16, 31949u16, 23196u16, 18259u16, 14713u16, 9398u16, 743u16, 7976u16, 20037u16, 21386u16, 30171u16, 26644u16, 55041u16, 51918u16, 60575u16, 61776u16, 41021u16, 48626u16, 39843u16, 34412u16];
+
+/// Find the static lookup table generated with the given length of bits, expression and input reflection.
+pub(crate) const fn find_table(bits: u8, poly: u16, refin: bool) -> Option<&'static [u16; 256]> {
+    match (bits, poly, refin) {
+        (16, 0x8408, true) => Some(&REF_16_8408),
+        (16, 0xA001, true) => Some(&REF_16_A001),
+        (16, 0xA6BC, true) => Some(&REF_16_A6BC),
+        (16, 0xD010, true) => Some(&REF_16_D010),
+        (16, 0x0589, false) => Some(&NO_REF_16_0589),
+        (16, 0x1021, false) => Some(&NO_REF_16_1021),
+        (16, 0x1DCF, false) => Some(&NO_REF_16_1DCF),
+        (16, 0x3D65, false) => Some(&NO_REF_16_3D65),
+        (16, 0x5935, false) => Some(&NO_REF_16_5935),
+        (16, 0x6F63, false) => Some(&NO_REF_16_6F63),
+        (16, 0x755B, false) => Some(&NO_REF_16_755B),
+        (16, 0x8005, false) => Some(&NO_REF_16_8005),
+        (16, 0x8BB7, false) => Some(&NO_REF_16_8BB7),
+        (16, 0xC867, false) => Some(&NO_REF_16_C867),
+        // despite its name, this table is not reflected
+        (16, 0xA097, false) => Some(&REF_16_A097),
+        _ => None,
+    }
+}
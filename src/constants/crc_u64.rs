@@ -1,11 +1,34 @@
 #[rustfmt::skip]
-pub(crate) const NO_REF_40_0000000004820009: [u64; 256] = [0u64, 75628553u64, 151257106u64, 226885659u64, 302514212u64, 378142765u64, 453771318u64, 529399871u64, 605028424u64, 546439233u64, 756285530u64, 697696339u64, 907542636u64, 848953445u64, 1058799742u64, 1000210551u64, 1210056848u64, 1285685401u64, 1092878466u64, 1168507019u64, 1512571060u64, 1588199613u64, 1395392678u64, 1471021231u64, 1815085272u64, 1756496081u64, 1697906890u64, 1639317699u64, 2117599484u64, 2059010293u64, 2000421102u64, 1941831911u64, 2420113696u64, 2495742249u64, 2571370802u64, 2646999355u64, 2185756932u64, 2261385485u64, 2337014038u64, 2412642591u64, 3025142120u64, 2966552929u64, 3176399226u64, 3117810035u64, 2790785356u64, 2732196165u64, 2942042462u64, 2883453271u64, 3630170544u64, 3705799097u64, 3512992162u64, 3588620715u64, 3395813780u64, 3471442333u64, 3278635398u64, 3354263951u64, 4235198968u64, 4176609777u64, 4118020586u64, 4059431395u64, 4000842204u64, 3942253013u64, 3883663822u64, 3825074631u64, 4840227392u64, 4899078729u64, 4991484498u64, 5050335835u64, 5142741604u64, 5201592941u64, 5293998710u64, 5352850047u64, 4371513864u64, 4296147457u64, 4522770970u64, 4447404563u64, 4674028076u64, 4598661669u64, 4825285182u64, 4749918775u64, 6050284240u64, 6109135577u64, 5933105858u64, 5991957195u64, 6352798452u64, 6411649789u64, 6235620070u64, 6294471407u64, 5581570712u64, 5506204305u64, 5464392330u64, 5389025923u64, 5884084924u64, 5808718517u64, 5766906542u64, 5691540135u64, 7260341088u64, 7319192425u64, 7411598194u64, 7470449531u64, 7025984324u64, 7084835661u64, 7177241430u64, 7236092767u64, 6791627560u64, 6716261153u64, 6942884666u64, 6867518259u64, 6557270796u64, 6481904389u64, 6708527902u64, 6633161495u64, 8470397936u64, 8529249273u64, 8353219554u64, 8412070891u64, 8236041172u64, 8294892509u64, 8118862790u64, 8177714127u64, 8001684408u64, 7926318001u64, 7884506026u64, 7809139619u64, 7767327644u64, 7691961237u64, 7650149262u64, 7574782855u64, 9680454784u64, 9756083337u64, 9798157458u64, 9873786011u64, 9982968996u64, 10058597549u64, 10100671670u64, 10176300223u64, 10285483208u64, 10226894017u64, 10403185882u64, 10344596691u64, 10587997420u64, 10529408229u64, 10705700094u64, 10647110903u64, 8743027728u64, 8818656281u64, 8592294914u64, 8667923467u64, 9045541940u64, 9121170493u64, 8894809126u64, 8970437679u64, 9348056152u64, 9289466961u64, 9197323338u64, 9138734147u64, 9650570364u64, 9591981173u64, 9499837550u64, 9441248359u64, 12100568480u64, 12176197033u64, 12218271154u64, 12293899707u64, 11866211716u64, 11941840269u64, 11983914390u64, 12059542943u64, 12705596904u64, 12647007713u64, 12823299578u64, 12764710387u64, 12471240140u64, 12412650949u64, 12588942814u64, 12530353623u64, 11163141424u64, 11238769977u64, 11012408610u64, 11088037163u64, 10928784660u64, 11004413213u64, 10778051846u64, 10853680399u64, 11768169848u64, 11709580657u64, 11617437034u64, 11558847843u64, 11533813084u64, 11475223893u64, 11383080270u64, 11324491079u64, 14520682176u64, 14579533513u64, 14638384850u64, 14697236187u64, 14823196388u64, 14882047725u64, 14940899062u64, 14999750399u64, 14051968648u64, 13976602241u64, 14169671322u64, 14094304915u64, 14354482860u64, 14279116453u64, 14472185534u64, 14396819127u64, 13583255120u64, 13642106457u64, 13432522306u64, 13491373643u64, 13885769332u64, 13944620669u64, 13735036518u64, 13793887855u64, 13114541592u64, 13039175185u64, 12963808778u64, 12888442371u64, 13417055804u64, 13341689397u64, 13266322990u64, 13190956583u64, 16940795872u64, 16999647209u64, 17058498546u64, 17117349883u64, 16706439108u64, 16765290445u64, 16824141782u64, 16882993119u64, 16472082344u64, 16396715937u64, 16589785018u64, 16514418611u64, 16237725580u64, 16162359173u64, 16355428254u64, 16280061847u64, 16003368816u64, 16062220153u64, 15852636002u64, 15911487339u64, 15769012052u64, 15827863389u64, 15618279238u64, 15677130575u64, 15534655288u64, 15459288881u64, 15383922474u64, 15308556067u64, 15300298524u64, 15224932117u64, 15149565710u64, 15074199303u64];
+pub(crate) static NO_REF_40_0000000004820009: [u64; 256] = [0u64, 75628553u64, 151257106u64, 226885659u64, 302514212u64, 378142765u64, 453771318u64, 529399871u64, 605028424u64, 546439233u64, 756285530u64, 697696339u64, 907542636u64, 848953445u64, 1058799742u64, 1000210551u64, 1210056848u64, 1285685401u64, 1092878466u64, 1168507019u64, 1512571060u64, 1588199613u64, 1395392678u64, 1471021231u64, 1815085272u64, 1756496081u64, 1697906890u64, 1639317699u64, 2117599484u64, 2059010293u64, 2000421102u64, 1941831911u64, 2420113696u64, 2495742249u64, 2571370802u64, 2646999355u64, 2185756932u64, 2261385485u64, 2337014038u64, 2412642591u64, 3025142120u64, 2966552929u64, 3176399226u64, 3117810035u64, 2790785356u64, 2732196165u64, 2942042462u64, 2883453271u64, 3630170544u64, 3705799097u64, 3512992162u64, 3588620715u64, 3395813780u64, 3471442333u64, 3278635398u64, 3354263951u64, 4235198968u64, 4176609777u64, 4118020586u64, 4059431395u64, 4000842204u64, 3942253013u64, 3883663822u64, 3825074631u64, 4840227392u64, 4899078729u64, 4991484498u64, 5050335835u64, 5142741604u64, 5201592941u64, 5293998710u64, 5352850047u64, 4371513864u64, 4296147457u64, 4522770970u64, 4447404563u64, 4674028076u64, 4598661669u64, 4825285182u64, 4749918775u64, 6050284240u64, 6109135577u64, 5933105858u64, 5991957195u64, 6352798452u64, 6411649789u64, 6235620070u64, 6294471407u64, 5581570712u64, 5506204305u64, 5464392330u64, 5389025923u64, 5884084924u64, 5808718517u64, 5766906542u64, 5691540135u64, 7260341088u64, 7319192425u64, 7411598194u64, 7470449531u64, 7025984324u64, 7084835661u64, 7177241430u64, 7236092767u64, 6791627560u64, 6716261153u64, 6942884666u64, 6867518259u64, 6557270796u64, 6481904389u64, 6708527902u64, 6633161495u64, 8470397936u64, 8529249273u64, 8353219554u64, 8412070891u64, 8236041172u64, 8294892509u64, 8118862790u64, 8177714127u64, 8001684408u64, 7926318001u64, 7884506026u64, 7809139619u64, 7767327644u64, 7691961237u64, 7650149262u64, 7574782855u64, 9680454784u64, 9756083337u64, 9798157458u64, 9873786011u64, 9982968996u64, 10058597549u64, 10100671670u64, 10176300223u64, 10285483208u64, 10226894017u64, 10403185882u64, 10344596691u64, 10587997420u64, 10529408229u64, 10705700094u64, 10647110903u64, 8743027728u64, 8818656281u64, 8592294914u64, 8667923467u64, 9045541940u64, 9121170493u64, 8894809126u64, 8970437679u64, 9348056152u64, 9289466961u64, 9197323338u64, 9138734147u64, 9650570364u64, 9591981173u64, 9499837550u64, 9441248359u64, 12100568480u64, 12176197033u64, 12218271154u64, 12293899707u64, 11866211716u64, 11941840269u64, 11983914390u64, 12059542943u64, 12705596904u64, 12647007713u64, 12823299578u64, 12764710387u64, 12471240140u64, 12412650949u64, 12588942814u64, 12530353623u64, 11163141424u64, 11238769977u64, 11012408610u64, 11088037163u64, 10928784660u64, 11004413213u64, 10778051846u64, 10853680399u64, 11768169848u64, 11709580657u64, 11617437034u64, 11558847843u64, 11533813084u64, 11475223893u64, 11383080270u64, 11324491079u64, 14520682176u64, 14579533513u64, 14638384850u64, 14697236187u64, 14823196388u64, 14882047725u64, 14940899062u64, 14999750399u64, 14051968648u64, 13976602241u64, 14169671322u64, 14094304915u64, 14354482860u64, 14279116453u64, 14472185534u64, 14396819127u64, 13583255120u64, 13642106457u64, 13432522306u64, 13491373643u64, 13885769332u64, 13944620669u64, 13735036518u64, 13793887855u64, 13114541592u64, 13039175185u64, 12963808778u64, 12888442371u64, 13417055804u64, 13341689397u64, 13266322990u64, 13190956583u64, 16940795872u64, 16999647209u64, 17058498546u64, 17117349883u64, 16706439108u64, 16765290445u64, 16824141782u64, 16882993119u64, 16472082344u64, 16396715937u64, 16589785018u64, 16514418611u64, 16237725580u64, 16162359173u64, 16355428254u64, 16280061847u64, 16003368816u64, 16062220153u64, 15852636002u64, 15911487339u64, 15769012052u64, 15827863389u64, 15618279238u64, 15677130575u64, 15534655288u64, 15459288881u64, 15383922474u64, 15308556067u64, 15300298524u64, 15224932117u64, 15149565710u64, 15074199303u64];
 
 #[rustfmt::skip]
-pub(crate) const NO_REF_64_42F0E1EBA9EA3693: [u64; 256] = [0u64, 4823603603198064275u64, 9647207206396128550u64, 14344283933443513269u64, 5274672035359026399u64, 847670339082705484u64, 14759040976900489721u64, 10241823793177474922u64, 10549344070718052798u64, 15030250704074698541u64, 1695340678165410968u64, 6158653484774949387u64, 15804726273676621153u64, 11071337880091427826u64, 6824194888265062471u64, 2036903512645398228u64, 7367177604490692079u64, 2651944067726553980u64, 16419204125234161865u64, 11613757334439845466u64, 3390681356330821936u64, 7926053118503640995u64, 12317306969549898774u64, 16726154088988619397u64, 17607865585094646865u64, 13162708473643690690u64, 8194994013375312247u64, 3695931686473304036u64, 13648389776530124942u64, 18417527692557321757u64, 4073807025290796456u64, 8825348881154370363u64, 14734355208981384158u64, 10271039580541631821u64, 5303888135453107960u64, 822984195088142443u64, 9604374506261047041u64, 14391664176758772114u64, 47380625301539367u64, 4780770595170139316u64, 6781362712661643872u64, 2084283301222999283u64, 15852106237007281990u64, 11028505464239851989u64, 1670654249350217407u64, 6187869865390245932u64, 10578560694269006745u64, 15005564104267687178u64, 12269926345859042865u64, 16768987096479742114u64, 3433514057002836759u64, 7878672873577829764u64, 16389988026750624494u64, 11638443477897467005u64, 7391863372946608072u64, 2622728278751721819u64, 4044590402276644751u64, 8850035479350698268u64, 13673076206955870889u64, 18388311311405091898u64, 8147614050581592912u64, 3738764100714335683u64, 17650697762308740726u64, 13115328684529279205u64, 15709965168302367023u64, 11021966344253216700u64, 6909860770376862729u64, 2095335087373712026u64, 10607776270906215920u64, 15115916238825782115u64, 1645968390176284886u64, 6063892853452478021u64, 5216239979862816913u64, 762004938812542466u64, 14808413130408695223u64, 10336584279807992612u64, 94761250603078734u64, 4872975272980325085u64, 9561541190340278632u64, 14285852213486374907u64, 13562725425323287744u64, 18359094313119879763u64, 4168566602445998566u64, 8874722219015798645u64, 17657238303940757535u64, 13257468400305012364u64, 8136561383943382329u64, 3610266854770152362u64, 3341308498700434814u64, 7831293060043656173u64, 12375739730780491864u64, 16811819059476047563u64, 7452841817450123681u64, 2710377314828461874u64, 16324444680414493831u64, 11564384134825822740u64, 1621282580641819377u64, 6093108618008534114u64, 10636992411005044695u64, 15091230119249932612u64, 6867028114005673518u64, 2142715359940571325u64, 15757345747155659528u64, 10979133309658045851u64, 9518708972583580495u64, 14333231979791697372u64, 142141253402664553u64, 4830142882085382394u64, 14783726745893216144u64, 10365800689136969987u64, 5245456557503443638u64, 737318311902463013u64, 8089180804553289502u64, 3653099890976004493u64, 17700070958701396536u64, 13210088128275084459u64, 4139350461810230209u64, 8899408340202190162u64, 13587411233247080167u64, 18329878549100632180u64, 16295228101163185824u64, 11589070762272702515u64, 7477528201428671366u64, 2681160907110034709u64, 12328359726370364031u64, 16854651450907929836u64, 3384140715920324441u64, 7783913295349006794u64, 17796789492404876493u64, 12973186262895182430u64, 8294265019745835499u64, 3597188614796881784u64, 13819721540753725458u64, 18246723593521770113u64, 4190670174747424052u64, 8707887697765516199u64, 7249714899603402099u64, 2768808468102880224u64, 16248400991498780757u64, 11785088403942012614u64, 3291936780352569772u64, 8025325358597240639u64, 12127785706904956042u64, 16915077318774037017u64, 10432479959725633826u64, 15147713122803500977u64, 1524009877625084932u64, 6329456346323069591u64, 15705454305770282493u64, 11170082187107838830u64, 6635271944638132443u64, 2226424485906433608u64, 189522501206157468u64, 4634679410803088911u64, 9745950545960650170u64, 14245012653811987241u64, 5445476407655580739u64, 676338306971005648u64, 14876502445374089573u64, 10124960353263198198u64, 4215391513593610003u64, 8678706776937023872u64, 13790540925671641653u64, 18271444552530207910u64, 8337133204891997132u64, 3549843186494580063u64, 17749444438031597290u64, 13016054137459951737u64, 12170653315997410989u64, 16867732534171963454u64, 3244592164593781643u64, 8068192726900473112u64, 16273122767886764658u64, 11755906975779290337u64, 7220533709540304724u64, 2793530071884239303u64, 6682616997400869628u64, 2183556611878603887u64, 15662586120087312346u64, 11217427617020813641u64, 1553190491096487459u64, 6304735387851432112u64, 10407758620342516485u64, 15176894045242543510u64, 14905683634900247362u64, 10100238751092381137u64, 5420754629656923748u64, 705519735670536439u64, 9793295161182637981u64, 14202145287119436046u64, 146654890503152315u64, 4682024195942093864u64, 3242565161283638754u64, 7930564333232481137u64, 12186217236017068228u64, 17000743249723264599u64, 7335380351123765565u64, 2827240748300537774u64, 16153640314560107547u64, 11735716164790313608u64, 13734056228011347036u64, 18188291445129067215u64, 4285430719881142650u64, 8757259798139230185u64, 17846161249714921603u64, 13067947420601767440u64, 8235833358291897765u64, 3511522545606540086u64, 5387043107155988493u64, 590673871457609374u64, 14925875833148783915u64, 10219719885873843128u64, 284282506805329106u64, 4684052045342640705u64, 9660285764170764788u64, 14186579979835500391u64, 15610694155489642931u64, 11120709418076880672u64, 6720936860919424149u64, 2284857304564388358u64, 10490913115006887276u64, 15233377424362361855u64, 1474636623804926026u64, 6234696958930763481u64, 16178361609106579004u64, 11706535215248140463u64, 7306199781952008986u64, 2851961734412043657u64, 12229085463316509411u64, 16953397843693241456u64, 3195220067441434565u64, 7973432182840617302u64, 8278700923620460418u64, 3464177731752866065u64, 17798816680404380324u64, 13110814815472245815u64, 4310152537884486493u64, 8728078392784608718u64, 13704874997943502459u64, 18213013024491712744u64, 9707630858549910483u64, 14143712128616820032u64, 241414281116563189u64, 4731397450835853414u64, 14955056402857342732u64, 10194998898151653791u64, 5362321814220069418u64, 619854820462849209u64, 1503817855483314797u64, 6209975379031176446u64, 10466191297540353867u64, 15262558828106308056u64, 6768281431840648882u64, 2241989909157107745u64, 15567826590698013588u64, 11168054230320002311u64];
+pub(crate) static NO_REF_64_42F0E1EBA9EA3693: [u64; 256] = [0u64, 4823603603198064275u64, 9647207206396128550u64, 14344283933443513269u64, 5274672035359026399u64, 847670339082705484u64, 14759040976900489721u64, 10241823793177474922u64, 10549344070718052798u64, 15030250704074698541u64, 1695340678165410968u64, 6158653484774949387u64, 15804726273676621153u64, 11071337880091427826u64, 6824194888265062471u64, 2036903512645398228u64, 7367177604490692079u64, 2651944067726553980u64, 16419204125234161865u64, 11613757334439845466u64, 3390681356330821936u64, 7926053118503640995u64, 12317306969549898774u64, 16726154088988619397u64, 17607865585094646865u64, 13162708473643690690u64, 8194994013375312247u64, 3695931686473304036u64, 13648389776530124942u64, 18417527692557321757u64, 4073807025290796456u64, 8825348881154370363u64, 14734355208981384158u64, 10271039580541631821u64, 5303888135453107960u64, 822984195088142443u64, 9604374506261047041u64, 14391664176758772114u64, 47380625301539367u64, 4780770595170139316u64, 6781362712661643872u64, 2084283301222999283u64, 15852106237007281990u64, 11028505464239851989u64, 1670654249350217407u64, 6187869865390245932u64, 10578560694269006745u64, 15005564104267687178u64, 12269926345859042865u64, 16768987096479742114u64, 3433514057002836759u64, 7878672873577829764u64, 16389988026750624494u64, 11638443477897467005u64, 7391863372946608072u64, 2622728278751721819u64, 4044590402276644751u64, 8850035479350698268u64, 13673076206955870889u64, 18388311311405091898u64, 8147614050581592912u64, 3738764100714335683u64, 17650697762308740726u64, 13115328684529279205u64, 15709965168302367023u64, 11021966344253216700u64, 6909860770376862729u64, 2095335087373712026u64, 10607776270906215920u64, 15115916238825782115u64, 1645968390176284886u64, 6063892853452478021u64, 5216239979862816913u64, 762004938812542466u64, 14808413130408695223u64, 10336584279807992612u64, 94761250603078734u64, 4872975272980325085u64, 9561541190340278632u64, 14285852213486374907u64, 13562725425323287744u64, 18359094313119879763u64, 4168566602445998566u64, 8874722219015798645u64, 17657238303940757535u64, 13257468400305012364u64, 8136561383943382329u64, 3610266854770152362u64, 3341308498700434814u64, 7831293060043656173u64, 12375739730780491864u64, 16811819059476047563u64, 7452841817450123681u64, 2710377314828461874u64, 16324444680414493831u64, 11564384134825822740u64, 1621282580641819377u64, 6093108618008534114u64, 10636992411005044695u64, 15091230119249932612u64, 6867028114005673518u64, 2142715359940571325u64, 15757345747155659528u64, 10979133309658045851u64, 9518708972583580495u64, 14333231979791697372u64, 142141253402664553u64, 4830142882085382394u64, 14783726745893216144u64, 10365800689136969987u64, 5245456557503443638u64, 737318311902463013u64, 8089180804553289502u64, 3653099890976004493u64, 17700070958701396536u64, 13210088128275084459u64, 4139350461810230209u64, 8899408340202190162u64, 13587411233247080167u64, 18329878549100632180u64, 16295228101163185824u64, 11589070762272702515u64, 7477528201428671366u64, 2681160907110034709u64, 12328359726370364031u64, 16854651450907929836u64, 3384140715920324441u64, 7783913295349006794u64, 17796789492404876493u64, 12973186262895182430u64, 8294265019745835499u64, 3597188614796881784u64, 13819721540753725458u64, 18246723593521770113u64, 4190670174747424052u64, 8707887697765516199u64, 7249714899603402099u64, 2768808468102880224u64, 16248400991498780757u64, 11785088403942012614u64, 3291936780352569772u64, 8025325358597240639u64, 12127785706904956042u64, 16915077318774037017u64, 10432479959725633826u64, 15147713122803500977u64, 1524009877625084932u64, 6329456346323069591u64, 15705454305770282493u64, 11170082187107838830u64, 6635271944638132443u64, 2226424485906433608u64, 189522501206157468u64, 4634679410803088911u64, 9745950545960650170u64, 14245012653811987241u64, 5445476407655580739u64, 676338306971005648u64, 14876502445374089573u64, 10124960353263198198u64, 4215391513593610003u64, 8678706776937023872u64, 13790540925671641653u64, 18271444552530207910u64, 8337133204891997132u64, 3549843186494580063u64, 17749444438031597290u64, 13016054137459951737u64, 12170653315997410989u64, 16867732534171963454u64, 3244592164593781643u64, 8068192726900473112u64, 16273122767886764658u64, 11755906975779290337u64, 7220533709540304724u64, 2793530071884239303u64, 6682616997400869628u64, 2183556611878603887u64, 15662586120087312346u64, 11217427617020813641u64, 1553190491096487459u64, 6304735387851432112u64, 10407758620342516485u64, 15176894045242543510u64, 14905683634900247362u64, 10100238751092381137u64, 5420754629656923748u64, 705519735670536439u64, 9793295161182637981u64, 14202145287119436046u64, 146654890503152315u64, 4682024195942093864u64, 3242565161283638754u64, 7930564333232481137u64, 12186217236017068228u64, 17000743249723264599u64, 7335380351123765565u64, 2827240748300537774u64, 16153640314560107547u64, 11735716164790313608u64, 13734056228011347036u64, 18188291445129067215u64, 4285430719881142650u64, 8757259798139230185u64, 17846161249714921603u64, 13067947420601767440u64, 8235833358291897765u64, 3511522545606540086u64, 5387043107155988493u64, 590673871457609374u64, 14925875833148783915u64, 10219719885873843128u64, 284282506805329106u64, 4684052045342640705u64, 9660285764170764788u64, 14186579979835500391u64, 15610694155489642931u64, 11120709418076880672u64, 6720936860919424149u64, 2284857304564388358u64, 10490913115006887276u64, 15233377424362361855u64, 1474636623804926026u64, 6234696958930763481u64, 16178361609106579004u64, 11706535215248140463u64, 7306199781952008986u64, 2851961734412043657u64, 12229085463316509411u64, 16953397843693241456u64, 3195220067441434565u64, 7973432182840617302u64, 8278700923620460418u64, 3464177731752866065u64, 17798816680404380324u64, 13110814815472245815u64, 4310152537884486493u64, 8728078392784608718u64, 13704874997943502459u64, 18213013024491712744u64, 9707630858549910483u64, 14143712128616820032u64, 241414281116563189u64, 4731397450835853414u64, 14955056402857342732u64, 10194998898151653791u64, 5362321814220069418u64, 619854820462849209u64, 1503817855483314797u64, 6209975379031176446u64, 10466191297540353867u64, 15262558828106308056u64, 6768281431840648882u64, 2241989909157107745u64, 15567826590698013588u64, 11168054230320002311u64];
 
 #[rustfmt::skip]
-pub(crate) const REF_64_D800000000000000: [u64; 256] = [0u64, 121597189939003392, 243194379878006784u64, 202661983231672320u64, 486388759756013568u64, 535928355657089024u64, 405323966463344640u64, 292733975779082240u64, 972777519512027136u64, 878201927337246720u64, 1071856711314178048u64, 1103381908705771520u64, 810647932926689280u64, 788129934789836800u64, 585467951558164480u64, 689050742987685888u64, 1945555039024054272u64, 1923037040887201792u64, 1756403854674493440u64, 1859986646104014848u64, 2143713422628356096u64, 2049137830453575680u64, 2206763817411543040u64, 2238289014803136512u64, 1621295865853378560u64, 1670835461754454016u64, 1576259869579673600u64, 1463669878895411200u64, 1170935903116328960u64, 1292533093055332352u64, 1378101485975371776u64, 1337569089329037312u64, 3891110078048108544u64, 4012707267987111936u64, 3846074081774403584u64, 3805541685128069120u64, 3512807709348986880u64, 3562347305250062336u64, 3719973292208029696u64, 3607383301523767296u64, 4287426845256712192u64, 4192851253081931776u64, 4098275660907151360u64, 4129800858298744832u64, 4413527634823086080u64, 4391009636686233600u64, 4476578029606273024u64, 4580160821035794432u64, 3242591731706757120u64, 3220073733569904640u64, 3341670923508908032u64, 3445253714938429440u64, 3152519739159347200u64, 3057944146984566784u64, 2927339757790822400u64, 2958864955182415872u64, 2341871806232657920u64, 2391411402133733376u64, 2585066186110664704u64, 2472476195426402304u64, 2756202971950743552u64, 2877800161889746944u64, 2675138178658074624u64, 2634605782011740160u64, 7782220156096217088u64, 7903817346035220480u64, 8025414535974223872u64, 7984882139327889408u64, 7692148163548807168u64, 7741687759449882624u64, 7611083370256138240u64, 7498493379571875840u64, 7025615418697973760u64, 6931039826523193344u64, 7124694610500124672u64, 7156219807891718144u64, 7439946584416059392u64, 7417428586279206912u64, 7214766603047534592u64, 7318349394477056000u64, 8574853690513424384u64, 8552335692376571904u64, 8385702506163863552u64, 8489285297593384960u64, 8196551321814302720u64, 8101975729639522304u64, 8259601716597489664u64, 8291126913989083136u64, 8827055269646172160u64, 8876594865547247616u64, 8782019273372467200u64, 8669429282688204800u64, 8953156059212546048u64, 9074753249151549440u64, 9160321642071588864u64, 9119789245425254400u64, 6485183463413514240u64, 6606780653352517632u64, 6440147467139809280u64, 6399615070493474816u64, 6683341847017816064u64, 6732881442918891520u64, 6890507429876858880u64, 6777917439192596480u64, 6305039478318694400u64, 6210463886143913984u64, 6115888293969133568u64, 6147413491360727040u64, 5854679515581644800u64, 5832161517444792320u64, 5917729910364831744u64, 6021312701794353152u64, 4683743612465315840u64, 4661225614328463360u64, 4782822804267466752u64, 4886405595696988160u64, 5170132372221329408u64, 5075556780046548992u64, 4944952390852804608u64, 4976477588244398080u64, 5512405943901487104u64, 5561945539802562560u64, 5755600323779493888u64, 5643010333095231488u64, 5350276357316149248u64, 5471873547255152640u64, 5269211564023480320u64, 5228679167377145856u64, 15564440312192434176u64, 15686037502131437568u64, 15807634692070440960u64, 15767102295424106496u64, 16050829071948447744u64, 16100368667849523200u64, 15969764278655778816u64, 15857174287971516416u64, 15384296327097614336u64, 15289720734922833920u64, 15483375518899765248u64, 15514900716291358720u64, 15222166740512276480u64, 15199648742375424000u64, 14996986759143751680u64, 15100569550573273088u64, 14051230837395947520u64, 14028712839259095040u64, 13862079653046386688u64, 13965662444475908096u64, 14249389221000249344u64, 14154813628825468928u64, 14312439615783436288u64, 14343964813175029760u64, 14879893168832118784u64, 14929432764733194240u64, 14834857172558413824u64, 14722267181874151424u64, 14429533206095069184u64, 14551130396034072576u64, 14636698788954112000u64, 14596166392307777536u64, 17149707381026848768u64, 17271304570965852160u64, 17104671384753143808u64, 17064138988106809344u64, 16771405012327727104u64, 16820944608228802560u64, 16978570595186769920u64, 16865980604502507520u64, 16393102643628605440u64, 16298527051453825024u64, 16203951459279044608u64, 16235476656670638080u64, 16519203433194979328u64, 16496685435058126848u64, 16582253827978166272u64, 16685836619407687680u64, 17654110539292344320u64, 17631592541155491840u64, 17753189731094495232u64, 17856772522524016640u64, 17564038546744934400u64, 17469462954570153984u64, 17338858565376409600u64, 17370383762768003072u64, 17906312118425092096u64, 17955851714326167552u64, 18149506498303098880u64, 18036916507618836480u64, 18320643284143177728u64, 18442240474082181120u64, 18239578490850508800u64, 18199046094204174336u64, 12970366926827028480u64, 13091964116766031872u64, 13213561306705035264u64, 13173028910058700800u64, 12880294934279618560u64, 12929834530180694016u64, 12799230140986949632u64, 12686640150302687232u64, 13366683694035632128u64, 13272108101860851712u64, 13465762885837783040u64, 13497288083229376512u64, 13781014859753717760u64, 13758496861616865280u64, 13555834878385192960u64, 13659417669814714368u64, 12610078956637388800u64, 12587560958500536320u64, 12420927772287827968u64, 12524510563717349376u64, 12231776587938267136u64, 12137200995763486720u64, 12294826982721454080u64, 12326352180113047552u64, 11709359031163289600u64, 11758898627064365056u64, 11664323034889584640u64, 11551733044205322240u64, 11835459820729663488u64, 11957057010668666880u64, 12042625403588706304u64, 12002093006942371840u64, 9367487224930631680u64, 9489084414869635072u64, 9322451228656926720u64, 9281918832010592256u64, 9565645608534933504u64, 9615185204436008960u64, 9772811191393976320u64, 9660221200709713920u64, 10340264744442658816u64, 10245689152267878400u64, 10151113560093097984u64, 10182638757484691456u64, 9889904781705609216u64, 9867386783568756736u64, 9952955176488796160u64, 10056537967918317568u64, 11024811887802974208u64, 11002293889666121728u64, 11123891079605125120u64, 11227473871034646528u64, 11511200647558987776u64, 11416625055384207360u64, 11286020666190462976u64, 11317545863582056448u64, 10700552714632298496u64, 10750092310533373952u64, 10943747094510305280u64, 10831157103826042880u64, 10538423128046960640u64, 10660020317985964032u64, 10457358334754291712u64, 10416825938107957248u64];
+pub(crate) static REF_64_D800000000000000: [u64; 256] = [0u64, 121597189939003392, 243194379878006784u64, 202661983231672320u64, 486388759756013568u64, 535928355657089024u64, 405323966463344640u64, 292733975779082240u64, 972777519512027136u64, 878201927337246720u64, 1071856711314178048u64, 1103381908705771520u64, 810647932926689280u64, 788129934789836800u64, 585467951558164480u64, 689050742987685888u64, 1945555039024054272u64, 1923037040887201792u64, 1756403854674493440u64, 1859986646104014848u64, 2143713422628356096u64, 2049137830453575680u64, 2206763817411543040u64, 2238289014803136512u64, 1621295865853378560u64, 1670835461754454016u64, 1576259869579673600u64, 1463669878895411200u64, 1170935903116328960u64, 1292533093055332352u64, 1378101485975371776u64, 1337569089329037312u64, 3891110078048108544u64, 4012707267987111936u64, 3846074081774403584u64, 3805541685128069120u64, 3512807709348986880u64, 3562347305250062336u64, 3719973292208029696u64, 3607383301523767296u64, 4287426845256712192u64, 4192851253081931776u64, 4098275660907151360u64, 4129800858298744832u64, 4413527634823086080u64, 4391009636686233600u64, 4476578029606273024u64, 4580160821035794432u64, 3242591731706757120u64, 3220073733569904640u64, 3341670923508908032u64, 3445253714938429440u64, 3152519739159347200u64, 3057944146984566784u64, 2927339757790822400u64, 2958864955182415872u64, 2341871806232657920u64, 2391411402133733376u64, 2585066186110664704u64, 2472476195426402304u64, 2756202971950743552u64, 2877800161889746944u64, 2675138178658074624u64, 2634605782011740160u64, 7782220156096217088u64, 7903817346035220480u64, 8025414535974223872u64, 7984882139327889408u64, 7692148163548807168u64, 7741687759449882624u64, 7611083370256138240u64, 7498493379571875840u64, 7025615418697973760u64, 6931039826523193344u64, 7124694610500124672u64, 7156219807891718144u64, 7439946584416059392u64, 7417428586279206912u64, 7214766603047534592u64, 7318349394477056000u64, 8574853690513424384u64, 8552335692376571904u64, 8385702506163863552u64, 8489285297593384960u64, 8196551321814302720u64, 8101975729639522304u64, 8259601716597489664u64, 8291126913989083136u64, 8827055269646172160u64, 8876594865547247616u64, 8782019273372467200u64, 8669429282688204800u64, 8953156059212546048u64, 9074753249151549440u64, 9160321642071588864u64, 9119789245425254400u64, 6485183463413514240u64, 6606780653352517632u64, 6440147467139809280u64, 6399615070493474816u64, 6683341847017816064u64, 6732881442918891520u64, 6890507429876858880u64, 6777917439192596480u64, 6305039478318694400u64, 6210463886143913984u64, 6115888293969133568u64, 6147413491360727040u64, 5854679515581644800u64, 5832161517444792320u64, 5917729910364831744u64, 6021312701794353152u64, 4683743612465315840u64, 4661225614328463360u64, 4782822804267466752u64, 4886405595696988160u64, 5170132372221329408u64, 5075556780046548992u64, 4944952390852804608u64, 4976477588244398080u64, 5512405943901487104u64, 5561945539802562560u64, 5755600323779493888u64, 5643010333095231488u64, 5350276357316149248u64, 5471873547255152640u64, 5269211564023480320u64, 5228679167377145856u64, 15564440312192434176u64, 15686037502131437568u64, 15807634692070440960u64, 15767102295424106496u64, 16050829071948447744u64, 16100368667849523200u64, 15969764278655778816u64, 15857174287971516416u64, 15384296327097614336u64, 15289720734922833920u64, 15483375518899765248u64, 15514900716291358720u64, 15222166740512276480u64, 15199648742375424000u64, 14996986759143751680u64, 15100569550573273088u64, 14051230837395947520u64, 14028712839259095040u64, 13862079653046386688u64, 13965662444475908096u64, 14249389221000249344u64, 14154813628825468928u64, 14312439615783436288u64, 14343964813175029760u64, 14879893168832118784u64, 14929432764733194240u64, 14834857172558413824u64, 14722267181874151424u64, 14429533206095069184u64, 14551130396034072576u64, 14636698788954112000u64, 14596166392307777536u64, 17149707381026848768u64, 17271304570965852160u64, 17104671384753143808u64, 17064138988106809344u64, 16771405012327727104u64, 16820944608228802560u64, 16978570595186769920u64, 16865980604502507520u64, 16393102643628605440u64, 16298527051453825024u64, 16203951459279044608u64, 16235476656670638080u64, 16519203433194979328u64, 16496685435058126848u64, 16582253827978166272u64, 16685836619407687680u64, 17654110539292344320u64, 17631592541155491840u64, 17753189731094495232u64, 17856772522524016640u64, 17564038546744934400u64, 17469462954570153984u64, 17338858565376409600u64, 17370383762768003072u64, 17906312118425092096u64, 17955851714326167552u64, 18149506498303098880u64, 18036916507618836480u64, 18320643284143177728u64, 18442240474082181120u64, 18239578490850508800u64, 18199046094204174336u64, 12970366926827028480u64, 13091964116766031872u64, 13213561306705035264u64, 13173028910058700800u64, 12880294934279618560u64, 12929834530180694016u64, 12799230140986949632u64, 12686640150302687232u64, 13366683694035632128u64, 13272108101860851712u64, 13465762885837783040u64, 13497288083229376512u64, 13781014859753717760u64, 13758496861616865280u64, 13555834878385192960u64, 13659417669814714368u64, 12610078956637388800u64, 12587560958500536320u64, 12420927772287827968u64, 12524510563717349376u64, 12231776587938267136u64, 12137200995763486720u64, 12294826982721454080u64, 12326352180113047552u64, 11709359031163289600u64, 11758898627064365056u64, 11664323034889584640u64, 11551733044205322240u64, 11835459820729663488u64, 11957057010668666880u64, 12042625403588706304u64, 12002093006942371840u64, 9367487224930631680u64, 9489084414869635072u64, 9322451228656926720u64, 9281918832010592256u64, 9565645608534933504u64, 9615185204436008960u64, 9772811191393976320u64, 9660221200709713920u64, 10340264744442658816u64, 10245689152267878400u64, 10151113560093097984u64, 10182638757484691456u64, 9889904781705609216u64, 9867386783568756736u64, 9952955176488796160u64, 10056537967918317568u64, 11024811887802974208u64, 11002293889666121728u64, 11123891079605125120u64, 11227473871034646528u64, 11511200647558987776u64, 11416625055384207360u64, 11286020666190462976u64, 11317545863582056448u64, 10700552714632298496u64, 10750092310533373952u64, 10943747094510305280u64, 10831157103826042880u64, 10538423128046960640u64, 10660020317985964032u64, 10457358334754291712u64, 10416825938107957248u64];
 
 #[rustfmt::skip]
-pub(crate) const REF_64_95AC9329AC4BC9B5: [u64; 256] = [0u64, 8851949072701294969u64, 17703898145402589938u64, 10333669153493130123u64, 13851072938616403599u64, 13465927519055396854u64, 3857338458010461309u64, 5715195658523061508u64, 12333367839138578037u64, 15127763206205961996u64, 6816212484437830791u64, 2612226237385041406u64, 7714676916020922618u64, 1281407202545942915u64, 11430391317046123016u64, 16463076249205199729u64, 9009731685717012353u64, 563108230357313272u64, 9851657908567506291u64, 17465080730062222346u64, 13632424968875661582u64, 14404880506683019383u64, 5224452474770082812u64, 3627802401766982277u64, 15429353832041845236u64, 12463821128841762957u64, 2562814405091885830u64, 6433535930597116543u64, 1592294032496338811u64, 7836410910743637506u64, 16404387395731993993u64, 11056451039949864176u64, 18019463371434024706u64, 9280105458721969787u64, 1126216460714626544u64, 8464919223366468745u64, 4190910634541279629u64, 4679640014836523252u64, 14959263154764675967u64, 13060872525739979270u64, 5852729821509460343u64, 3161916214005835790u64, 11856275032257016709u64, 16019730051968187132u64, 10448904949540165624u64, 16994763621833383553u64, 7255604803533964554u64, 2191395843288271987u64, 9734813498046853251u64, 18285020776702097914u64, 8262382231073956465u64, 608425843627928328u64, 5125628810183771660u64, 4465764294926438261u64, 12867071861194233086u64, 14432195567501024647u64, 3184588064992677622u64, 6262709589572306831u64, 15672821821487275012u64, 11770576130456212861u64, 17008134862606432377u64, 10867599606483677440u64, 1853769023980628619u64, 7161174014982448114u64, 16103423924954344815u64, 11935289383220651030u64, 3083341959784644509u64, 5769757520242456292u64, 2252432921429253088u64, 7321251034957484697u64, 16929838446732937490u64, 10388307452745547883u64, 8381821269082559258u64, 1047727658635319907u64, 9359280029673046504u64, 18102965619612993681u64, 13000435797616977301u64, 14894146905688698092u64, 4745161141923116903u64, 4252033715651608094u64, 11705459643018920686u64, 15612384854998895511u64, 6323832428011671580u64, 3250108949404244325u64, 7082685524280996961u64, 1770671381070249240u64, 10951102161764411027u64, 17087309740654948330u64, 674072313427442843u64, 8323419547594995170u64, 18224423522563763817u64, 9669888565606754064u64, 14511209607067929108u64, 12950765422787986285u64, 4382791686576543974u64, 5047054248884015519u64, 2696289253709771373u64, 6895947823530343188u64, 15049839570318909599u64, 12250835051042597350u64, 16524764462147912930u64, 11496477575961038235u64, 1216851687255856656u64, 7654800921679748969u64, 10251257620367543320u64, 17625884659327141217u64, 8931528589852876522u64, 84259039178430355u64, 5655163293556783767u64, 3792978414742418414u64, 13532134484260726885u64, 13912670750543257884u64, 6369176129985355244u64, 2502782282785952917u64, 12525419179144613662u64, 15495561035627234919u64, 10978437246791527267u64, 16321975555527844378u64, 7920669638525335953u64, 1671873238255513832u64, 17531166746306175897u64, 9913345878835194592u64, 503231997654823275u64, 8945175932061546514u64, 3707538047961257238u64, 5308515798192249967u64, 14322348029964896228u64, 13554501644362141341u64, 10785157014839085493u64, 17254666630495879372u64, 6925536469308201799u64, 1928669229005230654u64, 6166683919569289018u64, 3408106242218915395u64, 11539515040484912584u64, 15779741191858611377u64, 4504865842858506176u64, 4925828954283753145u64, 14642502069914969394u64, 12820884771576065099u64, 18355716529793696079u64, 9540007361421969462u64, 796147016248169405u64, 8202193697865996996u64, 16763642538165118516u64, 10555343349626187597u64, 2095455317270639814u64, 7479631577382337983u64, 2926364910754730171u64, 5928137516128508354u64, 15937228569359352393u64, 12102324735718361904u64, 4867406749023426625u64, 4131191115536978232u64, 13131477498808912563u64, 14763945261529023434u64, 9490322283846233806u64, 17972763431062038455u64, 8504067431303216188u64, 926884511990314309u64, 8051711962477172407u64, 1541670979892322254u64, 11100683476643087429u64, 16201132341218348348u64, 12647664856023343160u64, 15374718365700663617u64, 6500217898808488650u64, 2372580570961558451u64, 14165371048561993922u64, 13712881572587659707u64, 3541342762140498480u64, 5475551080882205513u64, 337036156713721421u64, 9112211761281881908u64, 17374189211922025663u64, 10071726351451997638u64, 1348144626854885686u64, 7524919785159454799u64, 16646839095189990340u64, 11375251796044276413u64, 15171913658969673657u64, 12129609824107054784u64, 2827581646778391883u64, 6766067242130363442u64, 13374985906044110659u64, 14070668113165684282u64, 5489218623395763633u64, 3960334819262667976u64, 8765583373153087948u64, 251615998827411637u64, 10094108497768031038u64, 17783882574922426951u64, 5392578507419542746u64, 3462768234654100899u64, 13791895647060686376u64, 14249064643987996497u64, 10011129131143811669u64, 17309264314385947436u64, 9177858264896848039u64, 398073508124084702u64, 16284634862666717871u64, 11179858319785628630u64, 1463182455377365085u64, 7968614284679676196u64, 2433703374511713312u64, 6565738749404456281u64, 15309601843359497938u64, 12587227855704700843u64, 4025855981238586203u64, 5550341738321543714u64, 14010231419946703273u64, 13309869690798280912u64, 17863057179705753044u64, 10177610780853122221u64, 168518078356860710u64, 8687094605961012831u64, 11310326587113567534u64, 16586241563491499095u64, 7585956829484836828u64, 1413790823389195941u64, 6687492953022055329u64, 2744609311697881816u64, 12213303662187237715u64, 15250927976100943914u64, 12738352259970710488u64, 14564578711588090529u64, 5005564565571905834u64, 4588929132448424019u64, 8142317431333358935u64, 731591227688682542u64, 9606093343850471333u64, 18417404465172059868u64, 2012927990619293101u64, 7005115709973351636u64, 17176652871151048543u64, 10702745209522052646u64, 15841339277050671906u64, 11605722277885901403u64, 3343746476511027664u64, 6106651831093618857u64, 14830152191845028953u64, 13193075276920315168u64, 4071158715666679467u64, 4803046671925235666u64, 1006463995309646550u64, 8588326435575524271u64, 17890351864123093028u64, 9412308762883553629u64, 7415076095922514476u64, 2035579357833339733u64, 10617031596384499934u64, 16829728831969243559u64, 12024401134718426275u64, 15854695815076877786u64, 6012200567359213137u64, 3006100283679606568u64];
+pub(crate) static REF_64_95AC9329AC4BC9B5: [u64; 256] = [0u64, 8851949072701294969u64, 17703898145402589938u64, 10333669153493130123u64, 13851072938616403599u64, 13465927519055396854u64, 3857338458010461309u64, 5715195658523061508u64, 12333367839138578037u64, 15127763206205961996u64, 6816212484437830791u64, 2612226237385041406u64, 7714676916020922618u64, 1281407202545942915u64, 11430391317046123016u64, 16463076249205199729u64, 9009731685717012353u64, 563108230357313272u64, 9851657908567506291u64, 17465080730062222346u64, 13632424968875661582u64, 14404880506683019383u64, 5224452474770082812u64, 3627802401766982277u64, 15429353832041845236u64, 12463821128841762957u64, 2562814405091885830u64, 6433535930597116543u64, 1592294032496338811u64, 7836410910743637506u64, 16404387395731993993u64, 11056451039949864176u64, 18019463371434024706u64, 9280105458721969787u64, 1126216460714626544u64, 8464919223366468745u64, 4190910634541279629u64, 4679640014836523252u64, 14959263154764675967u64, 13060872525739979270u64, 5852729821509460343u64, 3161916214005835790u64, 11856275032257016709u64, 16019730051968187132u64, 10448904949540165624u64, 16994763621833383553u64, 7255604803533964554u64, 2191395843288271987u64, 9734813498046853251u64, 18285020776702097914u64, 8262382231073956465u64, 608425843627928328u64, 5125628810183771660u64, 4465764294926438261u64, 12867071861194233086u64, 14432195567501024647u64, 3184588064992677622u64, 6262709589572306831u64, 15672821821487275012u64, 11770576130456212861u64, 17008134862606432377u64, 10867599606483677440u64, 1853769023980628619u64, 7161174014982448114u64, 16103423924954344815u64, 11935289383220651030u64, 3083341959784644509u64, 5769757520242456292u64, 2252432921429253088u64, 7321251034957484697u64, 16929838446732937490u64, 10388307452745547883u64, 8381821269082559258u64, 1047727658635319907u64, 9359280029673046504u64, 18102965619612993681u64, 13000435797616977301u64, 14894146905688698092u64, 4745161141923116903u64, 4252033715651608094u64, 11705459643018920686u64, 15612384854998895511u64, 6323832428011671580u64, 3250108949404244325u64, 7082685524280996961u64, 1770671381070249240u64, 10951102161764411027u64, 17087309740654948330u64, 674072313427442843u64, 8323419547594995170u64, 18224423522563763817u64, 9669888565606754064u64, 14511209607067929108u64, 12950765422787986285u64, 4382791686576543974u64, 5047054248884015519u64, 2696289253709771373u64, 6895947823530343188u64, 15049839570318909599u64, 12250835051042597350u64, 16524764462147912930u64, 11496477575961038235u64, 1216851687255856656u64, 7654800921679748969u64, 10251257620367543320u64, 17625884659327141217u64, 8931528589852876522u64, 84259039178430355u64, 5655163293556783767u64, 3792978414742418414u64, 13532134484260726885u64, 13912670750543257884u64, 6369176129985355244u64, 2502782282785952917u64, 12525419179144613662u64, 15495561035627234919u64, 10978437246791527267u64, 16321975555527844378u64, 7920669638525335953u64, 1671873238255513832u64, 17531166746306175897u64, 9913345878835194592u64, 503231997654823275u64, 8945175932061546514u64, 3707538047961257238u64, 5308515798192249967u64, 14322348029964896228u64, 13554501644362141341u64, 10785157014839085493u64, 17254666630495879372u64, 6925536469308201799u64, 1928669229005230654u64, 6166683919569289018u64, 3408106242218915395u64, 11539515040484912584u64, 15779741191858611377u64, 4504865842858506176u64, 4925828954283753145u64, 14642502069914969394u64, 12820884771576065099u64, 18355716529793696079u64, 9540007361421969462u64, 796147016248169405u64, 8202193697865996996u64, 16763642538165118516u64, 10555343349626187597u64, 2095455317270639814u64, 7479631577382337983u64, 2926364910754730171u64, 5928137516128508354u64, 15937228569359352393u64, 12102324735718361904u64, 4867406749023426625u64, 4131191115536978232u64, 13131477498808912563u64, 14763945261529023434u64, 9490322283846233806u64, 17972763431062038455u64, 8504067431303216188u64, 926884511990314309u64, 8051711962477172407u64, 1541670979892322254u64, 11100683476643087429u64, 16201132341218348348u64, 12647664856023343160u64, 15374718365700663617u64, 6500217898808488650u64, 2372580570961558451u64, 14165371048561993922u64, 13712881572587659707u64, 3541342762140498480u64, 5475551080882205513u64, 337036156713721421u64, 9112211761281881908u64, 17374189211922025663u64, 10071726351451997638u64, 1348144626854885686u64, 7524919785159454799u64, 16646839095189990340u64, 11375251796044276413u64, 15171913658969673657u64, 12129609824107054784u64, 2827581646778391883u64, 6766067242130363442u64, 13374985906044110659u64, 14070668113165684282u64, 5489218623395763633u64, 3960334819262667976u64, 8765583373153087948u64, 251615998827411637u64, 10094108497768031038u64, 17783882574922426951u64, 5392578507419542746u64, 3462768234654100899u64, 13791895647060686376u64, 14249064643987996497u64, 10011129131143811669u64, 17309264314385947436u64, 9177858264896848039u64, 398073508124084702u64, 16284634862666717871u64, 11179858319785628630u64, 1463182455377365085u64, 7968614284679676196u64, 2433703374511713312u64, 6565738749404456281u64, 15309601843359497938u64, 12587227855704700843u64, 4025855981238586203u64, 5550341738321543714u64, 14010231419946703273u64, 13309869690798280912u64, 17863057179705753044u64, 10177610780853122221u64, 168518078356860710u64, 8687094605961012831u64, 11310326587113567534u64, 16586241563491499095u64, 7585956829484836828u64, 1413790823389195941u64, 6687492953022055329u64, 2744609311697881816u64, 12213303662187237715u64, 15250927976100943914u64, 12738352259970710488u64, 14564578711588090529u64, 5005564565571905834u64, 4588929132448424019u64, 8142317431333358935u64, 731591227688682542u64, 9606093343850471333u64, 18417404465172059868u64, 2012927990619293101u64, 7005115709973351636u64, 17176652871151048543u64, 10702745209522052646u64, 15841339277050671906u64, 11605722277885901403u64, 3343746476511027664u64, 6106651831093618857u64, 14830152191845028953u64, 13193075276920315168u64, 4071158715666679467u64, 4803046671925235666u64, 1006463995309646550u64, 8588326435575524271u64, 17890351864123093028u64, 9412308762883553629u64, 7415076095922514476u64, 2035579357833339733u64, 10617031596384499934u64, 16829728831969243559u64, 12024401134718426275u64, 15854695815076877786u64, 6012200567359213137u64, 3006100283679606568u64];
+
+#[rustfmt::skip]
+pub(crate) static REF_64_92C64265D32139A4: [u64; 256] = [0u64, 579249812102468073u64, 1158499624204936146u64, 1736764273754698299u64, 2316999248409872292u64, 2895333149540423245u64, 3473528547509396598u64, 4052566135947507103u64, 4633998496819744584u64, 5208041016870231713u64, 5790666299080846490u64, 6365131031730617715u64, 6947057095018793196u64, 7521309605077948677u64, 8105132271895014206u64, 8679244044600031959u64, 9267996993639489168u64, 9842443864073391993u64, 10416082033740463426u64, 10990106691575082155u64, 11581332598161692980u64, 12155426507577100509u64, 12730262063461235430u64, 13304496710230780687u64, 13894114190037586392u64, 14472360967674249265u64, 15042619210155897354u64, 15621851150345266147u64, 16210264543790028412u64, 16789284261388781461u64, 17358488089200063918u64, 17936804119491257415u64, 2644053445006872169u64, 3222457731979681664u64, 3792666250855902651u64, 4371774225404705874u64, 330506680625859021u64, 909686106584078372u64, 1478838011229743647u64, 2057032274903694326u64, 7277488852672626977u64, 7851811748876030152u64, 8425395738900587251u64, 8999577897481417498u64, 4961127339061380741u64, 5535099473269610348u64, 6109878649951036759u64, 6684272996490114238u64, 11830704089837949177u64, 12404868385095614736u64, 12987479679373275947u64, 13561784712253514434u64, 9514056773273392989u64, 10088433257563047604u64, 10672239737960707215u64, 11246194009919513958u64, 16456258199229554609u64, 17035348302972554840u64, 17614579666810010723u64, 18192966082977017226u64, 14143551532875814933u64, 14721727924670220796u64, 15299902675887733703u64, 15879064229966408238u64, 5288106890013744338u64, 4713801582440140091u64, 6444915463959363328u64, 5870750893739896553u64, 7585332501711805302u64, 7011377954925415071u64, 8743548450809411748u64, 8169171691423738189u64, 661013361251718042u64, 82626670156464755u64, 1819372213168156744u64, 1240281834765345185u64, 2957676022459487294u64, 2378514193318348247u64, 4114064549807388652u64, 3535887883218953733u64, 14554977705345253954u64, 13975870005858915243u64, 15703623497752060304u64, 15125219485573279865u64, 16850791477801174502u64, 16272597489055470607u64, 17999155794962834996u64, 17419976643664426973u64, 9922254678122761482u64, 9348072794369514723u64, 11070198946539220696u64, 10495876325431836465u64, 12219757299902073518u64, 11645363228056361799u64, 13368545992980228476u64, 12794574133733800085u64, 7913582845880750779u64, 7339557913117885266u64, 9061630503524790633u64, 8487183358431076480u64, 5617417146008704287u64, 5043182224176694518u64, 6766309228851312333u64, 6192215044641875748u64, 3287052404930534899u64, 2707820190047800346u64, 4435524438759505441u64, 3857277386161041352u64, 989197855735093847u64, 410881550616316862u64, 2137388414855788933u64, 1558368422161016940u64, 17099107318846311467u64, 16520842944124132802u64, 18257569558446829561u64, 17678320021440380432u64, 14802092862846307215u64, 14223055549101562470u64, 15958584777341413469u64, 15380251151172663732u64, 12466947377288135523u64, 11892482919700828810u64, 13623582195407380657u64, 13049539950150922584u64, 10170495871778424007u64, 9596384374001653038u64, 11328538064512788245u64, 10754285829113444092u64, 10576213780027488676u64, 11155322574864738381u64, 9427603164880280182u64, 10006008272141536159u64, 12889830927918726656u64, 13468023812992020457u64, 11741501787479793106u64, 12320679834837355579u64, 15170665003423610604u64, 15744847990849267461u64, 14022755909850830142u64, 14597079634899059927u64, 17487096901618823496u64, 18061489878113624225u64, 16338343382847476378u64, 16912314147011429235u64, 1322026722503436084u64, 1896333125427951325u64, 165253340312929510u64, 739419005614871823u64, 3638744426336313488u64, 4212697869450293625u64, 2480563669530690370u64, 3054939324975518379u64, 5915352044918974588u64, 6493739839686637973u64, 4757028386636696494u64, 5336119868980353607u64, 8228129099614777304u64, 8807289833405005361u64, 7071775766437907466u64, 7649951337943866851u64, 13148209619722139597u64, 13726472890503472676u64, 11989712203398894623u64, 12568960636732933622u64, 10831280760191201385u64, 11410319169018421632u64, 9674753668436497339u64, 10253088389956158034u64, 17742097755038021765u64, 18316561117542852972u64, 16585427762611968855u64, 17159468912517515966u64, 15429109544644165409u64, 16003222146361781960u64, 14271032177066122483u64, 14845285516137876762u64, 3956791422940375389u64, 4530815251762395316u64, 2808708573004352143u64, 3383154614425656166u64, 1643526202465267449u64, 2217762219379752720u64, 494598927867546923u64, 1068694207427894466u64, 8549553659423155733u64, 9128784779223414780u64, 7401046431423153607u64, 7979292388670706734u64, 6233473688644067761u64, 6811791097703690328u64, 5085247935889212003u64, 5664269032256394122u64, 15827165691761501558u64, 15252719925268444319u64, 14679115826235770532u64, 14105092272073561933u64, 18123261007049581266u64, 17549166002551698235u64, 16974366716862152960u64, 16400130974741696745u64, 11234834292017408574u64, 10656588609463221207u64, 10086364448353389036u64, 9507133603514931205u64, 13532618457702624666u64, 12953597636163026035u64, 12384430089283751496u64, 11806112955320147873u64, 6574104809861069798u64, 5994856101699447311u64, 5415640380095600692u64, 4837376834218248669u64, 8871048877519010882u64, 8292713881305984427u64, 7714554772322082704u64, 7135516088533061241u64, 1978395711470187694u64, 1404354286502176071u64, 821763101232633724u64, 247299463933773461u64, 4274776829711577866u64, 3700523215711576803u64, 3116736844322033880u64, 2542623967944606001u64, 18380584046611262239u64, 17806418656002685686u64, 17223773281963821261u64, 16649467154001107236u64, 16083288047108009147u64, 15508912666490764626u64, 14925069907845451625u64, 14351116739827490432u64, 13788815735752713303u64, 13209724528337302974u64, 12630459091181094789u64, 12052071571073242732u64, 11492082685666010099u64, 10913907389222515226u64, 10335696366199799841u64, 9756535907203600840u64, 9190292023305631119u64, 8611886640981910630u64, 8041644023554004573u64, 7462534953922725812u64, 6894407867876356651u64, 6315229545590547394u64, 5746041342833005049u64, 5167848183099899920u64, 4595146011652815559u64, 4020822011777002286u64, 3447203933938178325u64, 2873020671416502524u64, 2297573005826407779u64, 1723601966969089162u64, 1148786502913203889u64, 574393251456601944u64];
+
+#[rustfmt::skip]
+pub(crate) static REF_64_9A6C9329AC4BC9B5: [u64; 256] = [0u64, 9182541432847960441u64, 18365082865695920882u64, 9345832722727082891u64, 14511413233979602575u64, 13117883710352670710u64, 4016934769805403261u64, 5247243509741595908u64, 11969702169228410485u64, 15668617373955487500u64, 6395407394255400071u64, 2859783479402063358u64, 8033869539610806522u64, 1157698950281609603u64, 10494487019483191816u64, 17207436825116529521u64, 8710242310496874369u64, 544390144406054648u64, 9672921298356239731u64, 17965965451118834698u64, 12790814788510800142u64, 14910550302343712887u64, 5719566958804126716u64, 3472568952111055493u64, 16067739079221613044u64, 11642617884288424077u64, 2315397900563219206u64, 6867711082173303423u64, 1702069273413494651u64, 7561550595985681922u64, 16808303987311785353u64, 10821560171884821744u64, 17420484620993748738u64, 10281452688370128507u64, 1088780288812109296u64, 8102801665828209801u64, 4081135393624123789u64, 5174050811428790516u64, 14303144984052136831u64, 13335169890544513542u64, 6331237281917575543u64, 2932936320451717134u64, 12178000930637296517u64, 15451291336501017340u64, 11439133917608253432u64, 16271795144152063617u64, 6945137904222110986u64, 2237417001980464243u64, 9881180485936394371u64, 17748670208579942906u64, 8646032624330580593u64, 617573780371024648u64, 4630795801126438412u64, 4552317850264964981u64, 13735422164346606846u64, 13974939467834563975u64, 3404138546826989302u64, 5788002041349785487u64, 15123101191971363844u64, 12578268576127440253u64, 16599996146375981177u64, 11038877129812111616u64, 1766230306223614603u64, 7488388675408585714u64, 15517955973396180335u64, 12120359180407907350u64, 2992425542307102621u64, 6262760941951170276u64, 2177560577624218592u64, 7014021097877803673u64, 16205603331656419602u64, 11496333698567045227u64, 8162270787248247578u64, 1020283848406030947u64, 10348101622857581032u64, 17362827168352323729u64, 13268962925398943125u64, 14360329612356938988u64, 5114174836390786919u64, 4149999036593995294u64, 12662474563835151086u64, 15047886789205674903u64, 5865872640903434268u64, 3317240731349735781u64, 7410885347125621857u64, 1852721336781405464u64, 10954198386508228243u64, 16675652587215613930u64, 695464411657452699u64, 8559154840590169570u64, 17832891829959241321u64, 9805981716846356240u64, 13890275808444221972u64, 13811093689104106349u64, 4474834003960928486u64, 4717306313667482015u64, 2781857646629810797u64, 6482320345254034196u64, 15584360551092445343u64, 12044936105578752486u64, 17292065248661161186u64, 10418850627624291739u64, 1235147560742049296u64, 7947394159970475881u64, 9261591602252876824u64, 18440332504439424353u64, 9104635700529929962u64, 86933051457181587u64, 5324711670898473623u64, 3930478940865573870u64, 13202527286532548709u64, 14435791994760012060u64, 6808277093653978604u64, 2383859105125700757u64, 11576004082699570974u64, 16125361296017327719u64, 10887802304290696035u64, 16751084157523239450u64, 7621461738281908625u64, 1633170428957798632u64, 17899336015843713945u64, 9730527881461623520u64, 484936124168630635u64, 8778683483337193490u64, 3532460612447229206u64, 5650648632384052335u64, 14976777350817171428u64, 12733579874785513117u64, 11127430586519243189u64, 16501861805283229900u64, 7255706616989801287u64, 2008467384902701630u64, 5984851084614205242u64, 3197703697127700035u64, 12525521883902340552u64, 15185406778534061233u64, 4355121155248437184u64, 4836460649178119865u64, 14028042195755607346u64, 13673894688497708107u64, 17659347437411533135u64, 9978967587602237494u64, 851034636706747325u64, 8404151993655892676u64, 16324541574496495156u64, 11376828069156237133u64, 2040567696812061894u64, 7151572492026068415u64, 3165618640958787771u64, 6089000465318648258u64, 15362738141892903497u64, 12276135533837191984u64, 4940590242968197185u64, 4323016312165290296u64, 13424492949564705459u64, 14205358170246726602u64, 10228349672781573838u64, 17482011809167009719u64, 8299998073187990588u64, 883115153111807301u64, 7758977986698090167u64, 1496212771153551310u64, 10768261529147802693u64, 16870057623371222332u64, 11731745281806868536u64, 15970178678932056385u64, 6634481462699471562u64, 2557087418195393459u64, 14821770694251243714u64, 12889145053243924923u64, 3705442673562810928u64, 5477099193254114121u64, 347732205828726349u64, 8916445914979620660u64, 18018485433612717247u64, 9610811077088298438u64, 1390928823314905398u64, 7792180275546222671u64, 17118309681180339140u64, 10592047673346876093u64, 15721915763462295481u64, 11907948279947413184u64, 2662355835449236811u64, 6601263643266274354u64, 13065363431662389059u64, 14573523159059271226u64, 5443901152145348017u64, 3810730869140954312u64, 8949668007921856972u64, 242468062084315317u64, 9434612627334964030u64, 18266752897469557319u64, 5563715293259621594u64, 3627853308494900643u64, 12964640690508068392u64, 14737282991092629329u64, 9535436420502803029u64, 18102883011221642028u64, 8829673718705766567u64, 425517318353373662u64, 16945538107983338159u64, 10683758673333097430u64, 1582809320479393885u64, 7681369070946451748u64, 2470295121484098592u64, 6712246474782352729u64, 15894788319940951762u64, 11816127157005858731u64, 3879488993349045083u64, 5384130764573901346u64, 14630883743593699753u64, 12998980579021361360u64, 18209271401059859924u64, 9501085674329060525u64, 173866102914363174u64, 9009242716806358623u64, 10649423341796947246u64, 17051941912459279447u64, 7860957881731147740u64, 1331177917724618405u64, 6532681715831194529u64, 2721950576072674008u64, 11850482417207202131u64, 15788404444129941546u64, 13616554187307957208u64, 14094374762992939681u64, 4767718210251401514u64, 4414836859352883283u64, 8472769053991669079u64, 791404660239696942u64, 10036468583241064357u64, 17592823520898193116u64, 1939744496674522541u64, 7315441871796849876u64, 16444536456726632287u64, 11193778306393792038u64, 15242923476563817250u64, 12459013669784255067u64, 3266340857915597264u64, 5925241208603601065u64, 14129842449788916825u64, 13509030938278026528u64, 4236384526995834539u64, 5018234291620532178u64, 969872248337261270u64, 8222268228363316143u64, 17557366966674386980u64, 10144002964842903901u64, 7064921224894458412u64, 2118192263497917269u64, 11301297264768104670u64, 16409064479275168167u64, 12351475057656208547u64, 15278375800261809626u64, 6175737528828104273u64, 3087868764414052136u64];
+
+#[rustfmt::skip]
+pub(crate) static REF_64_C96C5795D7870F42: [u64; 256] = [0u64, 12911341560706588527u64, 17619267392293085275u64, 5164075066763771700u64, 8921845837811637811u64, 14483170935171449180u64, 10328150133527543400u64, 4357999468653093127u64, 17843691675623275622u64, 4940391307328217865u64, 226782375002905661u64, 12685511915359257426u64, 10119945210068853333u64, 4566377562367245626u64, 8715998937306186254u64, 14689403211693301089u64, 9051005139383707209u64, 14895072503764629798u64, 9880782614656435730u64, 4193374422961527165u64, 453564750005811322u64, 13070904082541799189u64, 17496296445768931361u64, 4747102235666401102u64, 9960315520700766767u64, 4113029525020509504u64, 9132755124734491252u64, 14812441257301386523u64, 17431997874612372508u64, 4811156168024382323u64, 391483189436228679u64, 13132671735097031464u64, 18102010278767414418u64, 5195199925788447741u64, 1131375642422963401u64, 13591081480414639014u64, 9288535643022529185u64, 3731739485546663374u64, 8386748845923054330u64, 14361410892855143829u64, 907129500011622644u64, 13814943346342178715u64, 17875617253995106479u64, 5421418680781082560u64, 8594564625313771207u64, 14152643483341451688u64, 9494204471332802204u64, 3525329033817543155u64, 9704381199536204507u64, 3855837706121835956u64, 8226059050041019008u64, 13908973417437222383u64, 18265510249468982504u64, 5643692520190618503u64, 718348998302913715u64, 13463047253836762076u64, 8146277531524994749u64, 13989069943491807698u64, 9622312336048764646u64, 3938150108875254153u64, 782966378872457358u64, 13399312233903888353u64, 18327840216347633877u64, 5582173445676054458u64, 7257036000092981153u64, 15535280666427316430u64, 10390399851576895482u64, 2529986302517213333u64, 2262751284845926802u64, 12414353723947190013u64, 16997392145760156105u64, 6398650419759490726u64, 10599130201908394951u64, 2322133910755632296u64, 7463478971093326748u64, 15329644185724306675u64, 16773497691846108660u64, 6622864283287239323u64, 2036569382881248687u64, 12640783567252986560u64, 1814259000023245288u64, 12250853444207230599u64, 17125426475222188467u64, 6811676960462675676u64, 7132938157145702363u64, 15119434731753103540u64, 10842837361562165120u64, 2690676064372932847u64, 17189129250627542414u64, 6747026957542163169u64, 1875814858707893717u64, 12188560364711551674u64, 10762704257491731389u64, 2770420489343360210u64, 7050658067635086310u64, 15201536148867841161u64, 11493583972846619443u64, 3219832958944941148u64, 7711675412243671912u64, 15576564987190227975u64, 16452118100082038016u64, 6305011443818121839u64, 1213047649942025563u64, 11816267669673208372u64, 7503259434831574869u64, 15784731923736995898u64, 11287385040381237006u64, 3425713581329221729u64, 1436697996605827430u64, 11591809733187859977u64, 16677985422973077821u64, 6078267261889762898u64, 16292555063049989498u64, 5851447209550246421u64, 1630020308903038241u64, 11939238787801010766u64, 11081681957373440841u64, 3090674103720225830u64, 7876300217750508306u64, 16023932746787097725u64, 1565932757744914716u64, 12003503911822413427u64, 16230825569204842823u64, 5913566482019610152u64, 7956607163135676207u64, 15944361922680361024u64, 11164346891352108916u64, 3008957496780927003u64, 14514072000185962306u64, 8809633696146542637u64, 4460922918905818905u64, 10287960411460399222u64, 12879331835779764593u64, 113391187501452830u64, 5059972605034426666u64, 17660565739912801861u64, 4525502569691853604u64, 10224187249629523019u64, 14576435430675780479u64, 8748148222884465680u64, 4980157760350383383u64, 17740628527280140920u64, 12797300839518981452u64, 195741594718114339u64, 13040162471224305931u64, 565687821211481700u64, 4644267821511264592u64, 17536326748496696895u64, 14926957942186653496u64, 8937808626997553239u64, 4297282312656885603u64, 9839608450464401420u64, 4852190599768102253u64, 17327666750234135042u64, 13245728566574478646u64, 359174499151456857u64, 4073138765762497374u64, 10063573324157604913u64, 14700457781105076997u64, 9163920108173816938u64, 3628518000046490576u64, 9328460452529085631u64, 14330211790445699979u64, 8498696072880078052u64, 5299565100954197475u64, 18061012165519327884u64, 13623353920925351352u64, 1018284691440624343u64, 14265876314291404726u64, 8562713237611094233u64, 3566469078572851181u64, 9390260331795218562u64, 13702854325316886917u64, 937907429353946858u64, 5381352128745865694u64, 17978417549248290481u64, 5746791986423309721u64, 18225777846762470134u64, 13494053915084326338u64, 606523824971012781u64, 3751629717415787434u64, 9745292510640121029u64, 13876787882151992305u64, 8338992711486538910u64, 13285957365033343487u64, 815010154451519120u64, 5540840978686720420u64, 18431906428167644875u64, 14101316135270172620u64, 8115412784602421411u64, 3978303581567838103u64, 9519354766961195256u64, 12527462061959317731u64, 2230461459452909452u64, 6439665917889882296u64, 16893009583564617687u64, 15423350824487343824u64, 7288217715337890239u64, 2490078880175191691u64, 10493603952060017124u64, 6520081235612152965u64, 16813546994155744234u64, 12610022887636243678u64, 2148641156328442801u64, 2426095299884051126u64, 10557972909709735385u64, 15361512820870335213u64, 7350228890552538498u64, 15006518869663149738u64, 7165105895222849989u64, 2649782550477098737u64, 10947027550912647582u64, 12362696414880903321u64, 1783234539286425590u64, 6851427162658443458u64, 17022309211647725485u64, 2873395993211654860u64, 10722532847870938531u64, 15232418832718623383u64, 6938393941075996152u64, 6642978682516671743u64, 17230443782969840528u64, 12156534523779525796u64, 1989151790783919051u64, 6263731030979658865u64, 16556202624882645790u64, 11702894419100492842u64, 1245039440087595845u64, 3260040617806076482u64, 11390642587947386157u64, 15688795063501830681u64, 7680756410435167606u64, 11622868312827688983u64, 1324891275238549368u64, 6181348207440451660u64, 16638201170595874595u64, 15752600435501016612u64, 7616209416359311691u64, 3321489341258335871u64, 11328242235714328848u64, 3131865515489829432u64, 10977756817953029463u64, 16137146508898304611u64, 7844397531750915340u64, 5811434156413844491u64, 16395372229761246052u64, 11827132964039220304u64, 1660744670629167935u64, 15913214326271352414u64, 8068573254449152305u64, 2905717078206922245u64, 11204220263579804010u64, 12035829987123708013u64, 1452858539103461122u64, 6017914993561854006u64, 16189773752444600153u64];
+
+/// Find the static lookup table generated with the given length of bits, expression and input reflection.
+pub(crate) const fn find_table(bits: u8, poly: u64, refin: bool) -> Option<&'static [u64; 256]> {
+    match (bits, poly, refin) {
+        (40, 0x0004820009, false) => Some(&NO_REF_40_0000000004820009),
+        (64, 0x92C64265D32139A4, true) => Some(&REF_64_92C64265D32139A4),
+        (64, 0x95AC9329AC4BC9B5, true) => Some(&REF_64_95AC9329AC4BC9B5),
+        (64, 0x9A6C9329AC4BC9B5, true) => Some(&REF_64_9A6C9329AC4BC9B5),
+        (64, 0xC96C5795D7870F42, true) => Some(&REF_64_C96C5795D7870F42),
+        (64, 0xD800000000000000, true) => Some(&REF_64_D800000000000000),
+        (64, 0x42F0E1EBA9EA3693, false) => Some(&NO_REF_64_42F0E1EBA9EA3693),
+        _ => None,
+    }
+}
@@ -0,0 +1,993 @@
+#[cfg(feature = "alloc")]
+use alloc::vec::Vec;
+#[cfg(feature = "alloc")]
+use core::fmt::Debug;
+use core::fmt::{self, Binary, Display, Formatter, LowerHex, Octal, UpperHex};
+
+#[cfg(feature = "heapless")]
+use heapless::Vec as HeaplessVec;
+
+use crate::{
+    backend::Backend,
+    endian::Endian,
+    engine::CrcEngine,
+    errors::{CrcParamError, UnavailableBackend, check_crc_params, check_params},
+    hex,
+    params::CrcParams,
+    table_cache::CachedTable,
+};
+
+#[allow(clippy::upper_case_acronyms)]
+#[derive(Clone)]
+/// This struct can help you compute a CRC-128 (or CRC-x where **x** is equal or less than `128`) value.
+pub struct CRCu128 {
+    by_table:        bool,
+    poly:            u128,
+    lookup_table:    &'static [u128; 256],
+    sum:             u128,
+    pub(crate) bits: u8,
+    high_bit:        u128,
+    mask:            u128,
+    initial:         u128,
+    final_xor:       u128,
+    refin:           bool,
+    refout:          bool,
+    reorder:         bool,
+}
+
+#[cfg(feature = "alloc")]
+impl Debug for CRCu128 {
+    #[inline]
+    fn fmt(&self, f: &mut Formatter) -> Result<(), fmt::Error> {
+        if self.by_table {
+            debug_helper::impl_debug_for_struct!(CRCu128, f, self, let .lookup_table = self.lookup_table.as_ref(), (.sum, "0x{:032X}", self.sum), .bits, (.initial, "0x{:032X}", self.initial), (.final_xor, "0x{:032X}", self.final_xor), .refin, .refout, .reorder);
+        } else {
+            debug_helper::impl_debug_for_struct!(CRCu128, f, self, (.poly, "0x{:032X}", self.poly), (.sum, "0x{:032X}", self.sum), .bits, (.initial, "0x{:032X}", self.initial), (.final_xor, "0x{:032X}", self.final_xor), .refin, .refout, .reorder);
+        }
+    }
+}
+
+impl Display for CRCu128 {
+    #[inline]
+    fn fmt(&self, f: &mut Formatter) -> Result<(), fmt::Error> {
+        f.write_fmt(format_args!("0x{:01$X}", self.get_crc(), (self.bits as usize + 3) >> 2))
+    }
+}
+
+impl LowerHex for CRCu128 {
+    #[inline]
+    fn fmt(&self, f: &mut Formatter) -> Result<(), fmt::Error> {
+        hex::fmt_crc(f, self.get_crc(), self.bits, 4, false)
+    }
+}
+
+impl UpperHex for CRCu128 {
+    #[inline]
+    fn fmt(&self, f: &mut Formatter) -> Result<(), fmt::Error> {
+        hex::fmt_crc(f, self.get_crc(), self.bits, 4, true)
+    }
+}
+
+impl Octal for CRCu128 {
+    #[inline]
+    fn fmt(&self, f: &mut Formatter) -> Result<(), fmt::Error> {
+        hex::fmt_crc(f, self.get_crc(), self.bits, 3, false)
+    }
+}
+
+impl Binary for CRCu128 {
+    #[inline]
+    fn fmt(&self, f: &mut Formatter) -> Result<(), fmt::Error> {
+        hex::fmt_crc(f, self.get_crc(), self.bits, 1, false)
+    }
+}
+
+impl CRCu128 {
+    /// Create a `CRCu128` instance by providing the length of bits, expression, reflection, an initial value and a final xor value.
+    pub fn create_crc(
+        poly: u128,
+        bits: u8,
+        initial: u128,
+        final_xor: u128,
+        reflect: bool,
+    ) -> CRCu128 {
+        Self::create_crc_with_reflection(poly, bits, initial, final_xor, reflect, reflect)
+    }
+
+    /// Create a `CRCu128` instance by providing the length of bits, expression, input reflection, output reflection, an initial value and a final xor value.
+    ///
+    /// The expression needs to be reflected if `refin` is `true`.
+    pub fn create_crc_with_reflection(
+        poly: u128,
+        bits: u8,
+        initial: u128,
+        final_xor: u128,
+        refin: bool,
+        refout: bool,
+    ) -> CRCu128 {
+        debug_assert!(bits <= 128 && bits > 0);
+
+        let lookup_table =
+            if bits.is_multiple_of(8) { Self::lookup_table_of(bits, poly, refin) } else { None };
+
+        if let Some(lookup_table) = lookup_table {
+            Self::create(true, lookup_table, 0, bits, initial, final_xor, refin, refout)
+        } else {
+            Self::create(false, &[0u128; 256], poly, bits, initial, final_xor, refin, refout)
+        }
+    }
+
+    /// Set whether the bytes of the CRC value are output in reverse order, like `crc32` of the `mhash` library does (see [`CRCu32::crc32mhash`](crate::CRCu32::crc32mhash)). Only the bytes covered by the length of bits are reversed.
+    ///
+    /// This affects `get_crc`, the formatted output and the byte getters, and `resume_from` expects a CRC value in the same order. The state of the register does not depend on it.
+    #[inline]
+    pub fn with_reorder(mut self, reorder: bool) -> CRCu128 {
+        self.reorder = reorder;
+
+        self
+    }
+
+    /// Select the backend which processes the input, keeping the current state. An error is returned if the backend is not available for the algorithm or on the CPU. See [`Backend`] for details.
+    pub fn with_backend(mut self, backend: Backend) -> Result<CRCu128, UnavailableBackend> {
+        let poly = self.normal_poly();
+        let state = self.state();
+
+        match backend {
+            Backend::Bitwise => {
+                self.by_table = false;
+                self.lookup_table = &[0; 256];
+                self.poly = poly;
+            },
+            Backend::Table if self.switch_to_table(poly) => (),
+            _ => {
+                return Err(UnavailableBackend {
+                    backend,
+                });
+            },
+        }
+
+        self.set_state(state);
+
+        Ok(self)
+    }
+
+    /// Get the backend which processes the input.
+    pub fn backend(&self) -> Backend {
+        if self.by_table { Backend::Table } else { Backend::Bitwise }
+    }
+
+    /// Create a `CRCu128` instance from the parameters of a CRC algorithm.
+    pub fn from_params(params: &CrcParams) -> CRCu128 {
+        Self::create_crc_with_reflection(
+            params.create_crc_poly(),
+            params.width,
+            params.init,
+            params.xorout,
+            params.refin,
+            params.refout,
+        )
+    }
+
+    /// Create a `CRCu128` instance like [`CRCu128::create_crc`], but return an error instead of creating a broken instance if the parameters are invalid.
+    pub fn try_create_crc(
+        poly: u128,
+        bits: u8,
+        initial: u128,
+        final_xor: u128,
+        reflect: bool,
+    ) -> Result<CRCu128, CrcParamError> {
+        Self::try_create_crc_with_reflection(poly, bits, initial, final_xor, reflect, reflect)
+    }
+
+    /// Create a `CRCu128` instance like [`CRCu128::create_crc_with_reflection`], but return an error instead of creating a broken instance if the parameters are invalid.
+    pub fn try_create_crc_with_reflection(
+        poly: u128,
+        bits: u8,
+        initial: u128,
+        final_xor: u128,
+        refin: bool,
+        refout: bool,
+    ) -> Result<CRCu128, CrcParamError> {
+        check_params(128, bits, poly, initial, final_xor)?;
+
+        Ok(Self::create_crc_with_reflection(poly, bits, initial, final_xor, refin, refout))
+    }
+
+    /// Create a `CRCu128` instance from the parameters of a CRC algorithm, or return an error if the parameters are invalid.
+    pub fn try_from_params(params: &CrcParams) -> Result<CRCu128, CrcParamError> {
+        check_crc_params(128, params)?;
+
+        Ok(Self::from_params(params))
+    }
+
+    /// Create a `CRCu128` instance from the parameters of a CRC algorithm and a lookup table generated by [`CRCu128::table`] with the same parameters, so that the table does not need to be generated at runtime.
+    ///
+    /// The lookup table is only used if the length of bits is a multiple of `8`.
+    pub fn from_params_with_table(params: &CrcParams, table: &'static [u128; 256]) -> CRCu128 {
+        if params.width.is_multiple_of(8) {
+            Self::create(
+                true,
+                table,
+                0,
+                params.width,
+                params.init,
+                params.xorout,
+                params.refin,
+                params.refout,
+            )
+        } else {
+            Self::from_params(params)
+        }
+    }
+
+    /// Generate the lookup table of a CRC algorithm. It can be evaluated at compile time.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the length of bits of `params` is 0 or larger than `128`.
+    ///
+    /// ```
+    /// use crc_any::{
+    ///     CRCu128,
+    ///     params::{CRC_82_DARC, CrcParams},
+    /// };
+    ///
+    /// const PARAMS: CrcParams = CRC_82_DARC;
+    /// const TABLE: [u128; 256] = CRCu128::table(&PARAMS);
+    /// ```
+    pub const fn table(params: &CrcParams) -> [u128; 256] {
+        assert!(
+            params.width > 0 && params.width <= 128,
+            "the length of bits must be between 1 and 128"
+        );
+
+        if params.refin {
+            Self::crc_reflect_table(params.create_crc_poly())
+        } else {
+            Self::crc_table(params.poly, params.width)
+        }
+    }
+
+    /// Compute the CRC value of `data` in one shot. It can be evaluated at compile time, but it processes the data bit by bit, so prefer creating a `CRCu128` instance at runtime.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the length of bits of `params` is 0 or larger than `128`.
+    ///
+    /// ```
+    /// use crc_any::{CRCu128, params::CRC_82_DARC};
+    ///
+    /// const CHECK: u128 = CRCu128::checksum(&CRC_82_DARC, b"123456789");
+    ///
+    /// assert_eq!(0x09EA83F625023801FD612, CHECK);
+    /// ```
+    pub const fn checksum(params: &CrcParams, data: &[u8]) -> u128 {
+        assert!(
+            params.width > 0 && params.width <= 128,
+            "the length of bits must be between 1 and 128"
+        );
+
+        let high_bit = 1u128 << (params.width - 1);
+        let mask = ((high_bit - 1) << 1) | 1;
+        let poly = params.poly;
+
+        let mut sum = params.init;
+
+        let mut i = 0;
+
+        while i < data.len() {
+            let n = if params.refin { data[i].reverse_bits() } else { data[i] };
+
+            let mut b = 0x80u8;
+
+            while b != 0 {
+                let mut bit = sum & high_bit;
+
+                sum <<= 1;
+
+                if n & b != 0 {
+                    bit ^= high_bit;
+                }
+
+                if bit != 0 {
+                    sum ^= poly;
+                }
+
+                b >>= 1;
+            }
+
+            i += 1;
+        }
+
+        if params.refout {
+            sum = sum.reverse_bits() >> (u128::BITS - params.width as u32);
+        }
+
+        (sum ^ params.xorout) & mask
+    }
+
+    #[allow(clippy::too_many_arguments)]
+    #[inline]
+    fn create(
+        by_table: bool,
+        lookup_table: &'static [u128; 256],
+        mut poly: u128,
+        bits: u8,
+        initial: u128,
+        final_xor: u128,
+        refin: bool,
+        refout: bool,
+    ) -> CRCu128 {
+        let high_bit = 1 << u128::from(bits - 1);
+        let mask = ((high_bit - 1) << 1) | 1;
+
+        // the register is kept reflected only when a reflected lookup table is used
+        let sum =
+            if by_table && refin { Self::reflect_function(high_bit, initial) } else { initial };
+
+        if !by_table && refin {
+            poly = Self::reflect_function(high_bit, poly);
+        }
+
+        CRCu128 {
+            by_table,
+            poly,
+            lookup_table,
+            sum,
+            bits,
+            high_bit,
+            mask,
+            initial,
+            final_xor,
+            refin,
+            refout,
+            reorder: false,
+        }
+    }
+
+    #[inline]
+    fn reflect_function(high_bit: u128, n: u128) -> u128 {
+        let bits = high_bit.trailing_zeros() + 1;
+
+        n.reverse_bits() >> (u128::BITS - bits)
+    }
+
+    #[inline]
+    fn reflect_method(&self, n: u128) -> u128 {
+        Self::reflect_function(self.high_bit, n)
+    }
+
+    /// Get the lookup table of an expression from the cache, or `None` if the cache is full.
+    ///
+    /// Unlike the narrower types, a table is never stored in the instance, because a `[u128; 256]` table would make every `CRC` 4 KiB larger. Without a cached table, the bitwise loop is used instead.
+    fn lookup_table_of(bits: u8, poly: u128, refin: bool) -> Option<&'static [u128; 256]> {
+        u128::get_or_insert((bits, poly, refin), &|| {
+            if refin { Self::crc_reflect_table(poly) } else { Self::crc_table(poly, bits) }
+        })
+    }
+
+    /// Get the expression in normal (unreflected) form.
+    fn normal_poly(&self) -> u128 {
+        if !self.by_table {
+            self.poly
+        } else if self.refin {
+            // the entry of `0x80` of a reflected lookup table is the reflected expression
+            self.reflect_method(self.lookup_table[0x80])
+        } else {
+            // the entry of `1` of a normal lookup table is the expression
+            self.lookup_table[1]
+        }
+    }
+
+    /// Use the lookup table of the expression `poly` (in normal form) if the bitwise loop is used. Return `false` if there is no lookup table for it.
+    fn switch_to_table(&mut self, poly: u128) -> bool {
+        if !self.by_table {
+            if !self.bits.is_multiple_of(8) {
+                return false;
+            }
+
+            let poly = if self.refin { self.reflect_method(poly) } else { poly };
+
+            match Self::lookup_table_of(self.bits, poly, self.refin) {
+                Some(lookup_table) => self.lookup_table = lookup_table,
+                None => return false,
+            }
+
+            self.by_table = true;
+            self.poly = 0;
+        }
+
+        true
+    }
+
+    /// Update the current CRC state with bytes.
+    #[inline]
+    pub fn update(&mut self, data: &[u8]) {
+        if self.by_table {
+            if self.bits == 8 {
+                for n in data.iter().copied() {
+                    let index = (self.sum as u8 ^ n) as usize;
+                    self.sum = self.lookup_table[index];
+                }
+            } else if self.refin {
+                for n in data.iter().copied() {
+                    let index = ((self.sum as u8) ^ n) as usize;
+                    self.sum = (self.sum >> 8) ^ self.lookup_table[index];
+                }
+            } else {
+                for n in data.iter().copied() {
+                    let index = ((self.sum >> u128::from(self.bits - 8)) as u8 ^ n) as usize;
+                    self.sum = (self.sum << 8) ^ self.lookup_table[index];
+                }
+            }
+        } else if self.refin {
+            for n in data.iter().copied() {
+                let n = super::crc_u8::CRCu8::reflect_function(0x80, n);
+
+                let mut i = 0x80;
+
+                while i != 0 {
+                    let mut bit = self.sum & self.high_bit;
+
+                    self.sum <<= 1;
+
+                    if n & i != 0 {
+                        bit ^= self.high_bit;
+                    }
+
+                    if bit != 0 {
+                        self.sum ^= self.poly;
+                    }
+
+                    i >>= 1;
+                }
+            }
+        } else {
+            for n in data.iter().copied() {
+                let mut i = 0x80;
+
+                while i != 0 {
+                    let mut bit = self.sum & self.high_bit;
+
+                    self.sum <<= 1;
+
+                    if n & i != 0 {
+                        bit ^= self.high_bit;
+                    }
+
+                    if bit != 0 {
+                        self.sum ^= self.poly;
+                    }
+
+                    i >>= 1;
+                }
+            }
+        }
+    }
+
+    /// Update the current CRC state with the bytes of a `u16` value in little-endian byte order.
+    #[inline]
+    pub fn update_u16_le(&mut self, value: u16) {
+        self.update(&value.to_le_bytes());
+    }
+
+    /// Update the current CRC state with the bytes of a `u16` value in big-endian byte order.
+    #[inline]
+    pub fn update_u16_be(&mut self, value: u16) {
+        self.update(&value.to_be_bytes());
+    }
+
+    /// Update the current CRC state with the bytes of a `u32` value in little-endian byte order.
+    #[inline]
+    pub fn update_u32_le(&mut self, value: u32) {
+        self.update(&value.to_le_bytes());
+    }
+
+    /// Update the current CRC state with the bytes of a `u32` value in big-endian byte order.
+    #[inline]
+    pub fn update_u32_be(&mut self, value: u32) {
+        self.update(&value.to_be_bytes());
+    }
+
+    /// Update the current CRC state with the bytes of a `u64` value in little-endian byte order.
+    #[inline]
+    pub fn update_u64_le(&mut self, value: u64) {
+        self.update(&value.to_le_bytes());
+    }
+
+    /// Update the current CRC state with the bytes of a `u64` value in big-endian byte order.
+    #[inline]
+    pub fn update_u64_be(&mut self, value: u64) {
+        self.update(&value.to_be_bytes());
+    }
+
+    /// Update the current CRC state with the bytes of an `f32` value in little-endian byte order.
+    #[inline]
+    pub fn update_f32_le(&mut self, value: f32) {
+        self.update(&value.to_le_bytes());
+    }
+
+    /// Update the current CRC state with the bytes of an `f32` value in big-endian byte order.
+    #[inline]
+    pub fn update_f32_be(&mut self, value: f32) {
+        self.update(&value.to_be_bytes());
+    }
+
+    /// Update the current CRC state with the bytes of an `f64` value in little-endian byte order.
+    #[inline]
+    pub fn update_f64_le(&mut self, value: f64) {
+        self.update(&value.to_le_bytes());
+    }
+
+    /// Update the current CRC state with the bytes of an `f64` value in big-endian byte order.
+    #[inline]
+    pub fn update_f64_be(&mut self, value: f64) {
+        self.update(&value.to_be_bytes());
+    }
+
+    /// Update the current CRC state with a single bit.
+    pub fn update_bit(&mut self, bit: bool) {
+        if self.by_table && self.refin {
+            // the register is reflected, and the reflected expression is the entry of `0x80`
+            let low_bit = (self.sum & 1 != 0) ^ bit;
+
+            self.sum >>= 1;
+
+            if low_bit {
+                self.sum ^= self.lookup_table[0x80];
+            }
+        } else {
+            let poly = self.normal_poly();
+
+            let high_bit = (self.sum & self.high_bit != 0) ^ bit;
+
+            self.sum <<= 1;
+
+            if high_bit {
+                self.sum ^= poly;
+            }
+        }
+    }
+
+    /// Update the current CRC state with the low `nbits` bits of `value`, for messages whose length is not a multiple of 8 bits.
+    ///
+    /// The bits are taken in the same order as the bits of the bytes passed to `update`: from the least significant bit if the input is reflected, and from the most significant bit (bit `nbits - 1`) otherwise. Thus, `update_bits(u64::from(byte), 8)` is the same as `update(&[byte])`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `nbits` is larger than `64`.
+    pub fn update_bits(&mut self, value: u64, nbits: u32) {
+        assert!(nbits <= 64, "cannot update with more than 64 bits at once");
+
+        if self.refin {
+            for i in 0..nbits {
+                self.update_bit((value >> i) & 1 != 0);
+            }
+        } else {
+            for i in (0..nbits).rev() {
+                self.update_bit((value >> i) & 1 != 0);
+            }
+        }
+    }
+
+    /// Digest some data.
+    ///
+    /// This is a compatibility wrapper around [`CRCu128::update`].
+    #[inline]
+    pub fn digest<T: ?Sized + AsRef<[u8]>>(&mut self, data: &T) {
+        self.update(data.as_ref());
+    }
+
+    /// Reset the sum.
+    pub fn reset(&mut self) {
+        self.sum = if self.by_table && self.refin {
+            Self::reflect_function(self.high_bit, self.initial)
+        } else {
+            self.initial
+        };
+    }
+
+    /// Get the current state of the register, which can be restored later with [`CRCu128::set_state`] or [`CRCu128::from_state`].
+    ///
+    /// The state is always in normal (unreflected) form and is taken before the output reflection and the final xor value are applied, so it does not depend on how the CRC is computed.
+    pub fn state(&self) -> u128 {
+        let sum = self.sum & self.mask;
+
+        if self.by_table && self.refin { self.reflect_method(sum) } else { sum }
+    }
+
+    /// Restore a state obtained from [`CRCu128::state`] of an instance with the same parameters. You can continue calling `update` or `digest` after that.
+    pub fn set_state(&mut self, state: u128) {
+        let state = state & self.mask;
+
+        self.sum = if self.by_table && self.refin { self.reflect_method(state) } else { state };
+    }
+
+    /// Create a `CRCu128` instance from the parameters of a CRC algorithm and a state obtained from [`CRCu128::state`].
+    pub fn from_state(params: &CrcParams, state: u128) -> CRCu128 {
+        let mut crc = Self::from_params(params);
+
+        crc.set_state(state);
+
+        crc
+    }
+
+    /// Continue from a CRC value returned by [`CRCu128::get_crc`] of an instance with the same parameters, as if the data of that CRC value had been passed to `update` or `digest`.
+    pub fn resume_from(&mut self, crc: u128) {
+        let crc = if self.reorder { self.reorder_method(crc) } else { crc };
+
+        let sum = (crc ^ self.final_xor) & self.mask;
+
+        self.set_state(if self.refout { self.reflect_method(sum) } else { sum });
+    }
+
+    /// Create a `CRCu128` instance from the parameters of a CRC algorithm and a CRC value of some previous data computed with the same parameters. Updating the instance with more data gives the CRC value of the concatenated data.
+    pub fn resume(params: &CrcParams, crc: u128) -> CRCu128 {
+        let mut crc_instance = Self::from_params(params);
+
+        crc_instance.resume_from(crc);
+
+        crc_instance
+    }
+
+    /// Check whether this instance and `other` compute the same CRC algorithm, regardless of their current states and backends.
+    pub fn is_same_algorithm(&self, other: &CRCu128) -> bool {
+        self.bits == other.bits
+            && self.initial == other.initial
+            && self.final_xor == other.final_xor
+            && self.refin == other.refin
+            && self.refout == other.refout
+            && self.reorder == other.reorder
+            && self.normal_poly() == other.normal_poly()
+    }
+
+    /// Create a copy of this instance, including its current state. This is useful for computing the CRC values of many messages sharing a common prefix, which only needs to be processed once.
+    #[inline]
+    pub fn fork(&self) -> CRCu128 {
+        self.clone()
+    }
+
+    /// Get the current CRC value (it always returns a `u128` value). You can continue calling `update` or `digest` even after getting a CRC value.
+    pub fn get_crc(&self) -> u128 {
+        let sum = if (self.by_table && self.refin) == self.refout {
+            (self.sum ^ self.final_xor) & self.mask
+        } else {
+            (self.reflect_method(self.sum) ^ self.final_xor) & self.mask
+        };
+
+        if self.reorder { self.reorder_method(sum) } else { sum }
+    }
+
+    #[inline]
+    fn reorder_method(&self, sum: u128) -> u128 {
+        let mut new_sum = 0;
+
+        let e = u128::from(self.bits).div_ceil(8);
+
+        let e_dec = e - 1;
+
+        for i in 0..e {
+            new_sum |= ((sum >> ((e_dec - i) << 3)) & 0xFF) << (i << 3);
+        }
+
+        new_sum
+    }
+
+    const fn crc_reflect_table(poly_rev: u128) -> [u128; 256] {
+        let mut lookup_table = [0u128; 256];
+
+        let mut i = 0;
+
+        while i < 256 {
+            let mut v = i as u128;
+
+            let mut j = 0;
+
+            while j < 8 {
+                if v & 1 != 0 {
+                    v >>= 1;
+                    v ^= poly_rev;
+                } else {
+                    v >>= 1;
+                }
+
+                j += 1;
+            }
+
+            lookup_table[i] = v;
+
+            i += 1;
+        }
+
+        lookup_table
+    }
+
+    const fn crc_table(poly: u128, bits: u8) -> [u128; 256] {
+        let mut lookup_table = [0u128; 256];
+
+        let mask1 = 1u128 << (bits - 1);
+
+        let mask2 = ((mask1 - 1) << 1) | 1;
+
+        let mut i = 0;
+
+        while i < 256 {
+            let mut v = i as u128;
+
+            let mut j = 0;
+
+            while j < bits {
+                if v & mask1 == 0 {
+                    v <<= 1;
+                } else {
+                    v <<= 1;
+                    v ^= poly;
+                }
+
+                j += 1;
+            }
+
+            lookup_table[i] = v & mask2;
+
+            i += 1;
+        }
+
+        lookup_table
+    }
+}
+
+impl CRCu128 {
+    /// Get the current CRC value as a big-endian byte array. Its first bytes, as many as the CRC bits need, hold the CRC value, and the rest are zero. You can continue calling `update` or `digest` even after getting a CRC value.
+    #[inline]
+    pub fn get_crc_bytes_be(&self) -> [u8; 16] {
+        let crc = self.get_crc();
+
+        let e = usize::from(self.bits).div_ceil(8);
+
+        let mut bytes = [0u8; 16];
+
+        bytes[..e].copy_from_slice(&crc.to_be_bytes()[(16 - e)..]);
+
+        bytes
+    }
+
+    /// Get the current CRC value as a little-endian byte array. Its first bytes, as many as the CRC bits need, hold the CRC value, and the rest are zero. You can continue calling `update` or `digest` even after getting a CRC value.
+    #[inline]
+    pub fn get_crc_bytes_le(&self) -> [u8; 16] {
+        self.get_crc().to_le_bytes()
+    }
+
+    /// Get the current CRC value as uppercase hexadecimal digits in ASCII. Its first bytes, as many as [`CrcEngine::hex_len`], hold the digits, and the rest are zero. You can continue calling `update` or `digest` even after getting a CRC value.
+    #[inline]
+    pub fn to_hex_array(&self) -> [u8; 32] {
+        let mut hex = [0u8; 32];
+
+        self.write_crc_hex(&mut hex);
+
+        hex
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl CRCu128 {
+    /// Get the current CRC value (it always returns a vec instance with a length corresponding to the CRC bits). You can continue calling `update` or `digest` even after getting a CRC value.
+    #[inline]
+    pub fn get_crc_vec_le(&self) -> Vec<u8> {
+        let crc = self.get_crc();
+
+        let e = usize::from(self.bits).div_ceil(8);
+
+        crc.to_le_bytes()[..e].to_vec()
+    }
+
+    /// Get the current CRC value (it always returns a vec instance with a length corresponding to the CRC bits). You can continue calling `update` or `digest` even after getting a CRC value.
+    #[inline]
+    pub fn get_crc_vec_be(&self) -> Vec<u8> {
+        let crc = self.get_crc();
+
+        let e = usize::from(self.bits).div_ceil(8);
+
+        crc.to_be_bytes()[(16 - e)..].to_vec()
+    }
+}
+
+#[cfg(feature = "heapless")]
+impl CRCu128 {
+    /// Get the current CRC value (it always returns a heapless vec instance with a length corresponding to the CRC bits). You can continue calling `update` or `digest` even after getting a CRC value.
+    #[inline]
+    pub fn get_crc_heapless_vec_le(&self) -> HeaplessVec<u8, 16, u8> {
+        let crc = self.get_crc();
+
+        let e = usize::from(self.bits).div_ceil(8);
+
+        let mut vec = HeaplessVec::new();
+
+        vec.extend_from_slice(&crc.to_le_bytes()[..e]).unwrap();
+
+        vec
+    }
+
+    /// Get the current CRC value (it always returns a heapless vec instance with a length corresponding to the CRC bits). You can continue calling `update` or `digest` even after getting a CRC value.
+    #[inline]
+    pub fn get_crc_heapless_vec_be(&self) -> HeaplessVec<u8, 16, u8> {
+        let crc = self.get_crc();
+
+        let e = usize::from(self.bits).div_ceil(8);
+
+        let mut vec = HeaplessVec::new();
+
+        vec.extend_from_slice(&crc.to_be_bytes()[(16 - e)..]).unwrap();
+
+        vec
+    }
+}
+
+impl CRCu128 {
+    /// Append the current CRC value to `buf` in the given byte order, using as many bytes as the CRC bits need.
+    pub fn append_crc(&self, buf: &mut impl Extend<u8>, endian: Endian) {
+        let crc = self.get_crc();
+
+        let e = usize::from(self.bits).div_ceil(8);
+
+        match endian {
+            Endian::Big => buf.extend(crc.to_be_bytes()[(16 - e)..].iter().copied()),
+            Endian::Little => buf.extend(crc.to_le_bytes()[..e].iter().copied()),
+        }
+    }
+
+    /// Check whether `data_with_crc` ends with the CRC value of the data before it, in the given byte order. The data is computed from the current state, which is not changed.
+    pub fn verify_trailer(&self, data_with_crc: &[u8], endian: Endian) -> bool {
+        let e = usize::from(self.bits).div_ceil(8);
+
+        if data_with_crc.len() < e {
+            return false;
+        }
+
+        let (data, trailer) = data_with_crc.split_at(data_with_crc.len() - e);
+
+        let mut crc = self.clone();
+
+        crc.update(data);
+
+        let crc = crc.get_crc();
+
+        match endian {
+            Endian::Big => trailer == &crc.to_be_bytes()[(16 - e)..],
+            Endian::Little => trailer == &crc.to_le_bytes()[..e],
+        }
+    }
+
+    /// Check whether `data_with_crc` is an error-free codeword, that is, data followed by its CRC value in the bit order of the algorithm (big-endian if the input is not reflected, little-endian otherwise), by comparing the register with the residue of the algorithm after processing the whole codeword. The data is computed from the current state, which is not changed.
+    ///
+    /// If the CRC bits do not fill whole bytes, the input and output reflections differ, or the bytes of the CRC value are reordered, there is no such residue for byte-oriented codewords, so the trailer is compared with [`CRCu128::verify_trailer`] instead.
+    pub fn check_residue(&self, data_with_crc: &[u8]) -> bool {
+        if !self.bits.is_multiple_of(8) || self.refin != self.refout || self.reorder {
+            let endian = if self.refin { Endian::Little } else { Endian::Big };
+
+            return self.verify_trailer(data_with_crc, endian);
+        }
+
+        if data_with_crc.len() < usize::from(self.bits / 8) {
+            return false;
+        }
+
+        let mut crc = self.clone();
+
+        crc.update(data_with_crc);
+
+        crc.state() == self.residue_state()
+    }
+
+    /// Get the residue of the algorithm, which is the content of the register after processing an error-free codeword, reflected if the output is reflected, before the final xor value is applied. This is the `residue` parameter of the catalogue.
+    pub fn residue(&self) -> u128 {
+        let residue = self.residue_state();
+
+        if self.refout { self.reflect_method(residue) } else { residue }
+    }
+
+    /// The CRC bits of a codeword leave `final_xor` (in the bit order of the input) in the register, so the residue is `final_xor` shifted through the register by the length of bits.
+    fn residue_state(&self) -> u128 {
+        let poly = self.normal_poly();
+
+        let mut residue =
+            if self.refin { self.reflect_method(self.final_xor) } else { self.final_xor };
+
+        for _ in 0..self.bits {
+            residue =
+                if residue & self.high_bit != 0 { (residue << 1) ^ poly } else { residue << 1 };
+        }
+
+        residue & self.mask
+    }
+}
+
+/// Two instances are equal if they compute the same CRC algorithm and are in the same state.
+impl PartialEq for CRCu128 {
+    #[inline]
+    fn eq(&self, other: &CRCu128) -> bool {
+        self.is_same_algorithm(other) && self.state() == other.state()
+    }
+}
+
+impl Eq for CRCu128 {}
+
+impl CrcEngine for CRCu128 {
+    type Output = u128;
+
+    const WIDTH_MAX: u8 = 128;
+
+    #[inline]
+    fn create_crc(poly: u128, bits: u8, initial: u128, final_xor: u128, reflect: bool) -> CRCu128 {
+        CRCu128::create_crc(poly, bits, initial, final_xor, reflect)
+    }
+
+    #[inline]
+    fn from_params(params: &CrcParams) -> CRCu128 {
+        CRCu128::from_params(params)
+    }
+
+    #[inline]
+    fn try_from_params(params: &CrcParams) -> Result<CRCu128, CrcParamError> {
+        CRCu128::try_from_params(params)
+    }
+
+    #[inline]
+    fn width(&self) -> u8 {
+        self.bits
+    }
+
+    #[inline]
+    fn update(&mut self, data: &[u8]) {
+        CRCu128::update(self, data)
+    }
+
+    #[inline]
+    fn reset(&mut self) {
+        CRCu128::reset(self)
+    }
+
+    #[inline]
+    fn get_crc(&self) -> u128 {
+        CRCu128::get_crc(self)
+    }
+
+    #[inline]
+    fn state(&self) -> u128 {
+        CRCu128::state(self)
+    }
+
+    #[inline]
+    fn set_state(&mut self, state: u128) {
+        CRCu128::set_state(self, state)
+    }
+
+    #[inline]
+    fn resume_from(&mut self, crc: u128) {
+        CRCu128::resume_from(self, crc)
+    }
+}
+
+impl CRCu128 {
+    /// |Check|Poly|Init|Ref|XorOut|
+    /// |---|---|---|---|---|
+    /// |0x09EA83F625023801FD612|0x0308C0111011401440411 (rev: 0x220808A00A2022200C430)|0x000000000000000000000|true|0x000000000000000000000|
+    ///
+    /// ```
+    /// # use crc_any::CRCu128;
+    /// let mut crc = CRCu128::crc82darc();
+    /// crc.digest(b"123456789");
+    #[cfg_attr(
+        feature = "alloc",
+        doc = "assert_eq!(\"0x09EA83F625023801FD612\", &crc.to_string());"
+    )]
+    /// ```
+    pub fn crc82darc() -> CRCu128 {
+        Self::create_crc(
+            0x00000000000220808A00A2022200C430,
+            82,
+            0x00000000000000000000000000000000,
+            0x00000000000000000000000000000000,
+            true,
+        )
+    }
+}
@@ -1,14 +1,26 @@
 #[cfg(feature = "alloc")]
-use alloc::fmt::{self, Debug, Display, Formatter};
-#[cfg(feature = "alloc")]
 use alloc::vec::Vec;
+#[cfg(feature = "alloc")]
+use core::fmt::Debug;
+use core::fmt::{self, Binary, Display, Formatter, LowerHex, Octal, UpperHex};
 
 #[cfg(feature = "heapless")]
 use heapless::Vec as HeaplessVec;
 
-use crate::{constants::crc_u16::*, lookup_table::LookUpTable};
+use crate::{
+    backend::Backend,
+    constants::crc_u16::*,
+    endian::Endian,
+    engine::CrcEngine,
+    errors::{CrcParamError, UnavailableBackend, check_crc_params, check_params},
+    hex,
+    lookup_table::LookUpTable,
+    params::CrcParams,
+    table_cache,
+};
 
 #[allow(clippy::upper_case_acronyms)]
+#[derive(Clone)]
 /// This struct can help you compute a CRC-16 (or CRC-x where **x** is equal or less than `16`) value.
 pub struct CRCu16 {
     by_table:        bool,
@@ -20,7 +32,8 @@ pub struct CRCu16 {
     mask:            u16,
     initial:         u16,
     final_xor:       u16,
-    reflect:         bool,
+    refin:           bool,
+    refout:          bool,
     reorder:         bool,
 }
 
@@ -29,14 +42,13 @@ impl Debug for CRCu16 {
     #[inline]
     fn fmt(&self, f: &mut Formatter) -> Result<(), fmt::Error> {
         if self.by_table {
-            debug_helper::impl_debug_for_struct!(CRCu16, f, self, let .lookup_table = self.lookup_table.as_ref(), (.sum, "0x{:04X}", self.sum), .bits, (.initial, "0x{:04X}", self.initial), (.final_xor, "0x{:04X}", self.final_xor), .reflect, .reorder);
+            debug_helper::impl_debug_for_struct!(CRCu16, f, self, let .lookup_table = self.lookup_table.as_ref(), (.sum, "0x{:04X}", self.sum), .bits, (.initial, "0x{:04X}", self.initial), (.final_xor, "0x{:04X}", self.final_xor), .refin, .refout, .reorder);
         } else {
-            debug_helper::impl_debug_for_struct!(CRCu16, f, self, (.poly, "0x{:04X}", self.poly), (.sum, "0x{:04X}", self.sum), .bits, (.initial, "0x{:04X}", self.initial), (.final_xor, "0x{:04X}", self.final_xor), .reflect, .reorder);
+            debug_helper::impl_debug_for_struct!(CRCu16, f, self, (.poly, "0x{:04X}", self.poly), (.sum, "0x{:04X}", self.sum), .bits, (.initial, "0x{:04X}", self.initial), (.final_xor, "0x{:04X}", self.final_xor), .refin, .refout, .reorder);
         }
     }
 }
 
-#[cfg(feature = "alloc")]
 impl Display for CRCu16 {
     #[inline]
     fn fmt(&self, f: &mut Formatter) -> Result<(), fmt::Error> {
@@ -44,25 +56,57 @@ impl Display for CRCu16 {
     }
 }
 
+impl LowerHex for CRCu16 {
+    #[inline]
+    fn fmt(&self, f: &mut Formatter) -> Result<(), fmt::Error> {
+        hex::fmt_crc(f, u128::from(self.get_crc()), self.bits, 4, false)
+    }
+}
+
+impl UpperHex for CRCu16 {
+    #[inline]
+    fn fmt(&self, f: &mut Formatter) -> Result<(), fmt::Error> {
+        hex::fmt_crc(f, u128::from(self.get_crc()), self.bits, 4, true)
+    }
+}
+
+impl Octal for CRCu16 {
+    #[inline]
+    fn fmt(&self, f: &mut Formatter) -> Result<(), fmt::Error> {
+        hex::fmt_crc(f, u128::from(self.get_crc()), self.bits, 3, false)
+    }
+}
+
+impl Binary for CRCu16 {
+    #[inline]
+    fn fmt(&self, f: &mut Formatter) -> Result<(), fmt::Error> {
+        hex::fmt_crc(f, u128::from(self.get_crc()), self.bits, 1, false)
+    }
+}
+
 impl CRCu16 {
     /// Create a `CRCu16` instance by providing the length of bits, expression, reflection, an initial value and a final xor value.
     pub fn create_crc(poly: u16, bits: u8, initial: u16, final_xor: u16, reflect: bool) -> CRCu16 {
+        Self::create_crc_with_reflection(poly, bits, initial, final_xor, reflect, reflect)
+    }
+
+    /// Create a `CRCu16` instance by providing the length of bits, expression, input reflection, output reflection, an initial value and a final xor value.
+    ///
+    /// The expression needs to be reflected if `refin` is `true`.
+    pub fn create_crc_with_reflection(
+        poly: u16,
+        bits: u8,
+        initial: u16,
+        final_xor: u16,
+        refin: bool,
+        refout: bool,
+    ) -> CRCu16 {
         debug_assert!(bits <= 16 && bits > 0);
 
         if bits.is_multiple_of(8) {
-            let lookup_table = if reflect {
-                LookUpTable::Dynamic(Self::crc_reflect_table(poly))
-            } else {
-                LookUpTable::Dynamic(Self::crc_table(poly, bits))
-            };
+            let lookup_table = Self::lookup_table_of(bits, poly, refin);
 
-            Self::create_crc_with_exists_lookup_table(
-                lookup_table,
-                bits,
-                initial,
-                final_xor,
-                reflect,
-            )
+            Self::create(true, lookup_table, 0, bits, initial, final_xor, refin, refout)
         } else {
             Self::create(
                 false,
@@ -71,11 +115,203 @@ impl CRCu16 {
                 bits,
                 initial,
                 final_xor,
-                reflect,
+                refin,
+                refout,
+            )
+        }
+    }
+
+    /// Set whether the bytes of the CRC value are output in reverse order, like `crc32` of the `mhash` library does (see [`CRCu32::crc32mhash`](crate::CRCu32::crc32mhash)). Only the bytes covered by the length of bits are reversed.
+    ///
+    /// This affects `get_crc`, the formatted output and the byte getters, and `resume_from` expects a CRC value in the same order. The state of the register does not depend on it.
+    #[inline]
+    pub fn with_reorder(mut self, reorder: bool) -> CRCu16 {
+        self.reorder = reorder;
+
+        self
+    }
+
+    /// Select the backend which processes the input, keeping the current state. An error is returned if the backend is not available for the algorithm or on the CPU. See [`Backend`] for details.
+    pub fn with_backend(mut self, backend: Backend) -> Result<CRCu16, UnavailableBackend> {
+        let poly = self.normal_poly();
+        let state = self.state();
+
+        match backend {
+            Backend::Bitwise => {
+                self.by_table = false;
+                self.lookup_table = LookUpTable::Static(&[0; 256]);
+                self.poly = poly;
+            },
+            Backend::Table if self.bits.is_multiple_of(8) => {
+                self.switch_to_table(poly);
+            },
+            _ => {
+                return Err(UnavailableBackend {
+                    backend,
+                });
+            },
+        }
+
+        self.set_state(state);
+
+        Ok(self)
+    }
+
+    /// Get the backend which processes the input.
+    pub fn backend(&self) -> Backend {
+        if self.by_table { Backend::Table } else { Backend::Bitwise }
+    }
+
+    /// Create a `CRCu16` instance from the parameters of a CRC algorithm.
+    pub fn from_params(params: &CrcParams) -> CRCu16 {
+        Self::create_crc_with_reflection(
+            params.create_crc_poly() as u16,
+            params.width,
+            params.init as u16,
+            params.xorout as u16,
+            params.refin,
+            params.refout,
+        )
+    }
+
+    /// Create a `CRCu16` instance like [`CRCu16::create_crc`], but return an error instead of creating a broken instance if the parameters are invalid.
+    pub fn try_create_crc(
+        poly: u16,
+        bits: u8,
+        initial: u16,
+        final_xor: u16,
+        reflect: bool,
+    ) -> Result<CRCu16, CrcParamError> {
+        Self::try_create_crc_with_reflection(poly, bits, initial, final_xor, reflect, reflect)
+    }
+
+    /// Create a `CRCu16` instance like [`CRCu16::create_crc_with_reflection`], but return an error instead of creating a broken instance if the parameters are invalid.
+    pub fn try_create_crc_with_reflection(
+        poly: u16,
+        bits: u8,
+        initial: u16,
+        final_xor: u16,
+        refin: bool,
+        refout: bool,
+    ) -> Result<CRCu16, CrcParamError> {
+        check_params(16, bits, u128::from(poly), u128::from(initial), u128::from(final_xor))?;
+
+        Ok(Self::create_crc_with_reflection(poly, bits, initial, final_xor, refin, refout))
+    }
+
+    /// Create a `CRCu16` instance from the parameters of a CRC algorithm, or return an error if the parameters are invalid.
+    pub fn try_from_params(params: &CrcParams) -> Result<CRCu16, CrcParamError> {
+        check_crc_params(16, params)?;
+
+        Ok(Self::from_params(params))
+    }
+
+    /// Create a `CRCu16` instance from the parameters of a CRC algorithm and a lookup table generated by [`CRCu16::table`] with the same parameters, so that the table does not need to be generated at runtime.
+    ///
+    /// The lookup table is only used if the length of bits is a multiple of `8`.
+    pub fn from_params_with_table(params: &CrcParams, table: &'static [u16; 256]) -> CRCu16 {
+        if params.width.is_multiple_of(8) {
+            Self::create(
+                true,
+                LookUpTable::Static(table),
+                0,
+                params.width,
+                params.init as u16,
+                params.xorout as u16,
+                params.refin,
+                params.refout,
             )
+        } else {
+            Self::from_params(params)
         }
     }
 
+    /// Generate the lookup table of a CRC algorithm. It can be evaluated at compile time.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the length of bits of `params` is 0 or larger than `16`.
+    ///
+    /// ```
+    /// use crc_any::{
+    ///     CRCu16,
+    ///     params::{CRC_16_MODBUS, CrcParams},
+    /// };
+    ///
+    /// const PARAMS: CrcParams = CRC_16_MODBUS;
+    /// const TABLE: [u16; 256] = CRCu16::table(&PARAMS);
+    /// ```
+    pub const fn table(params: &CrcParams) -> [u16; 256] {
+        assert!(
+            params.width > 0 && params.width <= 16,
+            "the length of bits must be between 1 and 16"
+        );
+
+        if params.refin {
+            Self::crc_reflect_table(params.create_crc_poly() as u16)
+        } else {
+            Self::crc_table(params.poly as u16, params.width)
+        }
+    }
+
+    /// Compute the CRC value of `data` in one shot. It can be evaluated at compile time, but it processes the data bit by bit, so prefer creating a `CRCu16` instance at runtime.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the length of bits of `params` is 0 or larger than `16`.
+    ///
+    /// ```
+    /// use crc_any::{CRCu16, params::CRC_16_MODBUS};
+    ///
+    /// const CHECK: u16 = CRCu16::checksum(&CRC_16_MODBUS, b"123456789");
+    ///
+    /// assert_eq!(0x4B37, CHECK);
+    /// ```
+    pub const fn checksum(params: &CrcParams, data: &[u8]) -> u16 {
+        assert!(
+            params.width > 0 && params.width <= 16,
+            "the length of bits must be between 1 and 16"
+        );
+
+        let high_bit = 1u16 << (params.width - 1);
+        let mask = ((high_bit - 1) << 1) | 1;
+        let poly = params.poly as u16;
+
+        let mut sum = params.init as u16;
+
+        let mut i = 0;
+
+        while i < data.len() {
+            let n = if params.refin { data[i].reverse_bits() } else { data[i] };
+
+            let mut b = 0x80u8;
+
+            while b != 0 {
+                let mut bit = sum & high_bit;
+
+                sum <<= 1;
+
+                if n & b != 0 {
+                    bit ^= high_bit;
+                }
+
+                if bit != 0 {
+                    sum ^= poly;
+                }
+
+                b >>= 1;
+            }
+
+            i += 1;
+        }
+
+        if params.refout {
+            sum = sum.reverse_bits() >> (u16::BITS - params.width as u32);
+        }
+
+        (sum ^ params.xorout as u16) & mask
+    }
+
     #[inline]
     pub(crate) fn create_crc_with_exists_lookup_table(
         lookup_table: LookUpTable<u16>,
@@ -86,9 +322,10 @@ impl CRCu16 {
     ) -> CRCu16 {
         debug_assert!(bits.is_multiple_of(8));
 
-        Self::create(true, lookup_table, 0, bits, initial, final_xor, reflect)
+        Self::create(true, lookup_table, 0, bits, initial, final_xor, reflect, reflect)
     }
 
+    #[allow(clippy::too_many_arguments)]
     #[inline]
     fn create(
         by_table: bool,
@@ -97,14 +334,17 @@ impl CRCu16 {
         bits: u8,
         initial: u16,
         final_xor: u16,
-        reflect: bool,
+        refin: bool,
+        refout: bool,
     ) -> CRCu16 {
         let high_bit = 1 << u16::from(bits - 1);
         let mask = ((high_bit - 1) << 1) | 1;
 
-        let sum = if reflect { Self::reflect_function(high_bit, initial) } else { initial };
+        // the register is kept reflected only when a reflected lookup table is used
+        let sum =
+            if by_table && refin { Self::reflect_function(high_bit, initial) } else { initial };
 
-        if !by_table && reflect {
+        if !by_table && refin {
             poly = Self::reflect_function(high_bit, poly);
         }
 
@@ -118,7 +358,8 @@ impl CRCu16 {
             mask,
             initial,
             final_xor,
-            reflect,
+            refin,
+            refout,
             reorder: false,
         }
     }
@@ -135,6 +376,40 @@ impl CRCu16 {
         Self::reflect_function(self.high_bit, n)
     }
 
+    /// Get the lookup table of an expression, from the static tables, the cache, or by generating it.
+    fn lookup_table_of(bits: u8, poly: u16, refin: bool) -> LookUpTable<u16> {
+        match find_table(bits, poly, refin) {
+            Some(table) => LookUpTable::Static(table),
+            None => table_cache::lookup_table(bits, poly, refin, || {
+                if refin { Self::crc_reflect_table(poly) } else { Self::crc_table(poly, bits) }
+            }),
+        }
+    }
+
+    /// Get the expression in normal (unreflected) form.
+    fn normal_poly(&self) -> u16 {
+        if !self.by_table {
+            self.poly
+        } else if self.refin {
+            // the entry of `0x80` of a reflected lookup table is the reflected expression
+            self.reflect_method(self.lookup_table[0x80])
+        } else {
+            // the entry of `1` of a normal lookup table is the expression
+            self.lookup_table[1]
+        }
+    }
+
+    /// Use the lookup table of the expression `poly` (in normal form) if the bitwise loop is used.
+    fn switch_to_table(&mut self, poly: u16) {
+        if !self.by_table {
+            let poly = if self.refin { self.reflect_method(poly) } else { poly };
+
+            self.lookup_table = Self::lookup_table_of(self.bits, poly, self.refin);
+            self.by_table = true;
+            self.poly = 0;
+        }
+    }
+
     /// Update the current CRC state with bytes.
     #[inline]
     pub fn update(&mut self, data: &[u8]) {
@@ -144,7 +419,7 @@ impl CRCu16 {
                     let index = (self.sum as u8 ^ n) as usize;
                     self.sum = self.lookup_table[index];
                 }
-            } else if self.reflect {
+            } else if self.refin {
                 for n in data.iter().copied() {
                     let index = ((self.sum as u8) ^ n) as usize;
                     self.sum = (self.sum >> 8) ^ self.lookup_table[index];
@@ -155,7 +430,7 @@ impl CRCu16 {
                     self.sum = (self.sum << 8) ^ self.lookup_table[index];
                 }
             }
-        } else if self.reflect {
+        } else if self.refin {
             for n in data.iter().copied() {
                 let n = super::crc_u8::CRCu8::reflect_function(0x80, n);
 
@@ -200,6 +475,111 @@ impl CRCu16 {
         }
     }
 
+    /// Update the current CRC state with the bytes of a `u16` value in little-endian byte order.
+    #[inline]
+    pub fn update_u16_le(&mut self, value: u16) {
+        self.update(&value.to_le_bytes());
+    }
+
+    /// Update the current CRC state with the bytes of a `u16` value in big-endian byte order.
+    #[inline]
+    pub fn update_u16_be(&mut self, value: u16) {
+        self.update(&value.to_be_bytes());
+    }
+
+    /// Update the current CRC state with the bytes of a `u32` value in little-endian byte order.
+    #[inline]
+    pub fn update_u32_le(&mut self, value: u32) {
+        self.update(&value.to_le_bytes());
+    }
+
+    /// Update the current CRC state with the bytes of a `u32` value in big-endian byte order.
+    #[inline]
+    pub fn update_u32_be(&mut self, value: u32) {
+        self.update(&value.to_be_bytes());
+    }
+
+    /// Update the current CRC state with the bytes of a `u64` value in little-endian byte order.
+    #[inline]
+    pub fn update_u64_le(&mut self, value: u64) {
+        self.update(&value.to_le_bytes());
+    }
+
+    /// Update the current CRC state with the bytes of a `u64` value in big-endian byte order.
+    #[inline]
+    pub fn update_u64_be(&mut self, value: u64) {
+        self.update(&value.to_be_bytes());
+    }
+
+    /// Update the current CRC state with the bytes of an `f32` value in little-endian byte order.
+    #[inline]
+    pub fn update_f32_le(&mut self, value: f32) {
+        self.update(&value.to_le_bytes());
+    }
+
+    /// Update the current CRC state with the bytes of an `f32` value in big-endian byte order.
+    #[inline]
+    pub fn update_f32_be(&mut self, value: f32) {
+        self.update(&value.to_be_bytes());
+    }
+
+    /// Update the current CRC state with the bytes of an `f64` value in little-endian byte order.
+    #[inline]
+    pub fn update_f64_le(&mut self, value: f64) {
+        self.update(&value.to_le_bytes());
+    }
+
+    /// Update the current CRC state with the bytes of an `f64` value in big-endian byte order.
+    #[inline]
+    pub fn update_f64_be(&mut self, value: f64) {
+        self.update(&value.to_be_bytes());
+    }
+
+    /// Update the current CRC state with a single bit.
+    pub fn update_bit(&mut self, bit: bool) {
+        if self.by_table && self.refin {
+            // the register is reflected, and the reflected expression is the entry of `0x80`
+            let low_bit = (self.sum & 1 != 0) ^ bit;
+
+            self.sum >>= 1;
+
+            if low_bit {
+                self.sum ^= self.lookup_table[0x80];
+            }
+        } else {
+            let poly = self.normal_poly();
+
+            let high_bit = (self.sum & self.high_bit != 0) ^ bit;
+
+            self.sum <<= 1;
+
+            if high_bit {
+                self.sum ^= poly;
+            }
+        }
+    }
+
+    /// Update the current CRC state with the low `nbits` bits of `value`, for messages whose length is not a multiple of 8 bits.
+    ///
+    /// The bits are taken in the same order as the bits of the bytes passed to `update`: from the least significant bit if the input is reflected, and from the most significant bit (bit `nbits - 1`) otherwise. Thus, `update_bits(u64::from(byte), 8)` is the same as `update(&[byte])`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `nbits` is larger than `64`.
+    pub fn update_bits(&mut self, value: u64, nbits: u32) {
+        assert!(nbits <= 64, "cannot update with more than 64 bits at once");
+
+        if self.refin {
+            for i in 0..nbits {
+                self.update_bit((value >> i) & 1 != 0);
+            }
+        } else {
+            for i in (0..nbits).rev() {
+                self.update_bit((value >> i) & 1 != 0);
+            }
+        }
+    }
+
     /// Digest some data.
     ///
     /// This is a compatibility wrapper around [`CRCu16::update`].
@@ -210,87 +590,194 @@ impl CRCu16 {
 
     /// Reset the sum.
     pub fn reset(&mut self) {
-        self.sum = if self.reflect {
+        self.sum = if self.by_table && self.refin {
             Self::reflect_function(self.high_bit, self.initial)
         } else {
             self.initial
         };
     }
 
+    /// Get the current state of the register, which can be restored later with [`CRCu16::set_state`] or [`CRCu16::from_state`].
+    ///
+    /// The state is always in normal (unreflected) form and is taken before the output reflection and the final xor value are applied, so it does not depend on how the CRC is computed.
+    pub fn state(&self) -> u16 {
+        let sum = self.sum & self.mask;
+
+        if self.by_table && self.refin { self.reflect_method(sum) } else { sum }
+    }
+
+    /// Restore a state obtained from [`CRCu16::state`] of an instance with the same parameters. You can continue calling `update` or `digest` after that.
+    pub fn set_state(&mut self, state: u16) {
+        let state = state & self.mask;
+
+        self.sum = if self.by_table && self.refin { self.reflect_method(state) } else { state };
+    }
+
+    /// Create a `CRCu16` instance from the parameters of a CRC algorithm and a state obtained from [`CRCu16::state`].
+    pub fn from_state(params: &CrcParams, state: u16) -> CRCu16 {
+        let mut crc = Self::from_params(params);
+
+        crc.set_state(state);
+
+        crc
+    }
+
+    /// Continue from a CRC value returned by [`CRCu16::get_crc`] of an instance with the same parameters, as if the data of that CRC value had been passed to `update` or `digest`.
+    pub fn resume_from(&mut self, crc: u16) {
+        let crc = if self.reorder { self.reorder_method(crc) } else { crc };
+
+        let sum = (crc ^ self.final_xor) & self.mask;
+
+        self.set_state(if self.refout { self.reflect_method(sum) } else { sum });
+    }
+
+    /// Create a `CRCu16` instance from the parameters of a CRC algorithm and a CRC value of some previous data computed with the same parameters. Updating the instance with more data gives the CRC value of the concatenated data.
+    pub fn resume(params: &CrcParams, crc: u16) -> CRCu16 {
+        let mut crc_instance = Self::from_params(params);
+
+        crc_instance.resume_from(crc);
+
+        crc_instance
+    }
+
+    /// Check whether this instance and `other` compute the same CRC algorithm, regardless of their current states and backends.
+    pub fn is_same_algorithm(&self, other: &CRCu16) -> bool {
+        self.bits == other.bits
+            && self.initial == other.initial
+            && self.final_xor == other.final_xor
+            && self.refin == other.refin
+            && self.refout == other.refout
+            && self.reorder == other.reorder
+            && self.normal_poly() == other.normal_poly()
+    }
+
+    /// Create a copy of this instance, including its current state. This is useful for computing the CRC values of many messages sharing a common prefix, which only needs to be processed once.
+    #[inline]
+    pub fn fork(&self) -> CRCu16 {
+        self.clone()
+    }
+
     /// Get the current CRC value (it always returns a `u16` value). You can continue calling `update` or `digest` even after getting a CRC value.
     pub fn get_crc(&self) -> u16 {
-        let sum = if self.by_table || !self.reflect {
+        let sum = if (self.by_table && self.refin) == self.refout {
             (self.sum ^ self.final_xor) & self.mask
         } else {
             (self.reflect_method(self.sum) ^ self.final_xor) & self.mask
         };
 
-        if self.reorder {
-            let mut new_sum = 0;
+        if self.reorder { self.reorder_method(sum) } else { sum }
+    }
 
-            let e = u16::from(self.bits).div_ceil(8);
+    #[inline]
+    fn reorder_method(&self, sum: u16) -> u16 {
+        let mut new_sum = 0;
 
-            let e_dec = e - 1;
+        let e = u16::from(self.bits).div_ceil(8);
 
-            for i in 0..e {
-                new_sum |= ((sum >> ((e_dec - i) << 3)) & 0xFF) << (i << 3);
-            }
+        let e_dec = e - 1;
 
-            new_sum
-        } else {
-            sum
+        for i in 0..e {
+            new_sum |= ((sum >> ((e_dec - i) << 3)) & 0xFF) << (i << 3);
         }
+
+        new_sum
     }
 
-    fn crc_reflect_table(poly_rev: u16) -> [u16; 256] {
+    const fn crc_reflect_table(poly_rev: u16) -> [u16; 256] {
         let mut lookup_table = [0u16; 256];
 
-        for (i, e) in lookup_table.iter_mut().enumerate() {
+        let mut i = 0;
+
+        while i < 256 {
             let mut v = i as u16;
 
-            #[allow(clippy::branches_sharing_code)]
-            for _ in 0..8u8 {
+            let mut j = 0;
+
+            while j < 8 {
                 if v & 1 != 0 {
                     v >>= 1;
                     v ^= poly_rev;
                 } else {
                     v >>= 1;
                 }
+
+                j += 1;
             }
 
-            *e = v;
+            lookup_table[i] = v;
+
+            i += 1;
         }
 
         lookup_table
     }
 
-    fn crc_table(poly: u16, bits: u8) -> [u16; 256] {
+    const fn crc_table(poly: u16, bits: u8) -> [u16; 256] {
         let mut lookup_table = [0u16; 256];
 
-        let mask1 = 1u16 << u16::from(bits - 1);
+        let mask1 = 1u16 << (bits - 1);
 
         let mask2 = ((mask1 - 1) << 1) | 1;
 
-        for (i, e) in lookup_table.iter_mut().enumerate() {
+        let mut i = 0;
+
+        while i < 256 {
             let mut v = i as u16;
 
-            #[allow(clippy::branches_sharing_code)]
-            for _ in 0..bits {
+            let mut j = 0;
+
+            while j < bits {
                 if v & mask1 == 0 {
                     v <<= 1;
                 } else {
                     v <<= 1;
                     v ^= poly;
                 }
+
+                j += 1;
             }
 
-            *e = v & mask2;
+            lookup_table[i] = v & mask2;
+
+            i += 1;
         }
 
         lookup_table
     }
 }
 
+impl CRCu16 {
+    /// Get the current CRC value as a big-endian byte array. Its first bytes, as many as the CRC bits need, hold the CRC value, and the rest are zero. You can continue calling `update` or `digest` even after getting a CRC value.
+    #[inline]
+    pub fn get_crc_bytes_be(&self) -> [u8; 2] {
+        let crc = self.get_crc();
+
+        let e = usize::from(self.bits).div_ceil(8);
+
+        let mut bytes = [0u8; 2];
+
+        bytes[..e].copy_from_slice(&crc.to_be_bytes()[(2 - e)..]);
+
+        bytes
+    }
+
+    /// Get the current CRC value as a little-endian byte array. Its first bytes, as many as the CRC bits need, hold the CRC value, and the rest are zero. You can continue calling `update` or `digest` even after getting a CRC value.
+    #[inline]
+    pub fn get_crc_bytes_le(&self) -> [u8; 2] {
+        self.get_crc().to_le_bytes()
+    }
+
+    /// Get the current CRC value as uppercase hexadecimal digits in ASCII. Its first bytes, as many as [`CrcEngine::hex_len`], hold the digits, and the rest are zero. You can continue calling `update` or `digest` even after getting a CRC value.
+    #[inline]
+    pub fn to_hex_array(&self) -> [u8; 4] {
+        let mut hex = [0u8; 4];
+
+        self.write_crc_hex(&mut hex);
+
+        hex
+    }
+}
+
 #[cfg(feature = "alloc")]
 impl CRCu16 {
     /// Get the current CRC value (it always returns a vec instance with a length corresponding to the CRC bits). You can continue calling `update` or `digest` even after getting a CRC value.
@@ -345,6 +832,151 @@ impl CRCu16 {
     }
 }
 
+impl CRCu16 {
+    /// Append the current CRC value to `buf` in the given byte order, using as many bytes as the CRC bits need.
+    pub fn append_crc(&self, buf: &mut impl Extend<u8>, endian: Endian) {
+        let crc = self.get_crc();
+
+        let e = usize::from(self.bits).div_ceil(8);
+
+        match endian {
+            Endian::Big => buf.extend(crc.to_be_bytes()[(2 - e)..].iter().copied()),
+            Endian::Little => buf.extend(crc.to_le_bytes()[..e].iter().copied()),
+        }
+    }
+
+    /// Check whether `data_with_crc` ends with the CRC value of the data before it, in the given byte order. The data is computed from the current state, which is not changed.
+    pub fn verify_trailer(&self, data_with_crc: &[u8], endian: Endian) -> bool {
+        let e = usize::from(self.bits).div_ceil(8);
+
+        if data_with_crc.len() < e {
+            return false;
+        }
+
+        let (data, trailer) = data_with_crc.split_at(data_with_crc.len() - e);
+
+        let mut crc = self.clone();
+
+        crc.update(data);
+
+        let crc = crc.get_crc();
+
+        match endian {
+            Endian::Big => trailer == &crc.to_be_bytes()[(2 - e)..],
+            Endian::Little => trailer == &crc.to_le_bytes()[..e],
+        }
+    }
+
+    /// Check whether `data_with_crc` is an error-free codeword, that is, data followed by its CRC value in the bit order of the algorithm (big-endian if the input is not reflected, little-endian otherwise), by comparing the register with the residue of the algorithm after processing the whole codeword. The data is computed from the current state, which is not changed.
+    ///
+    /// If the CRC bits do not fill whole bytes, the input and output reflections differ, or the bytes of the CRC value are reordered, there is no such residue for byte-oriented codewords, so the trailer is compared with [`CRCu16::verify_trailer`] instead.
+    pub fn check_residue(&self, data_with_crc: &[u8]) -> bool {
+        if !self.bits.is_multiple_of(8) || self.refin != self.refout || self.reorder {
+            let endian = if self.refin { Endian::Little } else { Endian::Big };
+
+            return self.verify_trailer(data_with_crc, endian);
+        }
+
+        if data_with_crc.len() < usize::from(self.bits / 8) {
+            return false;
+        }
+
+        let mut crc = self.clone();
+
+        crc.update(data_with_crc);
+
+        crc.state() == self.residue_state()
+    }
+
+    /// Get the residue of the algorithm, which is the content of the register after processing an error-free codeword, reflected if the output is reflected, before the final xor value is applied. This is the `residue` parameter of the catalogue.
+    pub fn residue(&self) -> u16 {
+        let residue = self.residue_state();
+
+        if self.refout { self.reflect_method(residue) } else { residue }
+    }
+
+    /// The CRC bits of a codeword leave `final_xor` (in the bit order of the input) in the register, so the residue is `final_xor` shifted through the register by the length of bits.
+    fn residue_state(&self) -> u16 {
+        let poly = self.normal_poly();
+
+        let mut residue =
+            if self.refin { self.reflect_method(self.final_xor) } else { self.final_xor };
+
+        for _ in 0..self.bits {
+            residue =
+                if residue & self.high_bit != 0 { (residue << 1) ^ poly } else { residue << 1 };
+        }
+
+        residue & self.mask
+    }
+}
+
+/// Two instances are equal if they compute the same CRC algorithm and are in the same state.
+impl PartialEq for CRCu16 {
+    #[inline]
+    fn eq(&self, other: &CRCu16) -> bool {
+        self.is_same_algorithm(other) && self.state() == other.state()
+    }
+}
+
+impl Eq for CRCu16 {}
+
+impl CrcEngine for CRCu16 {
+    type Output = u16;
+
+    const WIDTH_MAX: u8 = 16;
+
+    #[inline]
+    fn create_crc(poly: u16, bits: u8, initial: u16, final_xor: u16, reflect: bool) -> CRCu16 {
+        CRCu16::create_crc(poly, bits, initial, final_xor, reflect)
+    }
+
+    #[inline]
+    fn from_params(params: &CrcParams) -> CRCu16 {
+        CRCu16::from_params(params)
+    }
+
+    #[inline]
+    fn try_from_params(params: &CrcParams) -> Result<CRCu16, CrcParamError> {
+        CRCu16::try_from_params(params)
+    }
+
+    #[inline]
+    fn width(&self) -> u8 {
+        self.bits
+    }
+
+    #[inline]
+    fn update(&mut self, data: &[u8]) {
+        CRCu16::update(self, data)
+    }
+
+    #[inline]
+    fn reset(&mut self) {
+        CRCu16::reset(self)
+    }
+
+    #[inline]
+    fn get_crc(&self) -> u16 {
+        CRCu16::get_crc(self)
+    }
+
+    #[inline]
+    fn state(&self) -> u16 {
+        CRCu16::state(self)
+    }
+
+    #[inline]
+    fn set_state(&mut self, state: u16) {
+        CRCu16::set_state(self, state)
+    }
+
+    #[inline]
+    fn resume_from(&mut self, crc: u16) {
+        CRCu16::resume_from(self, crc)
+    }
+}
+
 impl CRCu16 {
     /// |Check|Poly|Init|Ref|XorOut|
     /// |---|---|---|---|---|
@@ -402,6 +1034,20 @@ impl CRCu16 {
         Self::create_crc(0x0385, 11, 0x001A, 0x0000, false)
     }
 
+    /// |Check|Poly|Init|Ref|XorOut|
+    /// |---|---|---|---|---|
+    /// |0x061|0x307|0x000|false|0x000|
+    ///
+    /// ```
+    /// # use crc_any::CRCu16;
+    /// let mut crc = CRCu16::crc11umts();
+    /// crc.digest(b"123456789");
+    #[cfg_attr(feature = "alloc", doc = "assert_eq!(\"0x061\", &crc.to_string());")]
+    /// ```
+    pub fn crc11umts() -> CRCu16 {
+        Self::create_crc(0x0307, 11, 0x0000, 0x0000, false)
+    }
+
     /// |Check|Poly|Init|Ref|XorOut|
     /// |---|---|---|---|---|
     /// |0xF5B|0x80F|0x000|false|0x000|
@@ -444,6 +1090,20 @@ impl CRCu16 {
         Self::create_crc(0x0D31, 12, 0x0000, 0x0FFF, false)
     }
 
+    /// |Check|Poly|Init|Ref|XorOut|
+    /// |---|---|---|---|---|
+    /// |0xDAF|0x80F|0x000|in: false, out: true|0x000|
+    ///
+    /// ```
+    /// # use crc_any::CRCu16;
+    /// let mut crc = CRCu16::crc12umts();
+    /// crc.digest(b"123456789");
+    #[cfg_attr(feature = "alloc", doc = "assert_eq!(\"0xDAF\", &crc.to_string());")]
+    /// ```
+    pub fn crc12umts() -> CRCu16 {
+        Self::create_crc_with_reflection(0x080F, 12, 0x0000, 0x0000, false, true)
+    }
+
     /// |Check|Poly|Init|Ref|XorOut|
     /// |---|---|---|---|---|
     /// |0x04FA|0x1CF5|0x0000|false|0x0000|
@@ -599,6 +1259,23 @@ impl CRCu16 {
         Self::create_crc_with_exists_lookup_table(lookup_table, 16, 0xFFFF, 0x0000, false)
     }
 
+    /// |Check|Poly|Init|Ref|XorOut|
+    /// |---|---|---|---|---|
+    /// |0xAEE7|0x8005|0xFFFF|false|0x0000|
+    ///
+    /// ```
+    /// # use crc_any::CRCu16;
+    /// let mut crc = CRCu16::crc16cms();
+    /// crc.digest(b"123456789");
+    #[cfg_attr(feature = "alloc", doc = "assert_eq!(\"0xAEE7\", &crc.to_string());")]
+    /// ```
+    pub fn crc16cms() -> CRCu16 {
+        // Self::create_crc(0x8005, 16, 0xFFFF, 0x0000, false)
+
+        let lookup_table = LookUpTable::Static(&NO_REF_16_8005);
+        Self::create_crc_with_exists_lookup_table(lookup_table, 16, 0xFFFF, 0x0000, false)
+    }
+
     /// |Check|Poly|Init|Ref|XorOut|
     /// |---|---|---|---|---|
     /// |0x9ECF|0x8005|0x800D|false|0x0000|
@@ -701,6 +1378,57 @@ impl CRCu16 {
         Self::create_crc_with_exists_lookup_table(lookup_table, 16, 0xFFFF, 0xFFFF, false)
     }
 
+    /// |Check|Poly|Init|Ref|XorOut|
+    /// |---|---|---|---|---|
+    /// |0xCE3C|0x1021|0x0000|false|0xFFFF|
+    ///
+    /// ```
+    /// # use crc_any::CRCu16;
+    /// let mut crc = CRCu16::crc16gsm();
+    /// crc.digest(b"123456789");
+    #[cfg_attr(feature = "alloc", doc = "assert_eq!(\"0xCE3C\", &crc.to_string());")]
+    /// ```
+    pub fn crc16gsm() -> CRCu16 {
+        // Self::create_crc(0x1021, 16, 0x0000, 0xFFFF, false)
+
+        let lookup_table = LookUpTable::Static(&NO_REF_16_1021);
+        Self::create_crc_with_exists_lookup_table(lookup_table, 16, 0x0000, 0xFFFF, false)
+    }
+
+    /// |Check|Poly|Init|Ref|XorOut|
+    /// |---|---|---|---|---|
+    /// |0xBDF4|0x6F63|0x0000|false|0x0000|
+    ///
+    /// ```
+    /// # use crc_any::CRCu16;
+    /// let mut crc = CRCu16::crc16lj1200();
+    /// crc.digest(b"123456789");
+    #[cfg_attr(feature = "alloc", doc = "assert_eq!(\"0xBDF4\", &crc.to_string());")]
+    /// ```
+    pub fn crc16lj1200() -> CRCu16 {
+        // Self::create_crc(0x6F63, 16, 0x0000, 0x0000, false)
+
+        let lookup_table = LookUpTable::Static(&NO_REF_16_6F63);
+        Self::create_crc_with_exists_lookup_table(lookup_table, 16, 0x0000, 0x0000, false)
+    }
+
+    /// |Check|Poly|Init|Ref|XorOut|
+    /// |---|---|---|---|---|
+    /// |0x772B|0x5935|0xFFFF|false|0x0000|
+    ///
+    /// ```
+    /// # use crc_any::CRCu16;
+    /// let mut crc = CRCu16::crc16m17();
+    /// crc.digest(b"123456789");
+    #[cfg_attr(feature = "alloc", doc = "assert_eq!(\"0x772B\", &crc.to_string());")]
+    /// ```
+    pub fn crc16m17() -> CRCu16 {
+        // Self::create_crc(0x5935, 16, 0xFFFF, 0x0000, false)
+
+        let lookup_table = LookUpTable::Static(&NO_REF_16_5935);
+        Self::create_crc_with_exists_lookup_table(lookup_table, 16, 0xFFFF, 0x0000, false)
+    }
+
     /// |Check|Poly|Init|Ref|XorOut|
     /// |---|---|---|---|---|
     /// |0x44C2|0x8005 (rev: 0xA001)|0xFFFF|true|0xFFFF|
@@ -735,6 +1463,74 @@ impl CRCu16 {
         Self::create_crc_with_exists_lookup_table(lookup_table, 16, 0xFFFF, 0x0000, true)
     }
 
+    /// |Check|Poly|Init|Ref|XorOut|
+    /// |---|---|---|---|---|
+    /// |0xA066|0x080B (rev: 0xD010)|0xFFFF|true|0x0000|
+    ///
+    /// ```
+    /// # use crc_any::CRCu16;
+    /// let mut crc = CRCu16::crc16nrsc5();
+    /// crc.digest(b"123456789");
+    #[cfg_attr(feature = "alloc", doc = "assert_eq!(\"0xA066\", &crc.to_string());")]
+    /// ```
+    pub fn crc16nrsc5() -> CRCu16 {
+        // Self::create_crc(0xD010, 16, 0xFFFF, 0x0000, true)
+
+        let lookup_table = LookUpTable::Static(&REF_16_D010);
+        Self::create_crc_with_exists_lookup_table(lookup_table, 16, 0xFFFF, 0x0000, true)
+    }
+
+    /// |Check|Poly|Init|Ref|XorOut|
+    /// |---|---|---|---|---|
+    /// |0x5D38|0x5935|0x0000|false|0x0000|
+    ///
+    /// ```
+    /// # use crc_any::CRCu16;
+    /// let mut crc = CRCu16::crc16opensafety_a();
+    /// crc.digest(b"123456789");
+    #[cfg_attr(feature = "alloc", doc = "assert_eq!(\"0x5D38\", &crc.to_string());")]
+    /// ```
+    pub fn crc16opensafety_a() -> CRCu16 {
+        // Self::create_crc(0x5935, 16, 0x0000, 0x0000, false)
+
+        let lookup_table = LookUpTable::Static(&NO_REF_16_5935);
+        Self::create_crc_with_exists_lookup_table(lookup_table, 16, 0x0000, 0x0000, false)
+    }
+
+    /// |Check|Poly|Init|Ref|XorOut|
+    /// |---|---|---|---|---|
+    /// |0x20FE|0x755B|0x0000|false|0x0000|
+    ///
+    /// ```
+    /// # use crc_any::CRCu16;
+    /// let mut crc = CRCu16::crc16opensafety_b();
+    /// crc.digest(b"123456789");
+    #[cfg_attr(feature = "alloc", doc = "assert_eq!(\"0x20FE\", &crc.to_string());")]
+    /// ```
+    pub fn crc16opensafety_b() -> CRCu16 {
+        // Self::create_crc(0x755B, 16, 0x0000, 0x0000, false)
+
+        let lookup_table = LookUpTable::Static(&NO_REF_16_755B);
+        Self::create_crc_with_exists_lookup_table(lookup_table, 16, 0x0000, 0x0000, false)
+    }
+
+    /// |Check|Poly|Init|Ref|XorOut|
+    /// |---|---|---|---|---|
+    /// |0xA819|0x1DCF|0xFFFF|false|0xFFFF|
+    ///
+    /// ```
+    /// # use crc_any::CRCu16;
+    /// let mut crc = CRCu16::crc16profibus();
+    /// crc.digest(b"123456789");
+    #[cfg_attr(feature = "alloc", doc = "assert_eq!(\"0xA819\", &crc.to_string());")]
+    /// ```
+    pub fn crc16profibus() -> CRCu16 {
+        // Self::create_crc(0x1DCF, 16, 0xFFFF, 0xFFFF, false)
+
+        let lookup_table = LookUpTable::Static(&NO_REF_16_1DCF);
+        Self::create_crc_with_exists_lookup_table(lookup_table, 16, 0xFFFF, 0xFFFF, false)
+    }
+
     /// |Check|Poly|Init|Ref|XorOut|
     /// |---|---|---|---|---|
     /// |0x63D0|0x1021 (rev: 0x8408)|0xB2AA|true|0x0000|
@@ -906,6 +1702,61 @@ impl CRCu16 {
     }
 }
 
+impl CRCu16 {
+    #[inline]
+    fn update_raw(mut self, seed: u16, data: &[u8]) -> u16 {
+        self.sum = seed;
+
+        self.update(data);
+
+        self.sum
+    }
+
+    /// Update a raw CRC-16/ARC (reflected) register like `crc16(seed, data)` of the Linux kernel. The register starts from `seed` instead of an initial value, and is returned as is, without the final xor value applied.
+    ///
+    /// ```
+    /// # use crc_any::CRCu16;
+    /// assert_eq!(0xBB3D, CRCu16::crc16_raw(0, b"123456789"));
+    /// ```
+    #[inline]
+    pub fn crc16_raw(seed: u16, data: &[u8]) -> u16 {
+        Self::crc16().update_raw(seed, data)
+    }
+
+    /// Update a raw CRC-16/KERMIT (reflected) register like `crc_ccitt(seed, data)` of the Linux kernel. The register starts from `seed` instead of an initial value, and is returned as is, without the final xor value applied.
+    ///
+    /// ```
+    /// # use crc_any::CRCu16;
+    /// assert_eq!(0x2189, CRCu16::crc_ccitt_raw(0, b"123456789"));
+    /// ```
+    #[inline]
+    pub fn crc_ccitt_raw(seed: u16, data: &[u8]) -> u16 {
+        Self::crc16kermit().update_raw(seed, data)
+    }
+
+    /// Update a raw CRC-16/XMODEM (not reflected) register like `crc_itu_t(seed, data)` of the Linux kernel. The register starts from `seed` instead of an initial value, and is returned as is, without the final xor value applied.
+    ///
+    /// ```
+    /// # use crc_any::CRCu16;
+    /// assert_eq!(0x31C3, CRCu16::crc_itu_t_raw(0, b"123456789"));
+    /// ```
+    #[inline]
+    pub fn crc_itu_t_raw(seed: u16, data: &[u8]) -> u16 {
+        Self::crc16xmodem().update_raw(seed, data)
+    }
+
+    /// Update a raw CRC-16/T10-DIF (not reflected) register like `crc_t10dif_update(seed, data)` of the Linux kernel. The register starts from `seed` instead of an initial value, and is returned as is, without the final xor value applied.
+    ///
+    /// ```
+    /// # use crc_any::CRCu16;
+    /// assert_eq!(0xD0DB, CRCu16::crc_t10dif_raw(0, b"123456789"));
+    /// ```
+    #[inline]
+    pub fn crc_t10dif_raw(seed: u16, data: &[u8]) -> u16 {
+        Self::crc16t10_dif().update_raw(seed, data)
+    }
+}
+
 #[cfg(all(feature = "development", test))]
 mod tests {
     use alloc::{fmt::Write, string::String};
@@ -927,3 +1778,17 @@ mod tests {
         println!("let lookup_table = [{}];", s);
     }
 }
+
+#[cfg(test)]
+mod table_tests {
+    use super::*;
+
+    #[test]
+    fn custom_parameters_use_static_tables() {
+        assert!(
+            CRCu16::create_crc(0xA097, 16, 0x0000, 0x0000, false)
+                .lookup_table
+                .is_static(&REF_16_A097)
+        );
+    }
+}
@@ -1,12 +1,23 @@
 #[cfg(feature = "alloc")]
-use alloc::fmt::{self, Debug, Display, Formatter};
-#[cfg(feature = "alloc")]
 use alloc::vec::Vec;
+#[cfg(feature = "alloc")]
+use core::fmt::Debug;
+use core::fmt::{self, Binary, Display, Formatter, LowerHex, Octal, UpperHex};
 
 #[cfg(feature = "heapless")]
 use heapless::Vec as HeaplessVec;
 
-use crate::{constants::crc_u32::*, lookup_table::LookUpTable};
+use crate::{
+    backend::Backend,
+    constants::crc_u32::*,
+    endian::Endian,
+    engine::CrcEngine,
+    errors::{CrcParamError, UnavailableBackend, check_crc_params, check_params},
+    hex,
+    lookup_table::LookUpTable,
+    params::CrcParams,
+    table_cache,
+};
 
 #[derive(Clone, Copy, Debug, Eq, PartialEq)]
 enum Accelerator {
@@ -15,6 +26,7 @@ enum Accelerator {
 }
 
 #[allow(clippy::upper_case_acronyms)]
+#[derive(Clone)]
 /// This struct can help you compute a CRC-32 (or CRC-x where **x** is equal or less than `32`) value.
 pub struct CRCu32 {
     by_table:        bool,
@@ -26,9 +38,11 @@ pub struct CRCu32 {
     mask:            u32,
     initial:         u32,
     final_xor:       u32,
-    reflect:         bool,
+    refin:           bool,
+    refout:          bool,
     reorder:         bool,
     accelerator:     Accelerator,
+    sliced:          Option<&'static [[u32; 256]; 8]>,
 }
 
 #[cfg(feature = "alloc")]
@@ -36,14 +50,13 @@ impl Debug for CRCu32 {
     #[inline]
     fn fmt(&self, f: &mut Formatter) -> Result<(), fmt::Error> {
         if self.by_table {
-            debug_helper::impl_debug_for_struct!(CRCu32, f, self, let .lookup_table = self.lookup_table.as_ref(), (.sum, "0x{:08X}", self.sum), .bits, (.initial, "0x{:08X}", self.initial), (.final_xor, "0x{:08X}", self.final_xor), .reflect, .reorder);
+            debug_helper::impl_debug_for_struct!(CRCu32, f, self, let .lookup_table = self.lookup_table.as_ref(), (.sum, "0x{:08X}", self.sum), .bits, (.initial, "0x{:08X}", self.initial), (.final_xor, "0x{:08X}", self.final_xor), .refin, .refout, .reorder);
         } else {
-            debug_helper::impl_debug_for_struct!(CRCu32, f, self, (.poly, "0x{:08X}", self.poly), (.sum, "0x{:08X}", self.sum), .bits, (.initial, "0x{:08X}", self.initial), (.final_xor, "0x{:08X}", self.final_xor), .reflect, .reorder);
+            debug_helper::impl_debug_for_struct!(CRCu32, f, self, (.poly, "0x{:08X}", self.poly), (.sum, "0x{:08X}", self.sum), .bits, (.initial, "0x{:08X}", self.initial), (.final_xor, "0x{:08X}", self.final_xor), .refin, .refout, .reorder);
         }
     }
 }
 
-#[cfg(feature = "alloc")]
 impl Display for CRCu32 {
     #[inline]
     fn fmt(&self, f: &mut Formatter) -> Result<(), fmt::Error> {
@@ -51,31 +64,63 @@ impl Display for CRCu32 {
     }
 }
 
+impl LowerHex for CRCu32 {
+    #[inline]
+    fn fmt(&self, f: &mut Formatter) -> Result<(), fmt::Error> {
+        hex::fmt_crc(f, u128::from(self.get_crc()), self.bits, 4, false)
+    }
+}
+
+impl UpperHex for CRCu32 {
+    #[inline]
+    fn fmt(&self, f: &mut Formatter) -> Result<(), fmt::Error> {
+        hex::fmt_crc(f, u128::from(self.get_crc()), self.bits, 4, true)
+    }
+}
+
+impl Octal for CRCu32 {
+    #[inline]
+    fn fmt(&self, f: &mut Formatter) -> Result<(), fmt::Error> {
+        hex::fmt_crc(f, u128::from(self.get_crc()), self.bits, 3, false)
+    }
+}
+
+impl Binary for CRCu32 {
+    #[inline]
+    fn fmt(&self, f: &mut Formatter) -> Result<(), fmt::Error> {
+        hex::fmt_crc(f, u128::from(self.get_crc()), self.bits, 1, false)
+    }
+}
+
 impl CRCu32 {
     /// Create a `CRCu32` instance by providing the length of bits, expression, reflection, an initial value and a final xor value.
     pub fn create_crc(poly: u32, bits: u8, initial: u32, final_xor: u32, reflect: bool) -> CRCu32 {
+        Self::create_crc_with_reflection(poly, bits, initial, final_xor, reflect, reflect)
+    }
+
+    /// Create a `CRCu32` instance by providing the length of bits, expression, input reflection, output reflection, an initial value and a final xor value.
+    ///
+    /// The expression needs to be reflected if `refin` is `true`.
+    pub fn create_crc_with_reflection(
+        poly: u32,
+        bits: u8,
+        initial: u32,
+        final_xor: u32,
+        refin: bool,
+        refout: bool,
+    ) -> CRCu32 {
         debug_assert!(bits <= 32 && bits > 0);
 
-        let accelerator = if bits == 32 && reflect && poly == 0x82F63B78 {
+        let accelerator = if bits == 32 && refin && poly == 0x82F63B78 {
             Accelerator::Crc32c
         } else {
             Accelerator::None
         };
 
         let mut crc = if bits.is_multiple_of(8) {
-            let lookup_table = if reflect {
-                LookUpTable::Dynamic(Self::crc_reflect_table(poly))
-            } else {
-                LookUpTable::Dynamic(Self::crc_table(poly, bits))
-            };
+            let lookup_table = Self::lookup_table_of(bits, poly, refin);
 
-            Self::create_crc_with_exists_lookup_table(
-                lookup_table,
-                bits,
-                initial,
-                final_xor,
-                reflect,
-            )
+            Self::create(true, lookup_table, 0, bits, initial, final_xor, refin, refout)
         } else {
             Self::create(
                 false,
@@ -84,7 +129,8 @@ impl CRCu32 {
                 bits,
                 initial,
                 final_xor,
-                reflect,
+                refin,
+                refout,
             )
         };
 
@@ -93,6 +139,231 @@ impl CRCu32 {
         crc
     }
 
+    /// Set whether the bytes of the CRC value are output in reverse order, like `crc32` of the `mhash` library does (see [`CRCu32::crc32mhash`](crate::CRCu32::crc32mhash)). Only the bytes covered by the length of bits are reversed.
+    ///
+    /// This affects `get_crc`, the formatted output and the byte getters, and `resume_from` expects a CRC value in the same order. The state of the register does not depend on it.
+    #[inline]
+    pub fn with_reorder(mut self, reorder: bool) -> CRCu32 {
+        self.reorder = reorder;
+
+        self
+    }
+
+    /// Select the backend which processes the input, keeping the current state. An error is returned if the backend is not available for the algorithm or on the CPU. See [`Backend`] for details.
+    pub fn with_backend(mut self, backend: Backend) -> Result<CRCu32, UnavailableBackend> {
+        let poly = self.normal_poly();
+        let state = self.state();
+
+        match backend {
+            Backend::Bitwise => {
+                self.by_table = false;
+                self.lookup_table = LookUpTable::Static(&[0; 256]);
+                self.poly = poly;
+                self.accelerator = Accelerator::None;
+                self.sliced = None;
+            },
+            Backend::Table if self.bits.is_multiple_of(8) => {
+                self.switch_to_table(poly);
+                self.accelerator = Accelerator::None;
+                self.sliced = None;
+            },
+            Backend::Sliced => match Self::find_sliced_tables(self.bits, poly, self.refin) {
+                Some(tables) => {
+                    self.switch_to_table(poly);
+                    self.accelerator = Accelerator::None;
+                    self.sliced = Some(tables);
+                },
+                None => {
+                    return Err(UnavailableBackend {
+                        backend,
+                    });
+                },
+            },
+            Backend::Hardware
+                if self.bits == 32
+                    && self.refin
+                    && poly == 0x1EDC6F41
+                    && crc32c_hardware_available() =>
+            {
+                self.switch_to_table(poly);
+                self.accelerator = Accelerator::Crc32c;
+                self.sliced = None;
+            },
+            _ => {
+                return Err(UnavailableBackend {
+                    backend,
+                });
+            },
+        }
+
+        self.set_state(state);
+
+        Ok(self)
+    }
+
+    /// Get the backend which processes the input.
+    pub fn backend(&self) -> Backend {
+        if !self.by_table {
+            Backend::Bitwise
+        } else if self.accelerator == Accelerator::Crc32c && crc32c_hardware_available() {
+            Backend::Hardware
+        } else if self.sliced.is_some() {
+            Backend::Sliced
+        } else {
+            Backend::Table
+        }
+    }
+
+    /// Create a `CRCu32` instance from the parameters of a CRC algorithm.
+    pub fn from_params(params: &CrcParams) -> CRCu32 {
+        Self::create_crc_with_reflection(
+            params.create_crc_poly() as u32,
+            params.width,
+            params.init as u32,
+            params.xorout as u32,
+            params.refin,
+            params.refout,
+        )
+    }
+
+    /// Create a `CRCu32` instance like [`CRCu32::create_crc`], but return an error instead of creating a broken instance if the parameters are invalid.
+    pub fn try_create_crc(
+        poly: u32,
+        bits: u8,
+        initial: u32,
+        final_xor: u32,
+        reflect: bool,
+    ) -> Result<CRCu32, CrcParamError> {
+        Self::try_create_crc_with_reflection(poly, bits, initial, final_xor, reflect, reflect)
+    }
+
+    /// Create a `CRCu32` instance like [`CRCu32::create_crc_with_reflection`], but return an error instead of creating a broken instance if the parameters are invalid.
+    pub fn try_create_crc_with_reflection(
+        poly: u32,
+        bits: u8,
+        initial: u32,
+        final_xor: u32,
+        refin: bool,
+        refout: bool,
+    ) -> Result<CRCu32, CrcParamError> {
+        check_params(32, bits, u128::from(poly), u128::from(initial), u128::from(final_xor))?;
+
+        Ok(Self::create_crc_with_reflection(poly, bits, initial, final_xor, refin, refout))
+    }
+
+    /// Create a `CRCu32` instance from the parameters of a CRC algorithm, or return an error if the parameters are invalid.
+    pub fn try_from_params(params: &CrcParams) -> Result<CRCu32, CrcParamError> {
+        check_crc_params(32, params)?;
+
+        Ok(Self::from_params(params))
+    }
+
+    /// Create a `CRCu32` instance from the parameters of a CRC algorithm and a lookup table generated by [`CRCu32::table`] with the same parameters, so that the table does not need to be generated at runtime.
+    ///
+    /// The lookup table is only used if the length of bits is a multiple of `8`.
+    pub fn from_params_with_table(params: &CrcParams, table: &'static [u32; 256]) -> CRCu32 {
+        if params.width.is_multiple_of(8) {
+            Self::create(
+                true,
+                LookUpTable::Static(table),
+                0,
+                params.width,
+                params.init as u32,
+                params.xorout as u32,
+                params.refin,
+                params.refout,
+            )
+        } else {
+            Self::from_params(params)
+        }
+    }
+
+    /// Generate the lookup table of a CRC algorithm. It can be evaluated at compile time.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the length of bits of `params` is 0 or larger than `32`.
+    ///
+    /// ```
+    /// use crc_any::{
+    ///     CRCu32,
+    ///     params::{CRC_32_ISO_HDLC, CrcParams},
+    /// };
+    ///
+    /// const PARAMS: CrcParams = CRC_32_ISO_HDLC;
+    /// const TABLE: [u32; 256] = CRCu32::table(&PARAMS);
+    /// ```
+    pub const fn table(params: &CrcParams) -> [u32; 256] {
+        assert!(
+            params.width > 0 && params.width <= 32,
+            "the length of bits must be between 1 and 32"
+        );
+
+        if params.refin {
+            Self::crc_reflect_table(params.create_crc_poly() as u32)
+        } else {
+            Self::crc_table(params.poly as u32, params.width)
+        }
+    }
+
+    /// Compute the CRC value of `data` in one shot. It can be evaluated at compile time, but it processes the data bit by bit, so prefer creating a `CRCu32` instance at runtime.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the length of bits of `params` is 0 or larger than `32`.
+    ///
+    /// ```
+    /// use crc_any::{CRCu32, params::CRC_32_ISO_HDLC};
+    ///
+    /// const CHECK: u32 = CRCu32::checksum(&CRC_32_ISO_HDLC, b"123456789");
+    ///
+    /// assert_eq!(0xCBF43926, CHECK);
+    /// ```
+    pub const fn checksum(params: &CrcParams, data: &[u8]) -> u32 {
+        assert!(
+            params.width > 0 && params.width <= 32,
+            "the length of bits must be between 1 and 32"
+        );
+
+        let high_bit = 1u32 << (params.width - 1);
+        let mask = ((high_bit - 1) << 1) | 1;
+        let poly = params.poly as u32;
+
+        let mut sum = params.init as u32;
+
+        let mut i = 0;
+
+        while i < data.len() {
+            let n = if params.refin { data[i].reverse_bits() } else { data[i] };
+
+            let mut b = 0x80u8;
+
+            while b != 0 {
+                let mut bit = sum & high_bit;
+
+                sum <<= 1;
+
+                if n & b != 0 {
+                    bit ^= high_bit;
+                }
+
+                if bit != 0 {
+                    sum ^= poly;
+                }
+
+                b >>= 1;
+            }
+
+            i += 1;
+        }
+
+        if params.refout {
+            sum = sum.reverse_bits() >> (u32::BITS - params.width as u32);
+        }
+
+        (sum ^ params.xorout as u32) & mask
+    }
+
     #[inline]
     pub(crate) fn create_crc_with_exists_lookup_table(
         lookup_table: LookUpTable<u32>,
@@ -103,9 +374,10 @@ impl CRCu32 {
     ) -> CRCu32 {
         debug_assert!(bits.is_multiple_of(8));
 
-        Self::create(true, lookup_table, 0, bits, initial, final_xor, reflect)
+        Self::create(true, lookup_table, 0, bits, initial, final_xor, reflect, reflect)
     }
 
+    #[allow(clippy::too_many_arguments)]
     #[inline]
     fn create(
         by_table: bool,
@@ -114,14 +386,17 @@ impl CRCu32 {
         bits: u8,
         initial: u32,
         final_xor: u32,
-        reflect: bool,
+        refin: bool,
+        refout: bool,
     ) -> CRCu32 {
         let high_bit = 1 << u32::from(bits - 1);
         let mask = ((high_bit - 1) << 1) | 1;
 
-        let sum = if reflect { Self::reflect_function(high_bit, initial) } else { initial };
+        // the register is kept reflected only when a reflected lookup table is used
+        let sum =
+            if by_table && refin { Self::reflect_function(high_bit, initial) } else { initial };
 
-        if !by_table && reflect {
+        if !by_table && refin {
             poly = Self::reflect_function(high_bit, poly);
         }
 
@@ -135,9 +410,11 @@ impl CRCu32 {
             mask,
             initial,
             final_xor,
-            reflect,
+            refin,
+            refout,
             reorder: false,
             accelerator: Accelerator::None,
+            sliced: None,
         }
     }
 
@@ -153,6 +430,40 @@ impl CRCu32 {
         Self::reflect_function(self.high_bit, n)
     }
 
+    /// Get the lookup table of an expression, from the static tables, the cache, or by generating it.
+    fn lookup_table_of(bits: u8, poly: u32, refin: bool) -> LookUpTable<u32> {
+        match find_table(bits, poly, refin) {
+            Some(table) => LookUpTable::Static(table),
+            None => table_cache::lookup_table(bits, poly, refin, || {
+                if refin { Self::crc_reflect_table(poly) } else { Self::crc_table(poly, bits) }
+            }),
+        }
+    }
+
+    /// Get the expression in normal (unreflected) form.
+    fn normal_poly(&self) -> u32 {
+        if !self.by_table {
+            self.poly
+        } else if self.refin {
+            // the entry of `0x80` of a reflected lookup table is the reflected expression
+            self.reflect_method(self.lookup_table[0x80])
+        } else {
+            // the entry of `1` of a normal lookup table is the expression
+            self.lookup_table[1]
+        }
+    }
+
+    /// Use the lookup table of the expression `poly` (in normal form) if the bitwise loop is used.
+    fn switch_to_table(&mut self, poly: u32) {
+        if !self.by_table {
+            let poly = if self.refin { self.reflect_method(poly) } else { poly };
+
+            self.lookup_table = Self::lookup_table_of(self.bits, poly, self.refin);
+            self.by_table = true;
+            self.poly = 0;
+        }
+    }
+
     /// Update the current CRC state with bytes.
     #[inline]
     pub fn update(&mut self, data: &[u8]) {
@@ -164,13 +475,19 @@ impl CRCu32 {
             }
         }
 
+        if let Some(tables) = self.sliced {
+            self.update_sliced(tables, data);
+
+            return;
+        }
+
         if self.by_table {
             if self.bits == 8 {
                 for n in data.iter().copied() {
                     let index = (self.sum as u8 ^ n) as usize;
                     self.sum = self.lookup_table[index];
                 }
-            } else if self.reflect {
+            } else if self.refin {
                 for n in data.iter().copied() {
                     let index = ((self.sum as u8) ^ n) as usize;
                     self.sum = (self.sum >> 8) ^ self.lookup_table[index];
@@ -181,7 +498,7 @@ impl CRCu32 {
                     self.sum = (self.sum << 8) ^ self.lookup_table[index];
                 }
             }
-        } else if self.reflect {
+        } else if self.refin {
             for n in data.iter().copied() {
                 let n = super::crc_u8::CRCu8::reflect_function(0x80, n);
 
@@ -226,6 +543,200 @@ impl CRCu32 {
         }
     }
 
+    /// Update the register with slicing-by-8. The length of bits is always 32 here.
+    fn update_sliced(&mut self, tables: &[[u32; 256]; 8], data: &[u8]) {
+        let mut sum = self.sum;
+
+        let mut chunks = data.chunks_exact(8);
+
+        if self.refin {
+            for chunk in &mut chunks {
+                let low = sum ^ u32::from_le_bytes([chunk[0], chunk[1], chunk[2], chunk[3]]);
+                let high = u32::from_le_bytes([chunk[4], chunk[5], chunk[6], chunk[7]]);
+
+                sum = tables[7][(low & 0xFF) as usize]
+                    ^ tables[6][((low >> 8) & 0xFF) as usize]
+                    ^ tables[5][((low >> 16) & 0xFF) as usize]
+                    ^ tables[4][(low >> 24) as usize]
+                    ^ tables[3][(high & 0xFF) as usize]
+                    ^ tables[2][((high >> 8) & 0xFF) as usize]
+                    ^ tables[1][((high >> 16) & 0xFF) as usize]
+                    ^ tables[0][(high >> 24) as usize];
+            }
+
+            for n in chunks.remainder().iter().copied() {
+                sum = (sum >> 8) ^ tables[0][(sum as u8 ^ n) as usize];
+            }
+        } else {
+            for chunk in &mut chunks {
+                let high = sum ^ u32::from_be_bytes([chunk[0], chunk[1], chunk[2], chunk[3]]);
+                let low = u32::from_be_bytes([chunk[4], chunk[5], chunk[6], chunk[7]]);
+
+                sum = tables[7][(high >> 24) as usize]
+                    ^ tables[6][((high >> 16) & 0xFF) as usize]
+                    ^ tables[5][((high >> 8) & 0xFF) as usize]
+                    ^ tables[4][(high & 0xFF) as usize]
+                    ^ tables[3][(low >> 24) as usize]
+                    ^ tables[2][((low >> 16) & 0xFF) as usize]
+                    ^ tables[1][((low >> 8) & 0xFF) as usize]
+                    ^ tables[0][(low & 0xFF) as usize];
+            }
+
+            for n in chunks.remainder().iter().copied() {
+                sum = (sum << 8) ^ tables[0][((sum >> 24) as u8 ^ n) as usize];
+            }
+        }
+
+        self.sum = sum;
+    }
+
+    /// Find the slicing-by-8 tables of the given length of bits, expression (in normal form) and input reflection. They are only linked into a binary if [`CRCu32::with_backend`] is used.
+    fn find_sliced_tables(bits: u8, poly: u32, refin: bool) -> Option<&'static [[u32; 256]; 8]> {
+        static REF_04C11DB7: [[u32; 256]; 8] = CRCu32::crc_sliced_tables(&REF_32_EDB88320, true);
+        static NO_REF_04C11DB7: [[u32; 256]; 8] =
+            CRCu32::crc_sliced_tables(&NO_REF_32_04C11DB7, false);
+        static REF_1EDC6F41: [[u32; 256]; 8] = CRCu32::crc_sliced_tables(&REF_32_82F63B78, true);
+
+        match (bits, poly, refin) {
+            (32, 0x04C11DB7, true) => Some(&REF_04C11DB7),
+            (32, 0x04C11DB7, false) => Some(&NO_REF_04C11DB7),
+            (32, 0x1EDC6F41, true) => Some(&REF_1EDC6F41),
+            _ => None,
+        }
+    }
+
+    /// Extend a lookup table of 32 bits to the eight tables of slicing-by-8.
+    const fn crc_sliced_tables(table: &[u32; 256], reflect: bool) -> [[u32; 256]; 8] {
+        let mut tables = [*table; 8];
+
+        let mut k = 1;
+
+        while k < 8 {
+            let mut i = 0;
+
+            while i < 256 {
+                let v = tables[k - 1][i];
+
+                tables[k][i] = if reflect {
+                    (v >> 8) ^ table[(v & 0xFF) as usize]
+                } else {
+                    (v << 8) ^ table[(v >> 24) as usize]
+                };
+
+                i += 1;
+            }
+
+            k += 1;
+        }
+
+        tables
+    }
+
+    /// Update the current CRC state with the bytes of a `u16` value in little-endian byte order.
+    #[inline]
+    pub fn update_u16_le(&mut self, value: u16) {
+        self.update(&value.to_le_bytes());
+    }
+
+    /// Update the current CRC state with the bytes of a `u16` value in big-endian byte order.
+    #[inline]
+    pub fn update_u16_be(&mut self, value: u16) {
+        self.update(&value.to_be_bytes());
+    }
+
+    /// Update the current CRC state with the bytes of a `u32` value in little-endian byte order.
+    #[inline]
+    pub fn update_u32_le(&mut self, value: u32) {
+        self.update(&value.to_le_bytes());
+    }
+
+    /// Update the current CRC state with the bytes of a `u32` value in big-endian byte order.
+    #[inline]
+    pub fn update_u32_be(&mut self, value: u32) {
+        self.update(&value.to_be_bytes());
+    }
+
+    /// Update the current CRC state with the bytes of a `u64` value in little-endian byte order.
+    #[inline]
+    pub fn update_u64_le(&mut self, value: u64) {
+        self.update(&value.to_le_bytes());
+    }
+
+    /// Update the current CRC state with the bytes of a `u64` value in big-endian byte order.
+    #[inline]
+    pub fn update_u64_be(&mut self, value: u64) {
+        self.update(&value.to_be_bytes());
+    }
+
+    /// Update the current CRC state with the bytes of an `f32` value in little-endian byte order.
+    #[inline]
+    pub fn update_f32_le(&mut self, value: f32) {
+        self.update(&value.to_le_bytes());
+    }
+
+    /// Update the current CRC state with the bytes of an `f32` value in big-endian byte order.
+    #[inline]
+    pub fn update_f32_be(&mut self, value: f32) {
+        self.update(&value.to_be_bytes());
+    }
+
+    /// Update the current CRC state with the bytes of an `f64` value in little-endian byte order.
+    #[inline]
+    pub fn update_f64_le(&mut self, value: f64) {
+        self.update(&value.to_le_bytes());
+    }
+
+    /// Update the current CRC state with the bytes of an `f64` value in big-endian byte order.
+    #[inline]
+    pub fn update_f64_be(&mut self, value: f64) {
+        self.update(&value.to_be_bytes());
+    }
+
+    /// Update the current CRC state with a single bit.
+    pub fn update_bit(&mut self, bit: bool) {
+        if self.by_table && self.refin {
+            // the register is reflected, and the reflected expression is the entry of `0x80`
+            let low_bit = (self.sum & 1 != 0) ^ bit;
+
+            self.sum >>= 1;
+
+            if low_bit {
+                self.sum ^= self.lookup_table[0x80];
+            }
+        } else {
+            let poly = self.normal_poly();
+
+            let high_bit = (self.sum & self.high_bit != 0) ^ bit;
+
+            self.sum <<= 1;
+
+            if high_bit {
+                self.sum ^= poly;
+            }
+        }
+    }
+
+    /// Update the current CRC state with the low `nbits` bits of `value`, for messages whose length is not a multiple of 8 bits.
+    ///
+    /// The bits are taken in the same order as the bits of the bytes passed to `update`: from the least significant bit if the input is reflected, and from the most significant bit (bit `nbits - 1`) otherwise. Thus, `update_bits(u64::from(byte), 8)` is the same as `update(&[byte])`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `nbits` is larger than `64`.
+    pub fn update_bits(&mut self, value: u64, nbits: u32) {
+        assert!(nbits <= 64, "cannot update with more than 64 bits at once");
+
+        if self.refin {
+            for i in 0..nbits {
+                self.update_bit((value >> i) & 1 != 0);
+            }
+        } else {
+            for i in (0..nbits).rev() {
+                self.update_bit((value >> i) & 1 != 0);
+            }
+        }
+    }
+
     /// Digest some data.
     ///
     /// This is a compatibility wrapper around [`CRCu32::update`].
@@ -236,81 +747,156 @@ impl CRCu32 {
 
     /// Reset the sum.
     pub fn reset(&mut self) {
-        self.sum = if self.reflect {
+        self.sum = if self.by_table && self.refin {
             Self::reflect_function(self.high_bit, self.initial)
         } else {
             self.initial
         };
     }
 
+    /// Get the current state of the register, which can be restored later with [`CRCu32::set_state`] or [`CRCu32::from_state`].
+    ///
+    /// The state is always in normal (unreflected) form and is taken before the output reflection and the final xor value are applied, so it does not depend on how the CRC is computed.
+    pub fn state(&self) -> u32 {
+        let sum = self.sum & self.mask;
+
+        if self.by_table && self.refin { self.reflect_method(sum) } else { sum }
+    }
+
+    /// Restore a state obtained from [`CRCu32::state`] of an instance with the same parameters. You can continue calling `update` or `digest` after that.
+    pub fn set_state(&mut self, state: u32) {
+        let state = state & self.mask;
+
+        self.sum = if self.by_table && self.refin { self.reflect_method(state) } else { state };
+    }
+
+    /// Create a `CRCu32` instance from the parameters of a CRC algorithm and a state obtained from [`CRCu32::state`].
+    pub fn from_state(params: &CrcParams, state: u32) -> CRCu32 {
+        let mut crc = Self::from_params(params);
+
+        crc.set_state(state);
+
+        crc
+    }
+
+    /// Continue from a CRC value returned by [`CRCu32::get_crc`] of an instance with the same parameters, as if the data of that CRC value had been passed to `update` or `digest`.
+    pub fn resume_from(&mut self, crc: u32) {
+        let crc = if self.reorder { self.reorder_method(crc) } else { crc };
+
+        let sum = (crc ^ self.final_xor) & self.mask;
+
+        self.set_state(if self.refout { self.reflect_method(sum) } else { sum });
+    }
+
+    /// Create a `CRCu32` instance from the parameters of a CRC algorithm and a CRC value of some previous data computed with the same parameters. Updating the instance with more data gives the CRC value of the concatenated data.
+    pub fn resume(params: &CrcParams, crc: u32) -> CRCu32 {
+        let mut crc_instance = Self::from_params(params);
+
+        crc_instance.resume_from(crc);
+
+        crc_instance
+    }
+
+    /// Check whether this instance and `other` compute the same CRC algorithm, regardless of their current states and backends.
+    pub fn is_same_algorithm(&self, other: &CRCu32) -> bool {
+        self.bits == other.bits
+            && self.initial == other.initial
+            && self.final_xor == other.final_xor
+            && self.refin == other.refin
+            && self.refout == other.refout
+            && self.reorder == other.reorder
+            && self.normal_poly() == other.normal_poly()
+    }
+
+    /// Create a copy of this instance, including its current state. This is useful for computing the CRC values of many messages sharing a common prefix, which only needs to be processed once.
+    #[inline]
+    pub fn fork(&self) -> CRCu32 {
+        self.clone()
+    }
+
     /// Get the current CRC value (it always returns a `u32` value). You can continue calling `update` or `digest` even after getting a CRC value.
     pub fn get_crc(&self) -> u32 {
-        let sum = if self.by_table || !self.reflect {
+        let sum = if (self.by_table && self.refin) == self.refout {
             (self.sum ^ self.final_xor) & self.mask
         } else {
             (self.reflect_method(self.sum) ^ self.final_xor) & self.mask
         };
 
-        if self.reorder {
-            let mut new_sum = 0;
+        if self.reorder { self.reorder_method(sum) } else { sum }
+    }
 
-            let e = u32::from(self.bits).div_ceil(8);
+    #[inline]
+    fn reorder_method(&self, sum: u32) -> u32 {
+        let mut new_sum = 0;
 
-            let e_dec = e - 1;
+        let e = u32::from(self.bits).div_ceil(8);
 
-            for i in 0..e {
-                new_sum |= ((sum >> ((e_dec - i) * 8)) & 0xFF) << (i * 8);
-            }
+        let e_dec = e - 1;
 
-            new_sum
-        } else {
-            sum
+        for i in 0..e {
+            new_sum |= ((sum >> ((e_dec - i) * 8)) & 0xFF) << (i * 8);
         }
+
+        new_sum
     }
 
-    fn crc_reflect_table(poly_rev: u32) -> [u32; 256] {
+    const fn crc_reflect_table(poly_rev: u32) -> [u32; 256] {
         let mut lookup_table = [0u32; 256];
 
-        for (i, e) in lookup_table.iter_mut().enumerate() {
+        let mut i = 0;
+
+        while i < 256 {
             let mut v = i as u32;
 
-            #[allow(clippy::branches_sharing_code)]
-            for _ in 0..8u8 {
+            let mut j = 0;
+
+            while j < 8 {
                 if v & 1 != 0 {
                     v >>= 1;
                     v ^= poly_rev;
                 } else {
                     v >>= 1;
                 }
+
+                j += 1;
             }
 
-            *e = v;
+            lookup_table[i] = v;
+
+            i += 1;
         }
 
         lookup_table
     }
 
-    fn crc_table(poly: u32, bits: u8) -> [u32; 256] {
+    const fn crc_table(poly: u32, bits: u8) -> [u32; 256] {
         let mut lookup_table = [0u32; 256];
 
-        let mask1 = 1u32 << u32::from(bits - 1);
+        let mask1 = 1u32 << (bits - 1);
 
         let mask2 = ((mask1 - 1) << 1) | 1;
 
-        for (i, e) in lookup_table.iter_mut().enumerate() {
+        let mut i = 0;
+
+        while i < 256 {
             let mut v = i as u32;
 
-            #[allow(clippy::branches_sharing_code)]
-            for _ in 0..bits {
+            let mut j = 0;
+
+            while j < bits {
                 if v & mask1 == 0 {
                     v <<= 1;
                 } else {
                     v <<= 1;
                     v ^= poly;
                 }
+
+                j += 1;
             }
 
-            *e = v & mask2;
+            lookup_table[i] = v & mask2;
+
+            i += 1;
         }
 
         lookup_table
@@ -318,7 +904,28 @@ impl CRCu32 {
 }
 
 #[inline]
-fn crc32c_hardware_update(sum: u32, data: &[u8]) -> Option<u32> {
+fn crc32c_hardware_available() -> bool {
+    #[cfg(all(any(target_arch = "x86", target_arch = "x86_64"), target_feature = "sse4.2"))]
+    {
+        return true;
+    }
+
+    #[cfg(all(
+        feature = "std",
+        any(target_arch = "x86", target_arch = "x86_64"),
+        not(target_feature = "sse4.2")
+    ))]
+    {
+        if std::is_x86_feature_detected!("sse4.2") {
+            return true;
+        }
+    }
+
+    false
+}
+
+#[inline]
+pub(crate) fn crc32c_hardware_update(sum: u32, data: &[u8]) -> Option<u32> {
     #[cfg(all(any(target_arch = "x86", target_arch = "x86_64"), target_feature = "sse4.2"))]
     {
         return Some(unsafe { crc32c_sse42_update(sum, data) });
@@ -380,6 +987,38 @@ unsafe fn crc32c_sse42_update(mut sum: u32, data: &[u8]) -> u32 {
     sum
 }
 
+impl CRCu32 {
+    /// Get the current CRC value as a big-endian byte array. Its first bytes, as many as the CRC bits need, hold the CRC value, and the rest are zero. You can continue calling `update` or `digest` even after getting a CRC value.
+    #[inline]
+    pub fn get_crc_bytes_be(&self) -> [u8; 4] {
+        let crc = self.get_crc();
+
+        let e = usize::from(self.bits).div_ceil(8);
+
+        let mut bytes = [0u8; 4];
+
+        bytes[..e].copy_from_slice(&crc.to_be_bytes()[(4 - e)..]);
+
+        bytes
+    }
+
+    /// Get the current CRC value as a little-endian byte array. Its first bytes, as many as the CRC bits need, hold the CRC value, and the rest are zero. You can continue calling `update` or `digest` even after getting a CRC value.
+    #[inline]
+    pub fn get_crc_bytes_le(&self) -> [u8; 4] {
+        self.get_crc().to_le_bytes()
+    }
+
+    /// Get the current CRC value as uppercase hexadecimal digits in ASCII. Its first bytes, as many as [`CrcEngine::hex_len`], hold the digits, and the rest are zero. You can continue calling `update` or `digest` even after getting a CRC value.
+    #[inline]
+    pub fn to_hex_array(&self) -> [u8; 8] {
+        let mut hex = [0u8; 8];
+
+        self.write_crc_hex(&mut hex);
+
+        hex
+    }
+}
+
 #[cfg(feature = "alloc")]
 impl CRCu32 {
     /// Get the current CRC value (it always returns a vec instance with a length corresponding to the CRC bits). You can continue calling `update` or `digest` even after getting a CRC value.
@@ -434,6 +1073,151 @@ impl CRCu32 {
     }
 }
 
+impl CRCu32 {
+    /// Append the current CRC value to `buf` in the given byte order, using as many bytes as the CRC bits need.
+    pub fn append_crc(&self, buf: &mut impl Extend<u8>, endian: Endian) {
+        let crc = self.get_crc();
+
+        let e = usize::from(self.bits).div_ceil(8);
+
+        match endian {
+            Endian::Big => buf.extend(crc.to_be_bytes()[(4 - e)..].iter().copied()),
+            Endian::Little => buf.extend(crc.to_le_bytes()[..e].iter().copied()),
+        }
+    }
+
+    /// Check whether `data_with_crc` ends with the CRC value of the data before it, in the given byte order. The data is computed from the current state, which is not changed.
+    pub fn verify_trailer(&self, data_with_crc: &[u8], endian: Endian) -> bool {
+        let e = usize::from(self.bits).div_ceil(8);
+
+        if data_with_crc.len() < e {
+            return false;
+        }
+
+        let (data, trailer) = data_with_crc.split_at(data_with_crc.len() - e);
+
+        let mut crc = self.clone();
+
+        crc.update(data);
+
+        let crc = crc.get_crc();
+
+        match endian {
+            Endian::Big => trailer == &crc.to_be_bytes()[(4 - e)..],
+            Endian::Little => trailer == &crc.to_le_bytes()[..e],
+        }
+    }
+
+    /// Check whether `data_with_crc` is an error-free codeword, that is, data followed by its CRC value in the bit order of the algorithm (big-endian if the input is not reflected, little-endian otherwise), by comparing the register with the residue of the algorithm after processing the whole codeword. The data is computed from the current state, which is not changed.
+    ///
+    /// If the CRC bits do not fill whole bytes, the input and output reflections differ, or the bytes of the CRC value are reordered, there is no such residue for byte-oriented codewords, so the trailer is compared with [`CRCu32::verify_trailer`] instead.
+    pub fn check_residue(&self, data_with_crc: &[u8]) -> bool {
+        if !self.bits.is_multiple_of(8) || self.refin != self.refout || self.reorder {
+            let endian = if self.refin { Endian::Little } else { Endian::Big };
+
+            return self.verify_trailer(data_with_crc, endian);
+        }
+
+        if data_with_crc.len() < usize::from(self.bits / 8) {
+            return false;
+        }
+
+        let mut crc = self.clone();
+
+        crc.update(data_with_crc);
+
+        crc.state() == self.residue_state()
+    }
+
+    /// Get the residue of the algorithm, which is the content of the register after processing an error-free codeword, reflected if the output is reflected, before the final xor value is applied. This is the `residue` parameter of the catalogue.
+    pub fn residue(&self) -> u32 {
+        let residue = self.residue_state();
+
+        if self.refout { self.reflect_method(residue) } else { residue }
+    }
+
+    /// The CRC bits of a codeword leave `final_xor` (in the bit order of the input) in the register, so the residue is `final_xor` shifted through the register by the length of bits.
+    fn residue_state(&self) -> u32 {
+        let poly = self.normal_poly();
+
+        let mut residue =
+            if self.refin { self.reflect_method(self.final_xor) } else { self.final_xor };
+
+        for _ in 0..self.bits {
+            residue =
+                if residue & self.high_bit != 0 { (residue << 1) ^ poly } else { residue << 1 };
+        }
+
+        residue & self.mask
+    }
+}
+
+/// Two instances are equal if they compute the same CRC algorithm and are in the same state.
+impl PartialEq for CRCu32 {
+    #[inline]
+    fn eq(&self, other: &CRCu32) -> bool {
+        self.is_same_algorithm(other) && self.state() == other.state()
+    }
+}
+
+impl Eq for CRCu32 {}
+
+impl CrcEngine for CRCu32 {
+    type Output = u32;
+
+    const WIDTH_MAX: u8 = 32;
+
+    #[inline]
+    fn create_crc(poly: u32, bits: u8, initial: u32, final_xor: u32, reflect: bool) -> CRCu32 {
+        CRCu32::create_crc(poly, bits, initial, final_xor, reflect)
+    }
+
+    #[inline]
+    fn from_params(params: &CrcParams) -> CRCu32 {
+        CRCu32::from_params(params)
+    }
+
+    #[inline]
+    fn try_from_params(params: &CrcParams) -> Result<CRCu32, CrcParamError> {
+        CRCu32::try_from_params(params)
+    }
+
+    #[inline]
+    fn width(&self) -> u8 {
+        self.bits
+    }
+
+    #[inline]
+    fn update(&mut self, data: &[u8]) {
+        CRCu32::update(self, data)
+    }
+
+    #[inline]
+    fn reset(&mut self) {
+        CRCu32::reset(self)
+    }
+
+    #[inline]
+    fn get_crc(&self) -> u32 {
+        CRCu32::get_crc(self)
+    }
+
+    #[inline]
+    fn state(&self) -> u32 {
+        CRCu32::state(self)
+    }
+
+    #[inline]
+    fn set_state(&mut self, state: u32) {
+        CRCu32::set_state(self, state)
+    }
+
+    #[inline]
+    fn resume_from(&mut self, crc: u32) {
+        CRCu32::resume_from(self, crc)
+    }
+}
+
 impl CRCu32 {
     /// |Check|Poly|Init|Ref|XorOut|
     /// |---|---|---|---|---|
@@ -531,6 +1315,23 @@ impl CRCu32 {
         Self::create_crc_with_exists_lookup_table(lookup_table, 24, 0x00ABCDEF, 0x00000000, false)
     }
 
+    /// |Check|Poly|Init|Ref|XorOut|
+    /// |---|---|---|---|---|
+    /// |0xB4F3E6|0x328B63|0xFFFFFF|false|0xFFFFFF|
+    ///
+    /// ```
+    /// # use crc_any::CRCu32;
+    /// let mut crc = CRCu32::crc24interlaken();
+    /// crc.digest(b"123456789");
+    #[cfg_attr(feature = "alloc", doc = "assert_eq!(\"0xB4F3E6\", &crc.to_string());")]
+    /// ```
+    pub fn crc24interlaken() -> CRCu32 {
+        // Self::create_crc(0x00328B63, 24, 0x00FFFFFF, 0x00FFFFFF, false)
+
+        let lookup_table = LookUpTable::Static(&NO_REF_24_00328B63);
+        Self::create_crc_with_exists_lookup_table(lookup_table, 24, 0x00FFFFFF, 0x00FFFFFF, false)
+    }
+
     /// |Check|Poly|Init|Ref|XorOut|
     /// |---|---|---|---|---|
     /// |0xCDE703|0x864CFB|0x000000|false|0x000000|
@@ -596,6 +1397,20 @@ impl CRCu32 {
         Self::create_crc(0x2030B9C7, 30, 0x3FFFFFFF, 0x3FFFFFFF, false)
     }
 
+    /// |Check|Poly|Init|Ref|XorOut|
+    /// |---|---|---|---|---|
+    /// |0x0CE9E46C|0x04C11DB7|0x7FFFFFFF|false|0x7FFFFFFF|
+    ///
+    /// ```
+    /// # use crc_any::CRCu32;
+    /// let mut crc = CRCu32::crc31philips();
+    /// crc.digest(b"123456789");
+    #[cfg_attr(feature = "alloc", doc = "assert_eq!(\"0x0CE9E46C\", &crc.to_string());")]
+    /// ```
+    pub fn crc31philips() -> CRCu32 {
+        Self::create_crc(0x04C11DB7, 31, 0x7FFFFFFF, 0x7FFFFFFF, false)
+    }
+
     /// |Check|Poly|Init|Ref|XorOut|
     /// |---|---|---|---|---|
     /// |0xCBF43926|0x04C11DB7 (rev: 0xEDB88320)|0xFFFFFFFF|true|0xFFFFFFFF|
@@ -613,6 +1428,26 @@ impl CRCu32 {
         Self::create_crc_with_exists_lookup_table(lookup_table, 32, 0xFFFFFFFF, 0xFFFFFFFF, true)
     }
 
+    /// Create a CRC-32 instance which continues from the CRC-32 value `crc` of some previous data, like `crc32(crc, buf)` of zlib.
+    ///
+    /// ```
+    /// # use crc_any::CRCu32;
+    /// let mut crc = CRCu32::crc32();
+    /// crc.digest(b"12345");
+    ///
+    /// let mut crc = CRCu32::crc32_resume(crc.get_crc());
+    /// crc.digest(b"6789");
+    ///
+    /// assert_eq!(0xCBF43926, crc.get_crc());
+    /// ```
+    pub fn crc32_resume(crc: u32) -> CRCu32 {
+        let mut crc_instance = Self::crc32();
+
+        crc_instance.resume_from(crc);
+
+        crc_instance
+    }
+
     /// |Check|Poly|Init|Ref|XorOut|
     /// |---|---|---|---|---|
     /// |0x181989FC|0x04C11DB7|0xFFFFFFFF|false|0xFFFFFFFF|
@@ -643,6 +1478,23 @@ impl CRCu32 {
         crc
     }
 
+    /// |Check|Poly|Init|Ref|XorOut|
+    /// |---|---|---|---|---|
+    /// |0x1697D06A|0xF4ACFB13 (rev: 0xC8DF352F)|0xFFFFFFFF|true|0xFFFFFFFF|
+    ///
+    /// ```
+    /// # use crc_any::CRCu32;
+    /// let mut crc = CRCu32::crc32autosar();
+    /// crc.digest(b"123456789");
+    #[cfg_attr(feature = "alloc", doc = "assert_eq!(\"0x1697D06A\", &crc.to_string());")]
+    /// ```
+    pub fn crc32autosar() -> CRCu32 {
+        // Self::create_crc(0xC8DF352F, 32, 0xFFFFFFFF, 0xFFFFFFFF, true)
+
+        let lookup_table = LookUpTable::Static(&REF_32_C8DF352F);
+        Self::create_crc_with_exists_lookup_table(lookup_table, 32, 0xFFFFFFFF, 0xFFFFFFFF, true)
+    }
+
     /// |Check|Poly|Init|Ref|XorOut|
     /// |---|---|---|---|---|
     /// |0xFC891918|0x04C11DB7|0xFFFFFFFF|false|0xFFFFFFFF|
@@ -687,6 +1539,23 @@ impl CRCu32 {
         crc
     }
 
+    /// |Check|Poly|Init|Ref|XorOut|
+    /// |---|---|---|---|---|
+    /// |0x6EC2EDC4|0x8001801B (rev: 0xD8018001)|0x00000000|true|0x00000000|
+    ///
+    /// ```
+    /// # use crc_any::CRCu32;
+    /// let mut crc = CRCu32::crc32cdrom_edc();
+    /// crc.digest(b"123456789");
+    #[cfg_attr(feature = "alloc", doc = "assert_eq!(\"0x6EC2EDC4\", &crc.to_string());")]
+    /// ```
+    pub fn crc32cdrom_edc() -> CRCu32 {
+        // Self::create_crc(0xD8018001, 32, 0x00000000, 0x00000000, true)
+
+        let lookup_table = LookUpTable::Static(&REF_32_D8018001);
+        Self::create_crc_with_exists_lookup_table(lookup_table, 32, 0x00000000, 0x00000000, true)
+    }
+
     /// |Check|Poly|Init|Ref|XorOut|
     /// |---|---|---|---|---|
     /// |0x87315576|0xA833982B (rev: 0xD419CC15)|0xFFFFFFFF|true|0xFFFFFFFF|
@@ -772,6 +1641,23 @@ impl CRCu32 {
         Self::create_crc_with_exists_lookup_table(lookup_table, 32, 0xFFFFFFFF, 0x00000000, true)
     }
 
+    /// |Check|Poly|Init|Ref|XorOut|
+    /// |---|---|---|---|---|
+    /// |0xD2C22F51|0x741B8CD7 (rev: 0xEB31D82E)|0xFFFFFFFF|true|0x00000000|
+    ///
+    /// ```
+    /// # use crc_any::CRCu32;
+    /// let mut crc = CRCu32::crc32mef();
+    /// crc.digest(b"123456789");
+    #[cfg_attr(feature = "alloc", doc = "assert_eq!(\"0xD2C22F51\", &crc.to_string());")]
+    /// ```
+    pub fn crc32mef() -> CRCu32 {
+        // Self::create_crc(0xEB31D82E, 32, 0xFFFFFFFF, 0x00000000, true)
+
+        let lookup_table = LookUpTable::Static(&REF_32_EB31D82E);
+        Self::create_crc_with_exists_lookup_table(lookup_table, 32, 0xFFFFFFFF, 0x00000000, true)
+    }
+
     /// |Check|Poly|Init|Ref|XorOut|
     /// |---|---|---|---|---|
     /// |0xBD0BE338|0x000000AF|0x00000000|false|0x00000000|
@@ -790,6 +1676,50 @@ impl CRCu32 {
     }
 }
 
+impl CRCu32 {
+    #[inline]
+    fn update_raw(mut self, seed: u32, data: &[u8]) -> u32 {
+        self.sum = seed;
+
+        self.update(data);
+
+        self.sum
+    }
+
+    /// Update a raw CRC-32C (reflected) register like `crc32c(seed, data)` of the Linux kernel. The register starts from `seed` instead of an initial value, and is returned as is, without the final xor value applied.
+    ///
+    /// ```
+    /// # use crc_any::CRCu32;
+    /// assert_eq!(0xE3069283, !CRCu32::crc32c_raw(!0, b"123456789"));
+    /// ```
+    #[inline]
+    pub fn crc32c_raw(seed: u32, data: &[u8]) -> u32 {
+        Self::crc32c().update_raw(seed, data)
+    }
+
+    /// Update a raw CRC-32 (reflected) register like `crc32_le(seed, data)` of the Linux kernel. The register starts from `seed` instead of an initial value, and is returned as is, without the final xor value applied.
+    ///
+    /// ```
+    /// # use crc_any::CRCu32;
+    /// assert_eq!(0xCBF43926, !CRCu32::crc32_le_raw(!0, b"123456789"));
+    /// ```
+    #[inline]
+    pub fn crc32_le_raw(seed: u32, data: &[u8]) -> u32 {
+        Self::crc32().update_raw(seed, data)
+    }
+
+    /// Update a raw CRC-32 (not reflected) register like `crc32_be(seed, data)` of the Linux kernel. The register starts from `seed` instead of an initial value, and is returned as is, without the final xor value applied.
+    ///
+    /// ```
+    /// # use crc_any::CRCu32;
+    /// assert_eq!(0x0376E6E7, CRCu32::crc32_be_raw(!0, b"123456789"));
+    /// ```
+    #[inline]
+    pub fn crc32_be_raw(seed: u32, data: &[u8]) -> u32 {
+        Self::crc32mpeg2().update_raw(seed, data)
+    }
+}
+
 #[cfg(all(feature = "development", test))]
 mod tests {
     use alloc::{fmt::Write, string::String};
@@ -811,3 +1741,31 @@ mod tests {
         println!("let lookup_table = [{}];", s);
     }
 }
+
+#[cfg(test)]
+mod table_tests {
+    use super::*;
+
+    #[test]
+    fn custom_parameters_use_static_tables() {
+        assert!(
+            CRCu32::create_crc(0x04C11DB7, 32, 0xFFFFFFFF, 0xFFFFFFFF, false)
+                .lookup_table
+                .is_static(&NO_REF_32_04C11DB7)
+        );
+        assert!(
+            CRCu32::create_crc(0x82F63B78, 32, 0xFFFFFFFF, 0xFFFFFFFF, true)
+                .lookup_table
+                .is_static(&REF_32_82F63B78)
+        );
+    }
+
+    #[cfg(any(feature = "std", target_has_atomic = "8"))]
+    #[test]
+    fn identical_custom_parameters_share_a_cached_table() {
+        let a = CRCu32::create_crc(0x12345679, 32, 0xFFFFFFFF, 0x00000000, false);
+        let b = CRCu32::create_crc(0x12345679, 32, 0x00000000, 0xFFFFFFFF, false);
+
+        assert!(a.lookup_table.is_same_static(&b.lookup_table));
+    }
+}
@@ -1,14 +1,26 @@
 #[cfg(feature = "alloc")]
-use alloc::fmt::{self, Debug, Display, Formatter};
-#[cfg(feature = "alloc")]
 use alloc::vec::Vec;
+#[cfg(feature = "alloc")]
+use core::fmt::Debug;
+use core::fmt::{self, Binary, Display, Formatter, LowerHex, Octal, UpperHex};
 
 #[cfg(feature = "heapless")]
 use heapless::Vec as HeaplessVec;
 
-use crate::{constants::crc_u64::*, lookup_table::LookUpTable};
+use crate::{
+    backend::Backend,
+    constants::crc_u64::*,
+    endian::Endian,
+    engine::CrcEngine,
+    errors::{CrcParamError, UnavailableBackend, check_crc_params, check_params},
+    hex,
+    lookup_table::LookUpTable,
+    params::CrcParams,
+    table_cache,
+};
 
 #[allow(clippy::upper_case_acronyms)]
+#[derive(Clone)]
 /// This struct can help you compute a CRC-64 (or CRC-x where **x** is equal or less than `64`) value.
 pub struct CRCu64 {
     by_table:        bool,
@@ -20,8 +32,10 @@ pub struct CRCu64 {
     mask:            u64,
     initial:         u64,
     final_xor:       u64,
-    reflect:         bool,
+    refin:           bool,
+    refout:          bool,
     reorder:         bool,
+    sliced:          Option<&'static [[u64; 256]; 8]>,
 }
 
 #[cfg(feature = "alloc")]
@@ -29,14 +43,13 @@ impl Debug for CRCu64 {
     #[inline]
     fn fmt(&self, f: &mut Formatter) -> Result<(), fmt::Error> {
         if self.by_table {
-            debug_helper::impl_debug_for_struct!(CRCu64, f, self, let .lookup_table = self.lookup_table.as_ref(), (.sum, "0x{:016X}", self.sum), .bits, (.initial, "0x{:016X}", self.initial), (.final_xor, "0x{:016X}", self.final_xor), .reflect, .reorder);
+            debug_helper::impl_debug_for_struct!(CRCu64, f, self, let .lookup_table = self.lookup_table.as_ref(), (.sum, "0x{:016X}", self.sum), .bits, (.initial, "0x{:016X}", self.initial), (.final_xor, "0x{:016X}", self.final_xor), .refin, .refout, .reorder);
         } else {
-            debug_helper::impl_debug_for_struct!(CRCu64, f, self, (.poly, "0x{:016X}", self.poly), (.sum, "0x{:016X}", self.sum), .bits, (.initial, "0x{:016X}", self.initial), (.final_xor, "0x{:016X}", self.final_xor), .reflect, .reorder);
+            debug_helper::impl_debug_for_struct!(CRCu64, f, self, (.poly, "0x{:016X}", self.poly), (.sum, "0x{:016X}", self.sum), .bits, (.initial, "0x{:016X}", self.initial), (.final_xor, "0x{:016X}", self.final_xor), .refin, .refout, .reorder);
         }
     }
 }
 
-#[cfg(feature = "alloc")]
 impl Display for CRCu64 {
     #[inline]
     fn fmt(&self, f: &mut Formatter) -> Result<(), fmt::Error> {
@@ -44,25 +57,57 @@ impl Display for CRCu64 {
     }
 }
 
+impl LowerHex for CRCu64 {
+    #[inline]
+    fn fmt(&self, f: &mut Formatter) -> Result<(), fmt::Error> {
+        hex::fmt_crc(f, u128::from(self.get_crc()), self.bits, 4, false)
+    }
+}
+
+impl UpperHex for CRCu64 {
+    #[inline]
+    fn fmt(&self, f: &mut Formatter) -> Result<(), fmt::Error> {
+        hex::fmt_crc(f, u128::from(self.get_crc()), self.bits, 4, true)
+    }
+}
+
+impl Octal for CRCu64 {
+    #[inline]
+    fn fmt(&self, f: &mut Formatter) -> Result<(), fmt::Error> {
+        hex::fmt_crc(f, u128::from(self.get_crc()), self.bits, 3, false)
+    }
+}
+
+impl Binary for CRCu64 {
+    #[inline]
+    fn fmt(&self, f: &mut Formatter) -> Result<(), fmt::Error> {
+        hex::fmt_crc(f, u128::from(self.get_crc()), self.bits, 1, false)
+    }
+}
+
 impl CRCu64 {
     /// Create a `CRCu64` instance by providing the length of bits, expression, reflection, an initial value and a final xor value.
     pub fn create_crc(poly: u64, bits: u8, initial: u64, final_xor: u64, reflect: bool) -> CRCu64 {
+        Self::create_crc_with_reflection(poly, bits, initial, final_xor, reflect, reflect)
+    }
+
+    /// Create a `CRCu64` instance by providing the length of bits, expression, input reflection, output reflection, an initial value and a final xor value.
+    ///
+    /// The expression needs to be reflected if `refin` is `true`.
+    pub fn create_crc_with_reflection(
+        poly: u64,
+        bits: u8,
+        initial: u64,
+        final_xor: u64,
+        refin: bool,
+        refout: bool,
+    ) -> CRCu64 {
         debug_assert!(bits <= 64 && bits > 0);
 
         if bits.is_multiple_of(8) {
-            let lookup_table = if reflect {
-                LookUpTable::Dynamic(Self::crc_reflect_table(poly))
-            } else {
-                LookUpTable::Dynamic(Self::crc_table(poly, bits))
-            };
+            let lookup_table = Self::lookup_table_of(bits, poly, refin);
 
-            Self::create_crc_with_exists_lookup_table(
-                lookup_table,
-                bits,
-                initial,
-                final_xor,
-                reflect,
-            )
+            Self::create(true, lookup_table, 0, bits, initial, final_xor, refin, refout)
         } else {
             Self::create(
                 false,
@@ -71,9 +116,220 @@ impl CRCu64 {
                 bits,
                 initial,
                 final_xor,
-                reflect,
+                refin,
+                refout,
+            )
+        }
+    }
+
+    /// Set whether the bytes of the CRC value are output in reverse order, like `crc32` of the `mhash` library does (see [`CRCu32::crc32mhash`](crate::CRCu32::crc32mhash)). Only the bytes covered by the length of bits are reversed.
+    ///
+    /// This affects `get_crc`, the formatted output and the byte getters, and `resume_from` expects a CRC value in the same order. The state of the register does not depend on it.
+    #[inline]
+    pub fn with_reorder(mut self, reorder: bool) -> CRCu64 {
+        self.reorder = reorder;
+
+        self
+    }
+
+    /// Select the backend which processes the input, keeping the current state. An error is returned if the backend is not available for the algorithm or on the CPU. See [`Backend`] for details.
+    pub fn with_backend(mut self, backend: Backend) -> Result<CRCu64, UnavailableBackend> {
+        let poly = self.normal_poly();
+        let state = self.state();
+
+        match backend {
+            Backend::Bitwise => {
+                self.by_table = false;
+                self.lookup_table = LookUpTable::Static(&[0; 256]);
+                self.poly = poly;
+                self.sliced = None;
+            },
+            Backend::Table if self.bits.is_multiple_of(8) => {
+                self.switch_to_table(poly);
+                self.sliced = None;
+            },
+            Backend::Sliced => match Self::find_sliced_tables(self.bits, poly, self.refin) {
+                Some(tables) => {
+                    self.switch_to_table(poly);
+                    self.sliced = Some(tables);
+                },
+                None => {
+                    return Err(UnavailableBackend {
+                        backend,
+                    });
+                },
+            },
+            _ => {
+                return Err(UnavailableBackend {
+                    backend,
+                });
+            },
+        }
+
+        self.set_state(state);
+
+        Ok(self)
+    }
+
+    /// Get the backend which processes the input.
+    pub fn backend(&self) -> Backend {
+        if !self.by_table {
+            Backend::Bitwise
+        } else if self.sliced.is_some() {
+            Backend::Sliced
+        } else {
+            Backend::Table
+        }
+    }
+
+    /// Create a `CRCu64` instance from the parameters of a CRC algorithm.
+    pub fn from_params(params: &CrcParams) -> CRCu64 {
+        Self::create_crc_with_reflection(
+            params.create_crc_poly() as u64,
+            params.width,
+            params.init as u64,
+            params.xorout as u64,
+            params.refin,
+            params.refout,
+        )
+    }
+
+    /// Create a `CRCu64` instance like [`CRCu64::create_crc`], but return an error instead of creating a broken instance if the parameters are invalid.
+    pub fn try_create_crc(
+        poly: u64,
+        bits: u8,
+        initial: u64,
+        final_xor: u64,
+        reflect: bool,
+    ) -> Result<CRCu64, CrcParamError> {
+        Self::try_create_crc_with_reflection(poly, bits, initial, final_xor, reflect, reflect)
+    }
+
+    /// Create a `CRCu64` instance like [`CRCu64::create_crc_with_reflection`], but return an error instead of creating a broken instance if the parameters are invalid.
+    pub fn try_create_crc_with_reflection(
+        poly: u64,
+        bits: u8,
+        initial: u64,
+        final_xor: u64,
+        refin: bool,
+        refout: bool,
+    ) -> Result<CRCu64, CrcParamError> {
+        check_params(64, bits, u128::from(poly), u128::from(initial), u128::from(final_xor))?;
+
+        Ok(Self::create_crc_with_reflection(poly, bits, initial, final_xor, refin, refout))
+    }
+
+    /// Create a `CRCu64` instance from the parameters of a CRC algorithm, or return an error if the parameters are invalid.
+    pub fn try_from_params(params: &CrcParams) -> Result<CRCu64, CrcParamError> {
+        check_crc_params(64, params)?;
+
+        Ok(Self::from_params(params))
+    }
+
+    /// Create a `CRCu64` instance from the parameters of a CRC algorithm and a lookup table generated by [`CRCu64::table`] with the same parameters, so that the table does not need to be generated at runtime.
+    ///
+    /// The lookup table is only used if the length of bits is a multiple of `8`.
+    pub fn from_params_with_table(params: &CrcParams, table: &'static [u64; 256]) -> CRCu64 {
+        if params.width.is_multiple_of(8) {
+            Self::create(
+                true,
+                LookUpTable::Static(table),
+                0,
+                params.width,
+                params.init as u64,
+                params.xorout as u64,
+                params.refin,
+                params.refout,
             )
+        } else {
+            Self::from_params(params)
+        }
+    }
+
+    /// Generate the lookup table of a CRC algorithm. It can be evaluated at compile time.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the length of bits of `params` is 0 or larger than `64`.
+    ///
+    /// ```
+    /// use crc_any::{
+    ///     CRCu64,
+    ///     params::{CRC_64_XZ, CrcParams},
+    /// };
+    ///
+    /// const PARAMS: CrcParams = CRC_64_XZ;
+    /// const TABLE: [u64; 256] = CRCu64::table(&PARAMS);
+    /// ```
+    pub const fn table(params: &CrcParams) -> [u64; 256] {
+        assert!(
+            params.width > 0 && params.width <= 64,
+            "the length of bits must be between 1 and 64"
+        );
+
+        if params.refin {
+            Self::crc_reflect_table(params.create_crc_poly() as u64)
+        } else {
+            Self::crc_table(params.poly as u64, params.width)
+        }
+    }
+
+    /// Compute the CRC value of `data` in one shot. It can be evaluated at compile time, but it processes the data bit by bit, so prefer creating a `CRCu64` instance at runtime.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the length of bits of `params` is 0 or larger than `64`.
+    ///
+    /// ```
+    /// use crc_any::{CRCu64, params::CRC_64_XZ};
+    ///
+    /// const CHECK: u64 = CRCu64::checksum(&CRC_64_XZ, b"123456789");
+    ///
+    /// assert_eq!(0x995DC9BBDF1939FA, CHECK);
+    /// ```
+    pub const fn checksum(params: &CrcParams, data: &[u8]) -> u64 {
+        assert!(
+            params.width > 0 && params.width <= 64,
+            "the length of bits must be between 1 and 64"
+        );
+
+        let high_bit = 1u64 << (params.width - 1);
+        let mask = ((high_bit - 1) << 1) | 1;
+        let poly = params.poly as u64;
+
+        let mut sum = params.init as u64;
+
+        let mut i = 0;
+
+        while i < data.len() {
+            let n = if params.refin { data[i].reverse_bits() } else { data[i] };
+
+            let mut b = 0x80u8;
+
+            while b != 0 {
+                let mut bit = sum & high_bit;
+
+                sum <<= 1;
+
+                if n & b != 0 {
+                    bit ^= high_bit;
+                }
+
+                if bit != 0 {
+                    sum ^= poly;
+                }
+
+                b >>= 1;
+            }
+
+            i += 1;
+        }
+
+        if params.refout {
+            sum = sum.reverse_bits() >> (u64::BITS - params.width as u32);
         }
+
+        (sum ^ params.xorout as u64) & mask
     }
 
     #[inline]
@@ -86,9 +342,10 @@ impl CRCu64 {
     ) -> CRCu64 {
         debug_assert!(bits.is_multiple_of(8));
 
-        Self::create(true, lookup_table, 0, bits, initial, final_xor, reflect)
+        Self::create(true, lookup_table, 0, bits, initial, final_xor, reflect, reflect)
     }
 
+    #[allow(clippy::too_many_arguments)]
     #[inline]
     fn create(
         by_table: bool,
@@ -97,14 +354,17 @@ impl CRCu64 {
         bits: u8,
         initial: u64,
         final_xor: u64,
-        reflect: bool,
+        refin: bool,
+        refout: bool,
     ) -> CRCu64 {
         let high_bit = 1 << u64::from(bits - 1);
         let mask = ((high_bit - 1) << 1) | 1;
 
-        let sum = if reflect { Self::reflect_function(high_bit, initial) } else { initial };
+        // the register is kept reflected only when a reflected lookup table is used
+        let sum =
+            if by_table && refin { Self::reflect_function(high_bit, initial) } else { initial };
 
-        if !by_table && reflect {
+        if !by_table && refin {
             poly = Self::reflect_function(high_bit, poly);
         }
 
@@ -118,8 +378,10 @@ impl CRCu64 {
             mask,
             initial,
             final_xor,
-            reflect,
+            refin,
+            refout,
             reorder: false,
+            sliced: None,
         }
     }
 
@@ -135,16 +397,56 @@ impl CRCu64 {
         Self::reflect_function(self.high_bit, n)
     }
 
+    /// Get the lookup table of an expression, from the static tables, the cache, or by generating it.
+    fn lookup_table_of(bits: u8, poly: u64, refin: bool) -> LookUpTable<u64> {
+        match find_table(bits, poly, refin) {
+            Some(table) => LookUpTable::Static(table),
+            None => table_cache::lookup_table(bits, poly, refin, || {
+                if refin { Self::crc_reflect_table(poly) } else { Self::crc_table(poly, bits) }
+            }),
+        }
+    }
+
+    /// Get the expression in normal (unreflected) form.
+    fn normal_poly(&self) -> u64 {
+        if !self.by_table {
+            self.poly
+        } else if self.refin {
+            // the entry of `0x80` of a reflected lookup table is the reflected expression
+            self.reflect_method(self.lookup_table[0x80])
+        } else {
+            // the entry of `1` of a normal lookup table is the expression
+            self.lookup_table[1]
+        }
+    }
+
+    /// Use the lookup table of the expression `poly` (in normal form) if the bitwise loop is used.
+    fn switch_to_table(&mut self, poly: u64) {
+        if !self.by_table {
+            let poly = if self.refin { self.reflect_method(poly) } else { poly };
+
+            self.lookup_table = Self::lookup_table_of(self.bits, poly, self.refin);
+            self.by_table = true;
+            self.poly = 0;
+        }
+    }
+
     /// Update the current CRC state with bytes.
     #[inline]
     pub fn update(&mut self, data: &[u8]) {
+        if let Some(tables) = self.sliced {
+            self.update_sliced(tables, data);
+
+            return;
+        }
+
         if self.by_table {
             if self.bits == 8 {
                 for n in data.iter().copied() {
                     let index = (self.sum as u8 ^ n) as usize;
                     self.sum = self.lookup_table[index];
                 }
-            } else if self.reflect {
+            } else if self.refin {
                 for n in data.iter().copied() {
                     let index = ((self.sum as u8) ^ n) as usize;
                     self.sum = (self.sum >> 8) ^ self.lookup_table[index];
@@ -155,7 +457,7 @@ impl CRCu64 {
                     self.sum = (self.sum << 8) ^ self.lookup_table[index];
                 }
             }
-        } else if self.reflect {
+        } else if self.refin {
             for n in data.iter().copied() {
                 let n = super::crc_u8::CRCu8::reflect_function(0x80, n);
 
@@ -200,6 +502,197 @@ impl CRCu64 {
         }
     }
 
+    /// Update the register with slicing-by-8. The length of bits is always 64 here.
+    fn update_sliced(&mut self, tables: &[[u64; 256]; 8], data: &[u8]) {
+        let mut sum = self.sum;
+
+        let mut chunks = data.chunks_exact(8);
+
+        if self.refin {
+            for chunk in &mut chunks {
+                let x = sum ^ u64::from_le_bytes(chunk.try_into().unwrap());
+
+                sum = tables[7][(x & 0xFF) as usize]
+                    ^ tables[6][((x >> 8) & 0xFF) as usize]
+                    ^ tables[5][((x >> 16) & 0xFF) as usize]
+                    ^ tables[4][((x >> 24) & 0xFF) as usize]
+                    ^ tables[3][((x >> 32) & 0xFF) as usize]
+                    ^ tables[2][((x >> 40) & 0xFF) as usize]
+                    ^ tables[1][((x >> 48) & 0xFF) as usize]
+                    ^ tables[0][(x >> 56) as usize];
+            }
+
+            for n in chunks.remainder().iter().copied() {
+                sum = (sum >> 8) ^ tables[0][(sum as u8 ^ n) as usize];
+            }
+        } else {
+            for chunk in &mut chunks {
+                let x = sum ^ u64::from_be_bytes(chunk.try_into().unwrap());
+
+                sum = tables[7][(x >> 56) as usize]
+                    ^ tables[6][((x >> 48) & 0xFF) as usize]
+                    ^ tables[5][((x >> 40) & 0xFF) as usize]
+                    ^ tables[4][((x >> 32) & 0xFF) as usize]
+                    ^ tables[3][((x >> 24) & 0xFF) as usize]
+                    ^ tables[2][((x >> 16) & 0xFF) as usize]
+                    ^ tables[1][((x >> 8) & 0xFF) as usize]
+                    ^ tables[0][(x & 0xFF) as usize];
+            }
+
+            for n in chunks.remainder().iter().copied() {
+                sum = (sum << 8) ^ tables[0][((sum >> 56) as u8 ^ n) as usize];
+            }
+        }
+
+        self.sum = sum;
+    }
+
+    /// Find the slicing-by-8 tables of the given length of bits, expression (in normal form) and input reflection. They are only linked into a binary if [`CRCu64::with_backend`] is used.
+    fn find_sliced_tables(bits: u8, poly: u64, refin: bool) -> Option<&'static [[u64; 256]; 8]> {
+        static REF_42F0E1EBA9EA3693: [[u64; 256]; 8] =
+            CRCu64::crc_sliced_tables(&REF_64_C96C5795D7870F42, true);
+        static NO_REF_42F0E1EBA9EA3693: [[u64; 256]; 8] =
+            CRCu64::crc_sliced_tables(&NO_REF_64_42F0E1EBA9EA3693, false);
+
+        match (bits, poly, refin) {
+            (64, 0x42F0E1EBA9EA3693, true) => Some(&REF_42F0E1EBA9EA3693),
+            (64, 0x42F0E1EBA9EA3693, false) => Some(&NO_REF_42F0E1EBA9EA3693),
+            _ => None,
+        }
+    }
+
+    /// Extend a lookup table of 64 bits to the eight tables of slicing-by-8.
+    const fn crc_sliced_tables(table: &[u64; 256], reflect: bool) -> [[u64; 256]; 8] {
+        let mut tables = [*table; 8];
+
+        let mut k = 1;
+
+        while k < 8 {
+            let mut i = 0;
+
+            while i < 256 {
+                let v = tables[k - 1][i];
+
+                tables[k][i] = if reflect {
+                    (v >> 8) ^ table[(v & 0xFF) as usize]
+                } else {
+                    (v << 8) ^ table[(v >> 56) as usize]
+                };
+
+                i += 1;
+            }
+
+            k += 1;
+        }
+
+        tables
+    }
+
+    /// Update the current CRC state with the bytes of a `u16` value in little-endian byte order.
+    #[inline]
+    pub fn update_u16_le(&mut self, value: u16) {
+        self.update(&value.to_le_bytes());
+    }
+
+    /// Update the current CRC state with the bytes of a `u16` value in big-endian byte order.
+    #[inline]
+    pub fn update_u16_be(&mut self, value: u16) {
+        self.update(&value.to_be_bytes());
+    }
+
+    /// Update the current CRC state with the bytes of a `u32` value in little-endian byte order.
+    #[inline]
+    pub fn update_u32_le(&mut self, value: u32) {
+        self.update(&value.to_le_bytes());
+    }
+
+    /// Update the current CRC state with the bytes of a `u32` value in big-endian byte order.
+    #[inline]
+    pub fn update_u32_be(&mut self, value: u32) {
+        self.update(&value.to_be_bytes());
+    }
+
+    /// Update the current CRC state with the bytes of a `u64` value in little-endian byte order.
+    #[inline]
+    pub fn update_u64_le(&mut self, value: u64) {
+        self.update(&value.to_le_bytes());
+    }
+
+    /// Update the current CRC state with the bytes of a `u64` value in big-endian byte order.
+    #[inline]
+    pub fn update_u64_be(&mut self, value: u64) {
+        self.update(&value.to_be_bytes());
+    }
+
+    /// Update the current CRC state with the bytes of an `f32` value in little-endian byte order.
+    #[inline]
+    pub fn update_f32_le(&mut self, value: f32) {
+        self.update(&value.to_le_bytes());
+    }
+
+    /// Update the current CRC state with the bytes of an `f32` value in big-endian byte order.
+    #[inline]
+    pub fn update_f32_be(&mut self, value: f32) {
+        self.update(&value.to_be_bytes());
+    }
+
+    /// Update the current CRC state with the bytes of an `f64` value in little-endian byte order.
+    #[inline]
+    pub fn update_f64_le(&mut self, value: f64) {
+        self.update(&value.to_le_bytes());
+    }
+
+    /// Update the current CRC state with the bytes of an `f64` value in big-endian byte order.
+    #[inline]
+    pub fn update_f64_be(&mut self, value: f64) {
+        self.update(&value.to_be_bytes());
+    }
+
+    /// Update the current CRC state with a single bit.
+    pub fn update_bit(&mut self, bit: bool) {
+        if self.by_table && self.refin {
+            // the register is reflected, and the reflected expression is the entry of `0x80`
+            let low_bit = (self.sum & 1 != 0) ^ bit;
+
+            self.sum >>= 1;
+
+            if low_bit {
+                self.sum ^= self.lookup_table[0x80];
+            }
+        } else {
+            let poly = self.normal_poly();
+
+            let high_bit = (self.sum & self.high_bit != 0) ^ bit;
+
+            self.sum <<= 1;
+
+            if high_bit {
+                self.sum ^= poly;
+            }
+        }
+    }
+
+    /// Update the current CRC state with the low `nbits` bits of `value`, for messages whose length is not a multiple of 8 bits.
+    ///
+    /// The bits are taken in the same order as the bits of the bytes passed to `update`: from the least significant bit if the input is reflected, and from the most significant bit (bit `nbits - 1`) otherwise. Thus, `update_bits(u64::from(byte), 8)` is the same as `update(&[byte])`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `nbits` is larger than `64`.
+    pub fn update_bits(&mut self, value: u64, nbits: u32) {
+        assert!(nbits <= 64, "cannot update with more than 64 bits at once");
+
+        if self.refin {
+            for i in 0..nbits {
+                self.update_bit((value >> i) & 1 != 0);
+            }
+        } else {
+            for i in (0..nbits).rev() {
+                self.update_bit((value >> i) & 1 != 0);
+            }
+        }
+    }
+
     /// Digest some data.
     ///
     /// This is a compatibility wrapper around [`CRCu64::update`].
@@ -210,87 +703,194 @@ impl CRCu64 {
 
     /// Reset the sum.
     pub fn reset(&mut self) {
-        self.sum = if self.reflect {
+        self.sum = if self.by_table && self.refin {
             Self::reflect_function(self.high_bit, self.initial)
         } else {
             self.initial
         };
     }
 
+    /// Get the current state of the register, which can be restored later with [`CRCu64::set_state`] or [`CRCu64::from_state`].
+    ///
+    /// The state is always in normal (unreflected) form and is taken before the output reflection and the final xor value are applied, so it does not depend on how the CRC is computed.
+    pub fn state(&self) -> u64 {
+        let sum = self.sum & self.mask;
+
+        if self.by_table && self.refin { self.reflect_method(sum) } else { sum }
+    }
+
+    /// Restore a state obtained from [`CRCu64::state`] of an instance with the same parameters. You can continue calling `update` or `digest` after that.
+    pub fn set_state(&mut self, state: u64) {
+        let state = state & self.mask;
+
+        self.sum = if self.by_table && self.refin { self.reflect_method(state) } else { state };
+    }
+
+    /// Create a `CRCu64` instance from the parameters of a CRC algorithm and a state obtained from [`CRCu64::state`].
+    pub fn from_state(params: &CrcParams, state: u64) -> CRCu64 {
+        let mut crc = Self::from_params(params);
+
+        crc.set_state(state);
+
+        crc
+    }
+
+    /// Continue from a CRC value returned by [`CRCu64::get_crc`] of an instance with the same parameters, as if the data of that CRC value had been passed to `update` or `digest`.
+    pub fn resume_from(&mut self, crc: u64) {
+        let crc = if self.reorder { self.reorder_method(crc) } else { crc };
+
+        let sum = (crc ^ self.final_xor) & self.mask;
+
+        self.set_state(if self.refout { self.reflect_method(sum) } else { sum });
+    }
+
+    /// Create a `CRCu64` instance from the parameters of a CRC algorithm and a CRC value of some previous data computed with the same parameters. Updating the instance with more data gives the CRC value of the concatenated data.
+    pub fn resume(params: &CrcParams, crc: u64) -> CRCu64 {
+        let mut crc_instance = Self::from_params(params);
+
+        crc_instance.resume_from(crc);
+
+        crc_instance
+    }
+
+    /// Check whether this instance and `other` compute the same CRC algorithm, regardless of their current states and backends.
+    pub fn is_same_algorithm(&self, other: &CRCu64) -> bool {
+        self.bits == other.bits
+            && self.initial == other.initial
+            && self.final_xor == other.final_xor
+            && self.refin == other.refin
+            && self.refout == other.refout
+            && self.reorder == other.reorder
+            && self.normal_poly() == other.normal_poly()
+    }
+
+    /// Create a copy of this instance, including its current state. This is useful for computing the CRC values of many messages sharing a common prefix, which only needs to be processed once.
+    #[inline]
+    pub fn fork(&self) -> CRCu64 {
+        self.clone()
+    }
+
     /// Get the current CRC value (it always returns a `u64` value). You can continue calling `update` or `digest` even after getting a CRC value.
     pub fn get_crc(&self) -> u64 {
-        let sum = if self.by_table || !self.reflect {
+        let sum = if (self.by_table && self.refin) == self.refout {
             (self.sum ^ self.final_xor) & self.mask
         } else {
             (self.reflect_method(self.sum) ^ self.final_xor) & self.mask
         };
 
-        if self.reorder {
-            let mut new_sum = 0;
+        if self.reorder { self.reorder_method(sum) } else { sum }
+    }
 
-            let e = u64::from(self.bits).div_ceil(8);
+    #[inline]
+    fn reorder_method(&self, sum: u64) -> u64 {
+        let mut new_sum = 0;
 
-            let e_dec = e - 1;
+        let e = u64::from(self.bits).div_ceil(8);
 
-            for i in 0..e {
-                new_sum |= ((sum >> ((e_dec - i) * 8)) & 0xFF) << (i * 8);
-            }
+        let e_dec = e - 1;
 
-            new_sum
-        } else {
-            sum
+        for i in 0..e {
+            new_sum |= ((sum >> ((e_dec - i) * 8)) & 0xFF) << (i * 8);
         }
+
+        new_sum
     }
 
-    fn crc_reflect_table(poly_rev: u64) -> [u64; 256] {
+    const fn crc_reflect_table(poly_rev: u64) -> [u64; 256] {
         let mut lookup_table = [0u64; 256];
 
-        for (i, e) in lookup_table.iter_mut().enumerate() {
+        let mut i = 0;
+
+        while i < 256 {
             let mut v = i as u64;
 
-            #[allow(clippy::branches_sharing_code)]
-            for _ in 0..8u8 {
+            let mut j = 0;
+
+            while j < 8 {
                 if v & 1 != 0 {
                     v >>= 1;
                     v ^= poly_rev;
                 } else {
                     v >>= 1;
                 }
+
+                j += 1;
             }
 
-            *e = v;
+            lookup_table[i] = v;
+
+            i += 1;
         }
 
         lookup_table
     }
 
-    fn crc_table(poly: u64, bits: u8) -> [u64; 256] {
+    const fn crc_table(poly: u64, bits: u8) -> [u64; 256] {
         let mut lookup_table = [0u64; 256];
 
-        let mask1 = 1u64 << u64::from(bits - 1);
+        let mask1 = 1u64 << (bits - 1);
 
         let mask2 = ((mask1 - 1) << 1) | 1;
 
-        for (i, e) in lookup_table.iter_mut().enumerate() {
+        let mut i = 0;
+
+        while i < 256 {
             let mut v = i as u64;
 
-            #[allow(clippy::branches_sharing_code)]
-            for _ in 0..bits {
+            let mut j = 0;
+
+            while j < bits {
                 if v & mask1 == 0 {
                     v <<= 1;
                 } else {
                     v <<= 1;
                     v ^= poly;
                 }
+
+                j += 1;
             }
 
-            *e = v & mask2;
+            lookup_table[i] = v & mask2;
+
+            i += 1;
         }
 
         lookup_table
     }
 }
 
+impl CRCu64 {
+    /// Get the current CRC value as a big-endian byte array. Its first bytes, as many as the CRC bits need, hold the CRC value, and the rest are zero. You can continue calling `update` or `digest` even after getting a CRC value.
+    #[inline]
+    pub fn get_crc_bytes_be(&self) -> [u8; 8] {
+        let crc = self.get_crc();
+
+        let e = usize::from(self.bits).div_ceil(8);
+
+        let mut bytes = [0u8; 8];
+
+        bytes[..e].copy_from_slice(&crc.to_be_bytes()[(8 - e)..]);
+
+        bytes
+    }
+
+    /// Get the current CRC value as a little-endian byte array. Its first bytes, as many as the CRC bits need, hold the CRC value, and the rest are zero. You can continue calling `update` or `digest` even after getting a CRC value.
+    #[inline]
+    pub fn get_crc_bytes_le(&self) -> [u8; 8] {
+        self.get_crc().to_le_bytes()
+    }
+
+    /// Get the current CRC value as uppercase hexadecimal digits in ASCII. Its first bytes, as many as [`CrcEngine::hex_len`], hold the digits, and the rest are zero. You can continue calling `update` or `digest` even after getting a CRC value.
+    #[inline]
+    pub fn to_hex_array(&self) -> [u8; 16] {
+        let mut hex = [0u8; 16];
+
+        self.write_crc_hex(&mut hex);
+
+        hex
+    }
+}
+
 #[cfg(feature = "alloc")]
 impl CRCu64 {
     /// Get the current CRC value (it always returns a vec instance with a length corresponding to the CRC bits). You can continue calling `update` or `digest` even after getting a CRC value.
@@ -345,6 +945,151 @@ impl CRCu64 {
     }
 }
 
+impl CRCu64 {
+    /// Append the current CRC value to `buf` in the given byte order, using as many bytes as the CRC bits need.
+    pub fn append_crc(&self, buf: &mut impl Extend<u8>, endian: Endian) {
+        let crc = self.get_crc();
+
+        let e = usize::from(self.bits).div_ceil(8);
+
+        match endian {
+            Endian::Big => buf.extend(crc.to_be_bytes()[(8 - e)..].iter().copied()),
+            Endian::Little => buf.extend(crc.to_le_bytes()[..e].iter().copied()),
+        }
+    }
+
+    /// Check whether `data_with_crc` ends with the CRC value of the data before it, in the given byte order. The data is computed from the current state, which is not changed.
+    pub fn verify_trailer(&self, data_with_crc: &[u8], endian: Endian) -> bool {
+        let e = usize::from(self.bits).div_ceil(8);
+
+        if data_with_crc.len() < e {
+            return false;
+        }
+
+        let (data, trailer) = data_with_crc.split_at(data_with_crc.len() - e);
+
+        let mut crc = self.clone();
+
+        crc.update(data);
+
+        let crc = crc.get_crc();
+
+        match endian {
+            Endian::Big => trailer == &crc.to_be_bytes()[(8 - e)..],
+            Endian::Little => trailer == &crc.to_le_bytes()[..e],
+        }
+    }
+
+    /// Check whether `data_with_crc` is an error-free codeword, that is, data followed by its CRC value in the bit order of the algorithm (big-endian if the input is not reflected, little-endian otherwise), by comparing the register with the residue of the algorithm after processing the whole codeword. The data is computed from the current state, which is not changed.
+    ///
+    /// If the CRC bits do not fill whole bytes, the input and output reflections differ, or the bytes of the CRC value are reordered, there is no such residue for byte-oriented codewords, so the trailer is compared with [`CRCu64::verify_trailer`] instead.
+    pub fn check_residue(&self, data_with_crc: &[u8]) -> bool {
+        if !self.bits.is_multiple_of(8) || self.refin != self.refout || self.reorder {
+            let endian = if self.refin { Endian::Little } else { Endian::Big };
+
+            return self.verify_trailer(data_with_crc, endian);
+        }
+
+        if data_with_crc.len() < usize::from(self.bits / 8) {
+            return false;
+        }
+
+        let mut crc = self.clone();
+
+        crc.update(data_with_crc);
+
+        crc.state() == self.residue_state()
+    }
+
+    /// Get the residue of the algorithm, which is the content of the register after processing an error-free codeword, reflected if the output is reflected, before the final xor value is applied. This is the `residue` parameter of the catalogue.
+    pub fn residue(&self) -> u64 {
+        let residue = self.residue_state();
+
+        if self.refout { self.reflect_method(residue) } else { residue }
+    }
+
+    /// The CRC bits of a codeword leave `final_xor` (in the bit order of the input) in the register, so the residue is `final_xor` shifted through the register by the length of bits.
+    fn residue_state(&self) -> u64 {
+        let poly = self.normal_poly();
+
+        let mut residue =
+            if self.refin { self.reflect_method(self.final_xor) } else { self.final_xor };
+
+        for _ in 0..self.bits {
+            residue =
+                if residue & self.high_bit != 0 { (residue << 1) ^ poly } else { residue << 1 };
+        }
+
+        residue & self.mask
+    }
+}
+
+/// Two instances are equal if they compute the same CRC algorithm and are in the same state.
+impl PartialEq for CRCu64 {
+    #[inline]
+    fn eq(&self, other: &CRCu64) -> bool {
+        self.is_same_algorithm(other) && self.state() == other.state()
+    }
+}
+
+impl Eq for CRCu64 {}
+
+impl CrcEngine for CRCu64 {
+    type Output = u64;
+
+    const WIDTH_MAX: u8 = 64;
+
+    #[inline]
+    fn create_crc(poly: u64, bits: u8, initial: u64, final_xor: u64, reflect: bool) -> CRCu64 {
+        CRCu64::create_crc(poly, bits, initial, final_xor, reflect)
+    }
+
+    #[inline]
+    fn from_params(params: &CrcParams) -> CRCu64 {
+        CRCu64::from_params(params)
+    }
+
+    #[inline]
+    fn try_from_params(params: &CrcParams) -> Result<CRCu64, CrcParamError> {
+        CRCu64::try_from_params(params)
+    }
+
+    #[inline]
+    fn width(&self) -> u8 {
+        self.bits
+    }
+
+    #[inline]
+    fn update(&mut self, data: &[u8]) {
+        CRCu64::update(self, data)
+    }
+
+    #[inline]
+    fn reset(&mut self) {
+        CRCu64::reset(self)
+    }
+
+    #[inline]
+    fn get_crc(&self) -> u64 {
+        CRCu64::get_crc(self)
+    }
+
+    #[inline]
+    fn state(&self) -> u64 {
+        CRCu64::state(self)
+    }
+
+    #[inline]
+    fn set_state(&mut self, state: u64) {
+        CRCu64::set_state(self, state)
+    }
+
+    #[inline]
+    fn resume_from(&mut self, crc: u64) {
+        CRCu64::resume_from(self, crc)
+    }
+}
+
 impl CRCu64 {
     /// |Check|Poly|Init|Ref|XorOut|
     /// |---|---|---|---|---|
@@ -415,6 +1160,52 @@ impl CRCu64 {
         )
     }
 
+    /// |Check|Poly|Init|Ref|XorOut|
+    /// |---|---|---|---|---|
+    /// |0x75D4B74F024ECEEA|0x259C84CBA6426349 (rev: 0x92C64265D32139A4)|0xFFFFFFFFFFFFFFFF|true|0x0000000000000000|
+    ///
+    /// ```
+    /// # use crc_any::CRCu64;
+    /// let mut crc = CRCu64::crc64ms();
+    /// crc.digest(b"123456789");
+    #[cfg_attr(feature = "alloc", doc = "assert_eq!(\"0x75D4B74F024ECEEA\", &crc.to_string());")]
+    /// ```
+    pub fn crc64ms() -> CRCu64 {
+        // Self::create_crc(0x92C64265D32139A4, 64, 0xFFFFFFFFFFFFFFFF, 0x0000000000000000, true)
+
+        let lookup_table = LookUpTable::Static(&REF_64_92C64265D32139A4);
+        Self::create_crc_with_exists_lookup_table(
+            lookup_table,
+            64,
+            0xFFFFFFFFFFFFFFFF,
+            0x0000000000000000,
+            true,
+        )
+    }
+
+    /// |Check|Poly|Init|Ref|XorOut|
+    /// |---|---|---|---|---|
+    /// |0xAE8B14860A799888|0xAD93D23594C93659 (rev: 0x9A6C9329AC4BC9B5)|0xFFFFFFFFFFFFFFFF|true|0xFFFFFFFFFFFFFFFF|
+    ///
+    /// ```
+    /// # use crc_any::CRCu64;
+    /// let mut crc = CRCu64::crc64nvme();
+    /// crc.digest(b"123456789");
+    #[cfg_attr(feature = "alloc", doc = "assert_eq!(\"0xAE8B14860A799888\", &crc.to_string());")]
+    /// ```
+    pub fn crc64nvme() -> CRCu64 {
+        // Self::create_crc(0x9A6C9329AC4BC9B5, 64, 0xFFFFFFFFFFFFFFFF, 0xFFFFFFFFFFFFFFFF, true)
+
+        let lookup_table = LookUpTable::Static(&REF_64_9A6C9329AC4BC9B5);
+        Self::create_crc_with_exists_lookup_table(
+            lookup_table,
+            64,
+            0xFFFFFFFFFFFFFFFF,
+            0xFFFFFFFFFFFFFFFF,
+            true,
+        )
+    }
+
     /// |Check|Poly|Init|Ref|XorOut|
     /// |---|---|---|---|---|
     /// |0x62EC59E3F1A4F00A|0x42F0E1EBA9EA3693|0xFFFFFFFFFFFFFFFF|false|0xFFFFFFFFFFFFFFFF|
@@ -460,6 +1251,74 @@ impl CRCu64 {
             true,
         )
     }
+
+    /// |Check|Poly|Init|Ref|XorOut|
+    /// |---|---|---|---|---|
+    /// |0xE9C6D914C4B8D9CA|0xAD93D23594C935A9 (rev: 0x95AC9329AC4BC9B5)|0x0000000000000000|true|0x0000000000000000|
+    ///
+    /// ```
+    /// # use crc_any::CRCu64;
+    /// let mut crc = CRCu64::crc64redis();
+    /// crc.digest(b"123456789");
+    #[cfg_attr(feature = "alloc", doc = "assert_eq!(\"0xE9C6D914C4B8D9CA\", &crc.to_string());")]
+    /// ```
+    pub fn crc64redis() -> CRCu64 {
+        // Self::create_crc(0x95AC9329AC4BC9B5, 64, 0x0000000000000000, 0x0000000000000000, true)
+
+        let lookup_table = LookUpTable::Static(&REF_64_95AC9329AC4BC9B5);
+        Self::create_crc_with_exists_lookup_table(
+            lookup_table,
+            64,
+            0x0000000000000000,
+            0x0000000000000000,
+            true,
+        )
+    }
+
+    /// |Check|Poly|Init|Ref|XorOut|
+    /// |---|---|---|---|---|
+    /// |0x995DC9BBDF1939FA|0x42F0E1EBA9EA3693 (rev: 0xC96C5795D7870F42)|0xFFFFFFFFFFFFFFFF|true|0xFFFFFFFFFFFFFFFF|
+    ///
+    /// ```
+    /// # use crc_any::CRCu64;
+    /// let mut crc = CRCu64::crc64xz();
+    /// crc.digest(b"123456789");
+    #[cfg_attr(feature = "alloc", doc = "assert_eq!(\"0x995DC9BBDF1939FA\", &crc.to_string());")]
+    /// ```
+    pub fn crc64xz() -> CRCu64 {
+        // Self::create_crc(0xC96C5795D7870F42, 64, 0xFFFFFFFFFFFFFFFF, 0xFFFFFFFFFFFFFFFF, true)
+
+        let lookup_table = LookUpTable::Static(&REF_64_C96C5795D7870F42);
+        Self::create_crc_with_exists_lookup_table(
+            lookup_table,
+            64,
+            0xFFFFFFFFFFFFFFFF,
+            0xFFFFFFFFFFFFFFFF,
+            true,
+        )
+    }
+}
+
+impl CRCu64 {
+    #[inline]
+    fn update_raw(mut self, seed: u64, data: &[u8]) -> u64 {
+        self.sum = seed;
+
+        self.update(data);
+
+        self.sum
+    }
+
+    /// Update a raw CRC-64/ECMA-182 (not reflected) register like `crc64_be(seed, data)` of the Linux kernel. The register starts from `seed` instead of an initial value, and is returned as is, without the final xor value applied.
+    ///
+    /// ```
+    /// # use crc_any::CRCu64;
+    /// assert_eq!(0x6C40DF5F0B497347, CRCu64::crc64_be_raw(0, b"123456789"));
+    /// ```
+    #[inline]
+    pub fn crc64_be_raw(seed: u64, data: &[u8]) -> u64 {
+        Self::crc64().update_raw(seed, data)
+    }
 }
 
 #[cfg(all(feature = "development", test))]
@@ -483,3 +1342,23 @@ mod tests {
         println!("let lookup_table = [{}];", s);
     }
 }
+
+#[cfg(test)]
+mod table_tests {
+    use super::*;
+
+    #[test]
+    fn custom_parameters_use_static_tables() {
+        assert!(
+            CRCu64::create_crc(
+                0xD800000000000000,
+                64,
+                0xFFFFFFFFFFFFFFFF,
+                0xFFFFFFFFFFFFFFFF,
+                true
+            )
+            .lookup_table
+            .is_static(&REF_64_D800000000000000)
+        );
+    }
+}
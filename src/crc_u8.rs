@@ -1,22 +1,34 @@
 #[cfg(feature = "alloc")]
-use alloc::fmt::{self, Debug, Display, Formatter};
-
-use crate::{constants::crc_u8::*, lookup_table::LookUpTable};
+use core::fmt::Debug;
+use core::fmt::{self, Binary, Display, Formatter, LowerHex, Octal, UpperHex};
+
+use crate::{
+    backend::Backend,
+    constants::crc_u8::*,
+    endian::Endian,
+    engine::CrcEngine,
+    errors::{CrcParamError, UnavailableBackend, check_crc_params, check_params},
+    hex,
+    lookup_table::LookUpTable,
+    params::CrcParams,
+    table_cache,
+};
 
 #[allow(clippy::upper_case_acronyms)]
+#[derive(Clone)]
 /// This struct can help you compute a CRC-8 (or CRC-x where **x** is equal or less than `8`) value.
 pub struct CRCu8 {
     by_table:        bool,
     poly:            u8,
     lookup_table:    LookUpTable<u8>,
     sum:             u8,
-    #[cfg(any(feature = "alloc", feature = "heapless"))]
     pub(crate) bits: u8,
     high_bit:        u8,
     mask:            u8,
     initial:         u8,
     final_xor:       u8,
-    reflect:         bool,
+    refin:           bool,
+    refout:          bool,
 }
 
 #[cfg(feature = "alloc")]
@@ -24,14 +36,13 @@ impl Debug for CRCu8 {
     #[inline]
     fn fmt(&self, f: &mut Formatter) -> Result<(), fmt::Error> {
         if self.by_table {
-            debug_helper::impl_debug_for_struct!(CRCu8, f, self, let .lookup_table = self.lookup_table.as_ref(), (.sum, "0x{:02X}", self.sum), .bits, (.initial, "0x{:02X}", self.initial), (.final_xor, "0x{:02X}", self.final_xor), .reflect);
+            debug_helper::impl_debug_for_struct!(CRCu8, f, self, let .lookup_table = self.lookup_table.as_ref(), (.sum, "0x{:02X}", self.sum), .bits, (.initial, "0x{:02X}", self.initial), (.final_xor, "0x{:02X}", self.final_xor), .refin, .refout);
         } else {
-            debug_helper::impl_debug_for_struct!(CRCu8, f, self, (.poly, "0x{:02X}", self.poly), (.sum, "0x{:02X}", self.sum), .bits, (.initial, "0x{:02X}", self.initial), (.final_xor, "0x{:02X}", self.final_xor), .reflect);
+            debug_helper::impl_debug_for_struct!(CRCu8, f, self, (.poly, "0x{:02X}", self.poly), (.sum, "0x{:02X}", self.sum), .bits, (.initial, "0x{:02X}", self.initial), (.final_xor, "0x{:02X}", self.final_xor), .refin, .refout);
         }
     }
 }
 
-#[cfg(feature = "alloc")]
 impl Display for CRCu8 {
     #[inline]
     fn fmt(&self, f: &mut Formatter) -> Result<(), fmt::Error> {
@@ -39,25 +50,57 @@ impl Display for CRCu8 {
     }
 }
 
+impl LowerHex for CRCu8 {
+    #[inline]
+    fn fmt(&self, f: &mut Formatter) -> Result<(), fmt::Error> {
+        hex::fmt_crc(f, u128::from(self.get_crc()), self.bits, 4, false)
+    }
+}
+
+impl UpperHex for CRCu8 {
+    #[inline]
+    fn fmt(&self, f: &mut Formatter) -> Result<(), fmt::Error> {
+        hex::fmt_crc(f, u128::from(self.get_crc()), self.bits, 4, true)
+    }
+}
+
+impl Octal for CRCu8 {
+    #[inline]
+    fn fmt(&self, f: &mut Formatter) -> Result<(), fmt::Error> {
+        hex::fmt_crc(f, u128::from(self.get_crc()), self.bits, 3, false)
+    }
+}
+
+impl Binary for CRCu8 {
+    #[inline]
+    fn fmt(&self, f: &mut Formatter) -> Result<(), fmt::Error> {
+        hex::fmt_crc(f, u128::from(self.get_crc()), self.bits, 1, false)
+    }
+}
+
 impl CRCu8 {
     /// Create a `CRCu8` instance by providing the length of bits, expression, reflection, an initial value and a final xor value.
     pub fn create_crc(poly: u8, bits: u8, initial: u8, final_xor: u8, reflect: bool) -> CRCu8 {
+        Self::create_crc_with_reflection(poly, bits, initial, final_xor, reflect, reflect)
+    }
+
+    /// Create a `CRCu8` instance by providing the length of bits, expression, input reflection, output reflection, an initial value and a final xor value.
+    ///
+    /// The expression needs to be reflected if `refin` is `true`.
+    pub fn create_crc_with_reflection(
+        poly: u8,
+        bits: u8,
+        initial: u8,
+        final_xor: u8,
+        refin: bool,
+        refout: bool,
+    ) -> CRCu8 {
         debug_assert!(bits <= 8 && bits > 0);
 
         if bits.is_multiple_of(8) {
-            let lookup_table = if reflect {
-                LookUpTable::Dynamic(Self::crc_reflect_table(poly))
-            } else {
-                LookUpTable::Dynamic(Self::crc_table(poly))
-            };
-
-            Self::create_crc_with_exists_lookup_table(
-                lookup_table,
-                bits,
-                initial,
-                final_xor,
-                reflect,
-            )
+            let lookup_table = Self::lookup_table_of(bits, poly, refin);
+
+            Self::create(true, lookup_table, 0, bits, initial, final_xor, refin, refout)
         } else {
             Self::create(
                 false,
@@ -66,11 +109,199 @@ impl CRCu8 {
                 bits,
                 initial,
                 final_xor,
-                reflect,
+                refin,
+                refout,
+            )
+        }
+    }
+
+    /// Set whether the bytes of the CRC value are output in reverse order. A CRC value of a `CRCu8` instance has at most one byte, so this has no effect and only exists for consistency with the wider CRC structs.
+    #[inline]
+    pub fn with_reorder(self, _reorder: bool) -> CRCu8 {
+        self
+    }
+
+    /// Select the backend which processes the input, keeping the current state. An error is returned if the backend is not available for the algorithm or on the CPU. See [`Backend`] for details.
+    pub fn with_backend(mut self, backend: Backend) -> Result<CRCu8, UnavailableBackend> {
+        let poly = self.normal_poly();
+        let state = self.state();
+
+        match backend {
+            Backend::Bitwise => {
+                self.by_table = false;
+                self.lookup_table = LookUpTable::Static(&[0; 256]);
+                self.poly = poly;
+            },
+            Backend::Table if self.bits.is_multiple_of(8) => {
+                self.switch_to_table(poly);
+            },
+            _ => {
+                return Err(UnavailableBackend {
+                    backend,
+                });
+            },
+        }
+
+        self.set_state(state);
+
+        Ok(self)
+    }
+
+    /// Get the backend which processes the input.
+    pub fn backend(&self) -> Backend {
+        if self.by_table { Backend::Table } else { Backend::Bitwise }
+    }
+
+    /// Create a `CRCu8` instance from the parameters of a CRC algorithm.
+    pub fn from_params(params: &CrcParams) -> CRCu8 {
+        Self::create_crc_with_reflection(
+            params.create_crc_poly() as u8,
+            params.width,
+            params.init as u8,
+            params.xorout as u8,
+            params.refin,
+            params.refout,
+        )
+    }
+
+    /// Create a `CRCu8` instance like [`CRCu8::create_crc`], but return an error instead of creating a broken instance if the parameters are invalid.
+    pub fn try_create_crc(
+        poly: u8,
+        bits: u8,
+        initial: u8,
+        final_xor: u8,
+        reflect: bool,
+    ) -> Result<CRCu8, CrcParamError> {
+        Self::try_create_crc_with_reflection(poly, bits, initial, final_xor, reflect, reflect)
+    }
+
+    /// Create a `CRCu8` instance like [`CRCu8::create_crc_with_reflection`], but return an error instead of creating a broken instance if the parameters are invalid.
+    pub fn try_create_crc_with_reflection(
+        poly: u8,
+        bits: u8,
+        initial: u8,
+        final_xor: u8,
+        refin: bool,
+        refout: bool,
+    ) -> Result<CRCu8, CrcParamError> {
+        check_params(8, bits, u128::from(poly), u128::from(initial), u128::from(final_xor))?;
+
+        Ok(Self::create_crc_with_reflection(poly, bits, initial, final_xor, refin, refout))
+    }
+
+    /// Create a `CRCu8` instance from the parameters of a CRC algorithm, or return an error if the parameters are invalid.
+    pub fn try_from_params(params: &CrcParams) -> Result<CRCu8, CrcParamError> {
+        check_crc_params(8, params)?;
+
+        Ok(Self::from_params(params))
+    }
+
+    /// Create a `CRCu8` instance from the parameters of a CRC algorithm and a lookup table generated by [`CRCu8::table`] with the same parameters, so that the table does not need to be generated at runtime.
+    ///
+    /// The lookup table is only used if the length of bits is a multiple of `8`.
+    pub fn from_params_with_table(params: &CrcParams, table: &'static [u8; 256]) -> CRCu8 {
+        if params.width.is_multiple_of(8) {
+            Self::create(
+                true,
+                LookUpTable::Static(table),
+                0,
+                params.width,
+                params.init as u8,
+                params.xorout as u8,
+                params.refin,
+                params.refout,
             )
+        } else {
+            Self::from_params(params)
         }
     }
 
+    /// Generate the lookup table of a CRC algorithm. It can be evaluated at compile time.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the length of bits of `params` is 0 or larger than `8`.
+    ///
+    /// ```
+    /// use crc_any::{
+    ///     CRCu8,
+    ///     params::{CRC_8_SMBUS, CrcParams},
+    /// };
+    ///
+    /// const PARAMS: CrcParams = CRC_8_SMBUS;
+    /// const TABLE: [u8; 256] = CRCu8::table(&PARAMS);
+    /// ```
+    pub const fn table(params: &CrcParams) -> [u8; 256] {
+        assert!(
+            params.width > 0 && params.width <= 8,
+            "the length of bits must be between 1 and 8"
+        );
+
+        if params.refin {
+            Self::crc_reflect_table(params.create_crc_poly() as u8)
+        } else {
+            Self::crc_table(params.poly as u8)
+        }
+    }
+
+    /// Compute the CRC value of `data` in one shot. It can be evaluated at compile time, but it processes the data bit by bit, so prefer creating a `CRCu8` instance at runtime.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the length of bits of `params` is 0 or larger than `8`.
+    ///
+    /// ```
+    /// use crc_any::{CRCu8, params::CRC_8_SMBUS};
+    ///
+    /// const CHECK: u8 = CRCu8::checksum(&CRC_8_SMBUS, b"123456789");
+    ///
+    /// assert_eq!(0xF4, CHECK);
+    /// ```
+    pub const fn checksum(params: &CrcParams, data: &[u8]) -> u8 {
+        assert!(
+            params.width > 0 && params.width <= 8,
+            "the length of bits must be between 1 and 8"
+        );
+
+        let high_bit = 1u8 << (params.width - 1);
+        let mask = ((high_bit - 1) << 1) | 1;
+        let poly = params.poly as u8;
+
+        let mut sum = params.init as u8;
+
+        let mut i = 0;
+
+        while i < data.len() {
+            let n = if params.refin { data[i].reverse_bits() } else { data[i] };
+
+            let mut b = 0x80u8;
+
+            while b != 0 {
+                let mut bit = sum & high_bit;
+
+                sum <<= 1;
+
+                if n & b != 0 {
+                    bit ^= high_bit;
+                }
+
+                if bit != 0 {
+                    sum ^= poly;
+                }
+
+                b >>= 1;
+            }
+
+            i += 1;
+        }
+
+        if params.refout {
+            sum = sum.reverse_bits() >> (u8::BITS - params.width as u32);
+        }
+
+        (sum ^ params.xorout as u8) & mask
+    }
+
     #[inline]
     pub(crate) fn create_crc_with_exists_lookup_table(
         lookup_table: LookUpTable<u8>,
@@ -81,9 +312,10 @@ impl CRCu8 {
     ) -> CRCu8 {
         debug_assert!(bits.is_multiple_of(8));
 
-        Self::create(true, lookup_table, 0, bits, initial, final_xor, reflect)
+        Self::create(true, lookup_table, 0, bits, initial, final_xor, reflect, reflect)
     }
 
+    #[allow(clippy::too_many_arguments)]
     #[inline]
     fn create(
         by_table: bool,
@@ -92,14 +324,17 @@ impl CRCu8 {
         bits: u8,
         initial: u8,
         final_xor: u8,
-        reflect: bool,
+        refin: bool,
+        refout: bool,
     ) -> CRCu8 {
         let high_bit = 1 << (bits - 1);
         let mask = ((high_bit - 1) << 1) | 1;
 
-        let sum = if reflect { Self::reflect_function(high_bit, initial) } else { initial };
+        // the register is kept reflected only when a reflected lookup table is used
+        let sum =
+            if by_table && refin { Self::reflect_function(high_bit, initial) } else { initial };
 
-        if !by_table && reflect {
+        if !by_table && refin {
             poly = Self::reflect_function(high_bit, poly);
         }
 
@@ -108,13 +343,13 @@ impl CRCu8 {
             poly,
             lookup_table,
             sum,
-            #[cfg(any(feature = "alloc", feature = "heapless"))]
             bits,
             high_bit,
             mask,
             initial,
             final_xor,
-            reflect,
+            refin,
+            refout,
         }
     }
 
@@ -130,6 +365,40 @@ impl CRCu8 {
         Self::reflect_function(self.high_bit, n)
     }
 
+    /// Get the lookup table of an expression, from the static tables, the cache, or by generating it.
+    fn lookup_table_of(bits: u8, poly: u8, refin: bool) -> LookUpTable<u8> {
+        match find_table(bits, poly, refin) {
+            Some(table) => LookUpTable::Static(table),
+            None => table_cache::lookup_table(bits, poly, refin, || {
+                if refin { Self::crc_reflect_table(poly) } else { Self::crc_table(poly) }
+            }),
+        }
+    }
+
+    /// Get the expression in normal (unreflected) form.
+    fn normal_poly(&self) -> u8 {
+        if !self.by_table {
+            self.poly
+        } else if self.refin {
+            // the entry of `0x80` of a reflected lookup table is the reflected expression
+            self.reflect_method(self.lookup_table[0x80])
+        } else {
+            // the entry of `1` of a normal lookup table is the expression
+            self.lookup_table[1]
+        }
+    }
+
+    /// Use the lookup table of the expression `poly` (in normal form) if the bitwise loop is used.
+    fn switch_to_table(&mut self, poly: u8) {
+        if !self.by_table {
+            let poly = if self.refin { self.reflect_method(poly) } else { poly };
+
+            self.lookup_table = Self::lookup_table_of(self.bits, poly, self.refin);
+            self.by_table = true;
+            self.poly = 0;
+        }
+    }
+
     /// Update the current CRC state with bytes.
     #[inline]
     pub fn update(&mut self, data: &[u8]) {
@@ -155,7 +424,7 @@ impl CRCu8 {
             }
 
             self.sum = sum;
-        } else if self.reflect {
+        } else if self.refin {
             for n in data.iter().copied() {
                 let n = Self::reflect_function(0x80, n);
 
@@ -200,6 +469,111 @@ impl CRCu8 {
         }
     }
 
+    /// Update the current CRC state with the bytes of a `u16` value in little-endian byte order.
+    #[inline]
+    pub fn update_u16_le(&mut self, value: u16) {
+        self.update(&value.to_le_bytes());
+    }
+
+    /// Update the current CRC state with the bytes of a `u16` value in big-endian byte order.
+    #[inline]
+    pub fn update_u16_be(&mut self, value: u16) {
+        self.update(&value.to_be_bytes());
+    }
+
+    /// Update the current CRC state with the bytes of a `u32` value in little-endian byte order.
+    #[inline]
+    pub fn update_u32_le(&mut self, value: u32) {
+        self.update(&value.to_le_bytes());
+    }
+
+    /// Update the current CRC state with the bytes of a `u32` value in big-endian byte order.
+    #[inline]
+    pub fn update_u32_be(&mut self, value: u32) {
+        self.update(&value.to_be_bytes());
+    }
+
+    /// Update the current CRC state with the bytes of a `u64` value in little-endian byte order.
+    #[inline]
+    pub fn update_u64_le(&mut self, value: u64) {
+        self.update(&value.to_le_bytes());
+    }
+
+    /// Update the current CRC state with the bytes of a `u64` value in big-endian byte order.
+    #[inline]
+    pub fn update_u64_be(&mut self, value: u64) {
+        self.update(&value.to_be_bytes());
+    }
+
+    /// Update the current CRC state with the bytes of an `f32` value in little-endian byte order.
+    #[inline]
+    pub fn update_f32_le(&mut self, value: f32) {
+        self.update(&value.to_le_bytes());
+    }
+
+    /// Update the current CRC state with the bytes of an `f32` value in big-endian byte order.
+    #[inline]
+    pub fn update_f32_be(&mut self, value: f32) {
+        self.update(&value.to_be_bytes());
+    }
+
+    /// Update the current CRC state with the bytes of an `f64` value in little-endian byte order.
+    #[inline]
+    pub fn update_f64_le(&mut self, value: f64) {
+        self.update(&value.to_le_bytes());
+    }
+
+    /// Update the current CRC state with the bytes of an `f64` value in big-endian byte order.
+    #[inline]
+    pub fn update_f64_be(&mut self, value: f64) {
+        self.update(&value.to_be_bytes());
+    }
+
+    /// Update the current CRC state with a single bit.
+    pub fn update_bit(&mut self, bit: bool) {
+        if self.by_table && self.refin {
+            // the register is reflected, and the reflected expression is the entry of `0x80`
+            let low_bit = (self.sum & 1 != 0) ^ bit;
+
+            self.sum >>= 1;
+
+            if low_bit {
+                self.sum ^= self.lookup_table[0x80];
+            }
+        } else {
+            let poly = self.normal_poly();
+
+            let high_bit = (self.sum & self.high_bit != 0) ^ bit;
+
+            self.sum <<= 1;
+
+            if high_bit {
+                self.sum ^= poly;
+            }
+        }
+    }
+
+    /// Update the current CRC state with the low `nbits` bits of `value`, for messages whose length is not a multiple of 8 bits.
+    ///
+    /// The bits are taken in the same order as the bits of the bytes passed to `update`: from the least significant bit if the input is reflected, and from the most significant bit (bit `nbits - 1`) otherwise. Thus, `update_bits(u64::from(byte), 8)` is the same as `update(&[byte])`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `nbits` is larger than `64`.
+    pub fn update_bits(&mut self, value: u64, nbits: u32) {
+        assert!(nbits <= 64, "cannot update with more than 64 bits at once");
+
+        if self.refin {
+            for i in 0..nbits {
+                self.update_bit((value >> i) & 1 != 0);
+            }
+        } else {
+            for i in (0..nbits).rev() {
+                self.update_bit((value >> i) & 1 != 0);
+            }
+        }
+    }
+
     /// Digest some data.
     ///
     /// This is a compatibility wrapper around [`CRCu8::update`].
@@ -211,68 +585,308 @@ impl CRCu8 {
     /// Reset the sum.
     #[inline]
     pub fn reset(&mut self) {
-        self.sum = if self.reflect {
+        self.sum = if self.by_table && self.refin {
             Self::reflect_function(self.high_bit, self.initial)
         } else {
             self.initial
         };
     }
 
+    /// Get the current state of the register, which can be restored later with [`CRCu8::set_state`] or [`CRCu8::from_state`].
+    ///
+    /// The state is always in normal (unreflected) form and is taken before the output reflection and the final xor value are applied, so it does not depend on how the CRC is computed.
+    pub fn state(&self) -> u8 {
+        let sum = self.sum & self.mask;
+
+        if self.by_table && self.refin { self.reflect_method(sum) } else { sum }
+    }
+
+    /// Restore a state obtained from [`CRCu8::state`] of an instance with the same parameters. You can continue calling `update` or `digest` after that.
+    pub fn set_state(&mut self, state: u8) {
+        let state = state & self.mask;
+
+        self.sum = if self.by_table && self.refin { self.reflect_method(state) } else { state };
+    }
+
+    /// Create a `CRCu8` instance from the parameters of a CRC algorithm and a state obtained from [`CRCu8::state`].
+    pub fn from_state(params: &CrcParams, state: u8) -> CRCu8 {
+        let mut crc = Self::from_params(params);
+
+        crc.set_state(state);
+
+        crc
+    }
+
+    /// Continue from a CRC value returned by [`CRCu8::get_crc`] of an instance with the same parameters, as if the data of that CRC value had been passed to `update` or `digest`.
+    pub fn resume_from(&mut self, crc: u8) {
+        let sum = (crc ^ self.final_xor) & self.mask;
+
+        self.set_state(if self.refout { self.reflect_method(sum) } else { sum });
+    }
+
+    /// Create a `CRCu8` instance from the parameters of a CRC algorithm and a CRC value of some previous data computed with the same parameters. Updating the instance with more data gives the CRC value of the concatenated data.
+    pub fn resume(params: &CrcParams, crc: u8) -> CRCu8 {
+        let mut crc_instance = Self::from_params(params);
+
+        crc_instance.resume_from(crc);
+
+        crc_instance
+    }
+
+    /// Check whether this instance and `other` compute the same CRC algorithm, regardless of their current states and backends.
+    pub fn is_same_algorithm(&self, other: &CRCu8) -> bool {
+        self.bits == other.bits
+            && self.initial == other.initial
+            && self.final_xor == other.final_xor
+            && self.refin == other.refin
+            && self.refout == other.refout
+            && self.normal_poly() == other.normal_poly()
+    }
+
+    /// Create a copy of this instance, including its current state. This is useful for computing the CRC values of many messages sharing a common prefix, which only needs to be processed once.
+    #[inline]
+    pub fn fork(&self) -> CRCu8 {
+        self.clone()
+    }
+
     /// Get the current CRC value (it always returns a `u8` value). You can continue calling `update` or `digest` even after getting a CRC value.
     #[inline]
     pub fn get_crc(&self) -> u8 {
-        if self.by_table || !self.reflect {
+        if (self.by_table && self.refin) == self.refout {
             (self.sum ^ self.final_xor) & self.mask
         } else {
             (self.reflect_method(self.sum) ^ self.final_xor) & self.mask
         }
     }
 
-    fn crc_reflect_table(poly_rev: u8) -> [u8; 256] {
+    const fn crc_reflect_table(poly_rev: u8) -> [u8; 256] {
         let mut lookup_table = [0u8; 256];
 
-        for (i, e) in lookup_table.iter_mut().enumerate() {
+        let mut i = 0;
+
+        while i < 256 {
             let mut v = i as u8;
 
-            #[allow(clippy::branches_sharing_code)]
-            for _ in 0..8u8 {
+            let mut j = 0;
+
+            while j < 8 {
                 if v & 1 != 0 {
                     v >>= 1;
                     v ^= poly_rev;
                 } else {
                     v >>= 1;
                 }
+
+                j += 1;
             }
 
-            *e = v;
+            lookup_table[i] = v;
+
+            i += 1;
         }
 
         lookup_table
     }
 
-    fn crc_table(poly: u8) -> [u8; 256] {
+    const fn crc_table(poly: u8) -> [u8; 256] {
         let mut lookup_table = [0u8; 256];
 
-        for (i, e) in lookup_table.iter_mut().enumerate() {
+        let mut i = 0;
+
+        while i < 256 {
             let mut v = i as u8;
 
-            #[allow(clippy::branches_sharing_code)]
-            for _ in 0..8 {
+            let mut j = 0;
+
+            while j < 8 {
                 if v & 0x80 == 0 {
                     v <<= 1;
                 } else {
                     v <<= 1;
                     v ^= poly;
                 }
+
+                j += 1;
             }
 
-            *e = v;
+            lookup_table[i] = v;
+
+            i += 1;
         }
 
         lookup_table
     }
 }
 
+impl CRCu8 {
+    /// Get the current CRC value as a byte array, which has the same content in both byte orders. You can continue calling `update` or `digest` even after getting a CRC value.
+    #[inline]
+    pub fn get_crc_bytes_be(&self) -> [u8; 1] {
+        [self.get_crc()]
+    }
+
+    /// Get the current CRC value as a byte array, which has the same content in both byte orders. You can continue calling `update` or `digest` even after getting a CRC value.
+    #[inline]
+    pub fn get_crc_bytes_le(&self) -> [u8; 1] {
+        [self.get_crc()]
+    }
+
+    /// Get the current CRC value as uppercase hexadecimal digits in ASCII. Its first bytes, as many as [`CrcEngine::hex_len`], hold the digits, and the rest are zero. You can continue calling `update` or `digest` even after getting a CRC value.
+    #[inline]
+    pub fn to_hex_array(&self) -> [u8; 2] {
+        let mut hex = [0u8; 2];
+
+        self.write_crc_hex(&mut hex);
+
+        hex
+    }
+}
+
+impl CRCu8 {
+    /// Append the current CRC value to `buf` in the given byte order, using as many bytes as the CRC bits need.
+    pub fn append_crc(&self, buf: &mut impl Extend<u8>, endian: Endian) {
+        let crc = self.get_crc();
+
+        let e = usize::from(self.bits).div_ceil(8);
+
+        match endian {
+            Endian::Big => buf.extend(crc.to_be_bytes()[(1 - e)..].iter().copied()),
+            Endian::Little => buf.extend(crc.to_le_bytes()[..e].iter().copied()),
+        }
+    }
+
+    /// Check whether `data_with_crc` ends with the CRC value of the data before it, in the given byte order. The data is computed from the current state, which is not changed.
+    pub fn verify_trailer(&self, data_with_crc: &[u8], endian: Endian) -> bool {
+        let e = usize::from(self.bits).div_ceil(8);
+
+        if data_with_crc.len() < e {
+            return false;
+        }
+
+        let (data, trailer) = data_with_crc.split_at(data_with_crc.len() - e);
+
+        let mut crc = self.clone();
+
+        crc.update(data);
+
+        let crc = crc.get_crc();
+
+        match endian {
+            Endian::Big => trailer == &crc.to_be_bytes()[(1 - e)..],
+            Endian::Little => trailer == &crc.to_le_bytes()[..e],
+        }
+    }
+
+    /// Check whether `data_with_crc` is an error-free codeword, that is, data followed by its CRC value in the bit order of the algorithm (big-endian if the input is not reflected, little-endian otherwise), by comparing the register with the residue of the algorithm after processing the whole codeword. The data is computed from the current state, which is not changed.
+    ///
+    /// If the CRC bits do not fill whole bytes, the input and output reflections differ, or the bytes of the CRC value are reordered, there is no such residue for byte-oriented codewords, so the trailer is compared with [`CRCu8::verify_trailer`] instead.
+    pub fn check_residue(&self, data_with_crc: &[u8]) -> bool {
+        if !self.bits.is_multiple_of(8) || self.refin != self.refout {
+            let endian = if self.refin { Endian::Little } else { Endian::Big };
+
+            return self.verify_trailer(data_with_crc, endian);
+        }
+
+        if data_with_crc.len() < usize::from(self.bits / 8) {
+            return false;
+        }
+
+        let mut crc = self.clone();
+
+        crc.update(data_with_crc);
+
+        crc.state() == self.residue_state()
+    }
+
+    /// Get the residue of the algorithm, which is the content of the register after processing an error-free codeword, reflected if the output is reflected, before the final xor value is applied. This is the `residue` parameter of the catalogue.
+    pub fn residue(&self) -> u8 {
+        let residue = self.residue_state();
+
+        if self.refout { self.reflect_method(residue) } else { residue }
+    }
+
+    /// The CRC bits of a codeword leave `final_xor` (in the bit order of the input) in the register, so the residue is `final_xor` shifted through the register by the length of bits.
+    fn residue_state(&self) -> u8 {
+        let poly = self.normal_poly();
+
+        let mut residue =
+            if self.refin { self.reflect_method(self.final_xor) } else { self.final_xor };
+
+        for _ in 0..self.bits {
+            residue =
+                if residue & self.high_bit != 0 { (residue << 1) ^ poly } else { residue << 1 };
+        }
+
+        residue & self.mask
+    }
+}
+
+/// Two instances are equal if they compute the same CRC algorithm and are in the same state.
+impl PartialEq for CRCu8 {
+    #[inline]
+    fn eq(&self, other: &CRCu8) -> bool {
+        self.is_same_algorithm(other) && self.state() == other.state()
+    }
+}
+
+impl Eq for CRCu8 {}
+
+impl CrcEngine for CRCu8 {
+    type Output = u8;
+
+    const WIDTH_MAX: u8 = 8;
+
+    #[inline]
+    fn create_crc(poly: u8, bits: u8, initial: u8, final_xor: u8, reflect: bool) -> CRCu8 {
+        CRCu8::create_crc(poly, bits, initial, final_xor, reflect)
+    }
+
+    #[inline]
+    fn from_params(params: &CrcParams) -> CRCu8 {
+        CRCu8::from_params(params)
+    }
+
+    #[inline]
+    fn try_from_params(params: &CrcParams) -> Result<CRCu8, CrcParamError> {
+        CRCu8::try_from_params(params)
+    }
+
+    #[inline]
+    fn width(&self) -> u8 {
+        self.bits
+    }
+
+    #[inline]
+    fn update(&mut self, data: &[u8]) {
+        CRCu8::update(self, data)
+    }
+
+    #[inline]
+    fn reset(&mut self) {
+        CRCu8::reset(self)
+    }
+
+    #[inline]
+    fn get_crc(&self) -> u8 {
+        CRCu8::get_crc(self)
+    }
+
+    #[inline]
+    fn state(&self) -> u8 {
+        CRCu8::state(self)
+    }
+
+    #[inline]
+    fn set_state(&mut self, state: u8) {
+        CRCu8::set_state(self, state)
+    }
+
+    #[inline]
+    fn resume_from(&mut self, crc: u8) {
+        CRCu8::resume_from(self, crc)
+    }
+}
+
 impl CRCu8 {
     /// |Check|Poly|Init|Ref|XorOut|
     /// |---|---|---|---|---|
@@ -289,6 +903,20 @@ impl CRCu8 {
         Self::create_crc(0x03, 3, 0x00, 0x07, false)
     }
 
+    /// |Check|Poly|Init|Ref|XorOut|
+    /// |---|---|---|---|---|
+    /// |0x6|0x3 (rev: 0x6)|0x7|true|0x0|
+    ///
+    /// ```
+    /// # use crc_any::CRCu8;
+    /// let mut crc = CRCu8::crc3rohc();
+    /// crc.digest(b"123456789");
+    #[cfg_attr(feature = "alloc", doc = "assert_eq!(\"0x6\", &crc.to_string());")]
+    /// ```
+    pub fn crc3rohc() -> CRCu8 {
+        Self::create_crc(0x06, 3, 0x07, 0x00, true)
+    }
+
     /// |Check|Poly|Init|Ref|XorOut|
     /// |---|---|---|---|---|
     /// |0x7|0x3 (rev: 0xC)|0x0|true|0x0|
@@ -454,6 +1082,20 @@ impl CRCu8 {
         Self::create_crc(0x09, 7, 0x00, 0x00, false)
     }
 
+    /// |Check|Poly|Init|Ref|XorOut|
+    /// |---|---|---|---|---|
+    /// |0x53|0x4F (rev: 0x79)|0x7F|true|0x00|
+    ///
+    /// ```
+    /// # use crc_any::CRCu8;
+    /// let mut crc = CRCu8::crc7rohc();
+    /// crc.digest(b"123456789");
+    #[cfg_attr(feature = "alloc", doc = "assert_eq!(\"0x53\", &crc.to_string());")]
+    /// ```
+    pub fn crc7rohc() -> CRCu8 {
+        Self::create_crc(0x79, 7, 0x7F, 0x00, true)
+    }
+
     /// |Check|Poly|Init|Ref|XorOut|
     /// |---|---|---|---|---|
     /// |0x61|0x45|0x00|false|0x00|
@@ -487,6 +1129,40 @@ impl CRCu8 {
         Self::create_crc_with_exists_lookup_table(lookup_table, 8, 0x00, 0x00, false)
     }
 
+    /// |Check|Poly|Init|Ref|XorOut|
+    /// |---|---|---|---|---|
+    /// |0xDF|0x2F|0xFF|false|0xFF|
+    ///
+    /// ```
+    /// # use crc_any::CRCu8;
+    /// let mut crc = CRCu8::crc8autosar();
+    /// crc.digest(b"123456789");
+    #[cfg_attr(feature = "alloc", doc = "assert_eq!(\"0xDF\", &crc.to_string());")]
+    /// ```
+    pub fn crc8autosar() -> CRCu8 {
+        // Self::create_crc(0x2F, 8, 0xFF, 0xFF, false)
+
+        let lookup_table = LookUpTable::Static(&NO_REF_8_2F);
+        Self::create_crc_with_exists_lookup_table(lookup_table, 8, 0xFF, 0xFF, false)
+    }
+
+    /// |Check|Poly|Init|Ref|XorOut|
+    /// |---|---|---|---|---|
+    /// |0x26|0xA7 (rev: 0xE5)|0x00|true|0x00|
+    ///
+    /// ```
+    /// # use crc_any::CRCu8;
+    /// let mut crc = CRCu8::crc8bluetooth();
+    /// crc.digest(b"123456789");
+    #[cfg_attr(feature = "alloc", doc = "assert_eq!(\"0x26\", &crc.to_string());")]
+    /// ```
+    pub fn crc8bluetooth() -> CRCu8 {
+        // Self::create_crc(0xE5, 8, 0x00, 0x00, true)
+
+        let lookup_table = LookUpTable::Static(&REF_8_E5);
+        Self::create_crc_with_exists_lookup_table(lookup_table, 8, 0x00, 0x00, true)
+    }
+
     /// |Check|Poly|Init|Ref|XorOut|
     /// |---|---|---|---|---|
     /// |0xDA|0x9B|0xFF|false|0x00|
@@ -559,6 +1235,57 @@ impl CRCu8 {
         Self::create_crc_with_exists_lookup_table(lookup_table, 8, 0xFF, 0x00, true)
     }
 
+    /// |Check|Poly|Init|Ref|XorOut|
+    /// |---|---|---|---|---|
+    /// |0x37|0x1D|0x00|false|0x00|
+    ///
+    /// ```
+    /// # use crc_any::CRCu8;
+    /// let mut crc = CRCu8::crc8gsm_a();
+    /// crc.digest(b"123456789");
+    #[cfg_attr(feature = "alloc", doc = "assert_eq!(\"0x37\", &crc.to_string());")]
+    /// ```
+    pub fn crc8gsm_a() -> CRCu8 {
+        // Self::create_crc(0x1D, 8, 0x00, 0x00, false)
+
+        let lookup_table = LookUpTable::Static(&NO_REF_8_1D);
+        Self::create_crc_with_exists_lookup_table(lookup_table, 8, 0x00, 0x00, false)
+    }
+
+    /// |Check|Poly|Init|Ref|XorOut|
+    /// |---|---|---|---|---|
+    /// |0x94|0x49|0x00|false|0xFF|
+    ///
+    /// ```
+    /// # use crc_any::CRCu8;
+    /// let mut crc = CRCu8::crc8gsm_b();
+    /// crc.digest(b"123456789");
+    #[cfg_attr(feature = "alloc", doc = "assert_eq!(\"0x94\", &crc.to_string());")]
+    /// ```
+    pub fn crc8gsm_b() -> CRCu8 {
+        // Self::create_crc(0x49, 8, 0x00, 0xFF, false)
+
+        let lookup_table = LookUpTable::Static(&NO_REF_8_49);
+        Self::create_crc_with_exists_lookup_table(lookup_table, 8, 0x00, 0xFF, false)
+    }
+
+    /// |Check|Poly|Init|Ref|XorOut|
+    /// |---|---|---|---|---|
+    /// |0xB4|0x1D|0xFF|false|0x00|
+    ///
+    /// ```
+    /// # use crc_any::CRCu8;
+    /// let mut crc = CRCu8::crc8hitag();
+    /// crc.digest(b"123456789");
+    #[cfg_attr(feature = "alloc", doc = "assert_eq!(\"0xB4\", &crc.to_string());")]
+    /// ```
+    pub fn crc8hitag() -> CRCu8 {
+        // Self::create_crc(0x1D, 8, 0xFF, 0x00, false)
+
+        let lookup_table = LookUpTable::Static(&NO_REF_8_1D);
+        Self::create_crc_with_exists_lookup_table(lookup_table, 8, 0xFF, 0x00, false)
+    }
+
     /// |Check|Poly|Init|Ref|XorOut|
     /// |---|---|---|---|---|
     /// |0x7E|0x1D|0xFD|false|0x00|
@@ -595,6 +1322,23 @@ impl CRCu8 {
         Self::create_crc_with_exists_lookup_table(lookup_table, 8, 0x00, 0x55, false)
     }
 
+    /// |Check|Poly|Init|Ref|XorOut|
+    /// |---|---|---|---|---|
+    /// |0xEA|0x9B|0x00|false|0x00|
+    ///
+    /// ```
+    /// # use crc_any::CRCu8;
+    /// let mut crc = CRCu8::crc8lte();
+    /// crc.digest(b"123456789");
+    #[cfg_attr(feature = "alloc", doc = "assert_eq!(\"0xEA\", &crc.to_string());")]
+    /// ```
+    pub fn crc8lte() -> CRCu8 {
+        // Self::create_crc(0x9B, 8, 0x00, 0x00, false)
+
+        let lookup_table = LookUpTable::Static(&NO_REF_8_9B);
+        Self::create_crc_with_exists_lookup_table(lookup_table, 8, 0x00, 0x00, false)
+    }
+
     /// |Check|Poly|Init|Ref|XorOut|
     /// |---|---|---|---|---|
     /// |0xA1|0x31 (rev: 0x8C)|0x00|true|0x00|
@@ -613,6 +1357,57 @@ impl CRCu8 {
         Self::create_crc_with_exists_lookup_table(lookup_table, 8, 0x00, 0x00, true)
     }
 
+    /// |Check|Poly|Init|Ref|XorOut|
+    /// |---|---|---|---|---|
+    /// |0x99|0x1D|0xC7|false|0x00|
+    ///
+    /// ```
+    /// # use crc_any::CRCu8;
+    /// let mut crc = CRCu8::crc8mifare_mad();
+    /// crc.digest(b"123456789");
+    #[cfg_attr(feature = "alloc", doc = "assert_eq!(\"0x99\", &crc.to_string());")]
+    /// ```
+    pub fn crc8mifare_mad() -> CRCu8 {
+        // Self::create_crc(0x1D, 8, 0xC7, 0x00, false)
+
+        let lookup_table = LookUpTable::Static(&NO_REF_8_1D);
+        Self::create_crc_with_exists_lookup_table(lookup_table, 8, 0xC7, 0x00, false)
+    }
+
+    /// |Check|Poly|Init|Ref|XorOut|
+    /// |---|---|---|---|---|
+    /// |0xF7|0x31|0xFF|false|0x00|
+    ///
+    /// ```
+    /// # use crc_any::CRCu8;
+    /// let mut crc = CRCu8::crc8nrsc5();
+    /// crc.digest(b"123456789");
+    #[cfg_attr(feature = "alloc", doc = "assert_eq!(\"0xF7\", &crc.to_string());")]
+    /// ```
+    pub fn crc8nrsc5() -> CRCu8 {
+        // Self::create_crc(0x31, 8, 0xFF, 0x00, false)
+
+        let lookup_table = LookUpTable::Static(&NO_REF_8_31);
+        Self::create_crc_with_exists_lookup_table(lookup_table, 8, 0xFF, 0x00, false)
+    }
+
+    /// |Check|Poly|Init|Ref|XorOut|
+    /// |---|---|---|---|---|
+    /// |0x3E|0x2F|0x00|false|0x00|
+    ///
+    /// ```
+    /// # use crc_any::CRCu8;
+    /// let mut crc = CRCu8::crc8opensafety();
+    /// crc.digest(b"123456789");
+    #[cfg_attr(feature = "alloc", doc = "assert_eq!(\"0x3E\", &crc.to_string());")]
+    /// ```
+    pub fn crc8opensafety() -> CRCu8 {
+        // Self::create_crc(0x2F, 8, 0x00, 0x00, false)
+
+        let lookup_table = LookUpTable::Static(&NO_REF_8_2F);
+        Self::create_crc_with_exists_lookup_table(lookup_table, 8, 0x00, 0x00, false)
+    }
+
     /// |Check|Poly|Init|Ref|XorOut|
     /// |---|---|---|---|---|
     /// |0xD0|0x07 (rev: 0xE0)|0xFF|true|0x00|
@@ -631,6 +1426,23 @@ impl CRCu8 {
         Self::create_crc_with_exists_lookup_table(lookup_table, 8, 0xFF, 0x00, true)
     }
 
+    /// |Check|Poly|Init|Ref|XorOut|
+    /// |---|---|---|---|---|
+    /// |0x4B|0x1D|0xFF|false|0xFF|
+    ///
+    /// ```
+    /// # use crc_any::CRCu8;
+    /// let mut crc = CRCu8::crc8sae_j1850();
+    /// crc.digest(b"123456789");
+    #[cfg_attr(feature = "alloc", doc = "assert_eq!(\"0x4B\", &crc.to_string());")]
+    /// ```
+    pub fn crc8sae_j1850() -> CRCu8 {
+        // Self::create_crc(0x1D, 8, 0xFF, 0xFF, false)
+
+        let lookup_table = LookUpTable::Static(&NO_REF_8_1D);
+        Self::create_crc_with_exists_lookup_table(lookup_table, 8, 0xFF, 0xFF, false)
+    }
+
     /// |Check|Poly|Init|Ref|XorOut|
     /// |---|---|---|---|---|
     /// |0x25|0x9B (rev: 0xD9)|0x00|true|0x00|
@@ -671,3 +1483,13 @@ mod tests {
         println!("let lookup_table = [{}];", s);
     }
 }
+
+#[cfg(test)]
+mod table_tests {
+    use super::*;
+
+    #[test]
+    fn custom_parameters_use_static_tables() {
+        assert!(CRCu8::create_crc(0x8C, 8, 0x00, 0x00, true).lookup_table.is_static(&REF_8_8C));
+    }
+}
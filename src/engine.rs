@@ -0,0 +1,132 @@
+use core::fmt::Debug;
+
+use crate::{CrcParamError, CrcParams, hex};
+
+/// The common interface of `CRCu8`, `CRCu16`, `CRCu32`, `CRCu64` and `CRCu128`, which lets code be generic over the type of CRC values without going through the `CRC` enum.
+///
+/// ```
+/// use crc_any::{CRCu16, CRCu32, CrcEngine, params::*};
+///
+/// fn frame<E: CrcEngine>(
+///     params: &crc_any::CrcParams,
+///     payload: &[u8],
+///     buf: &mut [u8],
+/// ) -> usize {
+///     let mut crc = E::from_params(params);
+///
+///     crc.update(payload);
+///
+///     buf[..payload.len()].copy_from_slice(payload);
+///
+///     payload.len() + crc.write_crc_be(&mut buf[payload.len()..])
+/// }
+///
+/// let mut buf = [0u8; 16];
+///
+/// assert_eq!(11, frame::<CRCu16>(&CRC_16_MODBUS, b"123456789", &mut buf));
+/// assert_eq!([0x4B, 0x37], buf[9..11]);
+///
+/// assert_eq!(13, frame::<CRCu32>(&CRC_32_ISO_HDLC, b"123456789", &mut buf));
+/// assert_eq!([0xCB, 0xF4, 0x39, 0x26], buf[9..13]);
+/// ```
+pub trait CrcEngine: Sized {
+    /// The type of CRC values.
+    type Output: Copy + Eq + Debug + Into<u128>;
+
+    /// The maximum length of bits.
+    const WIDTH_MAX: u8;
+
+    /// Create an instance by providing the length of bits, expression, reflection, an initial value and a final xor value.
+    fn create_crc(
+        poly: Self::Output,
+        bits: u8,
+        initial: Self::Output,
+        final_xor: Self::Output,
+        reflect: bool,
+    ) -> Self;
+
+    /// Create an instance from the parameters of a CRC algorithm.
+    fn from_params(params: &CrcParams) -> Self;
+
+    /// Create an instance from the parameters of a CRC algorithm, or return an error if the parameters are invalid.
+    fn try_from_params(params: &CrcParams) -> Result<Self, CrcParamError>;
+
+    /// Get the length of bits.
+    fn width(&self) -> u8;
+
+    /// Update the current CRC state with bytes.
+    fn update(&mut self, data: &[u8]);
+
+    /// Reset the sum.
+    fn reset(&mut self);
+
+    /// Get the current CRC value. You can continue calling `update` even after getting a CRC value.
+    fn get_crc(&self) -> Self::Output;
+
+    /// Get the current state of the register in normal (unreflected) form, before the output reflection and the final xor value are applied.
+    fn state(&self) -> Self::Output;
+
+    /// Restore a state obtained from [`CrcEngine::state`] of an instance with the same parameters.
+    fn set_state(&mut self, state: Self::Output);
+
+    /// Continue from a CRC value returned by [`CrcEngine::get_crc`] of an instance with the same parameters, as if the data of that CRC value had been passed to `update`.
+    fn resume_from(&mut self, crc: Self::Output);
+
+    /// Get the number of bytes of a CRC value.
+    #[inline]
+    fn crc_len(&self) -> usize {
+        usize::from(self.width()).div_ceil(8)
+    }
+
+    /// Get the number of hexadecimal digits of a CRC value.
+    #[inline]
+    fn hex_len(&self) -> usize {
+        usize::from(self.width()).div_ceil(4)
+    }
+
+    /// Write the current CRC value into the beginning of `buf` in big-endian byte order, and return the number of bytes written.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `buf` is shorter than [`CrcEngine::crc_len`].
+    #[inline]
+    fn write_crc_be(&self, buf: &mut [u8]) -> usize {
+        let e = self.crc_len();
+
+        let crc: u128 = self.get_crc().into();
+
+        buf[..e].copy_from_slice(&crc.to_be_bytes()[(16 - e)..]);
+
+        e
+    }
+
+    /// Write the current CRC value into the beginning of `buf` in little-endian byte order, and return the number of bytes written.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `buf` is shorter than [`CrcEngine::crc_len`].
+    #[inline]
+    fn write_crc_le(&self, buf: &mut [u8]) -> usize {
+        let e = self.crc_len();
+
+        let crc: u128 = self.get_crc().into();
+
+        buf[..e].copy_from_slice(&crc.to_le_bytes()[..e]);
+
+        e
+    }
+
+    /// Write the current CRC value into the beginning of `buf` as uppercase hexadecimal digits in ASCII, padded with zeros to [`CrcEngine::hex_len`] digits, and return the number of bytes written.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `buf` is shorter than [`CrcEngine::hex_len`].
+    #[inline]
+    fn write_crc_hex(&self, buf: &mut [u8]) -> usize {
+        let e = self.hex_len();
+
+        hex::write_digits(self.get_crc().into(), e, 4, true, buf);
+
+        e
+    }
+}
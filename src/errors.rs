@@ -0,0 +1,228 @@
+use core::{
+    error::Error,
+    fmt::{self, Display, Formatter},
+};
+
+use crate::{backend::Backend, params::CrcParams};
+
+/// A value of the parameters of a CRC algorithm.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum CrcParamField {
+    /// The expression (`poly`).
+    Poly,
+    /// The initial value (`init`).
+    Init,
+    /// The final xor value (`xorout`).
+    Xorout,
+    /// The CRC value of `"123456789"` (`check`).
+    Check,
+    /// The residue (`residue`).
+    Residue,
+}
+
+impl Display for CrcParamField {
+    #[inline]
+    fn fmt(&self, f: &mut Formatter) -> Result<(), fmt::Error> {
+        f.write_str(match self {
+            CrcParamField::Poly => "poly",
+            CrcParamField::Init => "init",
+            CrcParamField::Xorout => "xorout",
+            CrcParamField::Check => "check",
+            CrcParamField::Residue => "residue",
+        })
+    }
+}
+
+/// Errors for creating a CRC instance from invalid parameters.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum CrcParamError {
+    /// The length of bits is `0`.
+    ZeroWidth,
+    /// The length of bits is larger than the CRC instance can hold.
+    WidthTooLarge {
+        /// The requested length of bits.
+        width: u8,
+        /// The maximum length of bits.
+        max:   u8,
+    },
+    /// A value has bits set above the length of bits.
+    ValueOutOfRange {
+        /// The value.
+        field: CrcParamField,
+        /// The length of bits.
+        width: u8,
+    },
+}
+
+impl Display for CrcParamError {
+    #[inline]
+    fn fmt(&self, f: &mut Formatter) -> Result<(), fmt::Error> {
+        match self {
+            CrcParamError::ZeroWidth => f.write_str("the length of bits must not be zero"),
+            CrcParamError::WidthTooLarge {
+                width,
+                max,
+            } => f.write_fmt(format_args!(
+                "the length of bits ({width}) must not be larger than {max}"
+            )),
+            CrcParamError::ValueOutOfRange {
+                field,
+                width,
+            } => f.write_fmt(format_args!("`{field}` does not fit in {width} bits")),
+        }
+    }
+}
+
+impl Error for CrcParamError {}
+
+/// An error for selecting a backend which is not available for the algorithm or on the CPU.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct UnavailableBackend {
+    /// The requested backend.
+    pub backend: Backend,
+}
+
+impl Display for UnavailableBackend {
+    #[inline]
+    fn fmt(&self, f: &mut Formatter) -> Result<(), fmt::Error> {
+        f.write_fmt(format_args!("the {:?} backend is not available for this CRC", self.backend))
+    }
+}
+
+impl Error for UnavailableBackend {}
+
+/// Errors for parsing a CRC value with [`parse_crc`](crate::parse_crc).
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum ParseCrcError {
+    /// The length of bits is `0` or larger than `128`.
+    InvalidWidth {
+        /// The requested length of bits.
+        width: u8,
+    },
+    /// There are no digits.
+    Empty,
+    /// A character is not a hexadecimal digit.
+    InvalidDigit,
+    /// The value does not fit in the length of bits.
+    ValueOutOfRange {
+        /// The length of bits.
+        width: u8,
+    },
+}
+
+impl Display for ParseCrcError {
+    #[inline]
+    fn fmt(&self, f: &mut Formatter) -> Result<(), fmt::Error> {
+        match self {
+            ParseCrcError::InvalidWidth {
+                width,
+            } => {
+                f.write_fmt(format_args!("the length of bits ({width}) must be between 1 and 128"))
+            },
+            ParseCrcError::Empty => f.write_str("the CRC value has no digits"),
+            ParseCrcError::InvalidDigit => {
+                f.write_str("the CRC value has a character which is not a hexadecimal digit")
+            },
+            ParseCrcError::ValueOutOfRange {
+                width,
+            } => f.write_fmt(format_args!("the CRC value does not fit in {width} bits")),
+        }
+    }
+}
+
+impl Error for ParseCrcError {}
+
+/// The check of a self-test which failed.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum SelfTestCheck {
+    /// The CRC value of `"123456789"` is not the `check` parameter of the algorithm.
+    Check,
+    /// The residue of the algorithm is not its `residue` parameter, or an error-free codeword is not accepted.
+    Residue,
+}
+
+/// An error for a failed [`self_test`](crate::self_test).
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct SelfTestFailure {
+    /// The canonical name of the algorithm which failed.
+    pub algorithm: &'static str,
+    /// The backend which failed.
+    pub backend:   Backend,
+    /// The check which failed.
+    pub check:     SelfTestCheck,
+}
+
+impl Display for SelfTestFailure {
+    #[inline]
+    fn fmt(&self, f: &mut Formatter) -> Result<(), fmt::Error> {
+        let check = match self.check {
+            SelfTestCheck::Check => "check value",
+            SelfTestCheck::Residue => "residue",
+        };
+
+        f.write_fmt(format_args!(
+            "the {check} of {} is wrong with the {:?} backend",
+            self.algorithm, self.backend
+        ))
+    }
+}
+
+impl Error for SelfTestFailure {}
+
+/// Check the length of bits and the values of a CRC against the maximum length of bits `max`.
+pub(crate) fn check_params(
+    max: u8,
+    bits: u8,
+    poly: u128,
+    initial: u128,
+    final_xor: u128,
+) -> Result<(), CrcParamError> {
+    if bits == 0 {
+        return Err(CrcParamError::ZeroWidth);
+    }
+
+    if bits > max {
+        return Err(CrcParamError::WidthTooLarge {
+            width: bits,
+            max,
+        });
+    }
+
+    let mask = u128::MAX >> (u128::BITS - u32::from(bits));
+
+    for (field, value) in [
+        (CrcParamField::Poly, poly),
+        (CrcParamField::Init, initial),
+        (CrcParamField::Xorout, final_xor),
+    ] {
+        if value & !mask != 0 {
+            return Err(CrcParamError::ValueOutOfRange {
+                field,
+                width: bits,
+            });
+        }
+    }
+
+    Ok(())
+}
+
+/// Check the length of bits and all the values of the parameters of a CRC algorithm against the maximum length of bits `max`.
+pub(crate) fn check_crc_params(max: u8, params: &CrcParams) -> Result<(), CrcParamError> {
+    check_params(max, params.width, params.poly, params.init, params.xorout)?;
+
+    let mask = u128::MAX >> (u128::BITS - u32::from(params.width));
+
+    for (field, value) in
+        [(CrcParamField::Check, params.check), (CrcParamField::Residue, params.residue)]
+    {
+        if value & !mask != 0 {
+            return Err(CrcParamError::ValueOutOfRange {
+                field,
+                width: params.width,
+            });
+        }
+    }
+
+    Ok(())
+}
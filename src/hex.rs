@@ -0,0 +1,98 @@
+use core::fmt::{self, Formatter};
+
+use crate::errors::ParseCrcError;
+
+const UPPER_DIGITS: &[u8; 16] = b"0123456789ABCDEF";
+const LOWER_DIGITS: &[u8; 16] = b"0123456789abcdef";
+
+/// Write `crc` into the beginning of `buf` as `len` digits of `shift` bits each (`1` for binary, `3` for octal and `4` for hexadecimal), padded with zeros.
+pub(crate) fn write_digits(crc: u128, len: usize, shift: u32, upper: bool, buf: &mut [u8]) {
+    let digits = if upper { UPPER_DIGITS } else { LOWER_DIGITS };
+
+    let mask = (1 << shift) - 1;
+
+    for (i, digit) in buf[..len].iter_mut().enumerate() {
+        let offset = (len - 1 - i) as u32 * shift;
+
+        *digit = digits[((crc >> offset) & mask) as usize];
+    }
+}
+
+/// Format `crc` as digits of `shift` bits each, as many as `bits` needs, so that the CRC values of an algorithm always have the same length. The width, fill, alignment and `#` flags of `f` are respected.
+pub(crate) fn fmt_crc(
+    f: &mut Formatter,
+    crc: u128,
+    bits: u8,
+    shift: u32,
+    upper: bool,
+) -> Result<(), fmt::Error> {
+    let mut buf = [0u8; 128];
+
+    let len = u32::from(bits).div_ceil(shift) as usize;
+
+    write_digits(crc, len, shift, upper, &mut buf);
+
+    let prefix = match shift {
+        1 => "0b",
+        3 => "0o",
+        _ => "0x",
+    };
+
+    // the digits are ASCII
+    f.pad_integral(true, prefix, core::str::from_utf8(&buf[..len]).unwrap())
+}
+
+/// Parse a CRC value of `bits` bits from a hexadecimal string, with or without the `0x` prefix, such as a CRC value formatted by this crate.
+///
+/// ```rust
+/// use crc_any::{CRC, ParseCrcError, parse_crc};
+///
+/// let mut crc = CRC::crc16xmodem();
+///
+/// crc.update(b"123456789");
+///
+/// assert_eq!(Ok(crc.get_crc_u128()), parse_crc("0x31C3", 16));
+/// assert_eq!(Ok(0x31C3), parse_crc("31c3", 16));
+///
+/// assert_eq!(
+///     Err(ParseCrcError::ValueOutOfRange {
+///         width: 8
+///     }),
+///     parse_crc("31C3", 8)
+/// );
+/// ```
+pub fn parse_crc(s: &str, bits: u8) -> Result<u128, ParseCrcError> {
+    if bits == 0 || bits > 128 {
+        return Err(ParseCrcError::InvalidWidth {
+            width: bits
+        });
+    }
+
+    let digits = s.strip_prefix("0x").or_else(|| s.strip_prefix("0X")).unwrap_or(s);
+
+    if digits.is_empty() {
+        return Err(ParseCrcError::Empty);
+    }
+
+    let out_of_range = ParseCrcError::ValueOutOfRange {
+        width: bits
+    };
+
+    let mut crc = 0u128;
+
+    for c in digits.chars() {
+        let digit = c.to_digit(16).ok_or(ParseCrcError::InvalidDigit)?;
+
+        if crc >> 124 != 0 {
+            return Err(out_of_range);
+        }
+
+        crc = (crc << 4) | u128::from(digit);
+    }
+
+    if bits < 128 && crc >> bits != 0 {
+        return Err(out_of_range);
+    }
+
+    Ok(crc)
+}
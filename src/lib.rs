@@ -21,9 +21,20 @@ crc24.update(b"hello");
 # }
 ```
 
+If an algorithm reflects its input and its output differently, such as CRC-12/UMTS, use the `create_crc_with_reflection` associated function instead.
+
+Some implementations output the bytes of a CRC value in reverse order, like `crc32` of the `mhash` library. To get such CRC values from any algorithm, use the `with_reorder` method right after creating an instance.
+
+CRCs up to 128 bits wide are supported. If the expression, the initial value or the final xor value of a CRC does not fit in a `u64`, use the `create_crc_u128` associated function instead, and use the `get_crc_u128` method to get the whole CRC value.
+
+The `create_crc` associated functions do not check their arguments. If the parameters come from user input, use the `try_create_crc`, `try_create_crc_with_reflection` or `try_from_params` associated functions instead. They return a `CrcParamError` if the length of bits is zero or too large, or if a value does not fit in the length of bits.
+
+If the parameters of a custom algorithm match a built-in one, the built-in static lookup table is used, and CRC-32C parameters also get the hardware acceleration described below. Other lookup tables generated for custom algorithms are cached and shared, so creating the same custom CRC again is cheap. With the `std` feature, the cache is process-wide. Without it, a few tables of each width are kept in static memory. When the cache is full, each instance generates its own table.
+
 For simpler usage, this crate also provides built-in functions for many common CRC variants:
 
  * crc3gsm
+ * crc3rohc
  * crc4itu
  * crc4interlaken
  * crc5epc
@@ -35,24 +46,37 @@ For simpler usage, this crate also provides built-in functions for many common C
  * crc6gsm
  * crc6itu
  * crc7
+ * crc7rohc
  * crc7umts
  * crc8
+ * crc8autosar
+ * crc8bluetooth
  * crc8cdma2000
  * crc8darc
  * crc8dvb_s2
  * crc8ebu
+ * crc8gsm_a
+ * crc8gsm_b
+ * crc8hitag
  * crc8icode
  * crc8itu
+ * crc8lte
  * crc8maxim
+ * crc8mifare_mad
+ * crc8nrsc5
+ * crc8opensafety
  * crc8rohc
+ * crc8sae_j1850
  * crc8wcdma
  * crc10
  * crc10cdma2000
  * crc10gsm
  * crc11
+ * crc11umts
  * crc12
  * crc12cdma2000
  * crc12gsm
+ * crc12umts
  * crc13bbc
  * crc14darc
  * crc14gsm
@@ -63,14 +87,22 @@ For simpler usage, this crate also provides built-in functions for many common C
  * crc16aug_ccitt
  * crc16buypass
  * crc16cdma2000
+ * crc16cms
  * crc16dds_110
  * crc16dect_r
  * crc16dect_x
  * crc16dnp
  * crc16en_13757
  * crc16genibus
+ * crc16gsm
+ * crc16lj1200
+ * crc16m17
  * crc16maxim
  * crc16mcrf4cc
+ * crc16nrsc5
+ * crc16opensafety_a
+ * crc16opensafety_b
+ * crc16profibus
  * crc16riello
  * crc16t10_dif
  * crc16teledisk
@@ -87,27 +119,37 @@ For simpler usage, this crate also provides built-in functions for many common C
  * crc24ble
  * crc24flexray_a
  * crc24flexray_b
+ * crc24interlaken
  * crc24lte_a
  * crc24lte_b
  * crc24os9
  * crc30cdma
+ * crc31philips
  * crc32
    * This is also called `crc32b` in `mhash`.
  * crc32mhash
+ * crc32autosar
    * The `mhash` library has two CRC32 variants named `crc32` and `crc32b`. In this crate, `crc32` matches `crc32b` from `mhash`, and `crc32mhash` matches `crc32` from `mhash`.
  * crc32bzip2
  * crc32c
+ * crc32cdrom_edc
  * crc32d
  * crc32mpeg2
  * crc32posix
  * crc32q
  * crc32jamcrc
+ * crc32mef
  * crc32xfer
  * crc40gsm
  * crc64
  * crc64iso
+ * crc64ms
+ * crc64nvme
  * crc64we
  * crc64jones
+ * crc64redis
+ * crc64xz
+ * crc82darc
 
 For example:
 
@@ -128,7 +170,130 @@ crc64.update(b"hello");
 # }
 ```
 
-After you get a CRC value, you can still call `update` to continue computing the CRC with more input data. The `digest` method is still available as a compatibility wrapper for input types that implement `AsRef<[u8]>`.
+After you get a CRC value, you can still call `update` to continue computing the CRC with more input data. To pass the bytes of an integer or a floating-point number, use the `update_u16_le`, `update_u32_be`, `update_f64_le` methods and so on, which take the byte order explicitly. The `digest` method is still available as a compatibility wrapper for input types that implement `AsRef<[u8]>`.
+
+To suspend a computation, for example to continue it in another process, use the `state` method to get the content of the register, and restore it later with the `set_state` method or the `from_state` associated function. If only the final CRC value of the previous data is available, such as with zlib's `crc32(crc, buf)` convention, use the `resume_from` method or the `resume` associated function instead.
+
+For code ported from the Linux kernel, the `crc32c_raw`, `crc32_le_raw` and `crc32_be_raw` associated functions of `CRCu32`, the `crc16_raw`, `crc_ccitt_raw`, `crc_itu_t_raw` and `crc_t10dif_raw` associated functions of `CRCu16`, and the `crc64_be_raw` associated function of `CRCu64` work on a raw register with a given seed, like `crc32c(seed, data)` of the kernel. No initial value or final xor value is applied.
+
+For messages whose length is not a multiple of 8 bits, such as USB tokens or CAN frames, use the `update_bits` method to pass the low bits of a value, or the `update_bit` method to pass a single bit. The bits are taken in the same order as the bits of bytes: from the least significant bit if the algorithm reflects its input, and from the most significant bit otherwise.
+
+All of the CRC structs can be cloned cheaply, and their `fork` methods copy an instance together with its current state, so messages sharing a common prefix only need to process it once. Two instances are equal if they compute the same algorithm and are in the same state. Use the `is_same_algorithm` method to compare only the algorithms.
+
+To build and check frames with a trailing CRC value, use the `append_crc` method to append the CRC value to a buffer in a given byte order (`Endian::Big` or `Endian::Little`), and the `verify_trailer` method to check a received frame. The `check_residue` method checks a frame which ends with its CRC value in the bit order of the algorithm without splitting it, by comparing the register with the residue of the algorithm, which the `residue` methods of the CRC structs return.
+
+## Algorithm Parameters
+
+The parameters of every built-in CRC function are also available as [`CrcParams`] constants in the [`params`] module, named after the [RevEng catalogue](https://reveng.sourceforge.io/crc-catalogue/). Each constant also records the check value and the residue of the algorithm, so algorithms can be stored and compared as data.
+
+```rust
+use crc_any::{CRC, params::CRC_32_ISCSI};
+
+let mut crc = CRC::from_params(&CRC_32_ISCSI);
+
+crc.update(b"123456789");
+
+assert_eq!(CRC_32_ISCSI.check, crc.get_crc_u128());
+```
+
+Note that the polynomial of a `CrcParams` is always in its normal form, while `create_crc` expects the reflected polynomial when `reflect` is `true`.
+
+Built-in algorithms can also be looked up by their catalogue names or aliases, which is useful when the algorithm comes from a configuration file. The [`registry`] module lists all of them.
+
+```rust
+use crc_any::CRC;
+
+let mut crc = CRC::from_name("CRC-16/MODBUS").unwrap();
+
+crc.update(b"123456789");
+
+assert_eq!(0x4B37, crc.get_crc());
+```
+
+The `table` and `checksum` associated functions of `CRCu8`, `CRCu16`, `CRCu32`, `CRCu64` and `CRCu128` are `const fn`s, so a lookup table or a CRC value can be computed at compile time. A table generated this way can be used with the `from_params_with_table` associated function.
+
+```rust
+use crc_any::{CRCu32, params::CRC_32_ISCSI};
+
+const TABLE: [u32; 256] = CRCu32::table(&CRC_32_ISCSI);
+const ID: u32 = CRCu32::checksum(&CRC_32_ISCSI, b"123456789");
+
+static FLASH_TABLE: [u32; 256] = TABLE;
+
+let mut crc = CRCu32::from_params_with_table(&CRC_32_ISCSI, &FLASH_TABLE);
+
+crc.update(b"123456789");
+
+assert_eq!(ID, crc.get_crc());
+```
+
+## Generic Code
+
+`CRCu8`, `CRCu16`, `CRCu32`, `CRCu64` and `CRCu128` implement the `CrcEngine` trait, so code can be generic over the type of CRC values without going through the `CRC` enum. The `write_crc_be` and `write_crc_le` methods of the trait write a CRC value into a byte buffer without any allocation.
+
+The `preset` module provides a zero-sized marker type for every built-in algorithm with a static lookup table, such as `Crc32IsoHdlc` and `Crc16Modbus`. A `Digest` of such a type only holds the register, and its update loop is specialized for the algorithm at compile time.
+
+```rust
+use crc_any::preset::{Crc16Modbus, Digest};
+
+let mut digest = Digest::<Crc16Modbus>::new();
+
+digest.update(b"123456789");
+
+assert_eq!(0x4B37, digest.get_crc());
+```
+
+## Multiple Algorithms
+
+To compute the CRC values of several algorithms for the same data while reading it only once, use `MultiCrc`, which requires the `alloc` feature. It updates all of its CRC instances from one `update` call, chunk by chunk, and returns the CRC values with their names.
+
+```rust
+# #[cfg(feature = "alloc")]
+# {
+use crc_any::{MultiCrc, params::*};
+
+let mut multi = MultiCrc::from_params(&[&CRC_32_ISO_HDLC, &CRC_32_ISCSI, &CRC_16_XMODEM]);
+
+multi.update(b"123456789");
+
+assert_eq!(("CRC-32/ISCSI", 0xE3069283), multi.results()[1]);
+# }
+```
+
+## One-shot Checksums
+
+The `checksum` module provides a function for every built-in algorithm, named after its name in the catalogue, which computes the CRC value of a buffer without creating a CRC instance. Its `CrcExt` trait adds some of them to byte slices.
+
+```rust
+use crc_any::checksum::{self, CrcExt};
+
+assert_eq!(0x4B37, checksum::crc16_modbus(b"123456789"));
+
+assert_eq!(0xCBF43926, b"123456789".crc32());
+```
+
+## Formatting and Parsing
+
+`CRC`, `CRCu8`, `CRCu16`, `CRCu32`, `CRCu64` and `CRCu128` implement `Display` (such as `0x31C3`), `LowerHex`, `UpperHex`, `Octal` and `Binary` without any feature. The digits are padded with zeros to the length of bits, so the CRC values of an algorithm always have the same length. The `to_hex_array` method writes the hexadecimal digits into a fixed-size array instead, and the `parse_crc` function parses them back, with or without the `0x` prefix.
+
+```rust
+use crc_any::{CRC, parse_crc};
+
+let mut crc = CRC::crc32();
+
+crc.update(b"");
+
+assert_eq!("00000000", format!("{crc:x}"));
+assert_eq!("0x00000000", format!("{crc:#x}"));
+
+crc.update(b"123456789");
+
+let hex = crc.to_hex_array();
+let hex = core::str::from_utf8(&hex[..crc.hex_len()]).unwrap();
+
+assert_eq!("CBF43926", hex);
+assert_eq!(Ok(0xCBF43926), parse_crc(hex, 32));
+```
 
 ## CRC-32C Hardware Acceleration
 
@@ -140,6 +305,31 @@ For builds that only run on CPUs known to support SSE4.2, you can enable that CP
 
 Do not enable these compile-time options for binaries that must run on older `x86` or `x86_64` CPUs without SSE4.2 support.
 
+## Backends
+
+Every instance picks a backend automatically: a bitwise loop for the lengths of bits which are not a multiple of 8, a 256-entry lookup table otherwise, and the SSE4.2 instructions for CRC-32C when the CPU supports them. Use the `backend` method to inspect the choice and the `with_backend` method to select another one. The slicing-by-8 backend, which processes eight bytes at a time with eight lookup tables, is never selected automatically. It is opt-in through `with_backend(Backend::Sliced)` for CRC-32/ISO-HDLC, CRC-32/BZIP2, CRC-32/ISCSI and the CRC-64 algorithms with the ECMA-182 polynomial. Selecting a backend which cannot compute the algorithm returns an `UnavailableBackend` error.
+
+```rust
+use crc_any::{Backend, CRC};
+
+let mut crc = CRC::crc32().with_backend(Backend::Bitwise).unwrap();
+
+crc.update(b"123456789");
+
+assert_eq!(Backend::Bitwise, crc.backend());
+assert_eq!(0xCBF43926, crc.get_crc());
+
+assert!(CRC::crc5usb().with_backend(Backend::Table).is_err());
+```
+
+## Self-test
+
+The `self_test` function checks every built-in algorithm with every backend available on the CPU, by computing the check value of `"123456789"` and verifying the residue, for example as a power-on self-test. It works without the `std` and `alloc` features, and returns a `SelfTestFailure` error with the name of the algorithm, the backend and the check which failed.
+
+```rust
+assert_eq!(Ok(()), crc_any::self_test());
+```
+
 ## No Std and Heapless Support
 
 To make sure this crate does not use heap allocation, disable the default features. This also disables the `std` runtime CPU feature detection path.
@@ -150,7 +340,9 @@ version = "*"
 default-features = false
 ```
 
-After disabling the default features, the `get_crc_vec_be` and `get_crc_vec_le` methods are not available. If you still need this crate to return a vector-like value without dynamic allocation, enable the `heapless` feature and use the `get_crc_heapless_vec_be` and `get_crc_heapless_vec_le` methods.
+After disabling the default features, the `get_crc_vec_be` and `get_crc_vec_le` methods are not available. If you still need this crate to return a vector-like value without dynamic allocation, enable the `heapless` feature and use the `get_crc_heapless_vec_be` and `get_crc_heapless_vec_le` methods (or, for a `CRC` wider than 64 bits, the `get_crc_u128_heapless_vec_be` and `get_crc_u128_heapless_vec_le` methods).
+
+Without any extra feature or dependency, the `get_crc_bytes_be` and `get_crc_bytes_le` methods of `CRCu8`, `CRCu16`, `CRCu32`, `CRCu64` and `CRCu128` return fixed-size arrays, and the `write_crc_be` and `write_crc_le` methods of `CRC` write the CRC value directly into a buffer, such as a frame being built.
 
 ```toml
 [dependencies.crc-any]
@@ -166,37 +358,63 @@ features = ["heapless"]
 #[macro_use]
 extern crate alloc;
 
-#[cfg(feature = "alloc")]
-use alloc::fmt::{self, Display, Formatter};
 #[cfg(feature = "alloc")]
 use alloc::vec::Vec;
+use core::fmt::{self, Binary, Display, Formatter, LowerHex, Octal, UpperHex};
 
 #[cfg(feature = "heapless")]
 use heapless::Vec as HeaplessVec;
 
+mod backend;
+pub mod checksum;
 mod constants;
+mod crc_u128;
 mod crc_u16;
 mod crc_u32;
 mod crc_u64;
 mod crc_u8;
+mod endian;
+mod engine;
+mod errors;
+mod hex;
 mod lookup_table;
-
+#[cfg(feature = "alloc")]
+mod multi;
+pub mod params;
+pub mod preset;
+pub mod registry;
+mod self_test;
+mod table_cache;
+
+pub use backend::Backend;
 pub use crc_u8::CRCu8;
 pub use crc_u16::CRCu16;
 pub use crc_u32::CRCu32;
 pub use crc_u64::CRCu64;
+pub use crc_u128::CRCu128;
+pub use endian::Endian;
+pub use engine::CrcEngine;
+pub use errors::{
+    CrcParamError, CrcParamField, ParseCrcError, SelfTestCheck, SelfTestFailure, UnavailableBackend,
+};
+pub use hex::parse_crc;
+#[cfg(feature = "alloc")]
+pub use multi::MultiCrc;
+pub use params::CrcParams;
+pub use self_test::self_test;
 
 #[allow(clippy::upper_case_acronyms, clippy::large_enum_variant)]
 /// This struct can help you compute a CRC value.
 #[cfg_attr(feature = "alloc", derive(Debug))]
+#[derive(Clone, PartialEq, Eq)]
 pub enum CRC {
     CRCu8(CRCu8),
     CRCu16(CRCu16),
     CRCu32(CRCu32),
     CRCu64(CRCu64),
+    CRCu128(CRCu128),
 }
 
-#[cfg(feature = "alloc")]
 impl Display for CRC {
     #[inline]
     fn fmt(&self, f: &mut Formatter) -> Result<(), fmt::Error> {
@@ -205,27 +423,272 @@ impl Display for CRC {
             CRC::CRCu16(crc) => Display::fmt(crc, f),
             CRC::CRCu32(crc) => Display::fmt(crc, f),
             CRC::CRCu64(crc) => Display::fmt(crc, f),
+            CRC::CRCu128(crc) => Display::fmt(crc, f),
+        }
+    }
+}
+
+impl LowerHex for CRC {
+    #[inline]
+    fn fmt(&self, f: &mut Formatter) -> Result<(), fmt::Error> {
+        match self {
+            CRC::CRCu8(crc) => LowerHex::fmt(crc, f),
+            CRC::CRCu16(crc) => LowerHex::fmt(crc, f),
+            CRC::CRCu32(crc) => LowerHex::fmt(crc, f),
+            CRC::CRCu64(crc) => LowerHex::fmt(crc, f),
+            CRC::CRCu128(crc) => LowerHex::fmt(crc, f),
+        }
+    }
+}
+
+impl UpperHex for CRC {
+    #[inline]
+    fn fmt(&self, f: &mut Formatter) -> Result<(), fmt::Error> {
+        match self {
+            CRC::CRCu8(crc) => UpperHex::fmt(crc, f),
+            CRC::CRCu16(crc) => UpperHex::fmt(crc, f),
+            CRC::CRCu32(crc) => UpperHex::fmt(crc, f),
+            CRC::CRCu64(crc) => UpperHex::fmt(crc, f),
+            CRC::CRCu128(crc) => UpperHex::fmt(crc, f),
+        }
+    }
+}
+
+impl Octal for CRC {
+    #[inline]
+    fn fmt(&self, f: &mut Formatter) -> Result<(), fmt::Error> {
+        match self {
+            CRC::CRCu8(crc) => Octal::fmt(crc, f),
+            CRC::CRCu16(crc) => Octal::fmt(crc, f),
+            CRC::CRCu32(crc) => Octal::fmt(crc, f),
+            CRC::CRCu64(crc) => Octal::fmt(crc, f),
+            CRC::CRCu128(crc) => Octal::fmt(crc, f),
+        }
+    }
+}
+
+impl Binary for CRC {
+    #[inline]
+    fn fmt(&self, f: &mut Formatter) -> Result<(), fmt::Error> {
+        match self {
+            CRC::CRCu8(crc) => Binary::fmt(crc, f),
+            CRC::CRCu16(crc) => Binary::fmt(crc, f),
+            CRC::CRCu32(crc) => Binary::fmt(crc, f),
+            CRC::CRCu64(crc) => Binary::fmt(crc, f),
+            CRC::CRCu128(crc) => Binary::fmt(crc, f),
         }
     }
 }
 
 impl CRC {
     /// Create a CRC instance by providing the length of bits, expression, reflection, an initial value and a final xor value.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `bits` is larger than `128`. Use [`CRC::try_create_crc`] to get an error instead.
     #[inline]
     pub fn create_crc(poly: u64, bits: u8, initial: u64, final_xor: u64, reflect: bool) -> CRC {
+        Self::create_crc_with_reflection(poly, bits, initial, final_xor, reflect, reflect)
+    }
+
+    /// Create a CRC instance by providing the length of bits, expression, input reflection, output reflection, an initial value and a final xor value.
+    ///
+    /// The expression needs to be reflected if `refin` is `true`. For a CRC wider than 64 bits whose expression does not fit in a `u64`, use [`CRC::create_crc_u128`] instead.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `bits` is larger than `128`. Use [`CRC::try_create_crc_with_reflection`] to get an error instead.
+    #[inline]
+    pub fn create_crc_with_reflection(
+        poly: u64,
+        bits: u8,
+        initial: u64,
+        final_xor: u64,
+        refin: bool,
+        refout: bool,
+    ) -> CRC {
+        Self::create_crc_with_reflection_u128(
+            u128::from(poly),
+            bits,
+            u128::from(initial),
+            u128::from(final_xor),
+            refin,
+            refout,
+        )
+    }
+
+    fn create_crc_with_reflection_u128(
+        poly: u128,
+        bits: u8,
+        initial: u128,
+        final_xor: u128,
+        refin: bool,
+        refout: bool,
+    ) -> CRC {
         if bits <= 8 {
-            Self::create_crc_u8(poly as u8, bits, initial as u8, final_xor as u8, reflect)
+            CRC::CRCu8(CRCu8::create_crc_with_reflection(
+                poly as u8,
+                bits,
+                initial as u8,
+                final_xor as u8,
+                refin,
+                refout,
+            ))
         } else if bits <= 16 {
-            Self::create_crc_u16(poly as u16, bits, initial as u16, final_xor as u16, reflect)
+            CRC::CRCu16(CRCu16::create_crc_with_reflection(
+                poly as u16,
+                bits,
+                initial as u16,
+                final_xor as u16,
+                refin,
+                refout,
+            ))
         } else if bits <= 32 {
-            Self::create_crc_u32(poly as u32, bits, initial as u32, final_xor as u32, reflect)
+            CRC::CRCu32(CRCu32::create_crc_with_reflection(
+                poly as u32,
+                bits,
+                initial as u32,
+                final_xor as u32,
+                refin,
+                refout,
+            ))
         } else if bits <= 64 {
-            Self::create_crc_u64(poly, bits, initial, final_xor, reflect)
+            CRC::CRCu64(CRCu64::create_crc_with_reflection(
+                poly as u64,
+                bits,
+                initial as u64,
+                final_xor as u64,
+                refin,
+                refout,
+            ))
         } else {
-            unimplemented!()
+            assert!(bits <= 128, "the length of bits ({bits}) must not be larger than 128");
+
+            CRC::CRCu128(CRCu128::create_crc_with_reflection(
+                poly, bits, initial, final_xor, refin, refout,
+            ))
         }
     }
 
+    /// Set whether the bytes of the CRC value are output in reverse order, like `crc32` of the `mhash` library does. Only the bytes covered by the length of bits are reversed.
+    ///
+    /// ```rust
+    /// use crc_any::CRC;
+    ///
+    /// let mut crc =
+    ///     CRC::create_crc(0x04C11DB7, 32, 0xFFFFFFFF, 0xFFFFFFFF, false)
+    ///         .with_reorder(true);
+    /// crc.update(b"123456789");
+    ///
+    /// assert_eq!(0x181989FC, crc.get_crc());
+    /// ```
+    #[inline]
+    pub fn with_reorder(self, reorder: bool) -> CRC {
+        match self {
+            CRC::CRCu8(crc) => CRC::CRCu8(crc.with_reorder(reorder)),
+            CRC::CRCu16(crc) => CRC::CRCu16(crc.with_reorder(reorder)),
+            CRC::CRCu32(crc) => CRC::CRCu32(crc.with_reorder(reorder)),
+            CRC::CRCu64(crc) => CRC::CRCu64(crc.with_reorder(reorder)),
+            CRC::CRCu128(crc) => CRC::CRCu128(crc.with_reorder(reorder)),
+        }
+    }
+
+    /// Select the backend which processes the input, keeping the current state. An error is returned if the backend is not available for the algorithm or on the CPU. See [`Backend`] for details.
+    ///
+    /// ```rust
+    /// use crc_any::{Backend, CRC};
+    ///
+    /// let mut crc = CRC::crc32().with_backend(Backend::Sliced).unwrap();
+    /// crc.update(b"123456789");
+    ///
+    /// assert_eq!(Backend::Sliced, crc.backend());
+    /// assert_eq!(0xCBF43926, crc.get_crc());
+    ///
+    /// assert!(CRC::crc16().with_backend(Backend::Sliced).is_err());
+    /// ```
+    #[inline]
+    pub fn with_backend(self, backend: Backend) -> Result<CRC, UnavailableBackend> {
+        Ok(match self {
+            CRC::CRCu8(crc) => CRC::CRCu8(crc.with_backend(backend)?),
+            CRC::CRCu16(crc) => CRC::CRCu16(crc.with_backend(backend)?),
+            CRC::CRCu32(crc) => CRC::CRCu32(crc.with_backend(backend)?),
+            CRC::CRCu64(crc) => CRC::CRCu64(crc.with_backend(backend)?),
+            CRC::CRCu128(crc) => CRC::CRCu128(crc.with_backend(backend)?),
+        })
+    }
+
+    /// Get the backend which processes the input.
+    #[inline]
+    pub fn backend(&self) -> Backend {
+        match self {
+            CRC::CRCu8(crc) => crc.backend(),
+            CRC::CRCu16(crc) => crc.backend(),
+            CRC::CRCu32(crc) => crc.backend(),
+            CRC::CRCu64(crc) => crc.backend(),
+            CRC::CRCu128(crc) => crc.backend(),
+        }
+    }
+
+    /// Create a CRC instance from the parameters of a CRC algorithm.
+    #[inline]
+    pub fn from_params(params: &CrcParams) -> CRC {
+        Self::create_crc_with_reflection_u128(
+            params.create_crc_poly(),
+            params.width,
+            params.init,
+            params.xorout,
+            params.refin,
+            params.refout,
+        )
+    }
+
+    /// Create a CRC instance like [`CRC::create_crc`], but return an error instead of creating a broken instance if the parameters are invalid.
+    #[inline]
+    pub fn try_create_crc(
+        poly: u64,
+        bits: u8,
+        initial: u64,
+        final_xor: u64,
+        reflect: bool,
+    ) -> Result<CRC, CrcParamError> {
+        Self::try_create_crc_with_reflection(poly, bits, initial, final_xor, reflect, reflect)
+    }
+
+    /// Create a CRC instance like [`CRC::create_crc_with_reflection`], but return an error instead of creating a broken instance if the parameters are invalid.
+    #[inline]
+    pub fn try_create_crc_with_reflection(
+        poly: u64,
+        bits: u8,
+        initial: u64,
+        final_xor: u64,
+        refin: bool,
+        refout: bool,
+    ) -> Result<CRC, CrcParamError> {
+        errors::check_params(
+            128,
+            bits,
+            u128::from(poly),
+            u128::from(initial),
+            u128::from(final_xor),
+        )?;
+
+        Ok(Self::create_crc_with_reflection(poly, bits, initial, final_xor, refin, refout))
+    }
+
+    /// Create a CRC instance from the parameters of a CRC algorithm, or return an error if the parameters are invalid.
+    #[inline]
+    pub fn try_from_params(params: &CrcParams) -> Result<CRC, CrcParamError> {
+        errors::check_crc_params(128, params)?;
+
+        Ok(Self::from_params(params))
+    }
+
+    /// Create a CRC instance of a built-in algorithm by its name in the RevEng catalogue (e.g. `"CRC-16/MODBUS"`), one of its aliases (e.g. `"X-25"`), or the name of its built-in function (e.g. `"crc32c"`). See the [`registry`] module.
+    #[inline]
+    pub fn from_name(name: &str) -> Option<CRC> {
+        registry::find(name).map(registry::Algorithm::create)
+    }
+
     /// Create a CRC instance by providing the length of bits, expression, reflection, an initial value and a final xor value.
     #[inline]
     pub fn create_crc_u8(poly: u8, bits: u8, initial: u8, final_xor: u8, reflect: bool) -> CRC {
@@ -258,6 +721,20 @@ impl CRC {
         CRC::CRCu64(crc)
     }
 
+    /// Create a CRC instance by providing the length of bits, expression, reflection, an initial value and a final xor value.
+    #[inline]
+    pub fn create_crc_u128(
+        poly: u128,
+        bits: u8,
+        initial: u128,
+        final_xor: u128,
+        reflect: bool,
+    ) -> CRC {
+        let crc = CRCu128::create_crc(poly, bits, initial, final_xor, reflect);
+
+        CRC::CRCu128(crc)
+    }
+
     /// Update the current CRC state with bytes.
     #[inline]
     pub fn update(&mut self, data: &[u8]) {
@@ -266,6 +743,105 @@ impl CRC {
             CRC::CRCu16(crc) => crc.update(data),
             CRC::CRCu32(crc) => crc.update(data),
             CRC::CRCu64(crc) => crc.update(data),
+            CRC::CRCu128(crc) => crc.update(data),
+        }
+    }
+
+    /// Update the current CRC state with the bytes of a `u16` value in little-endian byte order.
+    #[inline]
+    pub fn update_u16_le(&mut self, value: u16) {
+        self.update(&value.to_le_bytes());
+    }
+
+    /// Update the current CRC state with the bytes of a `u16` value in big-endian byte order.
+    #[inline]
+    pub fn update_u16_be(&mut self, value: u16) {
+        self.update(&value.to_be_bytes());
+    }
+
+    /// Update the current CRC state with the bytes of a `u32` value in little-endian byte order.
+    #[inline]
+    pub fn update_u32_le(&mut self, value: u32) {
+        self.update(&value.to_le_bytes());
+    }
+
+    /// Update the current CRC state with the bytes of a `u32` value in big-endian byte order.
+    #[inline]
+    pub fn update_u32_be(&mut self, value: u32) {
+        self.update(&value.to_be_bytes());
+    }
+
+    /// Update the current CRC state with the bytes of a `u64` value in little-endian byte order.
+    #[inline]
+    pub fn update_u64_le(&mut self, value: u64) {
+        self.update(&value.to_le_bytes());
+    }
+
+    /// Update the current CRC state with the bytes of a `u64` value in big-endian byte order.
+    #[inline]
+    pub fn update_u64_be(&mut self, value: u64) {
+        self.update(&value.to_be_bytes());
+    }
+
+    /// Update the current CRC state with the bytes of an `f32` value in little-endian byte order.
+    #[inline]
+    pub fn update_f32_le(&mut self, value: f32) {
+        self.update(&value.to_le_bytes());
+    }
+
+    /// Update the current CRC state with the bytes of an `f32` value in big-endian byte order.
+    #[inline]
+    pub fn update_f32_be(&mut self, value: f32) {
+        self.update(&value.to_be_bytes());
+    }
+
+    /// Update the current CRC state with the bytes of an `f64` value in little-endian byte order.
+    #[inline]
+    pub fn update_f64_le(&mut self, value: f64) {
+        self.update(&value.to_le_bytes());
+    }
+
+    /// Update the current CRC state with the bytes of an `f64` value in big-endian byte order.
+    #[inline]
+    pub fn update_f64_be(&mut self, value: f64) {
+        self.update(&value.to_be_bytes());
+    }
+
+    /// Update the current CRC state with a single bit.
+    #[inline]
+    pub fn update_bit(&mut self, bit: bool) {
+        match self {
+            CRC::CRCu8(crc) => crc.update_bit(bit),
+            CRC::CRCu16(crc) => crc.update_bit(bit),
+            CRC::CRCu32(crc) => crc.update_bit(bit),
+            CRC::CRCu64(crc) => crc.update_bit(bit),
+            CRC::CRCu128(crc) => crc.update_bit(bit),
+        }
+    }
+
+    /// Update the current CRC state with the low `nbits` bits of `value`, from the least significant bit if the input is reflected, and from the most significant bit otherwise. See [`CRCu32::update_bits`] for details.
+    ///
+    /// ```rust
+    /// use crc_any::CRC;
+    ///
+    /// // the 11 bits of the address and the endpoint of a USB token
+    /// let mut crc = CRC::crc5usb();
+    /// crc.update_bits(0x710, 11);
+    ///
+    /// assert_eq!(0x05, crc.get_crc());
+    /// ```
+    ///
+    /// # Panics
+    ///
+    /// Panics if `nbits` is larger than `64`.
+    #[inline]
+    pub fn update_bits(&mut self, value: u64, nbits: u32) {
+        match self {
+            CRC::CRCu8(crc) => crc.update_bits(value, nbits),
+            CRC::CRCu16(crc) => crc.update_bits(value, nbits),
+            CRC::CRCu32(crc) => crc.update_bits(value, nbits),
+            CRC::CRCu64(crc) => crc.update_bits(value, nbits),
+            CRC::CRCu128(crc) => crc.update_bits(value, nbits),
         }
     }
 
@@ -285,10 +861,122 @@ impl CRC {
             CRC::CRCu16(crc) => crc.reset(),
             CRC::CRCu32(crc) => crc.reset(),
             CRC::CRCu64(crc) => crc.reset(),
+            CRC::CRCu128(crc) => crc.reset(),
+        }
+    }
+
+    /// Get the current state of the register (it always returns a `u128` value), which can be restored later with [`CRC::set_state`] or [`CRC::from_state`].
+    ///
+    /// The state is always in normal (unreflected) form and is taken before the output reflection and the final xor value are applied, so it does not depend on how the CRC is computed.
+    #[inline]
+    pub fn state(&self) -> u128 {
+        match self {
+            CRC::CRCu8(crc) => u128::from(crc.state()),
+            CRC::CRCu16(crc) => u128::from(crc.state()),
+            CRC::CRCu32(crc) => u128::from(crc.state()),
+            CRC::CRCu64(crc) => u128::from(crc.state()),
+            CRC::CRCu128(crc) => crc.state(),
+        }
+    }
+
+    /// Restore a state obtained from [`CRC::state`] of an instance with the same parameters. You can continue calling `update` or `digest` after that.
+    #[inline]
+    pub fn set_state(&mut self, state: u128) {
+        match self {
+            CRC::CRCu8(crc) => crc.set_state(state as u8),
+            CRC::CRCu16(crc) => crc.set_state(state as u16),
+            CRC::CRCu32(crc) => crc.set_state(state as u32),
+            CRC::CRCu64(crc) => crc.set_state(state as u64),
+            CRC::CRCu128(crc) => crc.set_state(state),
         }
     }
 
+    /// Create a CRC instance from the parameters of a CRC algorithm and a state obtained from [`CRC::state`].
+    #[inline]
+    pub fn from_state(params: &CrcParams, state: u128) -> CRC {
+        let mut crc = Self::from_params(params);
+
+        crc.set_state(state);
+
+        crc
+    }
+
+    /// Continue from a CRC value returned by [`CRC::get_crc_u128`] (or [`CRC::get_crc`] for a CRC not wider than 64 bits) of an instance with the same parameters, as if the data of that CRC value had been passed to `update` or `digest`.
+    #[inline]
+    pub fn resume_from(&mut self, crc: u128) {
+        match self {
+            CRC::CRCu8(c) => c.resume_from(crc as u8),
+            CRC::CRCu16(c) => c.resume_from(crc as u16),
+            CRC::CRCu32(c) => c.resume_from(crc as u32),
+            CRC::CRCu64(c) => c.resume_from(crc as u64),
+            CRC::CRCu128(c) => c.resume_from(crc),
+        }
+    }
+
+    /// Create a CRC instance from the parameters of a CRC algorithm and a CRC value of some previous data computed with the same parameters. Updating the instance with more data gives the CRC value of the concatenated data.
+    ///
+    /// ```
+    /// use crc_any::{CRC, params::CRC_32_ISO_HDLC};
+    ///
+    /// let mut crc = CRC::crc32();
+    /// crc.update(b"12345");
+    ///
+    /// let mut crc = CRC::resume(&CRC_32_ISO_HDLC, crc.get_crc_u128());
+    /// crc.update(b"6789");
+    ///
+    /// assert_eq!(CRC_32_ISO_HDLC.check, crc.get_crc_u128());
+    /// ```
+    #[inline]
+    pub fn resume(params: &CrcParams, crc: u128) -> CRC {
+        let mut crc_instance = Self::from_params(params);
+
+        crc_instance.resume_from(crc);
+
+        crc_instance
+    }
+
+    /// Check whether this instance and `other` compute the same CRC algorithm, regardless of their current states.
+    #[inline]
+    pub fn is_same_algorithm(&self, other: &CRC) -> bool {
+        match (self, other) {
+            (CRC::CRCu8(a), CRC::CRCu8(b)) => a.is_same_algorithm(b),
+            (CRC::CRCu16(a), CRC::CRCu16(b)) => a.is_same_algorithm(b),
+            (CRC::CRCu32(a), CRC::CRCu32(b)) => a.is_same_algorithm(b),
+            (CRC::CRCu64(a), CRC::CRCu64(b)) => a.is_same_algorithm(b),
+            (CRC::CRCu128(a), CRC::CRCu128(b)) => a.is_same_algorithm(b),
+            _ => false,
+        }
+    }
+
+    /// Create a copy of this instance, including its current state.
+    ///
+    /// ```rust
+    /// use crc_any::CRC;
+    ///
+    /// let mut header = CRC::crc32();
+    /// header.update(b"1234");
+    ///
+    /// let mut a = header.fork();
+    /// a.update(b"56789");
+    ///
+    /// let mut b = header.fork();
+    /// b.update(b"5678");
+    ///
+    /// assert_eq!(0xCBF43926, a.get_crc());
+    /// assert!(a != b);
+    ///
+    /// b.update(b"9");
+    ///
+    /// assert!(a == b);
+    /// ```
+    #[inline]
+    pub fn fork(&self) -> CRC {
+        self.clone()
+    }
+
     /// Get the current CRC value (it always returns a `u64` value). You can continue calling `update` or `digest` even after getting a CRC value.
+    ///
+    /// For a CRC wider than 64 bits, only the low 64 bits are returned. Use [`CRC::get_crc_u128`] to get the whole value.
     #[inline]
     pub fn get_crc(&self) -> u64 {
         match self {
@@ -296,6 +984,184 @@ impl CRC {
             CRC::CRCu16(crc) => u64::from(crc.get_crc()),
             CRC::CRCu32(crc) => u64::from(crc.get_crc()),
             CRC::CRCu64(crc) => crc.get_crc(),
+            CRC::CRCu128(crc) => crc.get_crc() as u64,
+        }
+    }
+
+    /// Get the current CRC value (it always returns a `u128` value). You can continue calling `update` or `digest` even after getting a CRC value.
+    #[inline]
+    pub fn get_crc_u128(&self) -> u128 {
+        match self {
+            CRC::CRCu8(crc) => u128::from(crc.get_crc()),
+            CRC::CRCu16(crc) => u128::from(crc.get_crc()),
+            CRC::CRCu32(crc) => u128::from(crc.get_crc()),
+            CRC::CRCu64(crc) => u128::from(crc.get_crc()),
+            CRC::CRCu128(crc) => crc.get_crc(),
+        }
+    }
+}
+
+impl CRC {
+    /// Get the number of bytes of a CRC value.
+    #[inline]
+    pub fn crc_len(&self) -> usize {
+        match self {
+            CRC::CRCu8(crc) => crc.crc_len(),
+            CRC::CRCu16(crc) => crc.crc_len(),
+            CRC::CRCu32(crc) => crc.crc_len(),
+            CRC::CRCu64(crc) => crc.crc_len(),
+            CRC::CRCu128(crc) => crc.crc_len(),
+        }
+    }
+
+    /// Write the current CRC value into the beginning of `buf` in big-endian byte order, and return the number of bytes written. You can continue calling `update` or `digest` even after getting a CRC value.
+    ///
+    /// ```rust
+    /// use crc_any::CRC;
+    ///
+    /// let mut crc = CRC::crc24();
+    /// crc.update(b"123456789");
+    ///
+    /// let mut frame = [0u8; 8];
+    ///
+    /// assert_eq!(3, crc.write_crc_be(&mut frame));
+    /// assert_eq!([0x21, 0xCF, 0x02], frame[..3]);
+    /// ```
+    ///
+    /// # Panics
+    ///
+    /// Panics if `buf` is shorter than [`CRC::crc_len`].
+    #[inline]
+    pub fn write_crc_be(&self, buf: &mut [u8]) -> usize {
+        match self {
+            CRC::CRCu8(crc) => crc.write_crc_be(buf),
+            CRC::CRCu16(crc) => crc.write_crc_be(buf),
+            CRC::CRCu32(crc) => crc.write_crc_be(buf),
+            CRC::CRCu64(crc) => crc.write_crc_be(buf),
+            CRC::CRCu128(crc) => crc.write_crc_be(buf),
+        }
+    }
+
+    /// Write the current CRC value into the beginning of `buf` in little-endian byte order, and return the number of bytes written. You can continue calling `update` or `digest` even after getting a CRC value.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `buf` is shorter than [`CRC::crc_len`].
+    #[inline]
+    pub fn write_crc_le(&self, buf: &mut [u8]) -> usize {
+        match self {
+            CRC::CRCu8(crc) => crc.write_crc_le(buf),
+            CRC::CRCu16(crc) => crc.write_crc_le(buf),
+            CRC::CRCu32(crc) => crc.write_crc_le(buf),
+            CRC::CRCu64(crc) => crc.write_crc_le(buf),
+            CRC::CRCu128(crc) => crc.write_crc_le(buf),
+        }
+    }
+
+    /// Get the number of hexadecimal digits of a CRC value.
+    #[inline]
+    pub fn hex_len(&self) -> usize {
+        match self {
+            CRC::CRCu8(crc) => crc.hex_len(),
+            CRC::CRCu16(crc) => crc.hex_len(),
+            CRC::CRCu32(crc) => crc.hex_len(),
+            CRC::CRCu64(crc) => crc.hex_len(),
+            CRC::CRCu128(crc) => crc.hex_len(),
+        }
+    }
+
+    /// Get the current CRC value as uppercase hexadecimal digits in ASCII. Its first bytes, as many as [`CRC::hex_len`], hold the digits, and the rest are zero. You can continue calling `update` or `digest` even after getting a CRC value.
+    ///
+    /// ```rust
+    /// use crc_any::CRC;
+    ///
+    /// let mut crc = CRC::crc16xmodem();
+    ///
+    /// crc.update(b"123456789");
+    ///
+    /// let hex = crc.to_hex_array();
+    ///
+    /// assert_eq!(Ok("31C3"), core::str::from_utf8(&hex[..crc.hex_len()]));
+    /// ```
+    #[inline]
+    pub fn to_hex_array(&self) -> [u8; 32] {
+        let mut hex = [0u8; 32];
+
+        match self {
+            CRC::CRCu8(crc) => crc.write_crc_hex(&mut hex),
+            CRC::CRCu16(crc) => crc.write_crc_hex(&mut hex),
+            CRC::CRCu32(crc) => crc.write_crc_hex(&mut hex),
+            CRC::CRCu64(crc) => crc.write_crc_hex(&mut hex),
+            CRC::CRCu128(crc) => crc.write_crc_hex(&mut hex),
+        };
+
+        hex
+    }
+}
+
+impl CRC {
+    /// Append the current CRC value to `buf` in the given byte order, using as many bytes as the CRC bits need.
+    ///
+    /// ```rust
+    /// use crc_any::{CRC, Endian};
+    ///
+    /// let mut frame = b"123456789".to_vec();
+    ///
+    /// let mut crc = CRC::crc16modbus();
+    /// crc.update(&frame);
+    /// crc.append_crc(&mut frame, Endian::Little);
+    ///
+    /// assert_eq!(b"123456789\x37\x4B", frame.as_slice());
+    ///
+    /// let crc = CRC::crc16modbus();
+    ///
+    /// assert!(crc.verify_trailer(&frame, Endian::Little));
+    /// assert!(crc.check_residue(&frame));
+    /// ```
+    #[inline]
+    pub fn append_crc(&self, buf: &mut impl Extend<u8>, endian: Endian) {
+        match self {
+            CRC::CRCu8(crc) => crc.append_crc(buf, endian),
+            CRC::CRCu16(crc) => crc.append_crc(buf, endian),
+            CRC::CRCu32(crc) => crc.append_crc(buf, endian),
+            CRC::CRCu64(crc) => crc.append_crc(buf, endian),
+            CRC::CRCu128(crc) => crc.append_crc(buf, endian),
+        }
+    }
+
+    /// Check whether `data_with_crc` ends with the CRC value of the data before it, in the given byte order. The data is computed from the current state, which is not changed.
+    #[inline]
+    pub fn verify_trailer(&self, data_with_crc: &[u8], endian: Endian) -> bool {
+        match self {
+            CRC::CRCu8(crc) => crc.verify_trailer(data_with_crc, endian),
+            CRC::CRCu16(crc) => crc.verify_trailer(data_with_crc, endian),
+            CRC::CRCu32(crc) => crc.verify_trailer(data_with_crc, endian),
+            CRC::CRCu64(crc) => crc.verify_trailer(data_with_crc, endian),
+            CRC::CRCu128(crc) => crc.verify_trailer(data_with_crc, endian),
+        }
+    }
+
+    /// Check whether `data_with_crc` is an error-free codeword by comparing the register with the residue of the algorithm. See [`CRCu32::check_residue`] for details.
+    #[inline]
+    pub fn check_residue(&self, data_with_crc: &[u8]) -> bool {
+        match self {
+            CRC::CRCu8(crc) => crc.check_residue(data_with_crc),
+            CRC::CRCu16(crc) => crc.check_residue(data_with_crc),
+            CRC::CRCu32(crc) => crc.check_residue(data_with_crc),
+            CRC::CRCu64(crc) => crc.check_residue(data_with_crc),
+            CRC::CRCu128(crc) => crc.check_residue(data_with_crc),
+        }
+    }
+
+    /// Get the residue of the algorithm. See [`CRCu32::residue`] for details.
+    #[inline]
+    pub fn residue(&self) -> u128 {
+        match self {
+            CRC::CRCu8(crc) => u128::from(crc.residue()),
+            CRC::CRCu16(crc) => u128::from(crc.residue()),
+            CRC::CRCu32(crc) => u128::from(crc.residue()),
+            CRC::CRCu64(crc) => u128::from(crc.residue()),
+            CRC::CRCu128(crc) => crc.residue(),
         }
     }
 }
@@ -310,6 +1176,7 @@ impl CRC {
             CRC::CRCu16(crc) => crc.get_crc_vec_le(),
             CRC::CRCu32(crc) => crc.get_crc_vec_le(),
             CRC::CRCu64(crc) => crc.get_crc_vec_le(),
+            CRC::CRCu128(crc) => crc.get_crc_vec_le(),
         }
     }
 
@@ -321,6 +1188,7 @@ impl CRC {
             CRC::CRCu16(crc) => crc.get_crc_vec_be(),
             CRC::CRCu32(crc) => crc.get_crc_vec_be(),
             CRC::CRCu64(crc) => crc.get_crc_vec_be(),
+            CRC::CRCu128(crc) => crc.get_crc_vec_be(),
         }
     }
 }
@@ -328,17 +1196,12 @@ impl CRC {
 #[cfg(feature = "heapless")]
 impl CRC {
     /// Get the current CRC value (it always returns a vec instance with a length corresponding to the CRC bits). You can continue calling `update` or `digest` even after getting a CRC value.
+    ///
+    /// For a CRC wider than 64 bits, only the low 64 bits are returned, like [`CRC::get_crc`]. Use [`CRC::get_crc_u128_heapless_vec_le`] to get the whole value.
     pub fn get_crc_heapless_vec_le(&self) -> HeaplessVec<u8, 8, u8> {
         let mut vec = HeaplessVec::new();
 
-        let bits = match self {
-            CRC::CRCu8(crc) => crc.bits,
-            CRC::CRCu16(crc) => crc.bits,
-            CRC::CRCu32(crc) => crc.bits,
-            CRC::CRCu64(crc) => crc.bits,
-        };
-
-        let e = usize::from(bits).div_ceil(8);
+        let e = self.crc_len().min(8);
 
         let e_dec = e - 1;
 
@@ -354,17 +1217,12 @@ impl CRC {
     }
 
     /// Get the current CRC value (it always returns a vec instance with a length corresponding to the CRC bits). You can continue calling `update` or `digest` even after getting a CRC value.
+    ///
+    /// For a CRC wider than 64 bits, only the low 64 bits are returned, like [`CRC::get_crc`]. Use [`CRC::get_crc_u128_heapless_vec_be`] to get the whole value.
     pub fn get_crc_heapless_vec_be(&self) -> HeaplessVec<u8, 8, u8> {
         let mut vec = HeaplessVec::new();
 
-        let bits = match self {
-            CRC::CRCu8(crc) => crc.bits,
-            CRC::CRCu16(crc) => crc.bits,
-            CRC::CRCu32(crc) => crc.bits,
-            CRC::CRCu64(crc) => crc.bits,
-        };
-
-        let e = usize::from(bits).div_ceil(8);
+        let e = self.crc_len().min(8);
 
         let e_dec = e - 1;
 
@@ -378,6 +1236,28 @@ impl CRC {
 
         vec
     }
+
+    /// Get the current CRC value, including CRCs wider than 64 bits (it always returns a vec instance with a length corresponding to the CRC bits). You can continue calling `update` or `digest` even after getting a CRC value.
+    pub fn get_crc_u128_heapless_vec_le(&self) -> HeaplessVec<u8, 16, u8> {
+        let mut vec = HeaplessVec::new();
+
+        let e = self.crc_len();
+
+        vec.extend_from_slice(&self.get_crc_u128().to_le_bytes()[..e]).unwrap();
+
+        vec
+    }
+
+    /// Get the current CRC value, including CRCs wider than 64 bits (it always returns a vec instance with a length corresponding to the CRC bits). You can continue calling `update` or `digest` even after getting a CRC value.
+    pub fn get_crc_u128_heapless_vec_be(&self) -> HeaplessVec<u8, 16, u8> {
+        let mut vec = HeaplessVec::new();
+
+        let e = self.crc_len();
+
+        vec.extend_from_slice(&self.get_crc_u128().to_be_bytes()[(16 - e)..]).unwrap();
+
+        vec
+    }
 }
 
 impl CRC {
@@ -397,6 +1277,21 @@ impl CRC {
         CRC::CRCu8(CRCu8::crc3gsm())
     }
 
+    /// |Check|Poly|Init|Ref|XorOut|
+    /// |---|---|---|---|---|
+    /// |0x6|0x3 (rev: 0x6)|0x7|true|0x0|
+    ///
+    /// ```
+    /// # use crc_any::CRC;
+    /// let mut crc = CRC::crc3rohc();
+    /// crc.digest(b"123456789");
+    #[cfg_attr(feature = "alloc", doc = "assert_eq!(\"0x6\", &crc.to_string());")]
+    /// ```
+    #[inline]
+    pub fn crc3rohc() -> CRC {
+        CRC::CRCu8(CRCu8::crc3rohc())
+    }
+
     // TODO: CRC-4
 
     /// |Check|Poly|Init|Ref|XorOut|
@@ -565,6 +1460,21 @@ impl CRC {
         CRC::CRCu8(CRCu8::crc7())
     }
 
+    /// |Check|Poly|Init|Ref|XorOut|
+    /// |---|---|---|---|---|
+    /// |0x53|0x4F (rev: 0x79)|0x7F|true|0x00|
+    ///
+    /// ```
+    /// # use crc_any::CRC;
+    /// let mut crc = CRC::crc7rohc();
+    /// crc.digest(b"123456789");
+    #[cfg_attr(feature = "alloc", doc = "assert_eq!(\"0x53\", &crc.to_string());")]
+    /// ```
+    #[inline]
+    pub fn crc7rohc() -> CRC {
+        CRC::CRCu8(CRCu8::crc7rohc())
+    }
+
     /// |Check|Poly|Init|Ref|XorOut|
     /// |---|---|---|---|---|
     /// |0x61|0x45|0x00|false|0x00|
@@ -597,6 +1507,36 @@ impl CRC {
         CRC::CRCu8(CRCu8::crc8())
     }
 
+    /// |Check|Poly|Init|Ref|XorOut|
+    /// |---|---|---|---|---|
+    /// |0xDF|0x2F|0xFF|false|0xFF|
+    ///
+    /// ```
+    /// # use crc_any::CRC;
+    /// let mut crc = CRC::crc8autosar();
+    /// crc.digest(b"123456789");
+    #[cfg_attr(feature = "alloc", doc = "assert_eq!(\"0xDF\", &crc.to_string());")]
+    /// ```
+    #[inline]
+    pub fn crc8autosar() -> CRC {
+        CRC::CRCu8(CRCu8::crc8autosar())
+    }
+
+    /// |Check|Poly|Init|Ref|XorOut|
+    /// |---|---|---|---|---|
+    /// |0x26|0xA7 (rev: 0xE5)|0x00|true|0x00|
+    ///
+    /// ```
+    /// # use crc_any::CRC;
+    /// let mut crc = CRC::crc8bluetooth();
+    /// crc.digest(b"123456789");
+    #[cfg_attr(feature = "alloc", doc = "assert_eq!(\"0x26\", &crc.to_string());")]
+    /// ```
+    #[inline]
+    pub fn crc8bluetooth() -> CRC {
+        CRC::CRCu8(CRCu8::crc8bluetooth())
+    }
+
     /// |Check|Poly|Init|Ref|XorOut|
     /// |---|---|---|---|---|
     /// |0xDA|0x9B|0xFF|false|0x00|
@@ -608,98 +1548,203 @@ impl CRC {
     #[cfg_attr(feature = "alloc", doc = "assert_eq!(\"0xDA\", &crc.to_string());")]
     /// ```
     #[inline]
-    pub fn crc8cdma2000() -> CRC {
-        CRC::CRCu8(CRCu8::crc8cdma2000())
+    pub fn crc8cdma2000() -> CRC {
+        CRC::CRCu8(CRCu8::crc8cdma2000())
+    }
+
+    /// |Check|Poly|Init|Ref|XorOut|
+    /// |---|---|---|---|---|
+    /// |0xDA|0x39 (rev: 0x9C)|0x00|true|0x00|
+    ///
+    /// ```
+    /// # use crc_any::CRC;
+    /// let mut crc = CRC::crc8darc();
+    /// crc.digest(b"123456789");
+    #[cfg_attr(feature = "alloc", doc = "assert_eq!(\"0x15\", &crc.to_string());")]
+    /// ```
+    #[inline]
+    pub fn crc8darc() -> CRC {
+        CRC::CRCu8(CRCu8::crc8darc())
+    }
+
+    /// |Check|Poly|Init|Ref|XorOut|
+    /// |---|---|---|---|---|
+    /// |0xBC|0xD5|0x00|false|0x00|
+    ///
+    /// ```
+    /// # use crc_any::CRC;
+    /// let mut crc = CRC::crc8dvb_s2();
+    /// crc.digest(b"123456789");
+    #[cfg_attr(feature = "alloc", doc = "assert_eq!(\"0xBC\", &crc.to_string());")]
+    /// ```
+    #[inline]
+    pub fn crc8dvb_s2() -> CRC {
+        CRC::CRCu8(CRCu8::crc8dvb_s2())
+    }
+
+    /// |Check|Poly|Init|Ref|XorOut|
+    /// |---|---|---|---|---|
+    /// |0x97|0x1D (rev: 0xB8)|0xFF|true|0x00|
+    ///
+    /// ```
+    /// # use crc_any::CRC;
+    /// let mut crc = CRC::crc8ebu();
+    /// crc.digest(b"123456789");
+    #[cfg_attr(feature = "alloc", doc = "assert_eq!(\"0x97\", &crc.to_string());")]
+    /// ```
+    #[inline]
+    pub fn crc8ebu() -> CRC {
+        CRC::CRCu8(CRCu8::crc8ebu())
+    }
+
+    /// |Check|Poly|Init|Ref|XorOut|
+    /// |---|---|---|---|---|
+    /// |0x37|0x1D|0x00|false|0x00|
+    ///
+    /// ```
+    /// # use crc_any::CRC;
+    /// let mut crc = CRC::crc8gsm_a();
+    /// crc.digest(b"123456789");
+    #[cfg_attr(feature = "alloc", doc = "assert_eq!(\"0x37\", &crc.to_string());")]
+    /// ```
+    #[inline]
+    pub fn crc8gsm_a() -> CRC {
+        CRC::CRCu8(CRCu8::crc8gsm_a())
+    }
+
+    /// |Check|Poly|Init|Ref|XorOut|
+    /// |---|---|---|---|---|
+    /// |0x94|0x49|0x00|false|0xFF|
+    ///
+    /// ```
+    /// # use crc_any::CRC;
+    /// let mut crc = CRC::crc8gsm_b();
+    /// crc.digest(b"123456789");
+    #[cfg_attr(feature = "alloc", doc = "assert_eq!(\"0x94\", &crc.to_string());")]
+    /// ```
+    #[inline]
+    pub fn crc8gsm_b() -> CRC {
+        CRC::CRCu8(CRCu8::crc8gsm_b())
+    }
+
+    /// |Check|Poly|Init|Ref|XorOut|
+    /// |---|---|---|---|---|
+    /// |0xB4|0x1D|0xFF|false|0x00|
+    ///
+    /// ```
+    /// # use crc_any::CRC;
+    /// let mut crc = CRC::crc8hitag();
+    /// crc.digest(b"123456789");
+    #[cfg_attr(feature = "alloc", doc = "assert_eq!(\"0xB4\", &crc.to_string());")]
+    /// ```
+    #[inline]
+    pub fn crc8hitag() -> CRC {
+        CRC::CRCu8(CRCu8::crc8hitag())
+    }
+
+    /// |Check|Poly|Init|Ref|XorOut|
+    /// |---|---|---|---|---|
+    /// |0x7E|0x1D|0xFD|false|0x00|
+    ///
+    /// ```
+    /// # use crc_any::CRC;
+    /// let mut crc = CRC::crc8icode();
+    /// crc.digest(b"123456789");
+    #[cfg_attr(feature = "alloc", doc = "assert_eq!(\"0x7E\", &crc.to_string());")]
+    /// ```
+    #[inline]
+    pub fn crc8icode() -> CRC {
+        CRC::CRCu8(CRCu8::crc8icode())
     }
 
     /// |Check|Poly|Init|Ref|XorOut|
     /// |---|---|---|---|---|
-    /// |0xDA|0x39 (rev: 0x9C)|0x00|true|0x00|
+    /// |0xA1|0x07|0x00|false|0x55|
     ///
     /// ```
     /// # use crc_any::CRC;
-    /// let mut crc = CRC::crc8darc();
+    /// let mut crc = CRC::crc8itu();
     /// crc.digest(b"123456789");
-    #[cfg_attr(feature = "alloc", doc = "assert_eq!(\"0x15\", &crc.to_string());")]
+    #[cfg_attr(feature = "alloc", doc = "assert_eq!(\"0xA1\", &crc.to_string());")]
     /// ```
     #[inline]
-    pub fn crc8darc() -> CRC {
-        CRC::CRCu8(CRCu8::crc8darc())
+    pub fn crc8itu() -> CRC {
+        CRC::CRCu8(CRCu8::crc8itu())
     }
 
     /// |Check|Poly|Init|Ref|XorOut|
     /// |---|---|---|---|---|
-    /// |0xBC|0xD5|0x00|false|0x00|
+    /// |0xEA|0x9B|0x00|false|0x00|
     ///
     /// ```
     /// # use crc_any::CRC;
-    /// let mut crc = CRC::crc8dvb_s2();
+    /// let mut crc = CRC::crc8lte();
     /// crc.digest(b"123456789");
-    #[cfg_attr(feature = "alloc", doc = "assert_eq!(\"0xBC\", &crc.to_string());")]
+    #[cfg_attr(feature = "alloc", doc = "assert_eq!(\"0xEA\", &crc.to_string());")]
     /// ```
     #[inline]
-    pub fn crc8dvb_s2() -> CRC {
-        CRC::CRCu8(CRCu8::crc8dvb_s2())
+    pub fn crc8lte() -> CRC {
+        CRC::CRCu8(CRCu8::crc8lte())
     }
 
     /// |Check|Poly|Init|Ref|XorOut|
     /// |---|---|---|---|---|
-    /// |0x97|0x1D (rev: 0xB8)|0xFF|true|0x00|
+    /// |0xA1|0x31 (rev: 0x8C)|0x00|true|0x00|
     ///
     /// ```
     /// # use crc_any::CRC;
-    /// let mut crc = CRC::crc8ebu();
+    /// let mut crc = CRC::crc8maxim();
     /// crc.digest(b"123456789");
-    #[cfg_attr(feature = "alloc", doc = "assert_eq!(\"0x97\", &crc.to_string());")]
+    #[cfg_attr(feature = "alloc", doc = "assert_eq!(\"0xA1\", &crc.to_string());")]
     /// ```
     #[inline]
-    pub fn crc8ebu() -> CRC {
-        CRC::CRCu8(CRCu8::crc8ebu())
+    pub fn crc8maxim() -> CRC {
+        CRC::CRCu8(CRCu8::crc8maxim())
     }
 
     /// |Check|Poly|Init|Ref|XorOut|
     /// |---|---|---|---|---|
-    /// |0x7E|0x1D|0xFD|false|0x00|
+    /// |0x99|0x1D|0xC7|false|0x00|
     ///
     /// ```
     /// # use crc_any::CRC;
-    /// let mut crc = CRC::crc8icode();
+    /// let mut crc = CRC::crc8mifare_mad();
     /// crc.digest(b"123456789");
-    #[cfg_attr(feature = "alloc", doc = "assert_eq!(\"0x7E\", &crc.to_string());")]
+    #[cfg_attr(feature = "alloc", doc = "assert_eq!(\"0x99\", &crc.to_string());")]
     /// ```
     #[inline]
-    pub fn crc8icode() -> CRC {
-        CRC::CRCu8(CRCu8::crc8icode())
+    pub fn crc8mifare_mad() -> CRC {
+        CRC::CRCu8(CRCu8::crc8mifare_mad())
     }
 
     /// |Check|Poly|Init|Ref|XorOut|
     /// |---|---|---|---|---|
-    /// |0xA1|0x07|0x00|false|0x55|
+    /// |0xF7|0x31|0xFF|false|0x00|
     ///
     /// ```
     /// # use crc_any::CRC;
-    /// let mut crc = CRC::crc8itu();
+    /// let mut crc = CRC::crc8nrsc5();
     /// crc.digest(b"123456789");
-    #[cfg_attr(feature = "alloc", doc = "assert_eq!(\"0xA1\", &crc.to_string());")]
+    #[cfg_attr(feature = "alloc", doc = "assert_eq!(\"0xF7\", &crc.to_string());")]
     /// ```
     #[inline]
-    pub fn crc8itu() -> CRC {
-        CRC::CRCu8(CRCu8::crc8itu())
+    pub fn crc8nrsc5() -> CRC {
+        CRC::CRCu8(CRCu8::crc8nrsc5())
     }
 
     /// |Check|Poly|Init|Ref|XorOut|
     /// |---|---|---|---|---|
-    /// |0xA1|0x31 (rev: 0x8C)|0x00|true|0x00|
+    /// |0x3E|0x2F|0x00|false|0x00|
     ///
     /// ```
     /// # use crc_any::CRC;
-    /// let mut crc = CRC::crc8maxim();
+    /// let mut crc = CRC::crc8opensafety();
     /// crc.digest(b"123456789");
-    #[cfg_attr(feature = "alloc", doc = "assert_eq!(\"0xA1\", &crc.to_string());")]
+    #[cfg_attr(feature = "alloc", doc = "assert_eq!(\"0x3E\", &crc.to_string());")]
     /// ```
     #[inline]
-    pub fn crc8maxim() -> CRC {
-        CRC::CRCu8(CRCu8::crc8maxim())
+    pub fn crc8opensafety() -> CRC {
+        CRC::CRCu8(CRCu8::crc8opensafety())
     }
 
     /// |Check|Poly|Init|Ref|XorOut|
@@ -717,6 +1762,21 @@ impl CRC {
         CRC::CRCu8(CRCu8::crc8rohc())
     }
 
+    /// |Check|Poly|Init|Ref|XorOut|
+    /// |---|---|---|---|---|
+    /// |0x4B|0x1D|0xFF|false|0xFF|
+    ///
+    /// ```
+    /// # use crc_any::CRC;
+    /// let mut crc = CRC::crc8sae_j1850();
+    /// crc.digest(b"123456789");
+    #[cfg_attr(feature = "alloc", doc = "assert_eq!(\"0x4B\", &crc.to_string());")]
+    /// ```
+    #[inline]
+    pub fn crc8sae_j1850() -> CRC {
+        CRC::CRCu8(CRCu8::crc8sae_j1850())
+    }
+
     /// |Check|Poly|Init|Ref|XorOut|
     /// |---|---|---|---|---|
     /// |0x25|0x9B (rev: 0xD9)|0x00|true|0x00|
@@ -796,6 +1856,21 @@ impl CRC {
         CRC::CRCu16(CRCu16::crc11())
     }
 
+    /// |Check|Poly|Init|Ref|XorOut|
+    /// |---|---|---|---|---|
+    /// |0x061|0x307|0x000|false|0x000|
+    ///
+    /// ```
+    /// # use crc_any::CRC;
+    /// let mut crc = CRC::crc11umts();
+    /// crc.digest(b"123456789");
+    #[cfg_attr(feature = "alloc", doc = "assert_eq!(\"0x061\", &crc.to_string());")]
+    /// ```
+    #[inline]
+    pub fn crc11umts() -> CRC {
+        CRC::CRCu16(CRCu16::crc11umts())
+    }
+
     // TODO: CRC-12
 
     /// |Check|Poly|Init|Ref|XorOut|
@@ -843,6 +1918,21 @@ impl CRC {
         CRC::CRCu16(CRCu16::crc12gsm())
     }
 
+    /// |Check|Poly|Init|Ref|XorOut|
+    /// |---|---|---|---|---|
+    /// |0xDAF|0x80F|0x000|in: false, out: true|0x000|
+    ///
+    /// ```
+    /// # use crc_any::CRC;
+    /// let mut crc = CRC::crc12umts();
+    /// crc.digest(b"123456789");
+    #[cfg_attr(feature = "alloc", doc = "assert_eq!(\"0xDAF\", &crc.to_string());")]
+    /// ```
+    #[inline]
+    pub fn crc12umts() -> CRC {
+        CRC::CRCu16(CRCu16::crc12umts())
+    }
+
     // TODO: CRC-13
 
     /// |Check|Poly|Init|Ref|XorOut|
@@ -1001,6 +2091,21 @@ impl CRC {
         CRC::CRCu16(CRCu16::crc16cdma2000())
     }
 
+    /// |Check|Poly|Init|Ref|XorOut|
+    /// |---|---|---|---|---|
+    /// |0xAEE7|0x8005|0xFFFF|false|0x0000|
+    ///
+    /// ```
+    /// # use crc_any::CRC;
+    /// let mut crc = CRC::crc16cms();
+    /// crc.digest(b"123456789");
+    #[cfg_attr(feature = "alloc", doc = "assert_eq!(\"0xAEE7\", &crc.to_string());")]
+    /// ```
+    #[inline]
+    pub fn crc16cms() -> CRC {
+        CRC::CRCu16(CRCu16::crc16cms())
+    }
+
     /// |Check|Poly|Init|Ref|XorOut|
     /// |---|---|---|---|---|
     /// |0x9ECF|0x8005|0x800D|false|0x0000|
@@ -1091,6 +2196,51 @@ impl CRC {
         CRC::CRCu16(CRCu16::crc16genibus())
     }
 
+    /// |Check|Poly|Init|Ref|XorOut|
+    /// |---|---|---|---|---|
+    /// |0xCE3C|0x1021|0x0000|false|0xFFFF|
+    ///
+    /// ```
+    /// # use crc_any::CRC;
+    /// let mut crc = CRC::crc16gsm();
+    /// crc.digest(b"123456789");
+    #[cfg_attr(feature = "alloc", doc = "assert_eq!(\"0xCE3C\", &crc.to_string());")]
+    /// ```
+    #[inline]
+    pub fn crc16gsm() -> CRC {
+        CRC::CRCu16(CRCu16::crc16gsm())
+    }
+
+    /// |Check|Poly|Init|Ref|XorOut|
+    /// |---|---|---|---|---|
+    /// |0xBDF4|0x6F63|0x0000|false|0x0000|
+    ///
+    /// ```
+    /// # use crc_any::CRC;
+    /// let mut crc = CRC::crc16lj1200();
+    /// crc.digest(b"123456789");
+    #[cfg_attr(feature = "alloc", doc = "assert_eq!(\"0xBDF4\", &crc.to_string());")]
+    /// ```
+    #[inline]
+    pub fn crc16lj1200() -> CRC {
+        CRC::CRCu16(CRCu16::crc16lj1200())
+    }
+
+    /// |Check|Poly|Init|Ref|XorOut|
+    /// |---|---|---|---|---|
+    /// |0x772B|0x5935|0xFFFF|false|0x0000|
+    ///
+    /// ```
+    /// # use crc_any::CRC;
+    /// let mut crc = CRC::crc16m17();
+    /// crc.digest(b"123456789");
+    #[cfg_attr(feature = "alloc", doc = "assert_eq!(\"0x772B\", &crc.to_string());")]
+    /// ```
+    #[inline]
+    pub fn crc16m17() -> CRC {
+        CRC::CRCu16(CRCu16::crc16m17())
+    }
+
     /// |Check|Poly|Init|Ref|XorOut|
     /// |---|---|---|---|---|
     /// |0x44C2|0x8005 (rev: 0xA001)|0xFFFF|true|0xFFFF|
@@ -1121,6 +2271,66 @@ impl CRC {
         CRC::CRCu16(CRCu16::crc16mcrf4cc())
     }
 
+    /// |Check|Poly|Init|Ref|XorOut|
+    /// |---|---|---|---|---|
+    /// |0xA066|0x080B (rev: 0xD010)|0xFFFF|true|0x0000|
+    ///
+    /// ```
+    /// # use crc_any::CRC;
+    /// let mut crc = CRC::crc16nrsc5();
+    /// crc.digest(b"123456789");
+    #[cfg_attr(feature = "alloc", doc = "assert_eq!(\"0xA066\", &crc.to_string());")]
+    /// ```
+    #[inline]
+    pub fn crc16nrsc5() -> CRC {
+        CRC::CRCu16(CRCu16::crc16nrsc5())
+    }
+
+    /// |Check|Poly|Init|Ref|XorOut|
+    /// |---|---|---|---|---|
+    /// |0x5D38|0x5935|0x0000|false|0x0000|
+    ///
+    /// ```
+    /// # use crc_any::CRC;
+    /// let mut crc = CRC::crc16opensafety_a();
+    /// crc.digest(b"123456789");
+    #[cfg_attr(feature = "alloc", doc = "assert_eq!(\"0x5D38\", &crc.to_string());")]
+    /// ```
+    #[inline]
+    pub fn crc16opensafety_a() -> CRC {
+        CRC::CRCu16(CRCu16::crc16opensafety_a())
+    }
+
+    /// |Check|Poly|Init|Ref|XorOut|
+    /// |---|---|---|---|---|
+    /// |0x20FE|0x755B|0x0000|false|0x0000|
+    ///
+    /// ```
+    /// # use crc_any::CRC;
+    /// let mut crc = CRC::crc16opensafety_b();
+    /// crc.digest(b"123456789");
+    #[cfg_attr(feature = "alloc", doc = "assert_eq!(\"0x20FE\", &crc.to_string());")]
+    /// ```
+    #[inline]
+    pub fn crc16opensafety_b() -> CRC {
+        CRC::CRCu16(CRCu16::crc16opensafety_b())
+    }
+
+    /// |Check|Poly|Init|Ref|XorOut|
+    /// |---|---|---|---|---|
+    /// |0xA819|0x1DCF|0xFFFF|false|0xFFFF|
+    ///
+    /// ```
+    /// # use crc_any::CRC;
+    /// let mut crc = CRC::crc16profibus();
+    /// crc.digest(b"123456789");
+    #[cfg_attr(feature = "alloc", doc = "assert_eq!(\"0xA819\", &crc.to_string());")]
+    /// ```
+    #[inline]
+    pub fn crc16profibus() -> CRC {
+        CRC::CRCu16(CRCu16::crc16profibus())
+    }
+
     /// |Check|Poly|Init|Ref|XorOut|
     /// |---|---|---|---|---|
     /// |0x63D0|0x1021 (rev: 0x8408)|0xB2AA|true|0x0000|
@@ -1367,6 +2577,21 @@ impl CRC {
         CRC::CRCu32(CRCu32::crc24flexray_b())
     }
 
+    /// |Check|Poly|Init|Ref|XorOut|
+    /// |---|---|---|---|---|
+    /// |0xB4F3E6|0x328B63|0xFFFFFF|false|0xFFFFFF|
+    ///
+    /// ```
+    /// # use crc_any::CRC;
+    /// let mut crc = CRC::crc24interlaken();
+    /// crc.digest(b"123456789");
+    #[cfg_attr(feature = "alloc", doc = "assert_eq!(\"0xB4F3E6\", &crc.to_string());")]
+    /// ```
+    #[inline]
+    pub fn crc24interlaken() -> CRC {
+        CRC::CRCu32(CRCu32::crc24interlaken())
+    }
+
     /// |Check|Poly|Init|Ref|XorOut|
     /// |---|---|---|---|---|
     /// |0xCDE703|0x864CFB|0x000000|false|0x000000|
@@ -1429,6 +2654,21 @@ impl CRC {
         CRC::CRCu32(CRCu32::crc30cdma())
     }
 
+    /// |Check|Poly|Init|Ref|XorOut|
+    /// |---|---|---|---|---|
+    /// |0x0CE9E46C|0x04C11DB7|0x7FFFFFFF|false|0x7FFFFFFF|
+    ///
+    /// ```
+    /// # use crc_any::CRC;
+    /// let mut crc = CRC::crc31philips();
+    /// crc.digest(b"123456789");
+    #[cfg_attr(feature = "alloc", doc = "assert_eq!(\"0x0CE9E46C\", &crc.to_string());")]
+    /// ```
+    #[inline]
+    pub fn crc31philips() -> CRC {
+        CRC::CRCu32(CRCu32::crc31philips())
+    }
+
     // TODO: CRC-32
 
     /// |Check|Poly|Init|Ref|XorOut|
@@ -1463,6 +2703,21 @@ impl CRC {
         CRC::CRCu32(CRCu32::crc32mhash())
     }
 
+    /// |Check|Poly|Init|Ref|XorOut|
+    /// |---|---|---|---|---|
+    /// |0x1697D06A|0xF4ACFB13 (rev: 0xC8DF352F)|0xFFFFFFFF|true|0xFFFFFFFF|
+    ///
+    /// ```
+    /// # use crc_any::CRC;
+    /// let mut crc = CRC::crc32autosar();
+    /// crc.digest(b"123456789");
+    #[cfg_attr(feature = "alloc", doc = "assert_eq!(\"0x1697D06A\", &crc.to_string());")]
+    /// ```
+    #[inline]
+    pub fn crc32autosar() -> CRC {
+        CRC::CRCu32(CRCu32::crc32autosar())
+    }
+
     /// |Check|Poly|Init|Ref|XorOut|
     /// |---|---|---|---|---|
     /// |0xFC891918|0x04C11DB7|0xFFFFFFFF|false|0xFFFFFFFF|
@@ -1493,6 +2748,21 @@ impl CRC {
         CRC::CRCu32(CRCu32::crc32c())
     }
 
+    /// |Check|Poly|Init|Ref|XorOut|
+    /// |---|---|---|---|---|
+    /// |0x6EC2EDC4|0x8001801B (rev: 0xD8018001)|0x00000000|true|0x00000000|
+    ///
+    /// ```
+    /// # use crc_any::CRC;
+    /// let mut crc = CRC::crc32cdrom_edc();
+    /// crc.digest(b"123456789");
+    #[cfg_attr(feature = "alloc", doc = "assert_eq!(\"0x6EC2EDC4\", &crc.to_string());")]
+    /// ```
+    #[inline]
+    pub fn crc32cdrom_edc() -> CRC {
+        CRC::CRCu32(CRCu32::crc32cdrom_edc())
+    }
+
     /// |Check|Poly|Init|Ref|XorOut|
     /// |---|---|---|---|---|
     /// |0x87315576|0xA833982B (rev: 0xD419CC15)|0xFFFFFFFF|true|0xFFFFFFFF|
@@ -1568,6 +2838,21 @@ impl CRC {
         CRC::CRCu32(CRCu32::crc32jamcrc())
     }
 
+    /// |Check|Poly|Init|Ref|XorOut|
+    /// |---|---|---|---|---|
+    /// |0xD2C22F51|0x741B8CD7 (rev: 0xEB31D82E)|0xFFFFFFFF|true|0x00000000|
+    ///
+    /// ```
+    /// # use crc_any::CRC;
+    /// let mut crc = CRC::crc32mef();
+    /// crc.digest(b"123456789");
+    #[cfg_attr(feature = "alloc", doc = "assert_eq!(\"0xD2C22F51\", &crc.to_string());")]
+    /// ```
+    #[inline]
+    pub fn crc32mef() -> CRC {
+        CRC::CRCu32(CRCu32::crc32mef())
+    }
+
     /// |Check|Poly|Init|Ref|XorOut|
     /// |---|---|---|---|---|
     /// |0xBD0BE338|0x000000AF|0x00000000|false|0x00000000|
@@ -1632,6 +2917,36 @@ impl CRC {
         CRC::CRCu64(CRCu64::crc64iso())
     }
 
+    /// |Check|Poly|Init|Ref|XorOut|
+    /// |---|---|---|---|---|
+    /// |0x75D4B74F024ECEEA|0x259C84CBA6426349 (rev: 0x92C64265D32139A4)|0xFFFFFFFFFFFFFFFF|true|0x0000000000000000|
+    ///
+    /// ```
+    /// # use crc_any::CRC;
+    /// let mut crc = CRC::crc64ms();
+    /// crc.digest(b"123456789");
+    #[cfg_attr(feature = "alloc", doc = "assert_eq!(\"0x75D4B74F024ECEEA\", &crc.to_string());")]
+    /// ```
+    #[inline]
+    pub fn crc64ms() -> CRC {
+        CRC::CRCu64(CRCu64::crc64ms())
+    }
+
+    /// |Check|Poly|Init|Ref|XorOut|
+    /// |---|---|---|---|---|
+    /// |0xAE8B14860A799888|0xAD93D23594C93659 (rev: 0x9A6C9329AC4BC9B5)|0xFFFFFFFFFFFFFFFF|true|0xFFFFFFFFFFFFFFFF|
+    ///
+    /// ```
+    /// # use crc_any::CRC;
+    /// let mut crc = CRC::crc64nvme();
+    /// crc.digest(b"123456789");
+    #[cfg_attr(feature = "alloc", doc = "assert_eq!(\"0xAE8B14860A799888\", &crc.to_string());")]
+    /// ```
+    #[inline]
+    pub fn crc64nvme() -> CRC {
+        CRC::CRCu64(CRCu64::crc64nvme())
+    }
+
     /// |Check|Poly|Init|Ref|XorOut|
     /// |---|---|---|---|---|
     /// |0x62EC59E3F1A4F00A|0x42F0E1EBA9EA3693|0xFFFFFFFFFFFFFFFF|false|0xFFFFFFFFFFFFFFFF|
@@ -1661,4 +2976,52 @@ impl CRC {
     pub fn crc64jones() -> CRC {
         CRC::CRCu64(CRCu64::crc64jones())
     }
+
+    /// |Check|Poly|Init|Ref|XorOut|
+    /// |---|---|---|---|---|
+    /// |0xE9C6D914C4B8D9CA|0xAD93D23594C935A9 (rev: 0x95AC9329AC4BC9B5)|0x0000000000000000|true|0x0000000000000000|
+    ///
+    /// ```
+    /// # use crc_any::CRC;
+    /// let mut crc = CRC::crc64redis();
+    /// crc.digest(b"123456789");
+    #[cfg_attr(feature = "alloc", doc = "assert_eq!(\"0xE9C6D914C4B8D9CA\", &crc.to_string());")]
+    /// ```
+    #[inline]
+    pub fn crc64redis() -> CRC {
+        CRC::CRCu64(CRCu64::crc64redis())
+    }
+
+    /// |Check|Poly|Init|Ref|XorOut|
+    /// |---|---|---|---|---|
+    /// |0x995DC9BBDF1939FA|0x42F0E1EBA9EA3693 (rev: 0xC96C5795D7870F42)|0xFFFFFFFFFFFFFFFF|true|0xFFFFFFFFFFFFFFFF|
+    ///
+    /// ```
+    /// # use crc_any::CRC;
+    /// let mut crc = CRC::crc64xz();
+    /// crc.digest(b"123456789");
+    #[cfg_attr(feature = "alloc", doc = "assert_eq!(\"0x995DC9BBDF1939FA\", &crc.to_string());")]
+    /// ```
+    #[inline]
+    pub fn crc64xz() -> CRC {
+        CRC::CRCu64(CRCu64::crc64xz())
+    }
+
+    /// |Check|Poly|Init|Ref|XorOut|
+    /// |---|---|---|---|---|
+    /// |0x09EA83F625023801FD612|0x0308C0111011401440411 (rev: 0x220808A00A2022200C430)|0x000000000000000000000|true|0x000000000000000000000|
+    ///
+    /// ```
+    /// # use crc_any::CRC;
+    /// let mut crc = CRC::crc82darc();
+    /// crc.digest(b"123456789");
+    #[cfg_attr(
+        feature = "alloc",
+        doc = "assert_eq!(\"0x09EA83F625023801FD612\", &crc.to_string());"
+    )]
+    /// ```
+    #[inline]
+    pub fn crc82darc() -> CRC {
+        CRC::CRCu128(CRCu128::crc82darc())
+    }
 }
@@ -14,3 +14,34 @@ impl<T> core::ops::Deref for LookUpTable<T> {
         }
     }
 }
+
+impl<T: Copy> Clone for LookUpTable<T> {
+    #[inline]
+    fn clone(&self) -> LookUpTable<T> {
+        match *self {
+            LookUpTable::Static(s) => LookUpTable::Static(s),
+            LookUpTable::Dynamic(ref d) => LookUpTable::Dynamic(*d),
+        }
+    }
+}
+
+#[cfg(test)]
+impl<T> LookUpTable<T> {
+    /// Check whether this is the static lookup table `table` itself, not a copy of it.
+    pub(crate) fn is_static(&self, table: &'static [T; 256]) -> bool {
+        match *self {
+            LookUpTable::Static(s) => core::ptr::eq(s.as_ptr(), table.as_ptr()),
+            LookUpTable::Dynamic(_) => false,
+        }
+    }
+
+    /// Check whether this and `other` are the same static lookup table, such as a table shared through the cache.
+    pub(crate) fn is_same_static(&self, other: &LookUpTable<T>) -> bool {
+        match (self, other) {
+            (LookUpTable::Static(a), LookUpTable::Static(b)) => {
+                core::ptr::eq(a.as_ptr(), b.as_ptr())
+            },
+            _ => false,
+        }
+    }
+}
@@ -0,0 +1,106 @@
+use alloc::{borrow::Cow, vec::Vec};
+
+use crate::{CRC, CrcParams};
+
+/// The number of bytes passed to every CRC instance in turn, small enough to stay in the L1 cache.
+const CHUNK_SIZE: usize = 4096;
+
+/// A set of named CRC instances which are updated together, so that the data only needs to be read once.
+///
+/// ```rust
+/// use crc_any::{CRC, MultiCrc, params::*};
+///
+/// let mut multi = MultiCrc::new();
+///
+/// multi.push_params(&CRC_32_ISO_HDLC);
+/// multi.push_params(&CRC_32_ISCSI);
+/// multi.push("xmodem", CRC::crc16xmodem());
+///
+/// multi.update(b"123456789");
+///
+/// assert_eq!(
+///     vec![
+///         ("CRC-32/ISO-HDLC", 0xCBF43926),
+///         ("CRC-32/ISCSI", 0xE3069283),
+///         ("xmodem", 0x31C3)
+///     ],
+///     multi.results()
+/// );
+/// ```
+#[derive(Debug, Clone, Default)]
+pub struct MultiCrc {
+    crcs: Vec<(Cow<'static, str>, CRC)>,
+}
+
+impl MultiCrc {
+    /// Create an empty `MultiCrc` instance.
+    #[inline]
+    pub const fn new() -> MultiCrc {
+        MultiCrc {
+            crcs: Vec::new()
+        }
+    }
+
+    /// Create a `MultiCrc` instance with the algorithms of `params`, named after their names in the catalogue.
+    pub fn from_params(params: &[&CrcParams]) -> MultiCrc {
+        MultiCrc {
+            crcs: params
+                .iter()
+                .map(|params| (Cow::Borrowed(params.name), CRC::from_params(params)))
+                .collect(),
+        }
+    }
+
+    /// Add a CRC instance with a name, which can be a `&'static str` or a `String`, such as a name read from a configuration file. Its current state is kept.
+    #[inline]
+    pub fn push(&mut self, name: impl Into<Cow<'static, str>>, crc: CRC) {
+        self.crcs.push((name.into(), crc));
+    }
+
+    /// Add the algorithm of `params`, named after its name in the catalogue.
+    #[inline]
+    pub fn push_params(&mut self, params: &CrcParams) {
+        self.push(params.name, CRC::from_params(params));
+    }
+
+    /// Get the number of CRC instances.
+    #[inline]
+    pub fn len(&self) -> usize {
+        self.crcs.len()
+    }
+
+    /// Check whether there is no CRC instance.
+    #[inline]
+    pub fn is_empty(&self) -> bool {
+        self.crcs.is_empty()
+    }
+
+    /// Get the first CRC instance with the name.
+    #[inline]
+    pub fn get(&self, name: &str) -> Option<&CRC> {
+        self.crcs.iter().find(|(n, _)| n == name).map(|(_, crc)| crc)
+    }
+
+    /// Update the states of all CRC instances with bytes.
+    ///
+    /// The data is split into small chunks, and each chunk is passed to every CRC instance in turn while it is still in the cache.
+    pub fn update(&mut self, data: &[u8]) {
+        for chunk in data.chunks(CHUNK_SIZE) {
+            for (_, crc) in self.crcs.iter_mut() {
+                crc.update(chunk);
+            }
+        }
+    }
+
+    /// Reset the sums of all CRC instances.
+    pub fn reset(&mut self) {
+        for (_, crc) in self.crcs.iter_mut() {
+            crc.reset();
+        }
+    }
+
+    /// Get the current CRC values with the names of their CRC instances, in the order in which they were added. You can continue calling `update` even after getting the CRC values.
+    pub fn results(&self) -> Vec<(&str, u128)> {
+        self.crcs.iter().map(|(name, crc)| (name.as_ref(), crc.get_crc_u128())).collect()
+    }
+}
@@ -0,0 +1,1523 @@
+/*!
+Parameters of CRC algorithms.
+
+A CRC algorithm is fully described by the parameters of the Rocksoft™ model used by the [RevEng catalogue](https://reveng.sourceforge.io/crc-catalogue/): the width, the polynomial, the initial value, the input and output reflection, and the final XOR value. The catalogue additionally records the check value (the CRC of the ASCII string `"123456789"`) and the residue (the register content after a correct codeword has been processed, before the final XOR value is applied).
+
+Every built-in CRC function of this crate has a corresponding constant in this module, named after its canonical name in the catalogue.
+
+```rust
+use crc_any::{CRC, params::CRC_16_MODBUS};
+
+let mut crc = CRC::from_params(&CRC_16_MODBUS);
+
+crc.update(b"123456789");
+
+assert_eq!(CRC_16_MODBUS.check, crc.get_crc_u128());
+```
+*/
+
+/// The parameters of a CRC algorithm, as described in the RevEng catalogue.
+///
+/// Unlike the `create_crc` associated functions, the polynomial is always given in its normal (unreflected) form, whether or not the algorithm is reflected.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct CrcParams {
+    /// The name of the algorithm, e.g. `"CRC-16/MODBUS"`.
+    pub name:    &'static str,
+    /// The length of bits.
+    pub width:   u8,
+    /// The generator polynomial in normal form, without the leading `x^width` term.
+    pub poly:    u128,
+    /// The initial value of the register.
+    pub init:    u128,
+    /// Whether each input byte is reflected before it is processed.
+    pub refin:   bool,
+    /// Whether the register is reflected before the final XOR value is applied.
+    pub refout:  bool,
+    /// The value XORed with the register to get the CRC value.
+    pub xorout:  u128,
+    /// The CRC value of the ASCII string `"123456789"`.
+    pub check:   u128,
+    /// The register content after processing a message followed by its correct CRC value, without applying `xorout`.
+    pub residue: u128,
+}
+
+impl CrcParams {
+    /// Get the polynomial in the form expected by the `create_crc` associated functions, which is reflected when `refin` is `true`.
+    #[inline]
+    pub(crate) const fn create_crc_poly(&self) -> u128 {
+        if self.refin {
+            self.poly.reverse_bits() >> (u128::BITS - self.width as u32)
+        } else {
+            self.poly
+        }
+    }
+}
+
+/// `CRC-3/GSM`, used by [`CRC::crc3gsm`](crate::CRC::crc3gsm).
+pub const CRC_3_GSM: CrcParams = CrcParams {
+    name:    "CRC-3/GSM",
+    width:   3,
+    poly:    0x3,
+    init:    0x0,
+    refin:   false,
+    refout:  false,
+    xorout:  0x7,
+    check:   0x4,
+    residue: 0x2,
+};
+
+/// `CRC-3/ROHC`, used by [`CRC::crc3rohc`](crate::CRC::crc3rohc).
+pub const CRC_3_ROHC: CrcParams = CrcParams {
+    name:    "CRC-3/ROHC",
+    width:   3,
+    poly:    0x3,
+    init:    0x7,
+    refin:   true,
+    refout:  true,
+    xorout:  0x0,
+    check:   0x6,
+    residue: 0x0,
+};
+
+/// `CRC-4/G-704`, used by [`CRC::crc4itu`](crate::CRC::crc4itu).
+pub const CRC_4_G_704: CrcParams = CrcParams {
+    name:    "CRC-4/G-704",
+    width:   4,
+    poly:    0x3,
+    init:    0x0,
+    refin:   true,
+    refout:  true,
+    xorout:  0x0,
+    check:   0x7,
+    residue: 0x0,
+};
+
+/// `CRC-4/INTERLAKEN`, used by [`CRC::crc4interlaken`](crate::CRC::crc4interlaken).
+pub const CRC_4_INTERLAKEN: CrcParams = CrcParams {
+    name:    "CRC-4/INTERLAKEN",
+    width:   4,
+    poly:    0x3,
+    init:    0xF,
+    refin:   false,
+    refout:  false,
+    xorout:  0xF,
+    check:   0xB,
+    residue: 0x2,
+};
+
+/// `CRC-5/EPC-C1G2`, used by [`CRC::crc5epc`](crate::CRC::crc5epc).
+pub const CRC_5_EPC_C1G2: CrcParams = CrcParams {
+    name:    "CRC-5/EPC-C1G2",
+    width:   5,
+    poly:    0x09,
+    init:    0x09,
+    refin:   false,
+    refout:  false,
+    xorout:  0x00,
+    check:   0x00,
+    residue: 0x00,
+};
+
+/// `CRC-5/G-704`, used by [`CRC::crc5itu`](crate::CRC::crc5itu).
+pub const CRC_5_G_704: CrcParams = CrcParams {
+    name:    "CRC-5/G-704",
+    width:   5,
+    poly:    0x15,
+    init:    0x00,
+    refin:   true,
+    refout:  true,
+    xorout:  0x00,
+    check:   0x07,
+    residue: 0x00,
+};
+
+/// `CRC-5/USB`, used by [`CRC::crc5usb`](crate::CRC::crc5usb).
+pub const CRC_5_USB: CrcParams = CrcParams {
+    name:    "CRC-5/USB",
+    width:   5,
+    poly:    0x05,
+    init:    0x1F,
+    refin:   true,
+    refout:  true,
+    xorout:  0x1F,
+    check:   0x19,
+    residue: 0x06,
+};
+
+/// `CRC-6/CDMA2000-A`, used by [`CRC::crc6cdma2000_a`](crate::CRC::crc6cdma2000_a).
+pub const CRC_6_CDMA2000_A: CrcParams = CrcParams {
+    name:    "CRC-6/CDMA2000-A",
+    width:   6,
+    poly:    0x27,
+    init:    0x3F,
+    refin:   false,
+    refout:  false,
+    xorout:  0x00,
+    check:   0x0D,
+    residue: 0x00,
+};
+
+/// `CRC-6/CDMA2000-B`, used by [`CRC::crc6cdma2000_b`](crate::CRC::crc6cdma2000_b).
+pub const CRC_6_CDMA2000_B: CrcParams = CrcParams {
+    name:    "CRC-6/CDMA2000-B",
+    width:   6,
+    poly:    0x07,
+    init:    0x3F,
+    refin:   false,
+    refout:  false,
+    xorout:  0x00,
+    check:   0x3B,
+    residue: 0x00,
+};
+
+/// `CRC-6/DARC`, used by [`CRC::crc6darc`](crate::CRC::crc6darc).
+pub const CRC_6_DARC: CrcParams = CrcParams {
+    name:    "CRC-6/DARC",
+    width:   6,
+    poly:    0x19,
+    init:    0x00,
+    refin:   true,
+    refout:  true,
+    xorout:  0x00,
+    check:   0x26,
+    residue: 0x00,
+};
+
+/// `CRC-6/G-704`, used by [`CRC::crc6itu`](crate::CRC::crc6itu).
+pub const CRC_6_G_704: CrcParams = CrcParams {
+    name:    "CRC-6/G-704",
+    width:   6,
+    poly:    0x03,
+    init:    0x00,
+    refin:   true,
+    refout:  true,
+    xorout:  0x00,
+    check:   0x06,
+    residue: 0x00,
+};
+
+/// `CRC-6/GSM`, used by [`CRC::crc6gsm`](crate::CRC::crc6gsm).
+pub const CRC_6_GSM: CrcParams = CrcParams {
+    name:    "CRC-6/GSM",
+    width:   6,
+    poly:    0x2F,
+    init:    0x00,
+    refin:   false,
+    refout:  false,
+    xorout:  0x3F,
+    check:   0x13,
+    residue: 0x3A,
+};
+
+/// `CRC-7/MMC`, used by [`CRC::crc7`](crate::CRC::crc7).
+pub const CRC_7_MMC: CrcParams = CrcParams {
+    name:    "CRC-7/MMC",
+    width:   7,
+    poly:    0x09,
+    init:    0x00,
+    refin:   false,
+    refout:  false,
+    xorout:  0x00,
+    check:   0x75,
+    residue: 0x00,
+};
+
+/// `CRC-7/ROHC`, used by [`CRC::crc7rohc`](crate::CRC::crc7rohc).
+pub const CRC_7_ROHC: CrcParams = CrcParams {
+    name:    "CRC-7/ROHC",
+    width:   7,
+    poly:    0x4F,
+    init:    0x7F,
+    refin:   true,
+    refout:  true,
+    xorout:  0x00,
+    check:   0x53,
+    residue: 0x00,
+};
+
+/// `CRC-7/UMTS`, used by [`CRC::crc7umts`](crate::CRC::crc7umts).
+pub const CRC_7_UMTS: CrcParams = CrcParams {
+    name:    "CRC-7/UMTS",
+    width:   7,
+    poly:    0x45,
+    init:    0x00,
+    refin:   false,
+    refout:  false,
+    xorout:  0x00,
+    check:   0x61,
+    residue: 0x00,
+};
+
+/// `CRC-8/AUTOSAR`, used by [`CRC::crc8autosar`](crate::CRC::crc8autosar).
+pub const CRC_8_AUTOSAR: CrcParams = CrcParams {
+    name:    "CRC-8/AUTOSAR",
+    width:   8,
+    poly:    0x2F,
+    init:    0xFF,
+    refin:   false,
+    refout:  false,
+    xorout:  0xFF,
+    check:   0xDF,
+    residue: 0x42,
+};
+
+/// `CRC-8/BLUETOOTH`, used by [`CRC::crc8bluetooth`](crate::CRC::crc8bluetooth).
+pub const CRC_8_BLUETOOTH: CrcParams = CrcParams {
+    name:    "CRC-8/BLUETOOTH",
+    width:   8,
+    poly:    0xA7,
+    init:    0x00,
+    refin:   true,
+    refout:  true,
+    xorout:  0x00,
+    check:   0x26,
+    residue: 0x00,
+};
+
+/// `CRC-8/CDMA2000`, used by [`CRC::crc8cdma2000`](crate::CRC::crc8cdma2000).
+pub const CRC_8_CDMA2000: CrcParams = CrcParams {
+    name:    "CRC-8/CDMA2000",
+    width:   8,
+    poly:    0x9B,
+    init:    0xFF,
+    refin:   false,
+    refout:  false,
+    xorout:  0x00,
+    check:   0xDA,
+    residue: 0x00,
+};
+
+/// `CRC-8/DARC`, used by [`CRC::crc8darc`](crate::CRC::crc8darc).
+pub const CRC_8_DARC: CrcParams = CrcParams {
+    name:    "CRC-8/DARC",
+    width:   8,
+    poly:    0x39,
+    init:    0x00,
+    refin:   true,
+    refout:  true,
+    xorout:  0x00,
+    check:   0x15,
+    residue: 0x00,
+};
+
+/// `CRC-8/DVB-S2`, used by [`CRC::crc8dvb_s2`](crate::CRC::crc8dvb_s2).
+pub const CRC_8_DVB_S2: CrcParams = CrcParams {
+    name:    "CRC-8/DVB-S2",
+    width:   8,
+    poly:    0xD5,
+    init:    0x00,
+    refin:   false,
+    refout:  false,
+    xorout:  0x00,
+    check:   0xBC,
+    residue: 0x00,
+};
+
+/// `CRC-8/GSM-A`, used by [`CRC::crc8gsm_a`](crate::CRC::crc8gsm_a).
+pub const CRC_8_GSM_A: CrcParams = CrcParams {
+    name:    "CRC-8/GSM-A",
+    width:   8,
+    poly:    0x1D,
+    init:    0x00,
+    refin:   false,
+    refout:  false,
+    xorout:  0x00,
+    check:   0x37,
+    residue: 0x00,
+};
+
+/// `CRC-8/GSM-B`, used by [`CRC::crc8gsm_b`](crate::CRC::crc8gsm_b).
+pub const CRC_8_GSM_B: CrcParams = CrcParams {
+    name:    "CRC-8/GSM-B",
+    width:   8,
+    poly:    0x49,
+    init:    0x00,
+    refin:   false,
+    refout:  false,
+    xorout:  0xFF,
+    check:   0x94,
+    residue: 0x53,
+};
+
+/// `CRC-8/HITAG`, used by [`CRC::crc8hitag`](crate::CRC::crc8hitag).
+pub const CRC_8_HITAG: CrcParams = CrcParams {
+    name:    "CRC-8/HITAG",
+    width:   8,
+    poly:    0x1D,
+    init:    0xFF,
+    refin:   false,
+    refout:  false,
+    xorout:  0x00,
+    check:   0xB4,
+    residue: 0x00,
+};
+
+/// `CRC-8/I-432-1`, used by [`CRC::crc8itu`](crate::CRC::crc8itu).
+pub const CRC_8_I_432_1: CrcParams = CrcParams {
+    name:    "CRC-8/I-432-1",
+    width:   8,
+    poly:    0x07,
+    init:    0x00,
+    refin:   false,
+    refout:  false,
+    xorout:  0x55,
+    check:   0xA1,
+    residue: 0xAC,
+};
+
+/// `CRC-8/I-CODE`, used by [`CRC::crc8icode`](crate::CRC::crc8icode).
+pub const CRC_8_I_CODE: CrcParams = CrcParams {
+    name:    "CRC-8/I-CODE",
+    width:   8,
+    poly:    0x1D,
+    init:    0xFD,
+    refin:   false,
+    refout:  false,
+    xorout:  0x00,
+    check:   0x7E,
+    residue: 0x00,
+};
+
+/// `CRC-8/LTE`, used by [`CRC::crc8lte`](crate::CRC::crc8lte).
+pub const CRC_8_LTE: CrcParams = CrcParams {
+    name:    "CRC-8/LTE",
+    width:   8,
+    poly:    0x9B,
+    init:    0x00,
+    refin:   false,
+    refout:  false,
+    xorout:  0x00,
+    check:   0xEA,
+    residue: 0x00,
+};
+
+/// `CRC-8/MAXIM-DOW`, used by [`CRC::crc8maxim`](crate::CRC::crc8maxim).
+pub const CRC_8_MAXIM_DOW: CrcParams = CrcParams {
+    name:    "CRC-8/MAXIM-DOW",
+    width:   8,
+    poly:    0x31,
+    init:    0x00,
+    refin:   true,
+    refout:  true,
+    xorout:  0x00,
+    check:   0xA1,
+    residue: 0x00,
+};
+
+/// `CRC-8/MIFARE-MAD`, used by [`CRC::crc8mifare_mad`](crate::CRC::crc8mifare_mad).
+pub const CRC_8_MIFARE_MAD: CrcParams = CrcParams {
+    name:    "CRC-8/MIFARE-MAD",
+    width:   8,
+    poly:    0x1D,
+    init:    0xC7,
+    refin:   false,
+    refout:  false,
+    xorout:  0x00,
+    check:   0x99,
+    residue: 0x00,
+};
+
+/// `CRC-8/NRSC-5`, used by [`CRC::crc8nrsc5`](crate::CRC::crc8nrsc5).
+pub const CRC_8_NRSC_5: CrcParams = CrcParams {
+    name:    "CRC-8/NRSC-5",
+    width:   8,
+    poly:    0x31,
+    init:    0xFF,
+    refin:   false,
+    refout:  false,
+    xorout:  0x00,
+    check:   0xF7,
+    residue: 0x00,
+};
+
+/// `CRC-8/OPENSAFETY`, used by [`CRC::crc8opensafety`](crate::CRC::crc8opensafety).
+pub const CRC_8_OPENSAFETY: CrcParams = CrcParams {
+    name:    "CRC-8/OPENSAFETY",
+    width:   8,
+    poly:    0x2F,
+    init:    0x00,
+    refin:   false,
+    refout:  false,
+    xorout:  0x00,
+    check:   0x3E,
+    residue: 0x00,
+};
+
+/// `CRC-8/ROHC`, used by [`CRC::crc8rohc`](crate::CRC::crc8rohc).
+pub const CRC_8_ROHC: CrcParams = CrcParams {
+    name:    "CRC-8/ROHC",
+    width:   8,
+    poly:    0x07,
+    init:    0xFF,
+    refin:   true,
+    refout:  true,
+    xorout:  0x00,
+    check:   0xD0,
+    residue: 0x00,
+};
+
+/// `CRC-8/SAE-J1850`, used by [`CRC::crc8sae_j1850`](crate::CRC::crc8sae_j1850).
+pub const CRC_8_SAE_J1850: CrcParams = CrcParams {
+    name:    "CRC-8/SAE-J1850",
+    width:   8,
+    poly:    0x1D,
+    init:    0xFF,
+    refin:   false,
+    refout:  false,
+    xorout:  0xFF,
+    check:   0x4B,
+    residue: 0xC4,
+};
+
+/// `CRC-8/SMBUS`, used by [`CRC::crc8`](crate::CRC::crc8).
+pub const CRC_8_SMBUS: CrcParams = CrcParams {
+    name:    "CRC-8/SMBUS",
+    width:   8,
+    poly:    0x07,
+    init:    0x00,
+    refin:   false,
+    refout:  false,
+    xorout:  0x00,
+    check:   0xF4,
+    residue: 0x00,
+};
+
+/// `CRC-8/TECH-3250`, used by [`CRC::crc8ebu`](crate::CRC::crc8ebu).
+pub const CRC_8_TECH_3250: CrcParams = CrcParams {
+    name:    "CRC-8/TECH-3250",
+    width:   8,
+    poly:    0x1D,
+    init:    0xFF,
+    refin:   true,
+    refout:  true,
+    xorout:  0x00,
+    check:   0x97,
+    residue: 0x00,
+};
+
+/// `CRC-8/WCDMA`, used by [`CRC::crc8wcdma`](crate::CRC::crc8wcdma).
+pub const CRC_8_WCDMA: CrcParams = CrcParams {
+    name:    "CRC-8/WCDMA",
+    width:   8,
+    poly:    0x9B,
+    init:    0x00,
+    refin:   true,
+    refout:  true,
+    xorout:  0x00,
+    check:   0x25,
+    residue: 0x00,
+};
+
+/// `CRC-10/ATM`, used by [`CRC::crc10`](crate::CRC::crc10).
+pub const CRC_10_ATM: CrcParams = CrcParams {
+    name:    "CRC-10/ATM",
+    width:   10,
+    poly:    0x233,
+    init:    0x000,
+    refin:   false,
+    refout:  false,
+    xorout:  0x000,
+    check:   0x199,
+    residue: 0x000,
+};
+
+/// `CRC-10/CDMA2000`, used by [`CRC::crc10cdma2000`](crate::CRC::crc10cdma2000).
+pub const CRC_10_CDMA2000: CrcParams = CrcParams {
+    name:    "CRC-10/CDMA2000",
+    width:   10,
+    poly:    0x3D9,
+    init:    0x3FF,
+    refin:   false,
+    refout:  false,
+    xorout:  0x000,
+    check:   0x233,
+    residue: 0x000,
+};
+
+/// `CRC-10/GSM`, used by [`CRC::crc10gsm`](crate::CRC::crc10gsm).
+pub const CRC_10_GSM: CrcParams = CrcParams {
+    name:    "CRC-10/GSM",
+    width:   10,
+    poly:    0x175,
+    init:    0x000,
+    refin:   false,
+    refout:  false,
+    xorout:  0x3FF,
+    check:   0x12A,
+    residue: 0x0C6,
+};
+
+/// `CRC-11/FLEXRAY`, used by [`CRC::crc11`](crate::CRC::crc11).
+pub const CRC_11_FLEXRAY: CrcParams = CrcParams {
+    name:    "CRC-11/FLEXRAY",
+    width:   11,
+    poly:    0x385,
+    init:    0x01A,
+    refin:   false,
+    refout:  false,
+    xorout:  0x000,
+    check:   0x5A3,
+    residue: 0x000,
+};
+
+/// `CRC-11/UMTS`, used by [`CRC::crc11umts`](crate::CRC::crc11umts).
+pub const CRC_11_UMTS: CrcParams = CrcParams {
+    name:    "CRC-11/UMTS",
+    width:   11,
+    poly:    0x307,
+    init:    0x000,
+    refin:   false,
+    refout:  false,
+    xorout:  0x000,
+    check:   0x061,
+    residue: 0x000,
+};
+
+/// `CRC-12/CDMA2000`, used by [`CRC::crc12cdma2000`](crate::CRC::crc12cdma2000).
+pub const CRC_12_CDMA2000: CrcParams = CrcParams {
+    name:    "CRC-12/CDMA2000",
+    width:   12,
+    poly:    0xF13,
+    init:    0xFFF,
+    refin:   false,
+    refout:  false,
+    xorout:  0x000,
+    check:   0xD4D,
+    residue: 0x000,
+};
+
+/// `CRC-12/DECT`, used by [`CRC::crc12`](crate::CRC::crc12).
+pub const CRC_12_DECT: CrcParams = CrcParams {
+    name:    "CRC-12/DECT",
+    width:   12,
+    poly:    0x80F,
+    init:    0x000,
+    refin:   false,
+    refout:  false,
+    xorout:  0x000,
+    check:   0xF5B,
+    residue: 0x000,
+};
+
+/// `CRC-12/GSM`, used by [`CRC::crc12gsm`](crate::CRC::crc12gsm).
+pub const CRC_12_GSM: CrcParams = CrcParams {
+    name:    "CRC-12/GSM",
+    width:   12,
+    poly:    0xD31,
+    init:    0x000,
+    refin:   false,
+    refout:  false,
+    xorout:  0xFFF,
+    check:   0xB34,
+    residue: 0x178,
+};
+
+/// `CRC-12/UMTS`, used by [`CRC::crc12umts`](crate::CRC::crc12umts).
+pub const CRC_12_UMTS: CrcParams = CrcParams {
+    name:    "CRC-12/UMTS",
+    width:   12,
+    poly:    0x80F,
+    init:    0x000,
+    refin:   false,
+    refout:  true,
+    xorout:  0x000,
+    check:   0xDAF,
+    residue: 0x000,
+};
+
+/// `CRC-13/BBC`, used by [`CRC::crc13bbc`](crate::CRC::crc13bbc).
+pub const CRC_13_BBC: CrcParams = CrcParams {
+    name:    "CRC-13/BBC",
+    width:   13,
+    poly:    0x1CF5,
+    init:    0x0000,
+    refin:   false,
+    refout:  false,
+    xorout:  0x0000,
+    check:   0x04FA,
+    residue: 0x0000,
+};
+
+/// `CRC-14/DARC`, used by [`CRC::crc14darc`](crate::CRC::crc14darc).
+pub const CRC_14_DARC: CrcParams = CrcParams {
+    name:    "CRC-14/DARC",
+    width:   14,
+    poly:    0x0805,
+    init:    0x0000,
+    refin:   true,
+    refout:  true,
+    xorout:  0x0000,
+    check:   0x082D,
+    residue: 0x0000,
+};
+
+/// `CRC-14/GSM`, used by [`CRC::crc14gsm`](crate::CRC::crc14gsm).
+pub const CRC_14_GSM: CrcParams = CrcParams {
+    name:    "CRC-14/GSM",
+    width:   14,
+    poly:    0x202D,
+    init:    0x0000,
+    refin:   false,
+    refout:  false,
+    xorout:  0x3FFF,
+    check:   0x30AE,
+    residue: 0x031E,
+};
+
+/// `CRC-15/CAN`, used by [`CRC::crc15can`](crate::CRC::crc15can).
+pub const CRC_15_CAN: CrcParams = CrcParams {
+    name:    "CRC-15/CAN",
+    width:   15,
+    poly:    0x4599,
+    init:    0x0000,
+    refin:   false,
+    refout:  false,
+    xorout:  0x0000,
+    check:   0x059E,
+    residue: 0x0000,
+};
+
+/// `CRC-15/MPT1327`, used by [`CRC::crc15mpt1327`](crate::CRC::crc15mpt1327).
+pub const CRC_15_MPT1327: CrcParams = CrcParams {
+    name:    "CRC-15/MPT1327",
+    width:   15,
+    poly:    0x6815,
+    init:    0x0000,
+    refin:   false,
+    refout:  false,
+    xorout:  0x0001,
+    check:   0x2566,
+    residue: 0x6815,
+};
+
+/// `CRC-16/ARC`, used by [`CRC::crc16`](crate::CRC::crc16).
+pub const CRC_16_ARC: CrcParams = CrcParams {
+    name:    "CRC-16/ARC",
+    width:   16,
+    poly:    0x8005,
+    init:    0x0000,
+    refin:   true,
+    refout:  true,
+    xorout:  0x0000,
+    check:   0xBB3D,
+    residue: 0x0000,
+};
+
+/// `CRC-16/CDMA2000`, used by [`CRC::crc16cdma2000`](crate::CRC::crc16cdma2000).
+pub const CRC_16_CDMA2000: CrcParams = CrcParams {
+    name:    "CRC-16/CDMA2000",
+    width:   16,
+    poly:    0xC867,
+    init:    0xFFFF,
+    refin:   false,
+    refout:  false,
+    xorout:  0x0000,
+    check:   0x4C06,
+    residue: 0x0000,
+};
+
+/// `CRC-16/CMS`, used by [`CRC::crc16cms`](crate::CRC::crc16cms).
+pub const CRC_16_CMS: CrcParams = CrcParams {
+    name:    "CRC-16/CMS",
+    width:   16,
+    poly:    0x8005,
+    init:    0xFFFF,
+    refin:   false,
+    refout:  false,
+    xorout:  0x0000,
+    check:   0xAEE7,
+    residue: 0x0000,
+};
+
+/// `CRC-16/DDS-110`, used by [`CRC::crc16dds_110`](crate::CRC::crc16dds_110).
+pub const CRC_16_DDS_110: CrcParams = CrcParams {
+    name:    "CRC-16/DDS-110",
+    width:   16,
+    poly:    0x8005,
+    init:    0x800D,
+    refin:   false,
+    refout:  false,
+    xorout:  0x0000,
+    check:   0x9ECF,
+    residue: 0x0000,
+};
+
+/// `CRC-16/DECT-R`, used by [`CRC::crc16dect_r`](crate::CRC::crc16dect_r).
+pub const CRC_16_DECT_R: CrcParams = CrcParams {
+    name:    "CRC-16/DECT-R",
+    width:   16,
+    poly:    0x0589,
+    init:    0x0000,
+    refin:   false,
+    refout:  false,
+    xorout:  0x0001,
+    check:   0x007E,
+    residue: 0x0589,
+};
+
+/// `CRC-16/DECT-X`, used by [`CRC::crc16dect_x`](crate::CRC::crc16dect_x).
+pub const CRC_16_DECT_X: CrcParams = CrcParams {
+    name:    "CRC-16/DECT-X",
+    width:   16,
+    poly:    0x0589,
+    init:    0x0000,
+    refin:   false,
+    refout:  false,
+    xorout:  0x0000,
+    check:   0x007F,
+    residue: 0x0000,
+};
+
+/// `CRC-16/DNP`, used by [`CRC::crc16dnp`](crate::CRC::crc16dnp).
+pub const CRC_16_DNP: CrcParams = CrcParams {
+    name:    "CRC-16/DNP",
+    width:   16,
+    poly:    0x3D65,
+    init:    0x0000,
+    refin:   true,
+    refout:  true,
+    xorout:  0xFFFF,
+    check:   0xEA82,
+    residue: 0x66C5,
+};
+
+/// `CRC-16/EN-13757`, used by [`CRC::crc16en_13757`](crate::CRC::crc16en_13757).
+pub const CRC_16_EN_13757: CrcParams = CrcParams {
+    name:    "CRC-16/EN-13757",
+    width:   16,
+    poly:    0x3D65,
+    init:    0x0000,
+    refin:   false,
+    refout:  false,
+    xorout:  0xFFFF,
+    check:   0xC2B7,
+    residue: 0xA366,
+};
+
+/// `CRC-16/GENIBUS`, used by [`CRC::crc16genibus`](crate::CRC::crc16genibus).
+pub const CRC_16_GENIBUS: CrcParams = CrcParams {
+    name:    "CRC-16/GENIBUS",
+    width:   16,
+    poly:    0x1021,
+    init:    0xFFFF,
+    refin:   false,
+    refout:  false,
+    xorout:  0xFFFF,
+    check:   0xD64E,
+    residue: 0x1D0F,
+};
+
+/// `CRC-16/GSM`, used by [`CRC::crc16gsm`](crate::CRC::crc16gsm).
+pub const CRC_16_GSM: CrcParams = CrcParams {
+    name:    "CRC-16/GSM",
+    width:   16,
+    poly:    0x1021,
+    init:    0x0000,
+    refin:   false,
+    refout:  false,
+    xorout:  0xFFFF,
+    check:   0xCE3C,
+    residue: 0x1D0F,
+};
+
+/// `CRC-16/IBM-3740`, used by [`CRC::crc16ccitt_false`](crate::CRC::crc16ccitt_false).
+pub const CRC_16_IBM_3740: CrcParams = CrcParams {
+    name:    "CRC-16/IBM-3740",
+    width:   16,
+    poly:    0x1021,
+    init:    0xFFFF,
+    refin:   false,
+    refout:  false,
+    xorout:  0x0000,
+    check:   0x29B1,
+    residue: 0x0000,
+};
+
+/// `CRC-16/IBM-SDLC`, used by [`CRC::crc16_x25`](crate::CRC::crc16_x25).
+pub const CRC_16_IBM_SDLC: CrcParams = CrcParams {
+    name:    "CRC-16/IBM-SDLC",
+    width:   16,
+    poly:    0x1021,
+    init:    0xFFFF,
+    refin:   true,
+    refout:  true,
+    xorout:  0xFFFF,
+    check:   0x906E,
+    residue: 0xF0B8,
+};
+
+/// `CRC-16/ISO-IEC-14443-3-A`, used by [`CRC::crc_a`](crate::CRC::crc_a).
+pub const CRC_16_ISO_IEC_14443_3_A: CrcParams = CrcParams {
+    name:    "CRC-16/ISO-IEC-14443-3-A",
+    width:   16,
+    poly:    0x1021,
+    init:    0xC6C6,
+    refin:   true,
+    refout:  true,
+    xorout:  0x0000,
+    check:   0xBF05,
+    residue: 0x0000,
+};
+
+/// `CRC-16/KERMIT`, used by [`CRC::crc16kermit`](crate::CRC::crc16kermit).
+pub const CRC_16_KERMIT: CrcParams = CrcParams {
+    name:    "CRC-16/KERMIT",
+    width:   16,
+    poly:    0x1021,
+    init:    0x0000,
+    refin:   true,
+    refout:  true,
+    xorout:  0x0000,
+    check:   0x2189,
+    residue: 0x0000,
+};
+
+/// `CRC-16/LJ1200`, used by [`CRC::crc16lj1200`](crate::CRC::crc16lj1200).
+pub const CRC_16_LJ1200: CrcParams = CrcParams {
+    name:    "CRC-16/LJ1200",
+    width:   16,
+    poly:    0x6F63,
+    init:    0x0000,
+    refin:   false,
+    refout:  false,
+    xorout:  0x0000,
+    check:   0xBDF4,
+    residue: 0x0000,
+};
+
+/// `CRC-16/M17`, used by [`CRC::crc16m17`](crate::CRC::crc16m17).
+pub const CRC_16_M17: CrcParams = CrcParams {
+    name:    "CRC-16/M17",
+    width:   16,
+    poly:    0x5935,
+    init:    0xFFFF,
+    refin:   false,
+    refout:  false,
+    xorout:  0x0000,
+    check:   0x772B,
+    residue: 0x0000,
+};
+
+/// `CRC-16/MAXIM-DOW`, used by [`CRC::crc16maxim`](crate::CRC::crc16maxim).
+pub const CRC_16_MAXIM_DOW: CrcParams = CrcParams {
+    name:    "CRC-16/MAXIM-DOW",
+    width:   16,
+    poly:    0x8005,
+    init:    0x0000,
+    refin:   true,
+    refout:  true,
+    xorout:  0xFFFF,
+    check:   0x44C2,
+    residue: 0xB001,
+};
+
+/// `CRC-16/MCRF4XX`, used by [`CRC::crc16mcrf4cc`](crate::CRC::crc16mcrf4cc).
+pub const CRC_16_MCRF4XX: CrcParams = CrcParams {
+    name:    "CRC-16/MCRF4XX",
+    width:   16,
+    poly:    0x1021,
+    init:    0xFFFF,
+    refin:   true,
+    refout:  true,
+    xorout:  0x0000,
+    check:   0x6F91,
+    residue: 0x0000,
+};
+
+/// `CRC-16/MODBUS`, used by [`CRC::crc16modbus`](crate::CRC::crc16modbus).
+pub const CRC_16_MODBUS: CrcParams = CrcParams {
+    name:    "CRC-16/MODBUS",
+    width:   16,
+    poly:    0x8005,
+    init:    0xFFFF,
+    refin:   true,
+    refout:  true,
+    xorout:  0x0000,
+    check:   0x4B37,
+    residue: 0x0000,
+};
+
+/// `CRC-16/NRSC-5`, used by [`CRC::crc16nrsc5`](crate::CRC::crc16nrsc5).
+pub const CRC_16_NRSC_5: CrcParams = CrcParams {
+    name:    "CRC-16/NRSC-5",
+    width:   16,
+    poly:    0x080B,
+    init:    0xFFFF,
+    refin:   true,
+    refout:  true,
+    xorout:  0x0000,
+    check:   0xA066,
+    residue: 0x0000,
+};
+
+/// `CRC-16/OPENSAFETY-A`, used by [`CRC::crc16opensafety_a`](crate::CRC::crc16opensafety_a).
+pub const CRC_16_OPENSAFETY_A: CrcParams = CrcParams {
+    name:    "CRC-16/OPENSAFETY-A",
+    width:   16,
+    poly:    0x5935,
+    init:    0x0000,
+    refin:   false,
+    refout:  false,
+    xorout:  0x0000,
+    check:   0x5D38,
+    residue: 0x0000,
+};
+
+/// `CRC-16/OPENSAFETY-B`, used by [`CRC::crc16opensafety_b`](crate::CRC::crc16opensafety_b).
+pub const CRC_16_OPENSAFETY_B: CrcParams = CrcParams {
+    name:    "CRC-16/OPENSAFETY-B",
+    width:   16,
+    poly:    0x755B,
+    init:    0x0000,
+    refin:   false,
+    refout:  false,
+    xorout:  0x0000,
+    check:   0x20FE,
+    residue: 0x0000,
+};
+
+/// `CRC-16/PROFIBUS`, used by [`CRC::crc16profibus`](crate::CRC::crc16profibus).
+pub const CRC_16_PROFIBUS: CrcParams = CrcParams {
+    name:    "CRC-16/PROFIBUS",
+    width:   16,
+    poly:    0x1DCF,
+    init:    0xFFFF,
+    refin:   false,
+    refout:  false,
+    xorout:  0xFFFF,
+    check:   0xA819,
+    residue: 0xE394,
+};
+
+/// `CRC-16/RIELLO`, used by [`CRC::crc16riello`](crate::CRC::crc16riello).
+pub const CRC_16_RIELLO: CrcParams = CrcParams {
+    name:    "CRC-16/RIELLO",
+    width:   16,
+    poly:    0x1021,
+    init:    0xB2AA,
+    refin:   true,
+    refout:  true,
+    xorout:  0x0000,
+    check:   0x63D0,
+    residue: 0x0000,
+};
+
+/// `CRC-16/SPI-FUJITSU`, used by [`CRC::crc16aug_ccitt`](crate::CRC::crc16aug_ccitt).
+pub const CRC_16_SPI_FUJITSU: CrcParams = CrcParams {
+    name:    "CRC-16/SPI-FUJITSU",
+    width:   16,
+    poly:    0x1021,
+    init:    0x1D0F,
+    refin:   false,
+    refout:  false,
+    xorout:  0x0000,
+    check:   0xE5CC,
+    residue: 0x0000,
+};
+
+/// `CRC-16/T10-DIF`, used by [`CRC::crc16t10_dif`](crate::CRC::crc16t10_dif).
+pub const CRC_16_T10_DIF: CrcParams = CrcParams {
+    name:    "CRC-16/T10-DIF",
+    width:   16,
+    poly:    0x8BB7,
+    init:    0x0000,
+    refin:   false,
+    refout:  false,
+    xorout:  0x0000,
+    check:   0xD0DB,
+    residue: 0x0000,
+};
+
+/// `CRC-16/TELEDISK`, used by [`CRC::crc16teledisk`](crate::CRC::crc16teledisk).
+pub const CRC_16_TELEDISK: CrcParams = CrcParams {
+    name:    "CRC-16/TELEDISK",
+    width:   16,
+    poly:    0xA097,
+    init:    0x0000,
+    refin:   false,
+    refout:  false,
+    xorout:  0x0000,
+    check:   0x0FB3,
+    residue: 0x0000,
+};
+
+/// `CRC-16/TMS37157`, used by [`CRC::crc16tms13157`](crate::CRC::crc16tms13157).
+pub const CRC_16_TMS37157: CrcParams = CrcParams {
+    name:    "CRC-16/TMS37157",
+    width:   16,
+    poly:    0x1021,
+    init:    0x89EC,
+    refin:   true,
+    refout:  true,
+    xorout:  0x0000,
+    check:   0x26B1,
+    residue: 0x0000,
+};
+
+/// `CRC-16/UMTS`, used by [`CRC::crc16buypass`](crate::CRC::crc16buypass).
+pub const CRC_16_UMTS: CrcParams = CrcParams {
+    name:    "CRC-16/UMTS",
+    width:   16,
+    poly:    0x8005,
+    init:    0x0000,
+    refin:   false,
+    refout:  false,
+    xorout:  0x0000,
+    check:   0xFEE8,
+    residue: 0x0000,
+};
+
+/// `CRC-16/USB`, used by [`CRC::crc16usb`](crate::CRC::crc16usb).
+pub const CRC_16_USB: CrcParams = CrcParams {
+    name:    "CRC-16/USB",
+    width:   16,
+    poly:    0x8005,
+    init:    0xFFFF,
+    refin:   true,
+    refout:  true,
+    xorout:  0xFFFF,
+    check:   0xB4C8,
+    residue: 0xB001,
+};
+
+/// `CRC-16/XMODEM`, used by [`CRC::crc16xmodem`](crate::CRC::crc16xmodem).
+pub const CRC_16_XMODEM: CrcParams = CrcParams {
+    name:    "CRC-16/XMODEM",
+    width:   16,
+    poly:    0x1021,
+    init:    0x0000,
+    refin:   false,
+    refout:  false,
+    xorout:  0x0000,
+    check:   0x31C3,
+    residue: 0x0000,
+};
+
+/// `CRC-17/CAN-FD`, used by [`CRC::crc17can`](crate::CRC::crc17can).
+pub const CRC_17_CAN_FD: CrcParams = CrcParams {
+    name:    "CRC-17/CAN-FD",
+    width:   17,
+    poly:    0x1685B,
+    init:    0x00000,
+    refin:   false,
+    refout:  false,
+    xorout:  0x00000,
+    check:   0x04F03,
+    residue: 0x00000,
+};
+
+/// `CRC-21/CAN-FD`, used by [`CRC::crc21can`](crate::CRC::crc21can).
+pub const CRC_21_CAN_FD: CrcParams = CrcParams {
+    name:    "CRC-21/CAN-FD",
+    width:   21,
+    poly:    0x102899,
+    init:    0x000000,
+    refin:   false,
+    refout:  false,
+    xorout:  0x000000,
+    check:   0x0ED841,
+    residue: 0x000000,
+};
+
+/// `CRC-24/BLE`, used by [`CRC::crc24ble`](crate::CRC::crc24ble).
+pub const CRC_24_BLE: CrcParams = CrcParams {
+    name:    "CRC-24/BLE",
+    width:   24,
+    poly:    0x00065B,
+    init:    0x555555,
+    refin:   true,
+    refout:  true,
+    xorout:  0x000000,
+    check:   0xC25A56,
+    residue: 0x000000,
+};
+
+/// `CRC-24/FLEXRAY-A`, used by [`CRC::crc24flexray_a`](crate::CRC::crc24flexray_a).
+pub const CRC_24_FLEXRAY_A: CrcParams = CrcParams {
+    name:    "CRC-24/FLEXRAY-A",
+    width:   24,
+    poly:    0x5D6DCB,
+    init:    0xFEDCBA,
+    refin:   false,
+    refout:  false,
+    xorout:  0x000000,
+    check:   0x7979BD,
+    residue: 0x000000,
+};
+
+/// `CRC-24/FLEXRAY-B`, used by [`CRC::crc24flexray_b`](crate::CRC::crc24flexray_b).
+pub const CRC_24_FLEXRAY_B: CrcParams = CrcParams {
+    name:    "CRC-24/FLEXRAY-B",
+    width:   24,
+    poly:    0x5D6DCB,
+    init:    0xABCDEF,
+    refin:   false,
+    refout:  false,
+    xorout:  0x000000,
+    check:   0x1F23B8,
+    residue: 0x000000,
+};
+
+/// `CRC-24/INTERLAKEN`, used by [`CRC::crc24interlaken`](crate::CRC::crc24interlaken).
+pub const CRC_24_INTERLAKEN: CrcParams = CrcParams {
+    name:    "CRC-24/INTERLAKEN",
+    width:   24,
+    poly:    0x328B63,
+    init:    0xFFFFFF,
+    refin:   false,
+    refout:  false,
+    xorout:  0xFFFFFF,
+    check:   0xB4F3E6,
+    residue: 0x144E63,
+};
+
+/// `CRC-24/LTE-A`, used by [`CRC::crc24lte_a`](crate::CRC::crc24lte_a).
+pub const CRC_24_LTE_A: CrcParams = CrcParams {
+    name:    "CRC-24/LTE-A",
+    width:   24,
+    poly:    0x864CFB,
+    init:    0x000000,
+    refin:   false,
+    refout:  false,
+    xorout:  0x000000,
+    check:   0xCDE703,
+    residue: 0x000000,
+};
+
+/// `CRC-24/LTE-B`, used by [`CRC::crc24lte_b`](crate::CRC::crc24lte_b).
+pub const CRC_24_LTE_B: CrcParams = CrcParams {
+    name:    "CRC-24/LTE-B",
+    width:   24,
+    poly:    0x800063,
+    init:    0x000000,
+    refin:   false,
+    refout:  false,
+    xorout:  0x000000,
+    check:   0x23EF52,
+    residue: 0x000000,
+};
+
+/// `CRC-24/OPENPGP`, used by [`CRC::crc24`](crate::CRC::crc24).
+pub const CRC_24_OPENPGP: CrcParams = CrcParams {
+    name:    "CRC-24/OPENPGP",
+    width:   24,
+    poly:    0x864CFB,
+    init:    0xB704CE,
+    refin:   false,
+    refout:  false,
+    xorout:  0x000000,
+    check:   0x21CF02,
+    residue: 0x000000,
+};
+
+/// `CRC-24/OS-9`, used by [`CRC::crc24os9`](crate::CRC::crc24os9).
+pub const CRC_24_OS_9: CrcParams = CrcParams {
+    name:    "CRC-24/OS-9",
+    width:   24,
+    poly:    0x800063,
+    init:    0xFFFFFF,
+    refin:   false,
+    refout:  false,
+    xorout:  0xFFFFFF,
+    check:   0x200FA5,
+    residue: 0x800FE3,
+};
+
+/// `CRC-30/CDMA`, used by [`CRC::crc30cdma`](crate::CRC::crc30cdma).
+pub const CRC_30_CDMA: CrcParams = CrcParams {
+    name:    "CRC-30/CDMA",
+    width:   30,
+    poly:    0x2030B9C7,
+    init:    0x3FFFFFFF,
+    refin:   false,
+    refout:  false,
+    xorout:  0x3FFFFFFF,
+    check:   0x04C34ABF,
+    residue: 0x34EFA55A,
+};
+
+/// `CRC-31/PHILIPS`, used by [`CRC::crc31philips`](crate::CRC::crc31philips).
+pub const CRC_31_PHILIPS: CrcParams = CrcParams {
+    name:    "CRC-31/PHILIPS",
+    width:   31,
+    poly:    0x04C11DB7,
+    init:    0x7FFFFFFF,
+    refin:   false,
+    refout:  false,
+    xorout:  0x7FFFFFFF,
+    check:   0x0CE9E46C,
+    residue: 0x4EAF26F1,
+};
+
+/// `CRC-32/AIXM`, used by [`CRC::crc32q`](crate::CRC::crc32q).
+pub const CRC_32_AIXM: CrcParams = CrcParams {
+    name:    "CRC-32/AIXM",
+    width:   32,
+    poly:    0x814141AB,
+    init:    0x00000000,
+    refin:   false,
+    refout:  false,
+    xorout:  0x00000000,
+    check:   0x3010BF7F,
+    residue: 0x00000000,
+};
+
+/// `CRC-32/AUTOSAR`, used by [`CRC::crc32autosar`](crate::CRC::crc32autosar).
+pub const CRC_32_AUTOSAR: CrcParams = CrcParams {
+    name:    "CRC-32/AUTOSAR",
+    width:   32,
+    poly:    0xF4ACFB13,
+    init:    0xFFFFFFFF,
+    refin:   true,
+    refout:  true,
+    xorout:  0xFFFFFFFF,
+    check:   0x1697D06A,
+    residue: 0x904CDDBF,
+};
+
+/// `CRC-32/BASE91-D`, used by [`CRC::crc32d`](crate::CRC::crc32d).
+pub const CRC_32_BASE91_D: CrcParams = CrcParams {
+    name:    "CRC-32/BASE91-D",
+    width:   32,
+    poly:    0xA833982B,
+    init:    0xFFFFFFFF,
+    refin:   true,
+    refout:  true,
+    xorout:  0xFFFFFFFF,
+    check:   0x87315576,
+    residue: 0x45270551,
+};
+
+/// `CRC-32/BZIP2`, used by [`CRC::crc32bzip2`](crate::CRC::crc32bzip2).
+pub const CRC_32_BZIP2: CrcParams = CrcParams {
+    name:    "CRC-32/BZIP2",
+    width:   32,
+    poly:    0x04C11DB7,
+    init:    0xFFFFFFFF,
+    refin:   false,
+    refout:  false,
+    xorout:  0xFFFFFFFF,
+    check:   0xFC891918,
+    residue: 0xC704DD7B,
+};
+
+/// `CRC-32/CD-ROM-EDC`, used by [`CRC::crc32cdrom_edc`](crate::CRC::crc32cdrom_edc).
+pub const CRC_32_CD_ROM_EDC: CrcParams = CrcParams {
+    name:    "CRC-32/CD-ROM-EDC",
+    width:   32,
+    poly:    0x8001801B,
+    init:    0x00000000,
+    refin:   true,
+    refout:  true,
+    xorout:  0x00000000,
+    check:   0x6EC2EDC4,
+    residue: 0x00000000,
+};
+
+/// `CRC-32/CKSUM`, used by [`CRC::crc32posix`](crate::CRC::crc32posix).
+pub const CRC_32_CKSUM: CrcParams = CrcParams {
+    name:    "CRC-32/CKSUM",
+    width:   32,
+    poly:    0x04C11DB7,
+    init:    0x00000000,
+    refin:   false,
+    refout:  false,
+    xorout:  0xFFFFFFFF,
+    check:   0x765E7680,
+    residue: 0xC704DD7B,
+};
+
+/// `CRC-32/ISCSI`, used by [`CRC::crc32c`](crate::CRC::crc32c).
+pub const CRC_32_ISCSI: CrcParams = CrcParams {
+    name:    "CRC-32/ISCSI",
+    width:   32,
+    poly:    0x1EDC6F41,
+    init:    0xFFFFFFFF,
+    refin:   true,
+    refout:  true,
+    xorout:  0xFFFFFFFF,
+    check:   0xE3069283,
+    residue: 0xB798B438,
+};
+
+/// `CRC-32/ISO-HDLC`, used by [`CRC::crc32`](crate::CRC::crc32).
+pub const CRC_32_ISO_HDLC: CrcParams = CrcParams {
+    name:    "CRC-32/ISO-HDLC",
+    width:   32,
+    poly:    0x04C11DB7,
+    init:    0xFFFFFFFF,
+    refin:   true,
+    refout:  true,
+    xorout:  0xFFFFFFFF,
+    check:   0xCBF43926,
+    residue: 0xDEBB20E3,
+};
+
+/// `CRC-32/JAMCRC`, used by [`CRC::crc32jamcrc`](crate::CRC::crc32jamcrc).
+pub const CRC_32_JAMCRC: CrcParams = CrcParams {
+    name:    "CRC-32/JAMCRC",
+    width:   32,
+    poly:    0x04C11DB7,
+    init:    0xFFFFFFFF,
+    refin:   true,
+    refout:  true,
+    xorout:  0x00000000,
+    check:   0x340BC6D9,
+    residue: 0x00000000,
+};
+
+/// `CRC-32/MEF`, used by [`CRC::crc32mef`](crate::CRC::crc32mef).
+pub const CRC_32_MEF: CrcParams = CrcParams {
+    name:    "CRC-32/MEF",
+    width:   32,
+    poly:    0x741B8CD7,
+    init:    0xFFFFFFFF,
+    refin:   true,
+    refout:  true,
+    xorout:  0x00000000,
+    check:   0xD2C22F51,
+    residue: 0x00000000,
+};
+
+/// `CRC-32/MPEG-2`, used by [`CRC::crc32mpeg2`](crate::CRC::crc32mpeg2).
+pub const CRC_32_MPEG_2: CrcParams = CrcParams {
+    name:    "CRC-32/MPEG-2",
+    width:   32,
+    poly:    0x04C11DB7,
+    init:    0xFFFFFFFF,
+    refin:   false,
+    refout:  false,
+    xorout:  0x00000000,
+    check:   0x0376E6E7,
+    residue: 0x00000000,
+};
+
+/// `CRC-32/XFER`, used by [`CRC::crc32xfer`](crate::CRC::crc32xfer).
+pub const CRC_32_XFER: CrcParams = CrcParams {
+    name:    "CRC-32/XFER",
+    width:   32,
+    poly:    0x000000AF,
+    init:    0x00000000,
+    refin:   false,
+    refout:  false,
+    xorout:  0x00000000,
+    check:   0xBD0BE338,
+    residue: 0x00000000,
+};
+
+/// `CRC-40/GSM`, used by [`CRC::crc40gsm`](crate::CRC::crc40gsm).
+pub const CRC_40_GSM: CrcParams = CrcParams {
+    name:    "CRC-40/GSM",
+    width:   40,
+    poly:    0x0004820009,
+    init:    0x0000000000,
+    refin:   false,
+    refout:  false,
+    xorout:  0xFFFFFFFFFF,
+    check:   0xD4164FC646,
+    residue: 0xC4FF8071FF,
+};
+
+/// `CRC-64/ECMA-182`, used by [`CRC::crc64`](crate::CRC::crc64).
+pub const CRC_64_ECMA_182: CrcParams = CrcParams {
+    name:    "CRC-64/ECMA-182",
+    width:   64,
+    poly:    0x42F0E1EBA9EA3693,
+    init:    0x0000000000000000,
+    refin:   false,
+    refout:  false,
+    xorout:  0x0000000000000000,
+    check:   0x6C40DF5F0B497347,
+    residue: 0x0000000000000000,
+};
+
+/// `CRC-64/GO-ISO`, used by [`CRC::crc64iso`](crate::CRC::crc64iso).
+pub const CRC_64_GO_ISO: CrcParams = CrcParams {
+    name:    "CRC-64/GO-ISO",
+    width:   64,
+    poly:    0x000000000000001B,
+    init:    0xFFFFFFFFFFFFFFFF,
+    refin:   true,
+    refout:  true,
+    xorout:  0xFFFFFFFFFFFFFFFF,
+    check:   0xB90956C775A41001,
+    residue: 0x5300000000000000,
+};
+
+/// `CRC-64/MS`, used by [`CRC::crc64ms`](crate::CRC::crc64ms).
+pub const CRC_64_MS: CrcParams = CrcParams {
+    name:    "CRC-64/MS",
+    width:   64,
+    poly:    0x259C84CBA6426349,
+    init:    0xFFFFFFFFFFFFFFFF,
+    refin:   true,
+    refout:  true,
+    xorout:  0x0000000000000000,
+    check:   0x75D4B74F024ECEEA,
+    residue: 0x0000000000000000,
+};
+
+/// `CRC-64/NVME`, used by [`CRC::crc64nvme`](crate::CRC::crc64nvme).
+pub const CRC_64_NVME: CrcParams = CrcParams {
+    name:    "CRC-64/NVME",
+    width:   64,
+    poly:    0xAD93D23594C93659,
+    init:    0xFFFFFFFFFFFFFFFF,
+    refin:   true,
+    refout:  true,
+    xorout:  0xFFFFFFFFFFFFFFFF,
+    check:   0xAE8B14860A799888,
+    residue: 0xF310303B2B6F6E42,
+};
+
+/// `CRC-64/REDIS`, used by [`CRC::crc64redis`](crate::CRC::crc64redis).
+pub const CRC_64_REDIS: CrcParams = CrcParams {
+    name:    "CRC-64/REDIS",
+    width:   64,
+    poly:    0xAD93D23594C935A9,
+    init:    0x0000000000000000,
+    refin:   true,
+    refout:  true,
+    xorout:  0x0000000000000000,
+    check:   0xE9C6D914C4B8D9CA,
+    residue: 0x0000000000000000,
+};
+
+/// `CRC-64/WE`, used by [`CRC::crc64we`](crate::CRC::crc64we).
+pub const CRC_64_WE: CrcParams = CrcParams {
+    name:    "CRC-64/WE",
+    width:   64,
+    poly:    0x42F0E1EBA9EA3693,
+    init:    0xFFFFFFFFFFFFFFFF,
+    refin:   false,
+    refout:  false,
+    xorout:  0xFFFFFFFFFFFFFFFF,
+    check:   0x62EC59E3F1A4F00A,
+    residue: 0xFCACBEBD5931A992,
+};
+
+/// `CRC-64/XZ`, used by [`CRC::crc64xz`](crate::CRC::crc64xz).
+pub const CRC_64_XZ: CrcParams = CrcParams {
+    name:    "CRC-64/XZ",
+    width:   64,
+    poly:    0x42F0E1EBA9EA3693,
+    init:    0xFFFFFFFFFFFFFFFF,
+    refin:   true,
+    refout:  true,
+    xorout:  0xFFFFFFFFFFFFFFFF,
+    check:   0x995DC9BBDF1939FA,
+    residue: 0x49958C9ABD7D353F,
+};
+
+/// `CRC-82/DARC`, used by [`CRC::crc82darc`](crate::CRC::crc82darc).
+pub const CRC_82_DARC: CrcParams = CrcParams {
+    name:    "CRC-82/DARC",
+    width:   82,
+    poly:    0x0308C0111011401440411,
+    init:    0x000000000000000000000,
+    refin:   true,
+    refout:  true,
+    xorout:  0x000000000000000000000,
+    check:   0x09EA83F625023801FD612,
+    residue: 0x000000000000000000000,
+};
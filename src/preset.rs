@@ -0,0 +1,844 @@
+/*!
+Zero-sized marker types of the built-in algorithms which have a static lookup table, and [`Digest`], a CRC instance specialized for one of them at compile time.
+
+A `Digest` only holds the register, and its update loop is monomorphized for the width, the reflection and the lookup table of the algorithm, so there is no runtime dispatch. The SSE4.2 path of CRC-32C is not used by `Digest`.
+
+```rust
+use crc_any::preset::{Crc16Modbus, Crc32IsoHdlc, Digest};
+
+let mut digest = Digest::<Crc32IsoHdlc>::new();
+
+digest.update(b"123456789");
+
+assert_eq!(0xCBF43926, digest.get_crc());
+
+assert_eq!(0x4B37, Digest::<Crc16Modbus>::checksum(b"123456789"));
+assert_eq!(4, core::mem::size_of::<Digest<Crc32IsoHdlc>>());
+```
+*/
+
+use core::{
+    fmt::{self, Debug, Formatter},
+    marker::PhantomData,
+};
+
+use crate::{
+    constants::{crc_u8::*, crc_u16::*, crc_u32::*, crc_u64::*},
+    params::{self, CrcParams},
+};
+
+mod sealed {
+    pub trait Sealed {}
+}
+
+/// The type of the register of a preset. It is implemented for `u8`, `u16`, `u32` and `u64`.
+pub trait Register: sealed::Sealed + Copy + Eq + Debug + Into<u128> + 'static {
+    #[doc(hidden)]
+    fn from_u128(n: u128) -> Self;
+
+    #[doc(hidden)]
+    fn reflect(self, width: u8) -> Self;
+
+    #[doc(hidden)]
+    fn update(self, table: &[Self; 256], width: u8, reflect: bool, data: &[u8]) -> Self;
+}
+
+impl sealed::Sealed for u8 {}
+
+impl Register for u8 {
+    #[inline]
+    fn from_u128(n: u128) -> u8 {
+        n as u8
+    }
+
+    #[inline]
+    fn reflect(self, width: u8) -> u8 {
+        self.reverse_bits() >> (u8::BITS - u32::from(width))
+    }
+
+    #[inline]
+    fn update(mut self, table: &[u8; 256], _width: u8, _reflect: bool, data: &[u8]) -> u8 {
+        for n in data.iter().copied() {
+            self = table[(self ^ n) as usize];
+        }
+
+        self
+    }
+}
+
+macro_rules! impl_register {
+    ($($t:ty),*) => {
+        $(
+            impl sealed::Sealed for $t {}
+
+            impl Register for $t {
+                #[inline]
+                fn from_u128(n: u128) -> $t {
+                    n as $t
+                }
+
+                #[inline]
+                fn reflect(self, width: u8) -> $t {
+                    self.reverse_bits() >> (<$t>::BITS - u32::from(width))
+                }
+
+                #[inline]
+                fn update(mut self, table: &[$t; 256], width: u8, reflect: bool, data: &[u8]) -> $t {
+                    if reflect {
+                        for n in data.iter().copied() {
+                            let index = (self as u8 ^ n) as usize;
+                            self = (self >> 8) ^ table[index];
+                        }
+                    } else {
+                        for n in data.iter().copied() {
+                            let index = ((self >> (width - 8)) as u8 ^ n) as usize;
+                            self = (self << 8) ^ table[index];
+                        }
+                    }
+
+                    self
+                }
+            }
+        )*
+    };
+}
+
+impl_register!(u16, u32, u64);
+
+/// A built-in algorithm with a static lookup table. It is only implemented for the marker types in this module.
+pub trait Preset: sealed::Sealed {
+    /// The type of the register and of CRC values.
+    type Register: Register;
+
+    /// The parameters of the algorithm.
+    const PARAMS: CrcParams;
+
+    /// The lookup table of the algorithm.
+    const TABLE: &'static [Self::Register; 256];
+}
+
+/// A CRC instance of the algorithm `P`, which only holds the register.
+pub struct Digest<P: Preset> {
+    sum:     P::Register,
+    _preset: PhantomData<P>,
+}
+
+impl<P: Preset> Digest<P> {
+    /// Create a `Digest` instance.
+    #[inline]
+    pub fn new() -> Digest<P> {
+        Digest {
+            sum: Self::initial(), _preset: PhantomData
+        }
+    }
+
+    /// Compute the CRC value of `data` in one shot.
+    #[inline]
+    pub fn checksum(data: &[u8]) -> P::Register {
+        let mut digest = Self::new();
+
+        digest.update(data);
+
+        digest.get_crc()
+    }
+
+    #[inline]
+    fn initial() -> P::Register {
+        let initial = P::Register::from_u128(P::PARAMS.init);
+
+        // the register is kept reflected for a reflected lookup table
+        if P::PARAMS.refin { initial.reflect(P::PARAMS.width) } else { initial }
+    }
+
+    /// Update the current CRC state with bytes.
+    #[inline]
+    pub fn update(&mut self, data: &[u8]) {
+        self.sum = self.sum.update(P::TABLE, P::PARAMS.width, P::PARAMS.refin, data);
+    }
+
+    /// Reset the sum.
+    #[inline]
+    pub fn reset(&mut self) {
+        self.sum = Self::initial();
+    }
+
+    /// Get the current CRC value. You can continue calling `update` even after getting a CRC value.
+    #[inline]
+    pub fn get_crc(&self) -> P::Register {
+        let mask = u128::MAX >> (u128::BITS - u32::from(P::PARAMS.width));
+
+        P::Register::from_u128((self.sum.into() ^ P::PARAMS.xorout) & mask)
+    }
+}
+
+impl<P: Preset> Default for Digest<P> {
+    #[inline]
+    fn default() -> Digest<P> {
+        Self::new()
+    }
+}
+
+impl<P: Preset> Clone for Digest<P> {
+    #[inline]
+    fn clone(&self) -> Digest<P> {
+        *self
+    }
+}
+
+impl<P: Preset> Copy for Digest<P> {}
+
+impl<P: Preset> Debug for Digest<P> {
+    #[inline]
+    fn fmt(&self, f: &mut Formatter) -> Result<(), fmt::Error> {
+        f.debug_struct("Digest").field("name", &P::PARAMS.name).field("sum", &self.sum).finish()
+    }
+}
+
+macro_rules! preset {
+    ($(#[$attr:meta])* $name:ident, $register:ty, $params:ident, $table:ident) => {
+        $(#[$attr])*
+        #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+        pub struct $name;
+
+        impl sealed::Sealed for $name {}
+
+        impl Preset for $name {
+            type Register = $register;
+
+            const PARAMS: CrcParams = params::$params;
+            const TABLE: &'static [$register; 256] = &$table;
+        }
+    };
+}
+
+preset!(
+    /// `CRC-8/AUTOSAR`, the same algorithm as [`CRC::crc8autosar`](crate::CRC::crc8autosar).
+    Crc8Autosar,
+    u8,
+    CRC_8_AUTOSAR,
+    NO_REF_8_2F
+);
+
+preset!(
+    /// `CRC-8/BLUETOOTH`, the same algorithm as [`CRC::crc8bluetooth`](crate::CRC::crc8bluetooth).
+    Crc8Bluetooth,
+    u8,
+    CRC_8_BLUETOOTH,
+    REF_8_E5
+);
+
+preset!(
+    /// `CRC-8/CDMA2000`, the same algorithm as [`CRC::crc8cdma2000`](crate::CRC::crc8cdma2000).
+    Crc8Cdma2000,
+    u8,
+    CRC_8_CDMA2000,
+    NO_REF_8_9B
+);
+
+preset!(
+    /// `CRC-8/DARC`, the same algorithm as [`CRC::crc8darc`](crate::CRC::crc8darc).
+    Crc8Darc,
+    u8,
+    CRC_8_DARC,
+    REF_8_9C
+);
+
+preset!(
+    /// `CRC-8/DVB-S2`, the same algorithm as [`CRC::crc8dvb_s2`](crate::CRC::crc8dvb_s2).
+    Crc8DvbS2,
+    u8,
+    CRC_8_DVB_S2,
+    NO_REF_8_D5
+);
+
+preset!(
+    /// `CRC-8/GSM-A`, the same algorithm as [`CRC::crc8gsm_a`](crate::CRC::crc8gsm_a).
+    Crc8GsmA,
+    u8,
+    CRC_8_GSM_A,
+    NO_REF_8_1D
+);
+
+preset!(
+    /// `CRC-8/GSM-B`, the same algorithm as [`CRC::crc8gsm_b`](crate::CRC::crc8gsm_b).
+    Crc8GsmB,
+    u8,
+    CRC_8_GSM_B,
+    NO_REF_8_49
+);
+
+preset!(
+    /// `CRC-8/HITAG`, the same algorithm as [`CRC::crc8hitag`](crate::CRC::crc8hitag).
+    Crc8Hitag,
+    u8,
+    CRC_8_HITAG,
+    NO_REF_8_1D
+);
+
+preset!(
+    /// `CRC-8/I-432-1`, the same algorithm as [`CRC::crc8itu`](crate::CRC::crc8itu).
+    Crc8I4321,
+    u8,
+    CRC_8_I_432_1,
+    NO_REF_8_07
+);
+
+preset!(
+    /// `CRC-8/I-CODE`, the same algorithm as [`CRC::crc8icode`](crate::CRC::crc8icode).
+    Crc8ICode,
+    u8,
+    CRC_8_I_CODE,
+    NO_REF_8_1D
+);
+
+preset!(
+    /// `CRC-8/LTE`, the same algorithm as [`CRC::crc8lte`](crate::CRC::crc8lte).
+    Crc8Lte,
+    u8,
+    CRC_8_LTE,
+    NO_REF_8_9B
+);
+
+preset!(
+    /// `CRC-8/MAXIM-DOW`, the same algorithm as [`CRC::crc8maxim`](crate::CRC::crc8maxim).
+    Crc8MaximDow,
+    u8,
+    CRC_8_MAXIM_DOW,
+    REF_8_8C
+);
+
+preset!(
+    /// `CRC-8/MIFARE-MAD`, the same algorithm as [`CRC::crc8mifare_mad`](crate::CRC::crc8mifare_mad).
+    Crc8MifareMad,
+    u8,
+    CRC_8_MIFARE_MAD,
+    NO_REF_8_1D
+);
+
+preset!(
+    /// `CRC-8/NRSC-5`, the same algorithm as [`CRC::crc8nrsc5`](crate::CRC::crc8nrsc5).
+    Crc8Nrsc5,
+    u8,
+    CRC_8_NRSC_5,
+    NO_REF_8_31
+);
+
+preset!(
+    /// `CRC-8/OPENSAFETY`, the same algorithm as [`CRC::crc8opensafety`](crate::CRC::crc8opensafety).
+    Crc8Opensafety,
+    u8,
+    CRC_8_OPENSAFETY,
+    NO_REF_8_2F
+);
+
+preset!(
+    /// `CRC-8/ROHC`, the same algorithm as [`CRC::crc8rohc`](crate::CRC::crc8rohc).
+    Crc8Rohc,
+    u8,
+    CRC_8_ROHC,
+    REF_8_E0
+);
+
+preset!(
+    /// `CRC-8/SAE-J1850`, the same algorithm as [`CRC::crc8sae_j1850`](crate::CRC::crc8sae_j1850).
+    Crc8SaeJ1850,
+    u8,
+    CRC_8_SAE_J1850,
+    NO_REF_8_1D
+);
+
+preset!(
+    /// `CRC-8/SMBUS`, the same algorithm as [`CRC::crc8`](crate::CRC::crc8).
+    Crc8Smbus,
+    u8,
+    CRC_8_SMBUS,
+    NO_REF_8_07
+);
+
+preset!(
+    /// `CRC-8/TECH-3250`, the same algorithm as [`CRC::crc8ebu`](crate::CRC::crc8ebu).
+    Crc8Tech3250,
+    u8,
+    CRC_8_TECH_3250,
+    REF_8_B8
+);
+
+preset!(
+    /// `CRC-8/WCDMA`, the same algorithm as [`CRC::crc8wcdma`](crate::CRC::crc8wcdma).
+    Crc8Wcdma,
+    u8,
+    CRC_8_WCDMA,
+    REF_8_D9
+);
+
+preset!(
+    /// `CRC-16/ARC`, the same algorithm as [`CRC::crc16`](crate::CRC::crc16).
+    Crc16Arc,
+    u16,
+    CRC_16_ARC,
+    REF_16_A001
+);
+
+preset!(
+    /// `CRC-16/CDMA2000`, the same algorithm as [`CRC::crc16cdma2000`](crate::CRC::crc16cdma2000).
+    Crc16Cdma2000,
+    u16,
+    CRC_16_CDMA2000,
+    NO_REF_16_C867
+);
+
+preset!(
+    /// `CRC-16/CMS`, the same algorithm as [`CRC::crc16cms`](crate::CRC::crc16cms).
+    Crc16Cms,
+    u16,
+    CRC_16_CMS,
+    NO_REF_16_8005
+);
+
+preset!(
+    /// `CRC-16/DDS-110`, the same algorithm as [`CRC::crc16dds_110`](crate::CRC::crc16dds_110).
+    Crc16Dds110,
+    u16,
+    CRC_16_DDS_110,
+    NO_REF_16_8005
+);
+
+preset!(
+    /// `CRC-16/DECT-R`, the same algorithm as [`CRC::crc16dect_r`](crate::CRC::crc16dect_r).
+    Crc16DectR,
+    u16,
+    CRC_16_DECT_R,
+    NO_REF_16_0589
+);
+
+preset!(
+    /// `CRC-16/DECT-X`, the same algorithm as [`CRC::crc16dect_x`](crate::CRC::crc16dect_x).
+    Crc16DectX,
+    u16,
+    CRC_16_DECT_X,
+    NO_REF_16_0589
+);
+
+preset!(
+    /// `CRC-16/DNP`, the same algorithm as [`CRC::crc16dnp`](crate::CRC::crc16dnp).
+    Crc16Dnp,
+    u16,
+    CRC_16_DNP,
+    REF_16_A6BC
+);
+
+preset!(
+    /// `CRC-16/EN-13757`, the same algorithm as [`CRC::crc16en_13757`](crate::CRC::crc16en_13757).
+    Crc16En13757,
+    u16,
+    CRC_16_EN_13757,
+    NO_REF_16_3D65
+);
+
+preset!(
+    /// `CRC-16/GENIBUS`, the same algorithm as [`CRC::crc16genibus`](crate::CRC::crc16genibus).
+    Crc16Genibus,
+    u16,
+    CRC_16_GENIBUS,
+    NO_REF_16_1021
+);
+
+preset!(
+    /// `CRC-16/GSM`, the same algorithm as [`CRC::crc16gsm`](crate::CRC::crc16gsm).
+    Crc16Gsm,
+    u16,
+    CRC_16_GSM,
+    NO_REF_16_1021
+);
+
+preset!(
+    /// `CRC-16/IBM-3740`, the same algorithm as [`CRC::crc16ccitt_false`](crate::CRC::crc16ccitt_false).
+    Crc16Ibm3740,
+    u16,
+    CRC_16_IBM_3740,
+    NO_REF_16_1021
+);
+
+preset!(
+    /// `CRC-16/IBM-SDLC`, the same algorithm as [`CRC::crc16_x25`](crate::CRC::crc16_x25).
+    Crc16IbmSdlc,
+    u16,
+    CRC_16_IBM_SDLC,
+    REF_16_8408
+);
+
+preset!(
+    /// `CRC-16/ISO-IEC-14443-3-A`, the same algorithm as [`CRC::crc_a`](crate::CRC::crc_a).
+    Crc16IsoIec144433A,
+    u16,
+    CRC_16_ISO_IEC_14443_3_A,
+    REF_16_8408
+);
+
+preset!(
+    /// `CRC-16/KERMIT`, the same algorithm as [`CRC::crc16kermit`](crate::CRC::crc16kermit).
+    Crc16Kermit,
+    u16,
+    CRC_16_KERMIT,
+    REF_16_8408
+);
+
+preset!(
+    /// `CRC-16/LJ1200`, the same algorithm as [`CRC::crc16lj1200`](crate::CRC::crc16lj1200).
+    Crc16Lj1200,
+    u16,
+    CRC_16_LJ1200,
+    NO_REF_16_6F63
+);
+
+preset!(
+    /// `CRC-16/M17`, the same algorithm as [`CRC::crc16m17`](crate::CRC::crc16m17).
+    Crc16M17,
+    u16,
+    CRC_16_M17,
+    NO_REF_16_5935
+);
+
+preset!(
+    /// `CRC-16/MAXIM-DOW`, the same algorithm as [`CRC::crc16maxim`](crate::CRC::crc16maxim).
+    Crc16MaximDow,
+    u16,
+    CRC_16_MAXIM_DOW,
+    REF_16_A001
+);
+
+preset!(
+    /// `CRC-16/MCRF4XX`, the same algorithm as [`CRC::crc16mcrf4cc`](crate::CRC::crc16mcrf4cc).
+    Crc16Mcrf4xx,
+    u16,
+    CRC_16_MCRF4XX,
+    REF_16_8408
+);
+
+preset!(
+    /// `CRC-16/MODBUS`, the same algorithm as [`CRC::crc16modbus`](crate::CRC::crc16modbus).
+    Crc16Modbus,
+    u16,
+    CRC_16_MODBUS,
+    REF_16_A001
+);
+
+preset!(
+    /// `CRC-16/NRSC-5`, the same algorithm as [`CRC::crc16nrsc5`](crate::CRC::crc16nrsc5).
+    Crc16Nrsc5,
+    u16,
+    CRC_16_NRSC_5,
+    REF_16_D010
+);
+
+preset!(
+    /// `CRC-16/OPENSAFETY-A`, the same algorithm as [`CRC::crc16opensafety_a`](crate::CRC::crc16opensafety_a).
+    Crc16OpensafetyA,
+    u16,
+    CRC_16_OPENSAFETY_A,
+    NO_REF_16_5935
+);
+
+preset!(
+    /// `CRC-16/OPENSAFETY-B`, the same algorithm as [`CRC::crc16opensafety_b`](crate::CRC::crc16opensafety_b).
+    Crc16OpensafetyB,
+    u16,
+    CRC_16_OPENSAFETY_B,
+    NO_REF_16_755B
+);
+
+preset!(
+    /// `CRC-16/PROFIBUS`, the same algorithm as [`CRC::crc16profibus`](crate::CRC::crc16profibus).
+    Crc16Profibus,
+    u16,
+    CRC_16_PROFIBUS,
+    NO_REF_16_1DCF
+);
+
+preset!(
+    /// `CRC-16/RIELLO`, the same algorithm as [`CRC::crc16riello`](crate::CRC::crc16riello).
+    Crc16Riello,
+    u16,
+    CRC_16_RIELLO,
+    REF_16_8408
+);
+
+preset!(
+    /// `CRC-16/SPI-FUJITSU`, the same algorithm as [`CRC::crc16aug_ccitt`](crate::CRC::crc16aug_ccitt).
+    Crc16SpiFujitsu,
+    u16,
+    CRC_16_SPI_FUJITSU,
+    NO_REF_16_1021
+);
+
+preset!(
+    /// `CRC-16/T10-DIF`, the same algorithm as [`CRC::crc16t10_dif`](crate::CRC::crc16t10_dif).
+    Crc16T10Dif,
+    u16,
+    CRC_16_T10_DIF,
+    NO_REF_16_8BB7
+);
+
+preset!(
+    /// `CRC-16/TELEDISK`, the same algorithm as [`CRC::crc16teledisk`](crate::CRC::crc16teledisk).
+    Crc16Teledisk,
+    u16,
+    CRC_16_TELEDISK,
+    REF_16_A097
+);
+
+preset!(
+    /// `CRC-16/TMS37157`, the same algorithm as [`CRC::crc16tms13157`](crate::CRC::crc16tms13157).
+    Crc16Tms37157,
+    u16,
+    CRC_16_TMS37157,
+    REF_16_8408
+);
+
+preset!(
+    /// `CRC-16/UMTS`, the same algorithm as [`CRC::crc16buypass`](crate::CRC::crc16buypass).
+    Crc16Umts,
+    u16,
+    CRC_16_UMTS,
+    NO_REF_16_8005
+);
+
+preset!(
+    /// `CRC-16/USB`, the same algorithm as [`CRC::crc16usb`](crate::CRC::crc16usb).
+    Crc16Usb,
+    u16,
+    CRC_16_USB,
+    REF_16_A001
+);
+
+preset!(
+    /// `CRC-16/XMODEM`, the same algorithm as [`CRC::crc16xmodem`](crate::CRC::crc16xmodem).
+    Crc16Xmodem,
+    u16,
+    CRC_16_XMODEM,
+    NO_REF_16_1021
+);
+
+preset!(
+    /// `CRC-24/BLE`, the same algorithm as [`CRC::crc24ble`](crate::CRC::crc24ble).
+    Crc24Ble,
+    u32,
+    CRC_24_BLE,
+    REF_24_00DA6000
+);
+
+preset!(
+    /// `CRC-24/FLEXRAY-A`, the same algorithm as [`CRC::crc24flexray_a`](crate::CRC::crc24flexray_a).
+    Crc24FlexrayA,
+    u32,
+    CRC_24_FLEXRAY_A,
+    NO_REF_24_005D6DCB
+);
+
+preset!(
+    /// `CRC-24/FLEXRAY-B`, the same algorithm as [`CRC::crc24flexray_b`](crate::CRC::crc24flexray_b).
+    Crc24FlexrayB,
+    u32,
+    CRC_24_FLEXRAY_B,
+    NO_REF_24_005D6DCB
+);
+
+preset!(
+    /// `CRC-24/INTERLAKEN`, the same algorithm as [`CRC::crc24interlaken`](crate::CRC::crc24interlaken).
+    Crc24Interlaken,
+    u32,
+    CRC_24_INTERLAKEN,
+    NO_REF_24_00328B63
+);
+
+preset!(
+    /// `CRC-24/LTE-A`, the same algorithm as [`CRC::crc24lte_a`](crate::CRC::crc24lte_a).
+    Crc24LteA,
+    u32,
+    CRC_24_LTE_A,
+    NO_REF_24_00864CFB
+);
+
+preset!(
+    /// `CRC-24/LTE-B`, the same algorithm as [`CRC::crc24lte_b`](crate::CRC::crc24lte_b).
+    Crc24LteB,
+    u32,
+    CRC_24_LTE_B,
+    NO_REF_24_00800063
+);
+
+preset!(
+    /// `CRC-24/OPENPGP`, the same algorithm as [`CRC::crc24`](crate::CRC::crc24).
+    Crc24Openpgp,
+    u32,
+    CRC_24_OPENPGP,
+    NO_REF_24_00864CFB
+);
+
+preset!(
+    /// `CRC-24/OS-9`, the same algorithm as [`CRC::crc24os9`](crate::CRC::crc24os9).
+    Crc24Os9,
+    u32,
+    CRC_24_OS_9,
+    NO_REF_24_00800063
+);
+
+preset!(
+    /// `CRC-32/AIXM`, the same algorithm as [`CRC::crc32q`](crate::CRC::crc32q).
+    Crc32Aixm,
+    u32,
+    CRC_32_AIXM,
+    NO_REF_32_814141AB
+);
+
+preset!(
+    /// `CRC-32/AUTOSAR`, the same algorithm as [`CRC::crc32autosar`](crate::CRC::crc32autosar).
+    Crc32Autosar,
+    u32,
+    CRC_32_AUTOSAR,
+    REF_32_C8DF352F
+);
+
+preset!(
+    /// `CRC-32/BASE91-D`, the same algorithm as [`CRC::crc32d`](crate::CRC::crc32d).
+    Crc32Base91D,
+    u32,
+    CRC_32_BASE91_D,
+    REF_32_D419CC15
+);
+
+preset!(
+    /// `CRC-32/BZIP2`, the same algorithm as [`CRC::crc32bzip2`](crate::CRC::crc32bzip2).
+    Crc32Bzip2,
+    u32,
+    CRC_32_BZIP2,
+    NO_REF_32_04C11DB7
+);
+
+preset!(
+    /// `CRC-32/CD-ROM-EDC`, the same algorithm as [`CRC::crc32cdrom_edc`](crate::CRC::crc32cdrom_edc).
+    Crc32CdRomEdc,
+    u32,
+    CRC_32_CD_ROM_EDC,
+    REF_32_D8018001
+);
+
+preset!(
+    /// `CRC-32/CKSUM`, the same algorithm as [`CRC::crc32posix`](crate::CRC::crc32posix).
+    Crc32Cksum,
+    u32,
+    CRC_32_CKSUM,
+    NO_REF_32_04C11DB7
+);
+
+preset!(
+    /// `CRC-32/ISCSI`, the same algorithm as [`CRC::crc32c`](crate::CRC::crc32c).
+    Crc32Iscsi,
+    u32,
+    CRC_32_ISCSI,
+    REF_32_82F63B78
+);
+
+preset!(
+    /// `CRC-32/ISO-HDLC`, the same algorithm as [`CRC::crc32`](crate::CRC::crc32).
+    Crc32IsoHdlc,
+    u32,
+    CRC_32_ISO_HDLC,
+    REF_32_EDB88320
+);
+
+preset!(
+    /// `CRC-32/JAMCRC`, the same algorithm as [`CRC::crc32jamcrc`](crate::CRC::crc32jamcrc).
+    Crc32Jamcrc,
+    u32,
+    CRC_32_JAMCRC,
+    REF_32_EDB88320
+);
+
+preset!(
+    /// `CRC-32/MEF`, the same algorithm as [`CRC::crc32mef`](crate::CRC::crc32mef).
+    Crc32Mef,
+    u32,
+    CRC_32_MEF,
+    REF_32_EB31D82E
+);
+
+preset!(
+    /// `CRC-32/MPEG-2`, the same algorithm as [`CRC::crc32mpeg2`](crate::CRC::crc32mpeg2).
+    Crc32Mpeg2,
+    u32,
+    CRC_32_MPEG_2,
+    NO_REF_32_04C11DB7
+);
+
+preset!(
+    /// `CRC-32/XFER`, the same algorithm as [`CRC::crc32xfer`](crate::CRC::crc32xfer).
+    Crc32Xfer,
+    u32,
+    CRC_32_XFER,
+    NO_REF_32_000000AF
+);
+
+preset!(
+    /// `CRC-40/GSM`, the same algorithm as [`CRC::crc40gsm`](crate::CRC::crc40gsm).
+    Crc40Gsm,
+    u64,
+    CRC_40_GSM,
+    NO_REF_40_0000000004820009
+);
+
+preset!(
+    /// `CRC-64/ECMA-182`, the same algorithm as [`CRC::crc64`](crate::CRC::crc64).
+    Crc64Ecma182,
+    u64,
+    CRC_64_ECMA_182,
+    NO_REF_64_42F0E1EBA9EA3693
+);
+
+preset!(
+    /// `CRC-64/GO-ISO`, the same algorithm as [`CRC::crc64iso`](crate::CRC::crc64iso).
+    Crc64GoIso,
+    u64,
+    CRC_64_GO_ISO,
+    REF_64_D800000000000000
+);
+
+preset!(
+    /// `CRC-64/MS`, the same algorithm as [`CRC::crc64ms`](crate::CRC::crc64ms).
+    Crc64Ms,
+    u64,
+    CRC_64_MS,
+    REF_64_92C64265D32139A4
+);
+
+preset!(
+    /// `CRC-64/NVME`, the same algorithm as [`CRC::crc64nvme`](crate::CRC::crc64nvme).
+    Crc64Nvme,
+    u64,
+    CRC_64_NVME,
+    REF_64_9A6C9329AC4BC9B5
+);
+
+preset!(
+    /// `CRC-64/REDIS`, the same algorithm as [`CRC::crc64redis`](crate::CRC::crc64redis).
+    Crc64Redis,
+    u64,
+    CRC_64_REDIS,
+    REF_64_95AC9329AC4BC9B5
+);
+
+preset!(
+    /// `CRC-64/WE`, the same algorithm as [`CRC::crc64we`](crate::CRC::crc64we).
+    Crc64We,
+    u64,
+    CRC_64_WE,
+    NO_REF_64_42F0E1EBA9EA3693
+);
+
+preset!(
+    /// `CRC-64/XZ`, the same algorithm as [`CRC::crc64xz`](crate::CRC::crc64xz).
+    Crc64Xz,
+    u64,
+    CRC_64_XZ,
+    REF_64_C96C5795D7870F42
+);
@@ -0,0 +1,786 @@
+/*!
+A registry of the built-in CRC algorithms, so that they can be looked up by name.
+
+Names are matched case-insensitively, and any character which is not an ASCII letter or digit is ignored. Each algorithm can be found by its canonical name in the [RevEng catalogue](https://reveng.sourceforge.io/crc-catalogue/), by the aliases listed in the catalogue, and by the name of its built-in function in this crate.
+
+```rust
+use crc_any::{registry, CRC};
+
+let mut crc = CRC::from_name("X-25").unwrap();
+
+crc.update(b"123456789");
+
+assert_eq!(0x906E, crc.get_crc());
+
+assert_eq!("CRC-32/ISCSI", registry::find("crc32c").unwrap().name());
+```
+
+`crc32mhash` is not a catalogue algorithm and is therefore not registered.
+*/
+
+use crate::{CRC, params::*};
+
+/// A built-in CRC algorithm in the registry.
+#[derive(Debug, Clone, Copy)]
+pub struct Algorithm {
+    params:    &'static CrcParams,
+    aliases:   &'static [&'static str],
+    functions: &'static [&'static str],
+    create:    fn() -> CRC,
+}
+
+impl Algorithm {
+    /// Get the canonical name of this algorithm in the RevEng catalogue.
+    #[inline]
+    pub const fn name(&self) -> &'static str {
+        self.params.name
+    }
+
+    /// Get the parameters of this algorithm.
+    #[inline]
+    pub const fn params(&self) -> &'static CrcParams {
+        self.params
+    }
+
+    /// Get the other names of this algorithm in the RevEng catalogue.
+    #[inline]
+    pub const fn aliases(&self) -> &'static [&'static str] {
+        self.aliases
+    }
+
+    /// Get the names of the built-in functions of this algorithm, such as `["crc32c"]` for [`CRC::crc32c`].
+    #[inline]
+    pub const fn function_names(&self) -> &'static [&'static str] {
+        self.functions
+    }
+
+    /// Create a CRC instance of this algorithm by using its built-in function.
+    #[inline]
+    pub fn create(&self) -> CRC {
+        (self.create)()
+    }
+
+    /// Check whether `name` is the name, one of the aliases or the name of a built-in function of this algorithm.
+    #[inline]
+    pub fn is_named(&self, name: &str) -> bool {
+        name_eq(self.name(), name)
+            || self.aliases.iter().any(|alias| name_eq(alias, name))
+            || self.functions.iter().any(|function| name_eq(function, name))
+    }
+}
+
+/// Get an iterator over all registered algorithms, sorted by width and then by name.
+#[inline]
+pub fn algorithms() -> impl ExactSizeIterator<Item = &'static Algorithm> {
+    ALGORITHMS.iter()
+}
+
+/// Find a registered algorithm by its name, one of its aliases, or the name of one of its built-in functions.
+#[inline]
+pub fn find(name: &str) -> Option<&'static Algorithm> {
+    ALGORITHMS.iter().find(|algorithm| algorithm.is_named(name))
+}
+
+#[inline]
+fn name_eq(a: &str, b: &str) -> bool {
+    normalize(a).eq(normalize(b))
+}
+
+#[inline]
+fn normalize(s: &str) -> impl Iterator<Item = u8> + '_ {
+    s.bytes().filter(u8::is_ascii_alphanumeric).map(|b| b.to_ascii_uppercase())
+}
+
+static ALGORITHMS: [Algorithm; 113] = [
+    Algorithm {
+        params:    &CRC_3_GSM,
+        aliases:   &[],
+        functions: &["crc3gsm"],
+        create:    CRC::crc3gsm,
+    },
+    Algorithm {
+        params:    &CRC_3_ROHC,
+        aliases:   &[],
+        functions: &["crc3rohc"],
+        create:    CRC::crc3rohc,
+    },
+    Algorithm {
+        params:    &CRC_4_G_704,
+        aliases:   &["CRC-4/ITU"],
+        functions: &["crc4itu"],
+        create:    CRC::crc4itu,
+    },
+    Algorithm {
+        params:    &CRC_4_INTERLAKEN,
+        aliases:   &[],
+        functions: &["crc4interlaken"],
+        create:    CRC::crc4interlaken,
+    },
+    Algorithm {
+        params:    &CRC_5_EPC_C1G2,
+        aliases:   &["CRC-5/EPC"],
+        functions: &["crc5epc"],
+        create:    CRC::crc5epc,
+    },
+    Algorithm {
+        params:    &CRC_5_G_704,
+        aliases:   &["CRC-5/ITU"],
+        functions: &["crc5itu"],
+        create:    CRC::crc5itu,
+    },
+    Algorithm {
+        params:    &CRC_5_USB,
+        aliases:   &[],
+        functions: &["crc5usb"],
+        create:    CRC::crc5usb,
+    },
+    Algorithm {
+        params:    &CRC_6_CDMA2000_A,
+        aliases:   &[],
+        functions: &["crc6cdma2000_a"],
+        create:    CRC::crc6cdma2000_a,
+    },
+    Algorithm {
+        params:    &CRC_6_CDMA2000_B,
+        aliases:   &[],
+        functions: &["crc6cdma2000_b"],
+        create:    CRC::crc6cdma2000_b,
+    },
+    Algorithm {
+        params:    &CRC_6_DARC,
+        aliases:   &[],
+        functions: &["crc6darc"],
+        create:    CRC::crc6darc,
+    },
+    Algorithm {
+        params:    &CRC_6_G_704,
+        aliases:   &["CRC-6/ITU"],
+        functions: &["crc6itu"],
+        create:    CRC::crc6itu,
+    },
+    Algorithm {
+        params:    &CRC_6_GSM,
+        aliases:   &[],
+        functions: &["crc6gsm"],
+        create:    CRC::crc6gsm,
+    },
+    Algorithm {
+        params:    &CRC_7_MMC,
+        aliases:   &["CRC-7"],
+        functions: &["crc7"],
+        create:    CRC::crc7,
+    },
+    Algorithm {
+        params:    &CRC_7_ROHC,
+        aliases:   &[],
+        functions: &["crc7rohc"],
+        create:    CRC::crc7rohc,
+    },
+    Algorithm {
+        params:    &CRC_7_UMTS,
+        aliases:   &[],
+        functions: &["crc7umts"],
+        create:    CRC::crc7umts,
+    },
+    Algorithm {
+        params:    &CRC_8_AUTOSAR,
+        aliases:   &[],
+        functions: &["crc8autosar"],
+        create:    CRC::crc8autosar,
+    },
+    Algorithm {
+        params:    &CRC_8_BLUETOOTH,
+        aliases:   &[],
+        functions: &["crc8bluetooth"],
+        create:    CRC::crc8bluetooth,
+    },
+    Algorithm {
+        params:    &CRC_8_CDMA2000,
+        aliases:   &[],
+        functions: &["crc8cdma2000"],
+        create:    CRC::crc8cdma2000,
+    },
+    Algorithm {
+        params:    &CRC_8_DARC,
+        aliases:   &[],
+        functions: &["crc8darc"],
+        create:    CRC::crc8darc,
+    },
+    Algorithm {
+        params:    &CRC_8_DVB_S2,
+        aliases:   &[],
+        functions: &["crc8dvb_s2"],
+        create:    CRC::crc8dvb_s2,
+    },
+    Algorithm {
+        params:    &CRC_8_GSM_A,
+        aliases:   &[],
+        functions: &["crc8gsm_a"],
+        create:    CRC::crc8gsm_a,
+    },
+    Algorithm {
+        params:    &CRC_8_GSM_B,
+        aliases:   &[],
+        functions: &["crc8gsm_b"],
+        create:    CRC::crc8gsm_b,
+    },
+    Algorithm {
+        params:    &CRC_8_HITAG,
+        aliases:   &[],
+        functions: &["crc8hitag"],
+        create:    CRC::crc8hitag,
+    },
+    Algorithm {
+        params:    &CRC_8_I_432_1,
+        aliases:   &["CRC-8/ITU"],
+        functions: &["crc8itu"],
+        create:    CRC::crc8itu,
+    },
+    Algorithm {
+        params:    &CRC_8_I_CODE,
+        aliases:   &[],
+        functions: &["crc8icode"],
+        create:    CRC::crc8icode,
+    },
+    Algorithm {
+        params:    &CRC_8_LTE,
+        aliases:   &[],
+        functions: &["crc8lte"],
+        create:    CRC::crc8lte,
+    },
+    Algorithm {
+        params:    &CRC_8_MAXIM_DOW,
+        aliases:   &["CRC-8/MAXIM", "DOW-CRC"],
+        functions: &["crc8maxim"],
+        create:    CRC::crc8maxim,
+    },
+    Algorithm {
+        params:    &CRC_8_MIFARE_MAD,
+        aliases:   &[],
+        functions: &["crc8mifare_mad"],
+        create:    CRC::crc8mifare_mad,
+    },
+    Algorithm {
+        params:    &CRC_8_NRSC_5,
+        aliases:   &[],
+        functions: &["crc8nrsc5"],
+        create:    CRC::crc8nrsc5,
+    },
+    Algorithm {
+        params:    &CRC_8_OPENSAFETY,
+        aliases:   &[],
+        functions: &["crc8opensafety"],
+        create:    CRC::crc8opensafety,
+    },
+    Algorithm {
+        params:    &CRC_8_ROHC,
+        aliases:   &[],
+        functions: &["crc8rohc"],
+        create:    CRC::crc8rohc,
+    },
+    Algorithm {
+        params:    &CRC_8_SAE_J1850,
+        aliases:   &[],
+        functions: &["crc8sae_j1850"],
+        create:    CRC::crc8sae_j1850,
+    },
+    Algorithm {
+        params:    &CRC_8_SMBUS,
+        aliases:   &["CRC-8"],
+        functions: &["crc8"],
+        create:    CRC::crc8,
+    },
+    Algorithm {
+        params:    &CRC_8_TECH_3250,
+        aliases:   &["CRC-8/AES", "CRC-8/EBU"],
+        functions: &["crc8ebu"],
+        create:    CRC::crc8ebu,
+    },
+    Algorithm {
+        params:    &CRC_8_WCDMA,
+        aliases:   &[],
+        functions: &["crc8wcdma"],
+        create:    CRC::crc8wcdma,
+    },
+    Algorithm {
+        params:    &CRC_10_ATM,
+        aliases:   &["CRC-10", "CRC-10/I-610"],
+        functions: &["crc10"],
+        create:    CRC::crc10,
+    },
+    Algorithm {
+        params:    &CRC_10_CDMA2000,
+        aliases:   &[],
+        functions: &["crc10cdma2000"],
+        create:    CRC::crc10cdma2000,
+    },
+    Algorithm {
+        params:    &CRC_10_GSM,
+        aliases:   &[],
+        functions: &["crc10gsm"],
+        create:    CRC::crc10gsm,
+    },
+    Algorithm {
+        params:    &CRC_11_FLEXRAY,
+        aliases:   &["CRC-11"],
+        functions: &["crc11"],
+        create:    CRC::crc11,
+    },
+    Algorithm {
+        params:    &CRC_11_UMTS,
+        aliases:   &[],
+        functions: &["crc11umts"],
+        create:    CRC::crc11umts,
+    },
+    Algorithm {
+        params:    &CRC_12_CDMA2000,
+        aliases:   &[],
+        functions: &["crc12cdma2000"],
+        create:    CRC::crc12cdma2000,
+    },
+    Algorithm {
+        params:    &CRC_12_DECT,
+        aliases:   &["X-CRC-12"],
+        functions: &["crc12"],
+        create:    CRC::crc12,
+    },
+    Algorithm {
+        params:    &CRC_12_GSM,
+        aliases:   &[],
+        functions: &["crc12gsm"],
+        create:    CRC::crc12gsm,
+    },
+    Algorithm {
+        params:    &CRC_12_UMTS,
+        aliases:   &["CRC-12/3GPP"],
+        functions: &["crc12umts"],
+        create:    CRC::crc12umts,
+    },
+    Algorithm {
+        params:    &CRC_13_BBC,
+        aliases:   &[],
+        functions: &["crc13bbc"],
+        create:    CRC::crc13bbc,
+    },
+    Algorithm {
+        params:    &CRC_14_DARC,
+        aliases:   &[],
+        functions: &["crc14darc"],
+        create:    CRC::crc14darc,
+    },
+    Algorithm {
+        params:    &CRC_14_GSM,
+        aliases:   &[],
+        functions: &["crc14gsm"],
+        create:    CRC::crc14gsm,
+    },
+    Algorithm {
+        params:    &CRC_15_CAN,
+        aliases:   &["CRC-15"],
+        functions: &["crc15can"],
+        create:    CRC::crc15can,
+    },
+    Algorithm {
+        params:    &CRC_15_MPT1327,
+        aliases:   &[],
+        functions: &["crc15mpt1327"],
+        create:    CRC::crc15mpt1327,
+    },
+    Algorithm {
+        params:    &CRC_16_ARC,
+        aliases:   &["ARC", "CRC-16", "CRC-16/LHA", "CRC-IBM"],
+        functions: &["crc16"],
+        create:    CRC::crc16,
+    },
+    Algorithm {
+        params:    &CRC_16_CDMA2000,
+        aliases:   &[],
+        functions: &["crc16cdma2000"],
+        create:    CRC::crc16cdma2000,
+    },
+    Algorithm {
+        params:    &CRC_16_CMS,
+        aliases:   &[],
+        functions: &["crc16cms"],
+        create:    CRC::crc16cms,
+    },
+    Algorithm {
+        params:    &CRC_16_DDS_110,
+        aliases:   &[],
+        functions: &["crc16dds_110"],
+        create:    CRC::crc16dds_110,
+    },
+    Algorithm {
+        params:    &CRC_16_DECT_R,
+        aliases:   &["R-CRC-16"],
+        functions: &["crc16dect_r"],
+        create:    CRC::crc16dect_r,
+    },
+    Algorithm {
+        params:    &CRC_16_DECT_X,
+        aliases:   &["X-CRC-16"],
+        functions: &["crc16dect_x"],
+        create:    CRC::crc16dect_x,
+    },
+    Algorithm {
+        params:    &CRC_16_DNP,
+        aliases:   &[],
+        functions: &["crc16dnp"],
+        create:    CRC::crc16dnp,
+    },
+    Algorithm {
+        params:    &CRC_16_EN_13757,
+        aliases:   &[],
+        functions: &["crc16en_13757"],
+        create:    CRC::crc16en_13757,
+    },
+    Algorithm {
+        params:    &CRC_16_GENIBUS,
+        aliases:   &["CRC-16/DARC", "CRC-16/EPC", "CRC-16/EPC-C1G2", "CRC-16/I-CODE"],
+        functions: &["crc16genibus"],
+        create:    CRC::crc16genibus,
+    },
+    Algorithm {
+        params:    &CRC_16_GSM,
+        aliases:   &[],
+        functions: &["crc16gsm"],
+        create:    CRC::crc16gsm,
+    },
+    Algorithm {
+        params:    &CRC_16_IBM_3740,
+        aliases:   &["CRC-16/AUTOSAR", "CRC-16/CCITT-FALSE"],
+        functions: &["crc16ccitt_false"],
+        create:    CRC::crc16ccitt_false,
+    },
+    Algorithm {
+        params:    &CRC_16_IBM_SDLC,
+        aliases:   &["CRC-16/ISO-HDLC", "CRC-16/ISO-IEC-14443-3-B", "CRC-16/X-25", "CRC-B", "X-25"],
+        functions: &["crc16_x25"],
+        create:    CRC::crc16_x25,
+    },
+    Algorithm {
+        params:    &CRC_16_ISO_IEC_14443_3_A,
+        aliases:   &["CRC-A"],
+        functions: &["crc_a"],
+        create:    CRC::crc_a,
+    },
+    Algorithm {
+        params:    &CRC_16_KERMIT,
+        aliases:   &[
+            "CRC-16/BLUETOOTH",
+            "CRC-16/CCITT",
+            "CRC-16/CCITT-TRUE",
+            "CRC-16/V-41-LSB",
+            "CRC-CCITT",
+            "KERMIT",
+        ],
+        functions: &["crc16kermit"],
+        create:    CRC::crc16kermit,
+    },
+    Algorithm {
+        params:    &CRC_16_LJ1200,
+        aliases:   &[],
+        functions: &["crc16lj1200"],
+        create:    CRC::crc16lj1200,
+    },
+    Algorithm {
+        params:    &CRC_16_M17,
+        aliases:   &[],
+        functions: &["crc16m17"],
+        create:    CRC::crc16m17,
+    },
+    Algorithm {
+        params:    &CRC_16_MAXIM_DOW,
+        aliases:   &["CRC-16/MAXIM"],
+        functions: &["crc16maxim"],
+        create:    CRC::crc16maxim,
+    },
+    Algorithm {
+        params:    &CRC_16_MCRF4XX,
+        aliases:   &[],
+        functions: &["crc16mcrf4cc"],
+        create:    CRC::crc16mcrf4cc,
+    },
+    Algorithm {
+        params:    &CRC_16_MODBUS,
+        aliases:   &["MODBUS"],
+        functions: &["crc16modbus"],
+        create:    CRC::crc16modbus,
+    },
+    Algorithm {
+        params:    &CRC_16_NRSC_5,
+        aliases:   &[],
+        functions: &["crc16nrsc5"],
+        create:    CRC::crc16nrsc5,
+    },
+    Algorithm {
+        params:    &CRC_16_OPENSAFETY_A,
+        aliases:   &[],
+        functions: &["crc16opensafety_a"],
+        create:    CRC::crc16opensafety_a,
+    },
+    Algorithm {
+        params:    &CRC_16_OPENSAFETY_B,
+        aliases:   &[],
+        functions: &["crc16opensafety_b"],
+        create:    CRC::crc16opensafety_b,
+    },
+    Algorithm {
+        params:    &CRC_16_PROFIBUS,
+        aliases:   &["CRC-16/IEC-61158-2"],
+        functions: &["crc16profibus"],
+        create:    CRC::crc16profibus,
+    },
+    Algorithm {
+        params:    &CRC_16_RIELLO,
+        aliases:   &[],
+        functions: &["crc16riello"],
+        create:    CRC::crc16riello,
+    },
+    Algorithm {
+        params:    &CRC_16_SPI_FUJITSU,
+        aliases:   &["CRC-16/AUG-CCITT"],
+        functions: &["crc16aug_ccitt"],
+        create:    CRC::crc16aug_ccitt,
+    },
+    Algorithm {
+        params:    &CRC_16_T10_DIF,
+        aliases:   &[],
+        functions: &["crc16t10_dif"],
+        create:    CRC::crc16t10_dif,
+    },
+    Algorithm {
+        params:    &CRC_16_TELEDISK,
+        aliases:   &[],
+        functions: &["crc16teledisk"],
+        create:    CRC::crc16teledisk,
+    },
+    Algorithm {
+        params:    &CRC_16_TMS37157,
+        aliases:   &[],
+        functions: &["crc16tms13157"],
+        create:    CRC::crc16tms13157,
+    },
+    Algorithm {
+        params:    &CRC_16_UMTS,
+        aliases:   &["CRC-16/BUYPASS", "CRC-16/VERIFONE"],
+        functions: &["crc16buypass"],
+        create:    CRC::crc16buypass,
+    },
+    Algorithm {
+        params:    &CRC_16_USB,
+        aliases:   &[],
+        functions: &["crc16usb"],
+        create:    CRC::crc16usb,
+    },
+    Algorithm {
+        params:    &CRC_16_XMODEM,
+        aliases:   &["CRC-16/ACORN", "CRC-16/LTE", "CRC-16/V-41-MSB", "XMODEM", "ZMODEM"],
+        functions: &["crc16xmodem"],
+        create:    CRC::crc16xmodem,
+    },
+    Algorithm {
+        params:    &CRC_17_CAN_FD,
+        aliases:   &[],
+        functions: &["crc17can"],
+        create:    CRC::crc17can,
+    },
+    Algorithm {
+        params:    &CRC_21_CAN_FD,
+        aliases:   &[],
+        functions: &["crc21can"],
+        create:    CRC::crc21can,
+    },
+    Algorithm {
+        params:    &CRC_24_BLE,
+        aliases:   &[],
+        functions: &["crc24ble"],
+        create:    CRC::crc24ble,
+    },
+    Algorithm {
+        params:    &CRC_24_FLEXRAY_A,
+        aliases:   &[],
+        functions: &["crc24flexray_a"],
+        create:    CRC::crc24flexray_a,
+    },
+    Algorithm {
+        params:    &CRC_24_FLEXRAY_B,
+        aliases:   &[],
+        functions: &["crc24flexray_b"],
+        create:    CRC::crc24flexray_b,
+    },
+    Algorithm {
+        params:    &CRC_24_INTERLAKEN,
+        aliases:   &[],
+        functions: &["crc24interlaken"],
+        create:    CRC::crc24interlaken,
+    },
+    Algorithm {
+        params:    &CRC_24_LTE_A,
+        aliases:   &[],
+        functions: &["crc24lte_a"],
+        create:    CRC::crc24lte_a,
+    },
+    Algorithm {
+        params:    &CRC_24_LTE_B,
+        aliases:   &[],
+        functions: &["crc24lte_b"],
+        create:    CRC::crc24lte_b,
+    },
+    Algorithm {
+        params:    &CRC_24_OPENPGP,
+        aliases:   &["CRC-24"],
+        functions: &["crc24"],
+        create:    CRC::crc24,
+    },
+    Algorithm {
+        params:    &CRC_24_OS_9,
+        aliases:   &[],
+        functions: &["crc24os9"],
+        create:    CRC::crc24os9,
+    },
+    Algorithm {
+        params:    &CRC_30_CDMA,
+        aliases:   &[],
+        functions: &["crc30cdma"],
+        create:    CRC::crc30cdma,
+    },
+    Algorithm {
+        params:    &CRC_31_PHILIPS,
+        aliases:   &[],
+        functions: &["crc31philips"],
+        create:    CRC::crc31philips,
+    },
+    Algorithm {
+        params:    &CRC_32_AIXM,
+        aliases:   &["CRC-32Q"],
+        functions: &["crc32q"],
+        create:    CRC::crc32q,
+    },
+    Algorithm {
+        params:    &CRC_32_AUTOSAR,
+        aliases:   &[],
+        functions: &["crc32autosar"],
+        create:    CRC::crc32autosar,
+    },
+    Algorithm {
+        params:    &CRC_32_BASE91_D,
+        aliases:   &["CRC-32D"],
+        functions: &["crc32d"],
+        create:    CRC::crc32d,
+    },
+    Algorithm {
+        params:    &CRC_32_BZIP2,
+        aliases:   &["CRC-32/AAL5", "CRC-32/DECT-B", "B-CRC-32"],
+        functions: &["crc32bzip2"],
+        create:    CRC::crc32bzip2,
+    },
+    Algorithm {
+        params:    &CRC_32_CD_ROM_EDC,
+        aliases:   &[],
+        functions: &["crc32cdrom_edc"],
+        create:    CRC::crc32cdrom_edc,
+    },
+    Algorithm {
+        params:    &CRC_32_CKSUM,
+        aliases:   &["CKSUM", "CRC-32/POSIX"],
+        functions: &["crc32posix"],
+        create:    CRC::crc32posix,
+    },
+    Algorithm {
+        params:    &CRC_32_ISCSI,
+        aliases:   &[
+            "CRC-32/BASE91-C",
+            "CRC-32/CASTAGNOLI",
+            "CRC-32/INTERLAKEN",
+            "CRC-32C",
+            "CRC-32/NVME",
+        ],
+        functions: &["crc32c"],
+        create:    CRC::crc32c,
+    },
+    Algorithm {
+        params:    &CRC_32_ISO_HDLC,
+        aliases:   &["CRC-32", "CRC-32/ADCCP", "CRC-32/V-42", "CRC-32/XZ", "PKZIP"],
+        functions: &["crc32"],
+        create:    CRC::crc32,
+    },
+    Algorithm {
+        params:    &CRC_32_JAMCRC,
+        aliases:   &["JAMCRC"],
+        functions: &["crc32jamcrc"],
+        create:    CRC::crc32jamcrc,
+    },
+    Algorithm {
+        params:    &CRC_32_MEF,
+        aliases:   &[],
+        functions: &["crc32mef"],
+        create:    CRC::crc32mef,
+    },
+    Algorithm {
+        params:    &CRC_32_MPEG_2,
+        aliases:   &[],
+        functions: &["crc32mpeg2"],
+        create:    CRC::crc32mpeg2,
+    },
+    Algorithm {
+        params:    &CRC_32_XFER,
+        aliases:   &["XFER"],
+        functions: &["crc32xfer"],
+        create:    CRC::crc32xfer,
+    },
+    Algorithm {
+        params:    &CRC_40_GSM,
+        aliases:   &[],
+        functions: &["crc40gsm"],
+        create:    CRC::crc40gsm,
+    },
+    Algorithm {
+        params:    &CRC_64_ECMA_182,
+        aliases:   &["CRC-64"],
+        functions: &["crc64"],
+        create:    CRC::crc64,
+    },
+    Algorithm {
+        params:    &CRC_64_GO_ISO,
+        aliases:   &[],
+        functions: &["crc64iso"],
+        create:    CRC::crc64iso,
+    },
+    Algorithm {
+        params:    &CRC_64_MS,
+        aliases:   &[],
+        functions: &["crc64ms"],
+        create:    CRC::crc64ms,
+    },
+    Algorithm {
+        params:    &CRC_64_NVME,
+        aliases:   &[],
+        functions: &["crc64nvme"],
+        create:    CRC::crc64nvme,
+    },
+    Algorithm {
+        params:    &CRC_64_REDIS,
+        aliases:   &[],
+        functions: &["crc64redis", "crc64jones"],
+        create:    CRC::crc64redis,
+    },
+    Algorithm {
+        params:    &CRC_64_WE,
+        aliases:   &[],
+        functions: &["crc64we"],
+        create:    CRC::crc64we,
+    },
+    Algorithm {
+        params:    &CRC_64_XZ,
+        aliases:   &["CRC-64/GO-ECMA"],
+        functions: &["crc64xz"],
+        create:    CRC::crc64xz,
+    },
+    Algorithm {
+        params:    &CRC_82_DARC,
+        aliases:   &[],
+        functions: &["crc82darc"],
+        create:    CRC::crc82darc,
+    },
+];
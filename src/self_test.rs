@@ -0,0 +1,60 @@
+use crate::{
+    Backend,
+    errors::{SelfTestCheck, SelfTestFailure},
+    registry,
+};
+
+const CHECK_INPUT: &[u8] = b"123456789";
+
+const BACKENDS: [Backend; 4] =
+    [Backend::Bitwise, Backend::Table, Backend::Sliced, Backend::Hardware];
+
+/// Run a self-test of every built-in algorithm in the [`registry`] with every backend available for it on this CPU, such as a power-on self-test. It works without heap allocation.
+///
+/// For each pair, the CRC value of `"123456789"` is compared with the `check` parameter of the algorithm, and the codeword made of `"123456789"` and that CRC value is checked with [`CRC::check_residue`](crate::CRC::check_residue). The residue of the algorithm is also compared with its `residue` parameter.
+///
+/// ```rust
+/// assert_eq!(Ok(()), crc_any::self_test());
+/// ```
+pub fn self_test() -> Result<(), SelfTestFailure> {
+    for algorithm in registry::algorithms() {
+        let params = algorithm.params();
+
+        for backend in BACKENDS {
+            let Ok(crc) = algorithm.create().with_backend(backend) else {
+                continue;
+            };
+
+            let failure = |check| SelfTestFailure {
+                algorithm: params.name,
+                backend,
+                check,
+            };
+
+            let mut checked = crc.clone();
+
+            checked.update(CHECK_INPUT);
+
+            if checked.get_crc_u128() != params.check {
+                return Err(failure(SelfTestCheck::Check));
+            }
+
+            let mut codeword = [0u8; CHECK_INPUT.len() + 16];
+
+            codeword[..CHECK_INPUT.len()].copy_from_slice(CHECK_INPUT);
+
+            let len = CHECK_INPUT.len()
+                + if params.refin {
+                    checked.write_crc_le(&mut codeword[CHECK_INPUT.len()..])
+                } else {
+                    checked.write_crc_be(&mut codeword[CHECK_INPUT.len()..])
+                };
+
+            if crc.residue() != params.residue || !crc.check_residue(&codeword[..len]) {
+                return Err(failure(SelfTestCheck::Residue));
+            }
+        }
+    }
+
+    Ok(())
+}
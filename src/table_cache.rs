@@ -0,0 +1,228 @@
+//! A cache of the lookup tables generated for custom algorithms, keyed by the length of bits, the expression and the input reflection.
+//!
+//! With the `std` feature, the cache is process-wide and holds up to `STD_CAPACITY` tables of each type. Every table is leaked once, so it can be shared as a `&'static` table. Without the `std` feature, every type has a small fixed number of static slots instead (fewer for wider types, to keep the static memory small). When the cache is full, a table is generated for each instance as before, except for `u128`, whose instances use the bitwise loop instead of holding a 4 KiB table.
+
+use crate::lookup_table::LookUpTable;
+
+pub(crate) type Key<T> = (u8, T, bool);
+
+pub(crate) trait CachedTable: Copy + Eq + Send + Sync + 'static {
+    fn get_or_insert(
+        key: Key<Self>,
+        generate: &impl Fn() -> [Self; 256],
+    ) -> Option<&'static [Self; 256]>;
+}
+
+/// Get the lookup table for `(bits, poly, refin)` from the cache, or generate it with `generate`.
+#[inline]
+pub(crate) fn lookup_table<T: CachedTable>(
+    bits: u8,
+    poly: T,
+    refin: bool,
+    generate: impl Fn() -> [T; 256],
+) -> LookUpTable<T> {
+    match T::get_or_insert((bits, poly, refin), &generate) {
+        Some(table) => LookUpTable::Static(table),
+        None => LookUpTable::Dynamic(generate()),
+    }
+}
+
+#[cfg(feature = "std")]
+pub(crate) use std_cache::Cache;
+
+#[cfg(feature = "std")]
+mod std_cache {
+    use std::sync::{Mutex, PoisonError};
+
+    use super::Key;
+
+    const STD_CAPACITY: usize = 256;
+
+    type Entry<T> = (Key<T>, &'static [T; 256]);
+
+    pub(crate) struct Cache<T: 'static, const N: usize> {
+        entries: Mutex<Vec<Entry<T>>>,
+    }
+
+    impl<T: Copy + Eq, const N: usize> Cache<T, N> {
+        pub(crate) const fn new() -> Cache<T, N> {
+            Cache {
+                entries: Mutex::new(Vec::new())
+            }
+        }
+
+        pub(crate) fn get_or_insert(
+            &self,
+            key: Key<T>,
+            generate: &impl Fn() -> [T; 256],
+        ) -> Option<&'static [T; 256]> {
+            let mut entries = self.entries.lock().unwrap_or_else(PoisonError::into_inner);
+
+            if let Some((_, table)) = entries.iter().find(|(k, _)| *k == key) {
+                return Some(table);
+            }
+
+            if entries.len() >= STD_CAPACITY {
+                return None;
+            }
+
+            let table: &'static [T; 256] = Box::leak(Box::new(generate()));
+
+            entries.push((key, table));
+
+            Some(table)
+        }
+    }
+}
+
+#[cfg(all(not(feature = "std"), target_has_atomic = "8"))]
+pub(crate) use slot_cache::Cache;
+
+#[cfg(all(not(feature = "std"), target_has_atomic = "8"))]
+mod slot_cache {
+    use core::{
+        cell::UnsafeCell,
+        mem::MaybeUninit,
+        sync::atomic::{AtomicU8, Ordering},
+    };
+
+    use super::Key;
+
+    const EMPTY: u8 = 0;
+    const BUSY: u8 = 1;
+    const READY: u8 = 2;
+
+    struct Slot<T> {
+        state: AtomicU8,
+        key:   UnsafeCell<MaybeUninit<Key<T>>>,
+        table: UnsafeCell<MaybeUninit<[T; 256]>>,
+    }
+
+    // the key and the table of a slot are written only once, by the thread which moves the state from `EMPTY` to `BUSY`, and are read only after the state becomes `READY`
+    unsafe impl<T: Send + Sync> Sync for Slot<T> {}
+
+    pub(crate) struct Cache<T: 'static, const N: usize> {
+        slots: [Slot<T>; N],
+    }
+
+    impl<T: Copy + Eq, const N: usize> Cache<T, N> {
+        pub(crate) const fn new() -> Cache<T, N> {
+            Cache {
+                slots: [const {
+                    Slot {
+                        state: AtomicU8::new(EMPTY),
+                        key:   UnsafeCell::new(MaybeUninit::uninit()),
+                        table: UnsafeCell::new(MaybeUninit::uninit()),
+                    }
+                }; N],
+            }
+        }
+
+        pub(crate) fn get_or_insert(
+            &'static self,
+            key: Key<T>,
+            generate: &impl Fn() -> [T; 256],
+        ) -> Option<&'static [T; 256]> {
+            for slot in self.slots.iter() {
+                match slot.state.load(Ordering::Acquire) {
+                    READY => {
+                        // SAFETY: the slot is ready, so its key and table are initialized and never written again
+                        unsafe {
+                            if (*slot.key.get()).assume_init() == key {
+                                return Some((*slot.table.get()).assume_init_ref());
+                            }
+                        }
+                    },
+                    EMPTY
+                        if slot
+                            .state
+                            .compare_exchange(EMPTY, BUSY, Ordering::Acquire, Ordering::Relaxed)
+                            .is_ok() =>
+                    {
+                        // SAFETY: only this thread can access the slot while it is busy
+                        unsafe {
+                            (*slot.key.get()).write(key);
+                            (*slot.table.get()).write(generate());
+                        }
+
+                        slot.state.store(READY, Ordering::Release);
+
+                        // SAFETY: the table has just been initialized
+                        return Some(unsafe { (*slot.table.get()).assume_init_ref() });
+                    },
+                    _ => (),
+                }
+            }
+
+            None
+        }
+    }
+}
+
+#[cfg(all(not(feature = "std"), not(target_has_atomic = "8")))]
+pub(crate) struct Cache<T: 'static, const N: usize>(core::marker::PhantomData<T>);
+
+#[cfg(all(not(feature = "std"), not(target_has_atomic = "8")))]
+impl<T, const N: usize> Cache<T, N> {
+    pub(crate) const fn new() -> Cache<T, N> {
+        Cache(core::marker::PhantomData)
+    }
+
+    #[inline]
+    pub(crate) fn get_or_insert(
+        &self,
+        _key: Key<T>,
+        _generate: &impl Fn() -> [T; 256],
+    ) -> Option<&'static [T; 256]> {
+        None
+    }
+}
+
+macro_rules! impl_cached_table {
+    ($($t:ty => $slots:expr),*) => {
+        $(
+            impl CachedTable for $t {
+                #[inline]
+                fn get_or_insert(
+                    key: Key<$t>,
+                    generate: &impl Fn() -> [$t; 256],
+                ) -> Option<&'static [$t; 256]> {
+                    static CACHE: Cache<$t, $slots> = Cache::new();
+
+                    CACHE.get_or_insert(key, generate)
+                }
+            }
+        )*
+    };
+}
+
+impl_cached_table!(u8 => 4, u16 => 4, u32 => 4, u64 => 2, u128 => 1);
+
+#[cfg(all(test, any(feature = "std", target_has_atomic = "8")))]
+mod tests {
+    use core::cell::Cell;
+
+    use super::*;
+
+    #[test]
+    fn identical_keys_share_one_table() {
+        let generated = Cell::new(0);
+
+        let generate = || {
+            generated.set(generated.get() + 1);
+
+            [0x2468ACE0u32; 256]
+        };
+
+        let a = lookup_table(32, 0x2468ACE1u32, true, generate);
+        let b = lookup_table(32, 0x2468ACE1u32, true, generate);
+
+        assert!(a.is_same_static(&b));
+        assert_eq!(1, generated.get());
+
+        let c = lookup_table(32, 0x2468ACE1u32, false, generate);
+
+        assert!(!a.is_same_static(&c));
+        assert_eq!(2, generated.get());
+    }
+}
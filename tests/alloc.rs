@@ -1,6 +1,6 @@
 #![cfg(feature = "alloc")]
 
-use crc_any::{CRC, CRCu16, CRCu32, CRCu64};
+use crc_any::{CRC, CRCu16, CRCu32, CRCu64, CRCu128};
 
 #[test]
 fn crc() {
@@ -77,6 +77,29 @@ fn crc_u64() {
     assert_eq!(vec![152, 195, 5, 94, 90], crc.get_crc_vec_le());
 }
 
+#[test]
+fn crc_u128() {
+    let mut crc = CRCu128::crc82darc();
+
+    crc.digest(b"https://magiclen.org");
+
+    assert_eq!(vec![2, 18, 64, 92, 181, 229, 130, 145, 35, 235, 102], crc.get_crc_vec_be());
+    assert_eq!(vec![102, 235, 35, 145, 130, 229, 181, 92, 64, 18, 2], crc.get_crc_vec_le());
+
+    let mut crc = CRC::create_crc_u128(0x87, 128, 0, 0, false);
+
+    crc.digest(b"https://magiclen.org");
+
+    assert_eq!(
+        vec![196, 177, 90, 122, 178, 149, 128, 175, 158, 55, 151, 7, 175, 75, 146, 173],
+        crc.get_crc_vec_be()
+    );
+    assert_eq!(
+        vec![173, 146, 75, 175, 7, 151, 55, 158, 175, 128, 149, 178, 122, 90, 177, 196],
+        crc.get_crc_vec_le()
+    );
+}
+
 #[test]
 fn immutable_getters() {
     let mut crc = CRC::crc32c();
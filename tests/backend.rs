@@ -0,0 +1,132 @@
+use crc_any::{Backend, CRC, CRCu32, CRCu64, UnavailableBackend, registry};
+
+const CHECK_INPUT: &[u8] = b"123456789";
+
+const BACKENDS: [Backend; 4] =
+    [Backend::Bitwise, Backend::Table, Backend::Sliced, Backend::Hardware];
+
+const SLICED: [&str; 9] = [
+    "CRC-32/ISO-HDLC",
+    "CRC-32/BZIP2",
+    "CRC-32/MPEG-2",
+    "CRC-32/CKSUM",
+    "CRC-32/JAMCRC",
+    "CRC-32/ISCSI",
+    "CRC-64/ECMA-182",
+    "CRC-64/WE",
+    "CRC-64/XZ",
+];
+
+#[test]
+fn backends_of_algorithms() {
+    for algorithm in registry::algorithms() {
+        let params = algorithm.params();
+
+        for backend in BACKENDS {
+            let available = match backend {
+                Backend::Bitwise => true,
+                Backend::Table => params.width % 8 == 0,
+                Backend::Sliced => SLICED.contains(&params.name),
+                Backend::Hardware => params.name == "CRC-32/ISCSI",
+            };
+
+            match CRC::from_params(params).with_backend(backend) {
+                Ok(mut crc) => {
+                    assert!(available, "{} {backend:?}", params.name);
+                    assert_eq!(backend, crc.backend(), "{} {backend:?}", params.name);
+
+                    crc.update(CHECK_INPUT);
+
+                    assert_eq!(params.check, crc.get_crc_u128(), "{} {backend:?}", params.name);
+                },
+                Err(err) => {
+                    // the hardware backend also depends on the CPU
+                    assert!(
+                        !available || backend == Backend::Hardware,
+                        "{} {backend:?}",
+                        params.name
+                    );
+
+                    assert_eq!(
+                        UnavailableBackend {
+                            backend
+                        },
+                        err
+                    );
+                },
+            }
+        }
+    }
+}
+
+#[test]
+fn sliced_availability() {
+    for name in SLICED {
+        assert!(CRC::from_name(name).unwrap().with_backend(Backend::Sliced).is_ok(), "{name}");
+    }
+
+    assert!(CRCu64::crc64xz().with_backend(Backend::Sliced).is_ok());
+    assert!(CRCu64::crc64we().with_backend(Backend::Sliced).is_ok());
+    assert!(CRCu64::crc64iso().with_backend(Backend::Sliced).is_err());
+    assert!(CRCu32::crc32d().with_backend(Backend::Sliced).is_err());
+    assert!(CRCu32::crc24().with_backend(Backend::Sliced).is_err());
+}
+
+#[test]
+fn default_backends() {
+    assert_eq!(Backend::Bitwise, CRC::crc3gsm().backend());
+    assert_eq!(Backend::Table, CRC::crc16().backend());
+    assert_eq!(Backend::Table, CRC::crc32().backend());
+    assert_eq!(Backend::Bitwise, CRC::crc82darc().backend());
+
+    let crc32c = CRC::crc32c();
+
+    match CRC::crc32c().with_backend(Backend::Hardware) {
+        Ok(crc) => {
+            assert_eq!(Backend::Hardware, crc32c.backend());
+            assert_eq!(Backend::Hardware, crc.backend());
+        },
+        Err(_) => assert_eq!(Backend::Table, crc32c.backend()),
+    }
+
+    assert_eq!(Backend::Table, crc32c.with_backend(Backend::Table).unwrap().backend());
+}
+
+#[test]
+fn switch_backends() {
+    let data: Vec<u8> = (0..10_000u32).map(|n| (n * 31 + n / 7) as u8).collect();
+
+    for name in ["CRC-32/ISO-HDLC", "CRC-32/BZIP2", "CRC-32/ISCSI", "CRC-64/XZ", "CRC-64/ECMA-182"]
+    {
+        let mut expected = CRC::from_name(name).unwrap();
+        expected.update(&data);
+
+        let mut crc = CRC::from_name(name).unwrap();
+
+        for (i, chunk) in data.chunks(1234).enumerate() {
+            crc = crc.with_backend(BACKENDS[i % 3]).unwrap();
+            crc.update(chunk);
+        }
+
+        assert_eq!(expected.get_crc(), crc.get_crc(), "{name}");
+        assert!(expected == crc, "{name}");
+    }
+
+    let mut crc = CRCu32::crc24().with_backend(Backend::Bitwise).unwrap();
+    crc.update(&CHECK_INPUT[..3]);
+
+    let mut crc = crc.with_backend(Backend::Table).unwrap();
+    crc.update(&CHECK_INPUT[3..]);
+
+    assert_eq!(0x21CF02, crc.get_crc());
+    assert!(crc.is_same_algorithm(&CRCu32::crc24().with_backend(Backend::Bitwise).unwrap()));
+}
+
+#[cfg(feature = "alloc")]
+#[test]
+fn unavailable_backend_message() {
+    assert_eq!(
+        "the Sliced backend is not available for this CRC",
+        CRC::crc16().with_backend(Backend::Sliced).unwrap_err().to_string()
+    );
+}
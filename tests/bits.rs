@@ -0,0 +1,89 @@
+use crc_any::{CRC, CRCu8, CRCu16, CRCu32, CRCu64, CRCu128, registry};
+
+const CHECK_INPUT: &[u8] = b"123456789";
+
+#[test]
+fn bits_of_bytes() {
+    for algorithm in registry::algorithms() {
+        let params = algorithm.params();
+
+        let mut crc = CRC::from_params(params);
+
+        for n in CHECK_INPUT.iter().copied() {
+            crc.update_bits(u64::from(n), 8);
+        }
+
+        assert_eq!(params.check, crc.get_crc_u128(), "{}", params.name);
+
+        let mut crc = CRC::from_params(params);
+
+        crc.update(&CHECK_INPUT[..4]);
+
+        for n in CHECK_INPUT[4..].iter().copied() {
+            for i in 0..8 {
+                crc.update_bit(if params.refin { n >> i } else { n << i >> 7 } & 1 != 0);
+            }
+        }
+
+        assert_eq!(params.check, crc.get_crc_u128(), "{}", params.name);
+    }
+}
+
+#[test]
+fn bits_of_frames() {
+    let mut crc = CRCu8::crc5usb();
+    crc.update_bits(0x710, 11);
+
+    assert_eq!(0x05, crc.get_crc());
+
+    let mut crc = CRCu16::crc15can();
+    crc.update_bits(0x5A5A5, 19);
+
+    assert_eq!(0x11CC, crc.get_crc());
+
+    let mut crc = CRCu8::crc3gsm();
+    crc.update_bits(0b10110, 5);
+
+    assert_eq!(0x07, crc.get_crc());
+
+    let mut crc = CRCu32::crc32();
+    crc.update_bits(0xABC, 12);
+
+    assert_eq!(0x4CB0A31E, crc.get_crc());
+
+    let mut crc = CRCu16::crc16xmodem();
+    crc.update_bits(0xA, 4);
+    crc.update_bits(0xBC, 8);
+
+    assert_eq!(0x899C, crc.get_crc());
+
+    let mut crc = CRCu32::crc24();
+    crc.update_bits(0xABC, 12);
+
+    assert_eq!(0x32ECE6, crc.get_crc());
+}
+
+#[test]
+fn bits_of_wide_values() {
+    let mut a = CRCu64::crc64();
+    a.update_bits(0x0123456789ABCDEF, 64);
+
+    let mut b = CRCu64::crc64();
+    b.update(&0x0123456789ABCDEFu64.to_be_bytes());
+
+    assert_eq!(b.get_crc(), a.get_crc());
+
+    let mut a = CRCu128::crc82darc();
+    a.update_bits(0x0123456789ABCDEF, 64);
+
+    let mut b = CRCu128::crc82darc();
+    b.update(&0x0123456789ABCDEFu64.to_le_bytes());
+
+    assert_eq!(b.get_crc(), a.get_crc());
+}
+
+#[test]
+#[should_panic]
+fn too_many_bits() {
+    CRCu32::crc32().update_bits(0, 65);
+}
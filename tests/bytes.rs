@@ -0,0 +1,66 @@
+use crc_any::{CRC, CRCu8, CRCu16, CRCu32, CRCu64, CRCu128};
+
+const CHECK_INPUT: &[u8] = b"123456789";
+
+#[test]
+fn crc_bytes() {
+    let mut crc = CRCu8::crc8();
+    crc.update(CHECK_INPUT);
+
+    assert_eq!([0xF4], crc.get_crc_bytes_be());
+    assert_eq!([0xF4], crc.get_crc_bytes_le());
+
+    let mut crc = CRCu16::crc16modbus();
+    crc.update(CHECK_INPUT);
+
+    assert_eq!([0x4B, 0x37], crc.get_crc_bytes_be());
+    assert_eq!([0x37, 0x4B], crc.get_crc_bytes_le());
+
+    let mut crc = CRCu32::crc24();
+    crc.update(CHECK_INPUT);
+
+    assert_eq!([0x21, 0xCF, 0x02, 0x00], crc.get_crc_bytes_be());
+    assert_eq!([0x02, 0xCF, 0x21, 0x00], crc.get_crc_bytes_le());
+
+    let mut crc = CRCu64::crc40gsm();
+    crc.update(CHECK_INPUT);
+
+    assert_eq!([0xD4, 0x16, 0x4F, 0xC6, 0x46, 0, 0, 0], crc.get_crc_bytes_be());
+    assert_eq!([0x46, 0xC6, 0x4F, 0x16, 0xD4, 0, 0, 0], crc.get_crc_bytes_le());
+
+    let mut crc = CRCu128::crc82darc();
+    crc.update(CHECK_INPUT);
+
+    assert_eq!(
+        [0x00, 0x9E, 0xA8, 0x3F, 0x62, 0x50, 0x23, 0x80, 0x1F, 0xD6, 0x12, 0, 0, 0, 0, 0],
+        crc.get_crc_bytes_be()
+    );
+    assert_eq!(0x09EA83F625023801FD612u128.to_le_bytes(), crc.get_crc_bytes_le());
+}
+
+#[test]
+fn write_crc() {
+    let mut crc = CRC::crc32();
+    crc.update(CHECK_INPUT);
+
+    let mut frame = [0xAAu8; 6];
+
+    assert_eq!(4, crc.crc_len());
+    assert_eq!(4, crc.write_crc_be(&mut frame[1..]));
+    assert_eq!([0xAA, 0xCB, 0xF4, 0x39, 0x26, 0xAA], frame);
+
+    assert_eq!(4, crc.write_crc_le(&mut frame[1..]));
+    assert_eq!([0xAA, 0x26, 0x39, 0xF4, 0xCB, 0xAA], frame);
+
+    let mut crc = CRC::crc3gsm();
+    crc.update(CHECK_INPUT);
+
+    assert_eq!(1, crc.write_crc_be(&mut frame));
+    assert_eq!(0x04, frame[0]);
+}
+
+#[test]
+#[should_panic]
+fn write_crc_short_buffer() {
+    CRC::crc32().write_crc_be(&mut [0u8; 3]);
+}
@@ -0,0 +1,155 @@
+use crc_any::{
+    CRC,
+    checksum::{self, *},
+    params::*,
+    preset::{Crc16Modbus, Crc64Xz},
+};
+
+const CHECK_INPUT: &[u8] = b"123456789";
+
+macro_rules! check {
+    ($(($f:ident, $params:ident)),* $(,)?) => {
+        $(
+            assert_eq!($params.check, u128::from($f(CHECK_INPUT)), stringify!($f));
+        )*
+    };
+}
+
+#[test]
+fn checksum_functions() {
+    check!(
+        (crc3_gsm, CRC_3_GSM),
+        (crc3_rohc, CRC_3_ROHC),
+        (crc4_g_704, CRC_4_G_704),
+        (crc4_interlaken, CRC_4_INTERLAKEN),
+        (crc5_epc_c1g2, CRC_5_EPC_C1G2),
+        (crc5_g_704, CRC_5_G_704),
+        (crc5_usb, CRC_5_USB),
+        (crc6_cdma2000_a, CRC_6_CDMA2000_A),
+        (crc6_cdma2000_b, CRC_6_CDMA2000_B),
+        (crc6_darc, CRC_6_DARC),
+        (crc6_g_704, CRC_6_G_704),
+        (crc6_gsm, CRC_6_GSM),
+        (crc7_mmc, CRC_7_MMC),
+        (crc7_rohc, CRC_7_ROHC),
+        (crc7_umts, CRC_7_UMTS),
+        (crc8_autosar, CRC_8_AUTOSAR),
+        (crc8_bluetooth, CRC_8_BLUETOOTH),
+        (crc8_cdma2000, CRC_8_CDMA2000),
+        (crc8_darc, CRC_8_DARC),
+        (crc8_dvb_s2, CRC_8_DVB_S2),
+        (crc8_gsm_a, CRC_8_GSM_A),
+        (crc8_gsm_b, CRC_8_GSM_B),
+        (crc8_hitag, CRC_8_HITAG),
+        (crc8_i_432_1, CRC_8_I_432_1),
+        (crc8_i_code, CRC_8_I_CODE),
+        (crc8_lte, CRC_8_LTE),
+        (crc8_maxim_dow, CRC_8_MAXIM_DOW),
+        (crc8_mifare_mad, CRC_8_MIFARE_MAD),
+        (crc8_nrsc_5, CRC_8_NRSC_5),
+        (crc8_opensafety, CRC_8_OPENSAFETY),
+        (crc8_rohc, CRC_8_ROHC),
+        (crc8_sae_j1850, CRC_8_SAE_J1850),
+        (crc8_smbus, CRC_8_SMBUS),
+        (crc8_tech_3250, CRC_8_TECH_3250),
+        (crc8_wcdma, CRC_8_WCDMA),
+        (crc10_atm, CRC_10_ATM),
+        (crc10_cdma2000, CRC_10_CDMA2000),
+        (crc10_gsm, CRC_10_GSM),
+        (crc11_flexray, CRC_11_FLEXRAY),
+        (crc11_umts, CRC_11_UMTS),
+        (crc12_cdma2000, CRC_12_CDMA2000),
+        (crc12_dect, CRC_12_DECT),
+        (crc12_gsm, CRC_12_GSM),
+        (crc12_umts, CRC_12_UMTS),
+        (crc13_bbc, CRC_13_BBC),
+        (crc14_darc, CRC_14_DARC),
+        (crc14_gsm, CRC_14_GSM),
+        (crc15_can, CRC_15_CAN),
+        (crc15_mpt1327, CRC_15_MPT1327),
+        (crc16_arc, CRC_16_ARC),
+        (crc16_cdma2000, CRC_16_CDMA2000),
+        (crc16_cms, CRC_16_CMS),
+        (crc16_dds_110, CRC_16_DDS_110),
+        (crc16_dect_r, CRC_16_DECT_R),
+        (crc16_dect_x, CRC_16_DECT_X),
+        (crc16_dnp, CRC_16_DNP),
+        (crc16_en_13757, CRC_16_EN_13757),
+        (crc16_genibus, CRC_16_GENIBUS),
+        (crc16_gsm, CRC_16_GSM),
+        (crc16_ibm_3740, CRC_16_IBM_3740),
+        (crc16_ibm_sdlc, CRC_16_IBM_SDLC),
+        (crc16_iso_iec_14443_3_a, CRC_16_ISO_IEC_14443_3_A),
+        (crc16_kermit, CRC_16_KERMIT),
+        (crc16_lj1200, CRC_16_LJ1200),
+        (crc16_m17, CRC_16_M17),
+        (crc16_maxim_dow, CRC_16_MAXIM_DOW),
+        (crc16_mcrf4xx, CRC_16_MCRF4XX),
+        (crc16_modbus, CRC_16_MODBUS),
+        (crc16_nrsc_5, CRC_16_NRSC_5),
+        (crc16_opensafety_a, CRC_16_OPENSAFETY_A),
+        (crc16_opensafety_b, CRC_16_OPENSAFETY_B),
+        (crc16_profibus, CRC_16_PROFIBUS),
+        (crc16_riello, CRC_16_RIELLO),
+        (crc16_spi_fujitsu, CRC_16_SPI_FUJITSU),
+        (crc16_t10_dif, CRC_16_T10_DIF),
+        (crc16_teledisk, CRC_16_TELEDISK),
+        (crc16_tms37157, CRC_16_TMS37157),
+        (crc16_umts, CRC_16_UMTS),
+        (crc16_usb, CRC_16_USB),
+        (crc16_xmodem, CRC_16_XMODEM),
+        (crc17_can_fd, CRC_17_CAN_FD),
+        (crc21_can_fd, CRC_21_CAN_FD),
+        (crc24_ble, CRC_24_BLE),
+        (crc24_flexray_a, CRC_24_FLEXRAY_A),
+        (crc24_flexray_b, CRC_24_FLEXRAY_B),
+        (crc24_interlaken, CRC_24_INTERLAKEN),
+        (crc24_lte_a, CRC_24_LTE_A),
+        (crc24_lte_b, CRC_24_LTE_B),
+        (crc24_openpgp, CRC_24_OPENPGP),
+        (crc24_os_9, CRC_24_OS_9),
+        (crc30_cdma, CRC_30_CDMA),
+        (crc31_philips, CRC_31_PHILIPS),
+        (crc32_aixm, CRC_32_AIXM),
+        (crc32_autosar, CRC_32_AUTOSAR),
+        (crc32_base91_d, CRC_32_BASE91_D),
+        (crc32_bzip2, CRC_32_BZIP2),
+        (crc32_cd_rom_edc, CRC_32_CD_ROM_EDC),
+        (crc32_cksum, CRC_32_CKSUM),
+        (crc32_iscsi, CRC_32_ISCSI),
+        (crc32_iso_hdlc, CRC_32_ISO_HDLC),
+        (crc32_jamcrc, CRC_32_JAMCRC),
+        (crc32_mef, CRC_32_MEF),
+        (crc32_mpeg_2, CRC_32_MPEG_2),
+        (crc32_xfer, CRC_32_XFER),
+        (crc40_gsm, CRC_40_GSM),
+        (crc64_ecma_182, CRC_64_ECMA_182),
+        (crc64_go_iso, CRC_64_GO_ISO),
+        (crc64_ms, CRC_64_MS),
+        (crc64_nvme, CRC_64_NVME),
+        (crc64_redis, CRC_64_REDIS),
+        (crc64_we, CRC_64_WE),
+        (crc64_xz, CRC_64_XZ),
+        (crc82_darc, CRC_82_DARC),
+    );
+}
+
+#[test]
+fn checksum_mhash() {
+    let mut crc = CRC::crc32mhash();
+    crc.update(CHECK_INPUT);
+
+    assert_eq!(crc.get_crc(), u64::from(checksum::crc32_mhash(CHECK_INPUT)));
+}
+
+#[test]
+fn slice_extension() {
+    assert_eq!(0xF4, CHECK_INPUT.crc8());
+    assert_eq!(0xBB3D, CHECK_INPUT.crc16());
+    assert_eq!(0xCBF43926, b"123456789".crc32());
+    assert_eq!(0xE3069283, CHECK_INPUT.crc32c());
+    assert_eq!(0x6C40DF5F0B497347, CHECK_INPUT.crc64());
+    assert_eq!(0x4B37, CHECK_INPUT.crc::<Crc16Modbus>());
+    assert_eq!(0x995DC9BBDF1939FA, CHECK_INPUT.crc::<Crc64Xz>());
+    assert_eq!(0, [].crc8());
+}
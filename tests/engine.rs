@@ -0,0 +1,79 @@
+use crc_any::{CRCu8, CRCu16, CRCu32, CRCu64, CRCu128, CrcEngine, CrcParams, params::*};
+
+const CHECK_INPUT: &[u8] = b"123456789";
+
+fn check<E: CrcEngine>(params: &CrcParams) -> E::Output {
+    let mut crc = E::from_params(params);
+
+    crc.update(b"https://magiclen.org");
+    crc.reset();
+    crc.update(CHECK_INPUT);
+
+    assert_eq!(params.width, crc.width());
+    assert_eq!(params.check, crc.get_crc().into(), "{}", params.name);
+
+    crc.get_crc()
+}
+
+fn write<E: CrcEngine>(params: &CrcParams) -> (usize, [u8; 16], [u8; 16]) {
+    let mut crc = E::try_from_params(params).unwrap();
+
+    crc.update(CHECK_INPUT);
+
+    let mut be = [0u8; 16];
+    let mut le = [0u8; 16];
+
+    assert_eq!(crc.crc_len(), crc.write_crc_be(&mut be));
+    assert_eq!(crc.crc_len(), crc.write_crc_le(&mut le));
+
+    (crc.crc_len(), be, le)
+}
+
+#[test]
+fn width_max() {
+    assert_eq!(8, CRCu8::WIDTH_MAX);
+    assert_eq!(16, CRCu16::WIDTH_MAX);
+    assert_eq!(32, CRCu32::WIDTH_MAX);
+    assert_eq!(64, CRCu64::WIDTH_MAX);
+    assert_eq!(128, CRCu128::WIDTH_MAX);
+}
+
+#[test]
+fn generic_check() {
+    assert_eq!(0x4, check::<CRCu8>(&CRC_3_GSM));
+    assert_eq!(0xF4, check::<CRCu8>(&CRC_8_SMBUS));
+    assert_eq!(0x4B37, check::<CRCu16>(&CRC_16_MODBUS));
+    assert_eq!(0x21CF02, check::<CRCu32>(&CRC_24_OPENPGP));
+    assert_eq!(0xE3069283, check::<CRCu32>(&CRC_32_ISCSI));
+    assert_eq!(0x995DC9BBDF1939FA, check::<CRCu64>(&CRC_64_XZ));
+    assert_eq!(0x09EA83F625023801FD612, check::<CRCu128>(&CRC_82_DARC));
+}
+
+#[test]
+fn generic_write() {
+    let (len, be, le) = write::<CRCu16>(&CRC_16_MODBUS);
+
+    assert_eq!(2, len);
+    assert_eq!([0x4B, 0x37], be[..len]);
+    assert_eq!([0x37, 0x4B], le[..len]);
+
+    let (len, be, le) = write::<CRCu32>(&CRC_24_OPENPGP);
+
+    assert_eq!(3, len);
+    assert_eq!([0x21, 0xCF, 0x02], be[..len]);
+    assert_eq!([0x02, 0xCF, 0x21], le[..len]);
+
+    let (len, be, le) = write::<CRCu128>(&CRC_82_DARC);
+
+    assert_eq!(11, len);
+    assert_eq!([0x00, 0x9E, 0xA8, 0x3F, 0x62, 0x50, 0x23, 0x80, 0x1F, 0xD6, 0x12], be[..len]);
+    assert_eq!([0x12, 0xD6, 0x1F, 0x80, 0x23, 0x50, 0x62, 0x3F, 0xA8, 0x9E, 0x00], le[..len]);
+}
+
+#[test]
+#[should_panic]
+fn write_into_short_buffer() {
+    let crc = CRCu32::from_params(&CRC_32_ISO_HDLC);
+
+    crc.write_crc_be(&mut [0u8; 3]);
+}
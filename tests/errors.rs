@@ -0,0 +1,156 @@
+use crc_any::{
+    CRC, CRCu8, CRCu16, CRCu32, CRCu64, CRCu128, CrcParamError, CrcParamField, CrcParams, params::*,
+};
+
+const CHECK_INPUT: &[u8] = b"123456789";
+
+#[test]
+fn zero_width() {
+    assert_eq!(Some(CrcParamError::ZeroWidth), CRCu8::try_create_crc(0x07, 0, 0, 0, false).err());
+    assert_eq!(Some(CrcParamError::ZeroWidth), CRC::try_create_crc(0x07, 0, 0, 0, false).err());
+}
+
+#[test]
+fn width_too_large() {
+    assert_eq!(
+        Some(CrcParamError::WidthTooLarge {
+            width: 9, max: 8
+        }),
+        CRCu8::try_create_crc(0x07, 9, 0, 0, false).err()
+    );
+    assert_eq!(
+        Some(CrcParamError::WidthTooLarge {
+            width: 17, max: 16
+        }),
+        CRCu16::try_create_crc(0x8005, 17, 0, 0, false).err()
+    );
+    assert_eq!(
+        Some(CrcParamError::WidthTooLarge {
+            width: 33, max: 32
+        }),
+        CRCu32::try_create_crc(0x04C11DB7, 33, 0, 0, false).err()
+    );
+    assert_eq!(
+        Some(CrcParamError::WidthTooLarge {
+            width: 65, max: 64
+        }),
+        CRCu64::try_create_crc(0x42F0E1EBA9EA3693, 65, 0, 0, false).err()
+    );
+    assert_eq!(
+        Some(CrcParamError::WidthTooLarge {
+            width: 129, max: 128
+        }),
+        CRCu128::try_create_crc(0x87, 129, 0, 0, false).err()
+    );
+    assert_eq!(
+        Some(CrcParamError::WidthTooLarge {
+            width: 129, max: 128
+        }),
+        CRC::try_create_crc(0x87, 129, 0, 0, false).err()
+    );
+}
+
+#[test]
+fn value_out_of_range() {
+    assert_eq!(
+        Some(CrcParamError::ValueOutOfRange {
+            field: CrcParamField::Poly, width: 5
+        }),
+        CRCu8::try_create_crc(0x25, 5, 0x1F, 0x1F, false).err()
+    );
+    assert_eq!(
+        Some(CrcParamError::ValueOutOfRange {
+            field: CrcParamField::Init, width: 12
+        }),
+        CRCu16::try_create_crc(0x080F, 12, 0x1000, 0x000, false).err()
+    );
+    assert_eq!(
+        Some(CrcParamError::ValueOutOfRange {
+            field: CrcParamField::Xorout, width: 24
+        }),
+        CRC::try_create_crc(0x864CFB, 24, 0xB704CE, 0xFFFFFFFF, false).err()
+    );
+}
+
+#[test]
+fn valid_params() {
+    let mut crc = CRC::try_create_crc(0x864CFB, 24, 0xB704CE, 0x000000, false).unwrap();
+    crc.update(CHECK_INPUT);
+
+    assert_eq!(CRC_24_OPENPGP.check, crc.get_crc_u128());
+
+    let mut crc = CRCu16::try_create_crc_with_reflection(0x080F, 12, 0, 0, false, true).unwrap();
+    crc.update(CHECK_INPUT);
+
+    assert_eq!(0xDAF, crc.get_crc());
+}
+
+#[test]
+fn try_from_params() {
+    let mut crc = CRC::try_from_params(&CRC_82_DARC).unwrap();
+    crc.update(CHECK_INPUT);
+
+    assert_eq!(CRC_82_DARC.check, crc.get_crc_u128());
+
+    assert_eq!(
+        Some(CrcParamError::WidthTooLarge {
+            width: 82, max: 64
+        }),
+        CRCu64::try_from_params(&CRC_82_DARC).err()
+    );
+
+    let params = CrcParams {
+        init: 0x1_0000,
+        ..CRC_16_MODBUS
+    };
+
+    assert_eq!(
+        Some(CrcParamError::ValueOutOfRange {
+            field: CrcParamField::Init, width: 16
+        }),
+        CRC::try_from_params(&params).err()
+    );
+
+    let params = CrcParams {
+        residue: 0x1_0000,
+        ..CRC_16_MODBUS
+    };
+
+    assert_eq!(
+        Some(CrcParamError::ValueOutOfRange {
+            field: CrcParamField::Residue, width: 16
+        }),
+        CRCu16::try_from_params(&params).err()
+    );
+}
+
+#[test]
+fn value_out_of_range_message() {
+    let error = CRCu8::try_create_crc(0x25, 5, 0x1F, 0x1F, false).err().unwrap();
+
+    assert_eq!("`poly` does not fit in 5 bits", error.to_string());
+}
+
+#[test]
+#[should_panic]
+fn create_crc_wider_than_128_bits() {
+    CRC::create_crc(0x07, 129, 0, 0, false);
+}
+
+#[test]
+#[should_panic(expected = "the length of bits must be between 1 and 16")]
+fn table_wider_than_the_engine() {
+    CRCu16::table(&CRC_32_ISO_HDLC);
+}
+
+#[test]
+#[should_panic(expected = "the length of bits must be between 1 and 32")]
+fn checksum_with_zero_width() {
+    CRCu32::checksum(
+        &CrcParams {
+            width: 0,
+            ..CRC_32_ISO_HDLC
+        },
+        CHECK_INPUT,
+    );
+}
@@ -0,0 +1,65 @@
+use crc_any::{CRC, CRCu8, CRCu16, CRCu32, CRCu64, CRCu128};
+
+const CHECK_INPUT: &[u8] = b"123456789";
+
+#[test]
+fn fork_shares_prefix() {
+    let (head, tail) = CHECK_INPUT.split_at(4);
+
+    let mut crc = CRCu32::crc32c();
+    crc.update(head);
+
+    let mut forked = crc.fork();
+    forked.update(tail);
+
+    assert_eq!(0xE3069283, forked.get_crc());
+
+    crc.update(tail);
+
+    assert!(crc == forked);
+
+    let mut crc = CRCu8::crc8();
+    crc.update(head);
+
+    let mut forked = crc.fork();
+    forked.update(tail);
+    crc.update(tail);
+
+    assert_eq!(crc.get_crc(), forked.get_crc());
+
+    let mut crc = CRCu128::crc82darc();
+    crc.update(head);
+
+    let mut forked = crc.clone();
+    forked.update(tail);
+
+    assert_eq!(0x09EA83F625023801FD612, forked.get_crc());
+}
+
+#[test]
+fn same_algorithm() {
+    let a = CRCu16::crc16ccitt_false();
+    let b = CRCu16::create_crc(0x1021, 16, 0xFFFF, 0x0000, false);
+
+    assert!(a.is_same_algorithm(&b));
+    assert!(a == b);
+
+    let mut c = b.clone();
+    c.update(CHECK_INPUT);
+
+    assert!(a.is_same_algorithm(&c));
+    assert!(a != c);
+
+    assert!(!CRCu32::crc32().is_same_algorithm(&CRCu32::crc32jamcrc()));
+    assert!(!CRCu32::crc32().is_same_algorithm(&CRCu32::crc32mhash()));
+    assert!(!CRCu64::crc64iso().is_same_algorithm(&CRCu64::crc64xz()));
+
+    // bitwise instances
+    assert!(CRCu8::crc3gsm().is_same_algorithm(&CRCu8::create_crc(0x03, 3, 0x00, 0x07, false)));
+    assert!(!CRCu8::crc3gsm().is_same_algorithm(&CRCu8::crc3rohc()));
+
+    assert!(CRC::crc32().is_same_algorithm(&CRC::crc32()));
+    assert!(!CRC::crc16().is_same_algorithm(&CRC::crc32()));
+    assert!(CRC::crc8() != CRC::crc16());
+    assert!(CRC::crc64() == CRC::crc64().fork());
+}
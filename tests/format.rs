@@ -0,0 +1,138 @@
+use crc_any::{
+    CRC, CRCu8, CRCu16, CRCu32, CRCu64, CRCu128, CrcEngine, ParseCrcError, parse_crc, registry,
+};
+
+const CHECK_INPUT: &[u8] = b"123456789";
+
+#[test]
+fn formatting() {
+    let mut crc = CRC::crc16xmodem();
+
+    crc.update(CHECK_INPUT);
+
+    assert_eq!("0x31C3", format!("{crc}"));
+    assert_eq!("31c3", format!("{crc:x}"));
+    assert_eq!("31C3", format!("{crc:X}"));
+    assert_eq!("0x31c3", format!("{crc:#x}"));
+    assert_eq!("030703", format!("{crc:o}"));
+    assert_eq!("0011000111000011", format!("{crc:b}"));
+    assert_eq!("0b0011000111000011", format!("{crc:#b}"));
+    assert_eq!("000031c3", format!("{crc:08x}"));
+    assert_eq!("0x000031c3", format!("{crc:#010x}"));
+    assert_eq!("  31C3", format!("{crc:>6X}"));
+}
+
+#[test]
+fn zero_padding() {
+    let mut crc = CRCu8::crc3rohc();
+
+    crc.update(b"");
+
+    assert_eq!("7", format!("{crc:x}"));
+    assert_eq!("111", format!("{crc:b}"));
+
+    let crc = CRCu32::crc32();
+
+    assert_eq!("00000000", format!("{crc:x}"));
+
+    let mut crc = CRCu64::crc40gsm();
+
+    crc.update(CHECK_INPUT);
+
+    assert_eq!("d4164fc646", format!("{crc:x}"));
+
+    let mut crc = CRCu128::crc82darc();
+
+    crc.update(CHECK_INPUT);
+
+    assert_eq!("09ea83f625023801fd612", format!("{crc:x}"));
+
+    let mut crc = CRCu16::crc12cdma2000();
+
+    crc.update(b"");
+
+    assert_eq!("FFF", format!("{crc:X}"));
+}
+
+#[test]
+fn hex_arrays() {
+    for algorithm in registry::algorithms() {
+        let params = algorithm.params();
+
+        let mut crc = algorithm.create();
+
+        crc.update(CHECK_INPUT);
+
+        let hex = crc.to_hex_array();
+        let len = crc.hex_len();
+
+        assert_eq!(format!("{crc:X}").as_bytes(), &hex[..len], "{}", params.name);
+        assert!(hex[len..].iter().all(|&b| b == 0), "{}", params.name);
+
+        let hex = core::str::from_utf8(&hex[..len]).unwrap();
+
+        assert_eq!(Ok(params.check), parse_crc(hex, params.width), "{}", params.name);
+    }
+
+    let mut crc = CRCu16::crc16();
+
+    crc.update(CHECK_INPUT);
+
+    assert_eq!(*b"BB3D", crc.to_hex_array());
+    assert_eq!(4, crc.hex_len());
+
+    let mut crc = CRCu8::crc5usb();
+
+    crc.update(CHECK_INPUT);
+
+    assert_eq!(*b"19", crc.to_hex_array());
+    assert_eq!(2, crc.hex_len());
+}
+
+#[test]
+fn parsing() {
+    assert_eq!(Ok(0x31C3), parse_crc("0x31C3", 16));
+    assert_eq!(Ok(0x31C3), parse_crc("0X31c3", 16));
+    assert_eq!(Ok(0x31C3), parse_crc("31C3", 16));
+    assert_eq!(Ok(0x31C3), parse_crc("000031C3", 16));
+    assert_eq!(Ok(0x19), parse_crc("19", 5));
+    assert_eq!(Ok(u128::MAX), parse_crc("0xFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFF", 128));
+
+    assert_eq!(
+        Err(ParseCrcError::InvalidWidth {
+            width: 0
+        }),
+        parse_crc("0", 0)
+    );
+    assert_eq!(
+        Err(ParseCrcError::InvalidWidth {
+            width: 129
+        }),
+        parse_crc("0", 129)
+    );
+    assert_eq!(Err(ParseCrcError::Empty), parse_crc("", 16));
+    assert_eq!(Err(ParseCrcError::Empty), parse_crc("0x", 16));
+    assert_eq!(Err(ParseCrcError::InvalidDigit), parse_crc("+31C3", 16));
+    assert_eq!(Err(ParseCrcError::InvalidDigit), parse_crc("31G3", 16));
+    assert_eq!(Err(ParseCrcError::InvalidDigit), parse_crc(" 31C3", 16));
+    assert_eq!(
+        Err(ParseCrcError::ValueOutOfRange {
+            width: 5
+        }),
+        parse_crc("20", 5)
+    );
+    assert_eq!(
+        Err(ParseCrcError::ValueOutOfRange {
+            width: 128
+        }),
+        parse_crc("1FFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFF", 128)
+    );
+}
+
+#[test]
+fn parse_error_message() {
+    assert_eq!(
+        "the CRC value does not fit in 8 bits",
+        parse_crc("100", 8).unwrap_err().to_string()
+    );
+}
@@ -1,6 +1,6 @@
 #![cfg(feature = "heapless")]
 
-use crc_any::{CRC, CRCu16, CRCu32, CRCu64};
+use crc_any::{CRC, CRCu16, CRCu32, CRCu64, CRCu128};
 use heapless::Vec as HeaplessVec;
 
 #[test]
@@ -78,6 +78,46 @@ fn crc_u64() {
     assert_eq!(vec![152, 195, 5, 94, 90], crc.get_crc_heapless_vec_le().to_vec());
 }
 
+#[test]
+fn crc_u128() {
+    let mut crc = CRCu128::crc82darc();
+
+    crc.digest(b"https://magiclen.org");
+
+    assert_eq!(
+        vec![2, 18, 64, 92, 181, 229, 130, 145, 35, 235, 102],
+        crc.get_crc_heapless_vec_be().to_vec()
+    );
+    assert_eq!(
+        vec![102, 235, 35, 145, 130, 229, 181, 92, 64, 18, 2],
+        crc.get_crc_heapless_vec_le().to_vec()
+    );
+
+    let mut crc = CRC::create_crc_u128(0x87, 128, 0, 0, false);
+
+    crc.digest(b"https://magiclen.org");
+
+    assert_eq!(
+        vec![196, 177, 90, 122, 178, 149, 128, 175, 158, 55, 151, 7, 175, 75, 146, 173],
+        crc.get_crc_u128_heapless_vec_be().to_vec()
+    );
+    assert_eq!(
+        vec![173, 146, 75, 175, 7, 151, 55, 158, 175, 128, 149, 178, 122, 90, 177, 196],
+        crc.get_crc_u128_heapless_vec_le().to_vec()
+    );
+
+    // the 8-byte getters only return the low 64 bits, like `get_crc`
+    assert_eq!(vec![158, 55, 151, 7, 175, 75, 146, 173], crc.get_crc_heapless_vec_be().to_vec());
+    assert_eq!(vec![173, 146, 75, 175, 7, 151, 55, 158], crc.get_crc_heapless_vec_le().to_vec());
+
+    let mut crc = CRC::crc16();
+
+    crc.digest(b"https://magiclen.org");
+
+    assert_eq!(vec![77, 150], crc.get_crc_u128_heapless_vec_be().to_vec());
+    assert_eq!(vec![150, 77], crc.get_crc_u128_heapless_vec_le().to_vec());
+}
+
 #[test]
 fn heapless_getters_use_u8_len_type() {
     let mut crc = CRC::crc64();
@@ -0,0 +1,84 @@
+#![cfg(feature = "alloc")]
+
+use crc_any::{CRC, MultiCrc, params::*};
+
+const CHECK_INPUT: &[u8] = b"123456789";
+
+#[test]
+fn multi_crc() {
+    let mut multi =
+        MultiCrc::from_params(&[&CRC_32_ISO_HDLC, &CRC_32_ISCSI, &CRC_16_XMODEM, &CRC_82_DARC]);
+
+    assert_eq!(4, multi.len());
+
+    multi.update(&CHECK_INPUT[..4]);
+    multi.update(&CHECK_INPUT[4..]);
+
+    assert_eq!(
+        vec![
+            ("CRC-32/ISO-HDLC", CRC_32_ISO_HDLC.check),
+            ("CRC-32/ISCSI", CRC_32_ISCSI.check),
+            ("CRC-16/XMODEM", CRC_16_XMODEM.check),
+            ("CRC-82/DARC", CRC_82_DARC.check),
+        ],
+        multi.results()
+    );
+
+    assert_eq!(CRC_16_XMODEM.check, multi.get("CRC-16/XMODEM").unwrap().get_crc_u128());
+    assert!(multi.get("CRC-16/MODBUS").is_none());
+
+    multi.reset();
+    multi.update(CHECK_INPUT);
+
+    assert_eq!(CRC_32_ISCSI.check, multi.results()[1].1);
+}
+
+#[test]
+fn multi_crc_large_data() {
+    let data: Vec<u8> = (0..100_000u32).map(|n| (n * 7 + n / 251) as u8).collect();
+
+    let mut multi = MultiCrc::new();
+
+    assert!(multi.is_empty());
+
+    multi.push("crc32", CRC::crc32());
+    multi.push("crc64", CRC::crc64());
+
+    multi.update(&data[..10]);
+
+    let mut started = CRC::crc16modbus();
+    started.update(&data[..10]);
+
+    // the state of a pushed instance is kept
+    multi.push("crc16modbus", started);
+
+    multi.update(&data[10..]);
+
+    for ((name, value), mut crc) in
+        multi.results().into_iter().zip([CRC::crc32(), CRC::crc64(), CRC::crc16modbus()])
+    {
+        crc.update(&data);
+
+        assert_eq!(crc.get_crc_u128(), value, "{name}");
+    }
+}
+
+#[test]
+fn multi_crc_runtime_names() {
+    // such as names read from a configuration file
+    let names = String::from("crc-16/modbus, X-25");
+
+    let mut multi = MultiCrc::new();
+
+    for name in names.split(", ") {
+        multi.push(name.to_string(), CRC::from_name(name).unwrap());
+    }
+
+    multi.update(CHECK_INPUT);
+
+    assert_eq!(
+        vec![("crc-16/modbus", CRC_16_MODBUS.check), ("X-25", CRC_16_IBM_SDLC.check)],
+        multi.results()
+    );
+    assert_eq!(CRC_16_IBM_SDLC.check, multi.get("X-25").unwrap().get_crc_u128());
+}
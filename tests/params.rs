@@ -0,0 +1,357 @@
+use crc_any::{CRC, CRCu8, CRCu16, CRCu32, CRCu64, CRCu128, CrcParams, params::*, registry};
+
+const CHECK_INPUT: &[u8] = b"123456789";
+
+fn builtins() -> Vec<(CrcParams, fn() -> CRC)> {
+    vec![
+        (CRC_3_GSM, CRC::crc3gsm),
+        (CRC_3_ROHC, CRC::crc3rohc),
+        (CRC_4_G_704, CRC::crc4itu),
+        (CRC_4_INTERLAKEN, CRC::crc4interlaken),
+        (CRC_5_EPC_C1G2, CRC::crc5epc),
+        (CRC_5_G_704, CRC::crc5itu),
+        (CRC_5_USB, CRC::crc5usb),
+        (CRC_6_CDMA2000_A, CRC::crc6cdma2000_a),
+        (CRC_6_CDMA2000_B, CRC::crc6cdma2000_b),
+        (CRC_6_DARC, CRC::crc6darc),
+        (CRC_6_G_704, CRC::crc6itu),
+        (CRC_6_GSM, CRC::crc6gsm),
+        (CRC_7_MMC, CRC::crc7),
+        (CRC_7_ROHC, CRC::crc7rohc),
+        (CRC_7_UMTS, CRC::crc7umts),
+        (CRC_8_AUTOSAR, CRC::crc8autosar),
+        (CRC_8_BLUETOOTH, CRC::crc8bluetooth),
+        (CRC_8_CDMA2000, CRC::crc8cdma2000),
+        (CRC_8_DARC, CRC::crc8darc),
+        (CRC_8_DVB_S2, CRC::crc8dvb_s2),
+        (CRC_8_GSM_A, CRC::crc8gsm_a),
+        (CRC_8_GSM_B, CRC::crc8gsm_b),
+        (CRC_8_HITAG, CRC::crc8hitag),
+        (CRC_8_I_432_1, CRC::crc8itu),
+        (CRC_8_I_CODE, CRC::crc8icode),
+        (CRC_8_LTE, CRC::crc8lte),
+        (CRC_8_MAXIM_DOW, CRC::crc8maxim),
+        (CRC_8_MIFARE_MAD, CRC::crc8mifare_mad),
+        (CRC_8_NRSC_5, CRC::crc8nrsc5),
+        (CRC_8_OPENSAFETY, CRC::crc8opensafety),
+        (CRC_8_ROHC, CRC::crc8rohc),
+        (CRC_8_SAE_J1850, CRC::crc8sae_j1850),
+        (CRC_8_SMBUS, CRC::crc8),
+        (CRC_8_TECH_3250, CRC::crc8ebu),
+        (CRC_8_WCDMA, CRC::crc8wcdma),
+        (CRC_10_ATM, CRC::crc10),
+        (CRC_10_CDMA2000, CRC::crc10cdma2000),
+        (CRC_10_GSM, CRC::crc10gsm),
+        (CRC_11_FLEXRAY, CRC::crc11),
+        (CRC_11_UMTS, CRC::crc11umts),
+        (CRC_12_CDMA2000, CRC::crc12cdma2000),
+        (CRC_12_DECT, CRC::crc12),
+        (CRC_12_GSM, CRC::crc12gsm),
+        (CRC_12_UMTS, CRC::crc12umts),
+        (CRC_13_BBC, CRC::crc13bbc),
+        (CRC_14_DARC, CRC::crc14darc),
+        (CRC_14_GSM, CRC::crc14gsm),
+        (CRC_15_CAN, CRC::crc15can),
+        (CRC_15_MPT1327, CRC::crc15mpt1327),
+        (CRC_16_ARC, CRC::crc16),
+        (CRC_16_CDMA2000, CRC::crc16cdma2000),
+        (CRC_16_CMS, CRC::crc16cms),
+        (CRC_16_DDS_110, CRC::crc16dds_110),
+        (CRC_16_DECT_R, CRC::crc16dect_r),
+        (CRC_16_DECT_X, CRC::crc16dect_x),
+        (CRC_16_DNP, CRC::crc16dnp),
+        (CRC_16_EN_13757, CRC::crc16en_13757),
+        (CRC_16_GENIBUS, CRC::crc16genibus),
+        (CRC_16_GSM, CRC::crc16gsm),
+        (CRC_16_IBM_3740, CRC::crc16ccitt_false),
+        (CRC_16_IBM_SDLC, CRC::crc16_x25),
+        (CRC_16_ISO_IEC_14443_3_A, CRC::crc_a),
+        (CRC_16_KERMIT, CRC::crc16kermit),
+        (CRC_16_LJ1200, CRC::crc16lj1200),
+        (CRC_16_M17, CRC::crc16m17),
+        (CRC_16_MAXIM_DOW, CRC::crc16maxim),
+        (CRC_16_MCRF4XX, CRC::crc16mcrf4cc),
+        (CRC_16_MODBUS, CRC::crc16modbus),
+        (CRC_16_NRSC_5, CRC::crc16nrsc5),
+        (CRC_16_OPENSAFETY_A, CRC::crc16opensafety_a),
+        (CRC_16_OPENSAFETY_B, CRC::crc16opensafety_b),
+        (CRC_16_PROFIBUS, CRC::crc16profibus),
+        (CRC_16_RIELLO, CRC::crc16riello),
+        (CRC_16_SPI_FUJITSU, CRC::crc16aug_ccitt),
+        (CRC_16_T10_DIF, CRC::crc16t10_dif),
+        (CRC_16_TELEDISK, CRC::crc16teledisk),
+        (CRC_16_TMS37157, CRC::crc16tms13157),
+        (CRC_16_UMTS, CRC::crc16buypass),
+        (CRC_16_USB, CRC::crc16usb),
+        (CRC_16_XMODEM, CRC::crc16xmodem),
+        (CRC_17_CAN_FD, CRC::crc17can),
+        (CRC_21_CAN_FD, CRC::crc21can),
+        (CRC_24_BLE, CRC::crc24ble),
+        (CRC_24_FLEXRAY_A, CRC::crc24flexray_a),
+        (CRC_24_FLEXRAY_B, CRC::crc24flexray_b),
+        (CRC_24_INTERLAKEN, CRC::crc24interlaken),
+        (CRC_24_LTE_A, CRC::crc24lte_a),
+        (CRC_24_LTE_B, CRC::crc24lte_b),
+        (CRC_24_OPENPGP, CRC::crc24),
+        (CRC_24_OS_9, CRC::crc24os9),
+        (CRC_30_CDMA, CRC::crc30cdma),
+        (CRC_31_PHILIPS, CRC::crc31philips),
+        (CRC_32_AIXM, CRC::crc32q),
+        (CRC_32_AUTOSAR, CRC::crc32autosar),
+        (CRC_32_BASE91_D, CRC::crc32d),
+        (CRC_32_BZIP2, CRC::crc32bzip2),
+        (CRC_32_CD_ROM_EDC, CRC::crc32cdrom_edc),
+        (CRC_32_CKSUM, CRC::crc32posix),
+        (CRC_32_ISCSI, CRC::crc32c),
+        (CRC_32_ISO_HDLC, CRC::crc32),
+        (CRC_32_JAMCRC, CRC::crc32jamcrc),
+        (CRC_32_MEF, CRC::crc32mef),
+        (CRC_32_MPEG_2, CRC::crc32mpeg2),
+        (CRC_32_XFER, CRC::crc32xfer),
+        (CRC_40_GSM, CRC::crc40gsm),
+        (CRC_64_ECMA_182, CRC::crc64),
+        (CRC_64_GO_ISO, CRC::crc64iso),
+        (CRC_64_MS, CRC::crc64ms),
+        (CRC_64_NVME, CRC::crc64nvme),
+        (CRC_64_REDIS, CRC::crc64redis),
+        (CRC_64_WE, CRC::crc64we),
+        (CRC_64_XZ, CRC::crc64xz),
+        (CRC_82_DARC, CRC::crc82darc),
+    ]
+}
+
+#[test]
+fn from_params_matches_check() {
+    for (params, _) in builtins() {
+        let mut crc = CRC::from_params(&params);
+        crc.update(CHECK_INPUT);
+
+        assert_eq!(params.check, crc.get_crc_u128(), "{}", params.name);
+    }
+}
+
+#[test]
+fn builtin_matches_params() {
+    for (params, builtin) in builtins() {
+        let mut crc = builtin();
+        crc.update(CHECK_INPUT);
+
+        assert_eq!(params.check, crc.get_crc_u128(), "{}", params.name);
+    }
+}
+
+#[test]
+fn params_are_comparable() {
+    assert_eq!(CRC_16_MODBUS, CRC_16_MODBUS);
+    assert_ne!(CRC_16_MODBUS, CRC_16_USB);
+}
+
+#[test]
+fn from_name() {
+    for name in ["CRC-16/MODBUS", "CRC-32/ISO-HDLC", "CRC-32/ISCSI", "X-25", "KERMIT", "crc64jones"]
+    {
+        assert!(CRC::from_name(name).is_some(), "{name}");
+    }
+
+    let mut crc = CRC::from_name("crc-32/iso hdlc").unwrap();
+    crc.update(CHECK_INPUT);
+
+    assert_eq!(CRC_32_ISO_HDLC.check, crc.get_crc_u128());
+
+    let mut crc = CRC::from_name("crc16_x25").unwrap();
+    crc.update(CHECK_INPUT);
+
+    assert_eq!(CRC_16_IBM_SDLC.check, crc.get_crc_u128());
+
+    assert!(CRC::from_name("CRC-16/UNKNOWN").is_none());
+    assert!(CRC::from_name("").is_none());
+}
+
+#[test]
+fn registry_covers_builtins() {
+    for (params, builtin) in builtins() {
+        let algorithm = registry::find(params.name).unwrap();
+
+        assert_eq!(&params, algorithm.params());
+
+        let mut crc = algorithm.create();
+        crc.update(CHECK_INPUT);
+
+        let mut expected = builtin();
+        expected.update(CHECK_INPUT);
+
+        assert_eq!(expected.get_crc_u128(), crc.get_crc_u128(), "{}", params.name);
+    }
+
+    assert_eq!(builtins().len(), registry::algorithms().len());
+}
+
+#[test]
+fn registry_names_are_unique() {
+    for (i, a) in registry::algorithms().enumerate() {
+        for b in registry::algorithms().skip(i + 1) {
+            assert!(!a.is_named(b.name()), "{} {}", a.name(), b.name());
+
+            for alias in b.aliases() {
+                assert!(!a.is_named(alias), "{} {}", a.name(), alias);
+            }
+
+            for function in b.function_names() {
+                assert!(!a.is_named(function), "{} {}", a.name(), function);
+            }
+        }
+    }
+}
+
+#[test]
+fn registry_function_names() {
+    for algorithm in registry::algorithms() {
+        for function in algorithm.function_names() {
+            assert_eq!(algorithm.name(), registry::find(function).unwrap().name());
+        }
+
+        // aliases only hold names of the catalogue
+        for alias in algorithm.aliases() {
+            assert!(!alias.bytes().any(|b| b.is_ascii_lowercase()), "{alias}");
+        }
+    }
+
+    assert_eq!("CRC-64/GO-ISO", registry::find("crc64iso").unwrap().name());
+    assert_eq!(
+        &["crc64redis", "crc64jones"],
+        registry::find("crc64jones").unwrap().function_names()
+    );
+}
+
+#[test]
+fn const_checksum_matches_check() {
+    const CHECK: u32 = CRCu32::checksum(&CRC_32_ISO_HDLC, CHECK_INPUT);
+
+    assert_eq!(0xCBF43926, CHECK);
+
+    for (params, _) in builtins() {
+        let check = match params.width {
+            0..=8 => u128::from(CRCu8::checksum(&params, CHECK_INPUT)),
+            9..=16 => u128::from(CRCu16::checksum(&params, CHECK_INPUT)),
+            17..=32 => u128::from(CRCu32::checksum(&params, CHECK_INPUT)),
+            33..=64 => u128::from(CRCu64::checksum(&params, CHECK_INPUT)),
+            _ => CRCu128::checksum(&params, CHECK_INPUT),
+        };
+
+        assert_eq!(params.check, check, "{}", params.name);
+    }
+}
+
+#[test]
+fn from_params_with_const_table() {
+    static TABLE_8: [u8; 256] = CRCu8::table(&CRC_8_SMBUS);
+    static TABLE_16: [u16; 256] = CRCu16::table(&CRC_16_KERMIT);
+    static TABLE_24: [u32; 256] = CRCu32::table(&CRC_24_OPENPGP);
+    static TABLE_32: [u32; 256] = CRCu32::table(&CRC_32_ISCSI);
+    static TABLE_64: [u64; 256] = CRCu64::table(&CRC_64_XZ);
+
+    let mut crc = CRCu8::from_params_with_table(&CRC_8_SMBUS, &TABLE_8);
+    crc.update(CHECK_INPUT);
+    assert_eq!(CRC_8_SMBUS.check, u128::from(crc.get_crc()));
+
+    let mut crc = CRCu16::from_params_with_table(&CRC_16_KERMIT, &TABLE_16);
+    crc.update(CHECK_INPUT);
+    assert_eq!(CRC_16_KERMIT.check, u128::from(crc.get_crc()));
+
+    let mut crc = CRCu32::from_params_with_table(&CRC_24_OPENPGP, &TABLE_24);
+    crc.update(CHECK_INPUT);
+    assert_eq!(CRC_24_OPENPGP.check, u128::from(crc.get_crc()));
+
+    let mut crc = CRCu32::from_params_with_table(&CRC_32_ISCSI, &TABLE_32);
+    crc.update(CHECK_INPUT);
+    assert_eq!(CRC_32_ISCSI.check, u128::from(crc.get_crc()));
+
+    let mut crc = CRCu64::from_params_with_table(&CRC_64_XZ, &TABLE_64);
+    crc.update(CHECK_INPUT);
+    assert_eq!(CRC_64_XZ.check, u128::from(crc.get_crc()));
+}
+
+#[test]
+fn state_round_trip() {
+    let (head, tail) = CHECK_INPUT.split_at(4);
+
+    for (params, builtin) in builtins() {
+        let mut crc = builtin();
+        crc.update(head);
+
+        let state = crc.state();
+
+        let mut other = CRC::from_params(&params);
+        other.update(head);
+
+        assert_eq!(state, other.state(), "{}", params.name);
+
+        let mut restored = CRC::from_state(&params, state);
+        restored.update(tail);
+
+        let mut fresh = builtin();
+        fresh.set_state(state);
+        fresh.update(tail);
+
+        assert_eq!(params.check, restored.get_crc_u128(), "{}", params.name);
+        assert_eq!(params.check, fresh.get_crc_u128(), "{}", params.name);
+    }
+}
+
+#[test]
+fn state_round_trip_reordered() {
+    let mut crc = CRCu32::crc32mhash();
+    crc.update(&CHECK_INPUT[..4]);
+
+    let mut restored = CRCu32::crc32mhash();
+    restored.set_state(crc.state());
+
+    crc.update(&CHECK_INPUT[4..]);
+    restored.update(&CHECK_INPUT[4..]);
+
+    assert_eq!(0x181989FC, restored.get_crc());
+    assert_eq!(crc.get_crc(), restored.get_crc());
+}
+
+#[test]
+fn resume_from_finalized_crc() {
+    let (head, tail) = CHECK_INPUT.split_at(4);
+
+    for (params, builtin) in builtins() {
+        let mut crc = builtin();
+        crc.update(head);
+
+        let previous = crc.get_crc_u128();
+
+        let mut resumed = builtin();
+        resumed.resume_from(previous);
+        resumed.update(tail);
+
+        assert_eq!(params.check, resumed.get_crc_u128(), "{}", params.name);
+
+        let mut resumed = CRC::resume(&params, previous);
+        resumed.update(tail);
+
+        assert_eq!(params.check, resumed.get_crc_u128(), "{}", params.name);
+    }
+}
+
+#[test]
+fn resume_from_finalized_crc_reordered() {
+    let mut crc = CRCu32::crc32mhash();
+    crc.update(&CHECK_INPUT[..4]);
+
+    let mut resumed = CRCu32::crc32mhash();
+    resumed.resume_from(crc.get_crc());
+    resumed.update(&CHECK_INPUT[4..]);
+
+    assert_eq!(0x181989FC, resumed.get_crc());
+}
+
+#[test]
+fn crc32_resume() {
+    let mut crc = CRCu32::crc32_resume(0);
+    crc.update(CHECK_INPUT);
+
+    assert_eq!(0xCBF43926, crc.get_crc());
+}
@@ -0,0 +1,109 @@
+use crc_any::preset::*;
+
+const CHECK_INPUT: &[u8] = b"123456789";
+
+fn check<P: Preset>() {
+    assert_eq!(P::PARAMS.check, Digest::<P>::checksum(CHECK_INPUT).into(), "{}", P::PARAMS.name);
+
+    let mut digest = Digest::<P>::default();
+    digest.update(b"https://magiclen.org");
+    digest.reset();
+    digest.update(&CHECK_INPUT[..4]);
+
+    let mut copied = digest;
+    copied.update(&CHECK_INPUT[4..]);
+
+    assert_eq!(P::PARAMS.check, copied.get_crc().into(), "{}", P::PARAMS.name);
+}
+
+#[test]
+fn presets_match_check() {
+    check::<Crc8Autosar>();
+    check::<Crc8Bluetooth>();
+    check::<Crc8Cdma2000>();
+    check::<Crc8Darc>();
+    check::<Crc8DvbS2>();
+    check::<Crc8GsmA>();
+    check::<Crc8GsmB>();
+    check::<Crc8Hitag>();
+    check::<Crc8I4321>();
+    check::<Crc8ICode>();
+    check::<Crc8Lte>();
+    check::<Crc8MaximDow>();
+    check::<Crc8MifareMad>();
+    check::<Crc8Nrsc5>();
+    check::<Crc8Opensafety>();
+    check::<Crc8Rohc>();
+    check::<Crc8SaeJ1850>();
+    check::<Crc8Smbus>();
+    check::<Crc8Tech3250>();
+    check::<Crc8Wcdma>();
+    check::<Crc16Arc>();
+    check::<Crc16Cdma2000>();
+    check::<Crc16Cms>();
+    check::<Crc16Dds110>();
+    check::<Crc16DectR>();
+    check::<Crc16DectX>();
+    check::<Crc16Dnp>();
+    check::<Crc16En13757>();
+    check::<Crc16Genibus>();
+    check::<Crc16Gsm>();
+    check::<Crc16Ibm3740>();
+    check::<Crc16IbmSdlc>();
+    check::<Crc16IsoIec144433A>();
+    check::<Crc16Kermit>();
+    check::<Crc16Lj1200>();
+    check::<Crc16M17>();
+    check::<Crc16MaximDow>();
+    check::<Crc16Mcrf4xx>();
+    check::<Crc16Modbus>();
+    check::<Crc16Nrsc5>();
+    check::<Crc16OpensafetyA>();
+    check::<Crc16OpensafetyB>();
+    check::<Crc16Profibus>();
+    check::<Crc16Riello>();
+    check::<Crc16SpiFujitsu>();
+    check::<Crc16T10Dif>();
+    check::<Crc16Teledisk>();
+    check::<Crc16Tms37157>();
+    check::<Crc16Umts>();
+    check::<Crc16Usb>();
+    check::<Crc16Xmodem>();
+    check::<Crc24Ble>();
+    check::<Crc24FlexrayA>();
+    check::<Crc24FlexrayB>();
+    check::<Crc24Interlaken>();
+    check::<Crc24LteA>();
+    check::<Crc24LteB>();
+    check::<Crc24Openpgp>();
+    check::<Crc24Os9>();
+    check::<Crc32Aixm>();
+    check::<Crc32Autosar>();
+    check::<Crc32Base91D>();
+    check::<Crc32Bzip2>();
+    check::<Crc32CdRomEdc>();
+    check::<Crc32Cksum>();
+    check::<Crc32Iscsi>();
+    check::<Crc32IsoHdlc>();
+    check::<Crc32Jamcrc>();
+    check::<Crc32Mef>();
+    check::<Crc32Mpeg2>();
+    check::<Crc32Xfer>();
+    check::<Crc40Gsm>();
+    check::<Crc64Ecma182>();
+    check::<Crc64GoIso>();
+    check::<Crc64Ms>();
+    check::<Crc64Nvme>();
+    check::<Crc64Redis>();
+    check::<Crc64We>();
+    check::<Crc64Xz>();
+}
+
+#[test]
+fn digest_only_holds_the_register() {
+    assert_eq!(0, size_of::<Crc32IsoHdlc>());
+    assert_eq!(1, size_of::<Digest<Crc8Smbus>>());
+    assert_eq!(2, size_of::<Digest<Crc16Modbus>>());
+    assert_eq!(4, size_of::<Digest<Crc24Openpgp>>());
+    assert_eq!(8, size_of::<Digest<Crc64Xz>>());
+}
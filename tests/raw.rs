@@ -0,0 +1,56 @@
+use crc_any::{CRCu16, CRCu32, CRCu64};
+
+const CHECK_INPUT: &[u8] = b"123456789";
+
+#[test]
+fn raw_chaining() {
+    let (head, tail) = CHECK_INPUT.split_at(4);
+
+    let raw32: [fn(u32, &[u8]) -> u32; 3] =
+        [CRCu32::crc32c_raw, CRCu32::crc32_le_raw, CRCu32::crc32_be_raw];
+
+    for f in raw32 {
+        assert_eq!(f(0x12345678, CHECK_INPUT), f(f(0x12345678, head), tail));
+    }
+
+    let raw16: [fn(u16, &[u8]) -> u16; 4] =
+        [CRCu16::crc16_raw, CRCu16::crc_ccitt_raw, CRCu16::crc_itu_t_raw, CRCu16::crc_t10dif_raw];
+
+    for f in raw16 {
+        assert_eq!(f(0x1234, CHECK_INPUT), f(f(0x1234, head), tail));
+    }
+
+    assert_eq!(
+        CRCu64::crc64_be_raw(0x0123456789ABCDEF, CHECK_INPUT),
+        CRCu64::crc64_be_raw(CRCu64::crc64_be_raw(0x0123456789ABCDEF, head), tail)
+    );
+}
+
+#[test]
+fn raw_matches_presets() {
+    let mut crc = CRCu32::crc32c();
+    crc.update(CHECK_INPUT);
+
+    assert_eq!(crc.get_crc(), !CRCu32::crc32c_raw(!0, CHECK_INPUT));
+
+    let mut crc = CRCu32::crc32();
+    crc.update(CHECK_INPUT);
+
+    assert_eq!(crc.get_crc(), !CRCu32::crc32_le_raw(!0, CHECK_INPUT));
+
+    let mut crc = CRCu32::crc32bzip2();
+    crc.update(CHECK_INPUT);
+
+    assert_eq!(crc.get_crc(), !CRCu32::crc32_be_raw(!0, CHECK_INPUT));
+
+    let mut crc = CRCu16::crc16modbus();
+    crc.update(CHECK_INPUT);
+
+    assert_eq!(crc.get_crc(), CRCu16::crc16_raw(!0, CHECK_INPUT));
+}
+
+#[test]
+fn raw_empty_data() {
+    assert_eq!(0xDEADBEEF, CRCu32::crc32c_raw(0xDEADBEEF, &[]));
+    assert_eq!(0xBEEF, CRCu16::crc_itu_t_raw(0xBEEF, &[]));
+}
@@ -0,0 +1,64 @@
+use crc_any::{CRC, CRCu8, CRCu16, CRCu32, CRCu64};
+
+const CHECK_INPUT: &[u8] = b"123456789";
+
+#[test]
+fn crc12_umts() {
+    // CRC-12/UMTS: refin = false, refout = true
+    let mut crc = CRC::create_crc_with_reflection(0x080F, 12, 0x0000, 0x0000, false, true);
+    crc.update(CHECK_INPUT);
+
+    assert_eq!(0xDAF, crc.get_crc());
+}
+
+#[test]
+fn reflected_input_only() {
+    let mut crc = CRCu16::create_crc_with_reflection(0xA001, 16, 0x0000, 0x0000, true, false);
+    crc.update(CHECK_INPUT);
+
+    assert_eq!(0xBCDD, crc.get_crc());
+
+    let mut crc =
+        CRCu32::create_crc_with_reflection(0xEDB88320, 32, 0xFFFFFFFF, 0xFFFFFFFF, true, false);
+    crc.update(CHECK_INPUT);
+
+    assert_eq!(0x649C2FD3, crc.get_crc());
+}
+
+#[test]
+fn reflected_output_only() {
+    let mut crc = CRCu64::create_crc_with_reflection(
+        0x42F0E1EBA9EA3693,
+        64,
+        0x0000000000000000,
+        0x0000000000000000,
+        false,
+        true,
+    );
+    crc.update(CHECK_INPUT);
+
+    assert_eq!(0xE2CE92D0FAFB0236, crc.get_crc());
+}
+
+// Before 4.0.0, the initial value of a reflected CRC whose length of bits is not a multiple of 8 was not reflected into the register, which gave wrong CRC values when the initial value is not bit-symmetric.
+#[test]
+fn reflected_bitwise_with_asymmetric_initial_value() {
+    let mut crc = CRCu8::create_crc(0x14, 5, 0x01, 0x00, true);
+    crc.update(CHECK_INPUT);
+
+    // replaces 0x02
+    assert_eq!(0x04, crc.get_crc());
+
+    let mut crc = CRCu16::create_crc(0x15E7, 13, 0x0123, 0x0000, true);
+    crc.update(CHECK_INPUT);
+
+    // replaces 0x07C6
+    assert_eq!(0x0A91, crc.get_crc());
+
+    let mut crc = CRCu16::create_crc(0x15E7, 13, 0x0123, 0x0000, true);
+    crc.update(CHECK_INPUT);
+    crc.reset();
+    crc.update(CHECK_INPUT);
+
+    assert_eq!(0x0A91, crc.get_crc());
+}
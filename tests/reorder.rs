@@ -0,0 +1,68 @@
+use crc_any::{CRC, CRCu16, CRCu32, CRCu64, CRCu128};
+
+const CHECK_INPUT: &[u8] = b"123456789";
+
+#[test]
+fn reorder_custom() {
+    let mut crc =
+        CRCu32::create_crc(0x04C11DB7, 32, 0xFFFFFFFF, 0xFFFFFFFF, false).with_reorder(true);
+    crc.update(CHECK_INPUT);
+
+    let mut mhash = CRCu32::crc32mhash();
+    mhash.update(CHECK_INPUT);
+
+    assert_eq!(mhash.get_crc(), crc.get_crc());
+    assert!(mhash == crc);
+
+    let mut crc = CRCu16::crc16modbus().with_reorder(true);
+    crc.update(CHECK_INPUT);
+
+    assert_eq!(0x374B, crc.get_crc());
+
+    let mut crc = CRCu64::crc40gsm().with_reorder(true);
+    crc.update(CHECK_INPUT);
+
+    assert_eq!(0x46C64F16D4, crc.get_crc());
+
+    let mut crc = CRCu128::crc82darc().with_reorder(true);
+    crc.update(CHECK_INPUT);
+
+    assert_eq!(0x09EA83F625023801FD612u128.swap_bytes() >> 40, crc.get_crc());
+
+    let mut crc = CRC::crc24().with_reorder(true);
+    crc.update(CHECK_INPUT);
+
+    assert_eq!(0x02CF21, crc.get_crc());
+}
+
+#[test]
+fn reorder_state_and_resume() {
+    let (head, tail) = CHECK_INPUT.split_at(4);
+
+    let mut a = CRCu16::crc16modbus();
+    a.update(head);
+
+    let mut b = CRCu16::crc16modbus().with_reorder(true);
+    b.update(head);
+
+    assert_eq!(a.state(), b.state());
+    assert_eq!(a.get_crc().swap_bytes(), b.get_crc());
+    assert!(!a.is_same_algorithm(&b));
+
+    let mut resumed = CRCu16::crc16modbus().with_reorder(true);
+    resumed.resume_from(b.get_crc());
+    resumed.update(tail);
+
+    assert_eq!(0x374B, resumed.get_crc());
+}
+
+#[cfg(feature = "alloc")]
+#[test]
+fn reorder_bytes() {
+    let mut crc = CRCu32::crc24().with_reorder(true);
+    crc.update(CHECK_INPUT);
+
+    assert_eq!(vec![0x02, 0xCF, 0x21], crc.get_crc_vec_be());
+    assert_eq!(vec![0x21, 0xCF, 0x02], crc.get_crc_vec_le());
+    assert_eq!("0x02CF21", crc.to_string());
+}
@@ -1,4 +1,4 @@
-use crc_any::{CRC, CRCu16, CRCu32, CRCu64};
+use crc_any::{CRC, CRCu16, CRCu32, CRCu64, CRCu128};
 
 const CHECK_INPUT: &[u8] = b"123456789";
 
@@ -66,6 +66,23 @@ fn reset_matches_fresh_for_reflected_crc64() {
     assert_eq!(expected, reused.get_crc());
 }
 
+#[test]
+fn reset_matches_fresh_for_reflected_crc128() {
+    let create = || CRCu128::create_crc(0xE1 << 120, 128, u128::MAX, u128::MAX, true);
+
+    let mut fresh = create();
+    fresh.digest(CHECK_INPUT);
+    let expected = fresh.get_crc();
+
+    let mut reused = create();
+    reused.digest(CHECK_INPUT);
+    reused.reset();
+    reused.digest(CHECK_INPUT);
+
+    assert_eq!(0x6A67AEF13176B1FE3E1C000000000000, expected);
+    assert_eq!(expected, reused.get_crc());
+}
+
 #[test]
 fn enum_reset_matches_fresh_for_reflected_crc() {
     let mut fresh = CRC::crc16riello();
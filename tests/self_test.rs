@@ -0,0 +1,34 @@
+use crc_any::{registry, self_test};
+
+#[test]
+fn self_test_passes() {
+    assert_eq!(Ok(()), self_test());
+}
+
+#[test]
+fn residues_of_algorithms() {
+    for algorithm in registry::algorithms() {
+        assert_eq!(
+            algorithm.params().residue,
+            algorithm.create().residue(),
+            "{}",
+            algorithm.name()
+        );
+    }
+}
+
+#[cfg(feature = "alloc")]
+#[test]
+fn self_test_failure_message() {
+    use crc_any::{Backend, SelfTestCheck, SelfTestFailure};
+
+    assert_eq!(
+        "the residue of CRC-16/ARC is wrong with the Table backend",
+        SelfTestFailure {
+            algorithm: "CRC-16/ARC",
+            backend:   Backend::Table,
+            check:     SelfTestCheck::Residue,
+        }
+        .to_string()
+    );
+}
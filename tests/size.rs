@@ -0,0 +1,13 @@
+use core::mem::size_of;
+
+use crc_any::{CRC, CRCu64, CRCu128};
+
+#[test]
+fn crc_stays_small() {
+    // a `CRCu128` instance never holds a `[u128; 256]` table
+    assert!(size_of::<CRCu128>() < 256, "{}", size_of::<CRCu128>());
+
+    // the largest variant holds a `[u64; 256]` table
+    assert!(size_of::<CRC>() <= size_of::<CRCu64>() + 16, "{}", size_of::<CRC>());
+    assert!(size_of::<CRC>() < size_of::<[u128; 256]>(), "{}", size_of::<CRC>());
+}
@@ -0,0 +1,115 @@
+use crc_any::{CRC, CRCu8, CrcParams};
+
+const CHECK_INPUT: &[u8] = b"123456789";
+
+#[test]
+fn custom_tables_beyond_cache_capacity() {
+    for poly in 0..=255u16 {
+        for refin in [false, true] {
+            let params = CrcParams {
+                name: "custom",
+                width: 8,
+                poly: u128::from(poly),
+                init: 0xFF,
+                refin,
+                refout: refin,
+                xorout: 0x00,
+                check: 0,
+                residue: 0,
+            };
+
+            let expected = CRCu8::checksum(&params, CHECK_INPUT);
+
+            for _ in 0..2 {
+                let mut crc = CRC::from_params(&params);
+                crc.update(CHECK_INPUT);
+
+                assert_eq!(u64::from(expected), crc.get_crc(), "{poly:#04X} {refin}");
+            }
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+#[test]
+fn custom_tables_across_threads() {
+    // not the expression of a built-in algorithm, so the table comes from the cache
+    let params = CrcParams {
+        name:    "custom",
+        width:   32,
+        poly:    0x12345679,
+        init:    0xFFFFFFFF,
+        refin:   false,
+        refout:  false,
+        xorout:  0x00000000,
+        check:   0,
+        residue: 0,
+    };
+
+    let expected = crc_any::CRCu32::checksum(&params, CHECK_INPUT);
+
+    let threads: Vec<_> = (0..8)
+        .map(|_| {
+            std::thread::spawn(|| {
+                let mut crc =
+                    crc_any::CRCu32::create_crc(0x12345679, 32, 0xFFFFFFFF, 0x00000000, false);
+                crc.update(CHECK_INPUT);
+
+                crc.get_crc()
+            })
+        })
+        .collect();
+
+    for thread in threads {
+        assert_eq!(expected, thread.join().unwrap());
+    }
+}
+
+#[test]
+fn custom_parameters_of_presets() {
+    let pairs = [
+        (CRC::create_crc(0x04C11DB7, 32, 0xFFFFFFFF, 0xFFFFFFFF, false), CRC::crc32bzip2()),
+        (CRC::create_crc(0x82F63B78, 32, 0xFFFFFFFF, 0xFFFFFFFF, true), CRC::crc32c()),
+        (CRC::create_crc(0xA097, 16, 0x0000, 0x0000, false), CRC::crc16teledisk()),
+        (CRC::create_crc(0x8C, 8, 0x00, 0x00, true), CRC::crc8maxim()),
+        (
+            CRC::create_crc(0xD800000000000000, 64, 0xFFFFFFFFFFFFFFFF, 0xFFFFFFFFFFFFFFFF, true),
+            CRC::crc64iso(),
+        ),
+    ];
+
+    for (mut custom, mut preset) in pairs {
+        assert!(custom.is_same_algorithm(&preset));
+        assert_eq!(preset.backend(), custom.backend());
+
+        custom.update(CHECK_INPUT);
+        preset.update(CHECK_INPUT);
+
+        assert_eq!(preset.get_crc(), custom.get_crc());
+    }
+}
+
+#[test]
+fn custom_u128_tables_beyond_cache_capacity() {
+    for poly in 0..8u128 {
+        let params = CrcParams {
+            name:    "custom",
+            width:   128,
+            poly:    (poly << 64) | 0x87,
+            init:    u128::MAX,
+            refin:   false,
+            refout:  false,
+            xorout:  0,
+            check:   0,
+            residue: 0,
+        };
+
+        let expected = crc_any::CRCu128::checksum(&params, CHECK_INPUT);
+
+        // without a free slot in the cache, the bitwise loop is used instead of a table of its own
+        let mut crc = CRC::from_params(&params);
+        crc.update(CHECK_INPUT);
+
+        assert_eq!(expected, crc.get_crc_u128(), "{poly}");
+    }
+}
@@ -0,0 +1,94 @@
+use crc_any::{CRC, CRCu8, CRCu16, CRCu32, CRCu64, CRCu128, CrcParams, Endian, registry};
+
+const CHECK_INPUT: &[u8] = b"123456789";
+
+fn residue(params: &CrcParams) -> u128 {
+    match params.width {
+        0..=8 => CRCu8::from_params(params).residue().into(),
+        9..=16 => CRCu16::from_params(params).residue().into(),
+        17..=32 => CRCu32::from_params(params).residue().into(),
+        33..=64 => CRCu64::from_params(params).residue().into(),
+        _ => CRCu128::from_params(params).residue(),
+    }
+}
+
+#[test]
+fn catalogue_residues() {
+    for algorithm in registry::algorithms() {
+        let params = algorithm.params();
+
+        assert_eq!(params.residue, residue(params), "{}", params.name);
+    }
+}
+
+#[test]
+fn append_and_check() {
+    for algorithm in registry::algorithms() {
+        let params = algorithm.params();
+
+        let endian = if params.refin { Endian::Little } else { Endian::Big };
+
+        let mut frame = [0u8; 32];
+        frame[..CHECK_INPUT.len()].copy_from_slice(CHECK_INPUT);
+
+        let mut crc = CRC::from_params(params);
+        crc.update(CHECK_INPUT);
+
+        let trailer = &mut frame[CHECK_INPUT.len()..];
+
+        let len = CHECK_INPUT.len()
+            + match endian {
+                Endian::Big => crc.write_crc_be(trailer),
+                Endian::Little => crc.write_crc_le(trailer),
+            };
+
+        let crc = CRC::from_params(params);
+
+        assert!(crc.verify_trailer(&frame[..len], endian), "{}", params.name);
+        assert!(crc.check_residue(&frame[..len]), "{}", params.name);
+
+        frame[0] ^= 0x01;
+
+        assert!(!crc.verify_trailer(&frame[..len], endian), "{}", params.name);
+        assert!(!crc.check_residue(&frame[..len]), "{}", params.name);
+    }
+}
+
+#[test]
+fn too_short() {
+    let crc = CRCu32::crc32();
+
+    assert!(!crc.verify_trailer(&[0x00; 3], Endian::Little));
+    assert!(!crc.check_residue(&[0x00; 3]));
+}
+
+#[cfg(feature = "alloc")]
+#[test]
+fn append_crc() {
+    let mut crc = CRCu32::crc32();
+    crc.update(CHECK_INPUT);
+
+    let mut frame = CHECK_INPUT.to_vec();
+    crc.append_crc(&mut frame, Endian::Little);
+
+    assert_eq!([0x26, 0x39, 0xF4, 0xCB], frame[CHECK_INPUT.len()..]);
+
+    let crc = CRCu32::crc32();
+
+    assert!(crc.verify_trailer(&frame, Endian::Little));
+    assert!(!crc.verify_trailer(&frame, Endian::Big));
+    assert!(crc.check_residue(&frame));
+
+    let mut frame = Vec::new();
+
+    let mut crc = CRCu16::crc16xmodem();
+    crc.update(CHECK_INPUT);
+    crc.append_crc(&mut frame, Endian::Big);
+
+    let mut crc = CRCu16::crc16xmodem();
+    crc.update(CHECK_INPUT);
+
+    // the current state is taken as the state before the trailer
+    assert!(crc.verify_trailer(&frame, Endian::Big));
+    assert!(crc.check_residue(&frame));
+}
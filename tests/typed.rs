@@ -0,0 +1,64 @@
+use crc_any::{CRC, CRCu8, CRCu16, CRCu32, CRCu64, CRCu128};
+
+#[test]
+fn typed_updates() {
+    let mut a = CRC::crc32();
+    a.update_u16_le(0x0102);
+    a.update_u16_be(0x0304);
+    a.update_u32_le(0x05060708);
+    a.update_u32_be(0x090A0B0C);
+    a.update_u64_le(0x0D0E0F1011121314);
+    a.update_u64_be(0x15161718191A1B1C);
+    a.update_f32_le(1.5);
+    a.update_f32_be(-2.25);
+    a.update_f64_le(core::f64::consts::PI);
+    a.update_f64_be(f64::MIN_POSITIVE);
+
+    let mut b = CRC::crc32();
+    b.update(&[0x02, 0x01, 0x03, 0x04]);
+    b.update(&[0x08, 0x07, 0x06, 0x05, 0x09, 0x0A, 0x0B, 0x0C]);
+    b.update(&[0x14, 0x13, 0x12, 0x11, 0x10, 0x0F, 0x0E, 0x0D]);
+    b.update(&[0x15, 0x16, 0x17, 0x18, 0x19, 0x1A, 0x1B, 0x1C]);
+    b.update(&1.5f32.to_le_bytes());
+    b.update(&(-2.25f32).to_be_bytes());
+    b.update(&core::f64::consts::PI.to_le_bytes());
+    b.update(&f64::MIN_POSITIVE.to_be_bytes());
+
+    assert_eq!(b.get_crc(), a.get_crc());
+}
+
+#[test]
+fn typed_updates_of_engines() {
+    let mut crc = CRCu8::crc8();
+    crc.update_u32_be(0x31323334);
+    crc.update_u32_le(0x38373635);
+    crc.update(b"9");
+
+    assert_eq!(0xF4, crc.get_crc());
+
+    let mut crc = CRCu16::crc16modbus();
+    crc.update_u64_be(0x3132333435363738);
+    crc.update(b"9");
+
+    assert_eq!(0x4B37, crc.get_crc());
+
+    let mut crc = CRCu32::crc32c();
+    crc.update_u64_le(0x3837363534333231);
+    crc.update(b"9");
+
+    assert_eq!(0xE3069283, crc.get_crc());
+
+    let mut crc = CRCu64::crc64xz();
+    crc.update_u16_be(0x3132);
+    crc.update_u16_le(0x3433);
+    crc.update_u32_be(0x35363738);
+    crc.update(b"9");
+
+    assert_eq!(0x995DC9BBDF1939FA, crc.get_crc());
+
+    let mut crc = CRCu128::crc82darc();
+    crc.update_u64_be(0x3132333435363738);
+    crc.update(b"9");
+
+    assert_eq!(0x09EA83F625023801FD612, crc.get_crc());
+}